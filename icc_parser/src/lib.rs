@@ -1,3 +1,4 @@
+#![cfg_attr(not(feature = "std"), no_std)]
 // Throughout this file when a comment mentions "the specification"
 // it's referring to this document:
 // http://www.color.org/specification/ICC1v43_2010-12.pdf
@@ -8,11 +9,17 @@ mod tests;
 mod tags;
 pub use tags::*;
 
+extern crate alloc;
+
+use alloc::borrow::ToOwned;
+use alloc::string::String;
+use alloc::vec::Vec;
 use core::convert::TryInto;
+use core::str;
 use kcolor_types::*;
-use std::str;
 
 #[derive(Debug)]
+#[allow(dead_code)]
 pub struct Locale {
     language: [u8; 2],
     country: [u8; 2],
@@ -37,13 +44,14 @@ impl Locale {
 pub struct ShortString([u8; 4]);
 
 impl ShortString {
+    /// Signatures that aren't valid UTF-8 return an empty string.
     pub fn into_str(&self) -> &str {
-        str::from_utf8(&self.0).unwrap()
+        str::from_utf8(&self.0).unwrap_or("")
     }
 }
 
-impl std::fmt::Debug for ShortString {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+impl core::fmt::Debug for ShortString {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.write_str(self.into_str())
     }
 }
@@ -55,13 +63,14 @@ pub struct ColorProfile {
 }
 
 #[derive(Debug)]
-
+#[allow(dead_code)]
 pub struct VersionNumber {
     major: u8,
     minor: u8,
 }
 
 #[derive(Debug)]
+#[allow(dead_code)]
 /// This needs documentation.
 pub struct Header {
     size: u32,
@@ -85,11 +94,14 @@ pub struct Header {
 #[derive(Debug)]
 pub enum ParseError {
     UnableToParse,
+    /// The profile is truncated, or an offset or length in it points past its end.
+    UnexpectedEnd,
     UnimplementedInICCParser,
     NoMoreTags,
 }
 
 #[derive(Debug)]
+#[allow(dead_code)]
 pub struct DateTime {
     year: u16,
     month: u16,
@@ -161,39 +173,27 @@ impl<'a> ICCParser<'a> {
     }
 
     fn read_u8(&mut self) -> Result<u8, ParseError> {
-        self.i += 1;
-        Ok(self.bytes[self.i - 1])
+        Ok(self.read_array::<1>()?[0])
     }
 
     fn read_u16(&mut self) -> Result<u16, ParseError> {
-        let result = Ok(u16::from_be_bytes(
-            (&self.bytes[self.i..self.i + 2])
-                .try_into()
-                .map_err(|_| ParseError::UnableToParse)?,
-        ));
-        self.i += 2;
-        result
+        Ok(u16::from_be_bytes(self.read_array()?))
     }
 
     fn read_u32(&mut self) -> Result<u32, ParseError> {
-        let result = Ok(u32::from_be_bytes(
-            (&self.bytes[self.i..self.i + 4])
-                .try_into()
-                .map_err(|_| ParseError::UnableToParse)?,
-        ));
-        self.i += 4;
-        result
+        Ok(u32::from_be_bytes(self.read_array()?))
     }
 
     fn read_i32(&mut self) -> Result<i32, ParseError> {
-        let result = Ok(i32::from_be_bytes(
-            (&self.bytes[self.i..self.i + 4])
-                .try_into()
-                .map_err(|_| ParseError::UnableToParse)?,
-        ));
-        self.i += 4;
-        result
+        Ok(i32::from_be_bytes(self.read_array()?))
+    }
+
+    fn read_array<const N: usize>(&mut self) -> Result<[u8; N], ParseError> {
+        self.read_bytes(N)?
+            .try_into()
+            .map_err(|_| ParseError::UnableToParse)
     }
+
     fn read_s15_fixed_16_number(&mut self) -> Result<f64, ParseError> {
         let u = self.read_i32()?;
         Ok(u as f64 / 65535.0)
@@ -215,38 +215,37 @@ impl<'a> ICCParser<'a> {
     }
 
     fn read_short_string(&mut self) -> Result<ShortString, ParseError> {
-        let mut short_string = [0; 4];
-        short_string.copy_from_slice(&self.bytes[self.i..self.i + 4]);
-        self.i += 4;
-        Ok(ShortString(short_string))
+        Ok(ShortString(self.read_array()?))
     }
 
     /// Length is in characters
     fn read_u16_string(&mut self, start: usize, length_bytes: usize) -> Result<String, ParseError> {
         let old_i = self.i;
         self.i = start;
-        let length_chars = length_bytes / 2;
-        let mut chars = Vec::with_capacity(length_chars);
-        for _ in 0..length_chars {
-            let c = self.read_u16()?;
-            chars.push(c);
-        }
+        // Read all the bytes first so a length past the end of the profile fails before allocating.
+        let bytes = self.read_bytes(length_bytes - length_bytes % 2)?;
+        let chars: Vec<u16> = bytes
+            .chunks_exact(2)
+            .map(|c| u16::from_be_bytes([c[0], c[1]]))
+            .collect();
 
         self.i = old_i;
-        Ok(String::from_utf16(&chars).map_err(|_| ParseError::UnableToParse)?)
+        String::from_utf16(&chars).map_err(|_| ParseError::UnableToParse)
     }
 
     fn read_utf8_string(&mut self, bytes: usize) -> Result<&'a str, ParseError> {
-        let result = Ok(str::from_utf8(&self.bytes[self.i..self.i + bytes])
-            .map_err(|_| ParseError::UnableToParse)?);
-        self.i += bytes;
-        result
+        str::from_utf8(self.read_bytes(bytes)?).map_err(|_| ParseError::UnableToParse)
     }
 
+    /// All reads go through this, which errors instead of reading past the end of the profile.
     fn read_bytes(&mut self, bytes: usize) -> Result<&'a [u8], ParseError> {
-        let result = Ok(&self.bytes[self.i..self.i + bytes]);
-        self.i += bytes;
-        result
+        let end = self.i.checked_add(bytes).ok_or(ParseError::UnexpectedEnd)?;
+        let result = self
+            .bytes
+            .get(self.i..end)
+            .ok_or(ParseError::UnexpectedEnd)?;
+        self.i = end;
+        Ok(result)
     }
 
     fn parse_date_time(&mut self) -> Result<DateTime, ParseError> {
@@ -313,7 +312,6 @@ impl<'a> ICCParser<'a> {
         // CMM stands for 'Color Management Module'
         // A list of CMM signatures is here: http://www.color.org/registry/signature/TagRegistry-2019-10.pdf
         let preferred_cmm_type = self.read_short_string()?;
-        println!("Preferred CMM Type: {}", preferred_cmm_type.into_str());
 
         // Read profile version number
         let version_number = VersionNumber {
@@ -336,14 +334,12 @@ impl<'a> ICCParser<'a> {
         };
 
         let color_space_type = self.parse_color_space_type()?;
-        println!("Color space type: {:?}", color_space_type);
 
         // Parse the PCS (Profile connection space)
         // For all profile classes, other than DeviceLink this will be either PCSXYZ or PCSLAB
         let connection_space = self.parse_color_space_type()?;
 
         let date_time = self.parse_date_time()?;
-        println!("Date Time: {:?}", date_time);
 
         // ‘acsp’ (61637370h)
         // This appears to just be a value that can be checked for validity.
@@ -403,7 +399,7 @@ impl<'a> ICCParser<'a> {
 
         Ok(Header {
             size,
-            preferred_cmm_type: preferred_cmm_type,
+            preferred_cmm_type,
             version_number,
             class,
             color_space_type,
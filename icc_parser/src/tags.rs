@@ -22,6 +22,7 @@ pub enum TagType {
     GreenToneReproductionCurve,
     BlueToneReproductionCurve,
     ChromaticAdaptationMatrix,
+    VideoCardGamma,
     Other(ShortString),
 }
 
@@ -31,10 +32,68 @@ pub enum TagData {
     MultiLocalizedStrings(Vec<(Locale, String)>),
     XYZ(XYZ),
     ParametricCurve(ParametricCurve),
+    Curve(Curve),
     Array9([f64; 9]), // This does not directly correspond to an item in the spec. It's just for convenience.
+    VideoCardGamma(VideoCardGamma),
     Unknown,
 }
 
+/// A curve that converts from nonlinear to linear values, sampled or as a gamma.
+/// See section 10.6 on page 53 of the specification.
+#[derive(Debug, Clone, PartialEq)]
+pub enum Curve {
+    /// Values are unchanged.
+    Identity,
+    /// `Y = X ^ gamma`
+    Gamma(f64),
+    /// At least 2 evenly spaced samples of the curve from 0.0 to 1.0, normalized to 0.0 to 1.0.
+    Table(Vec<f64>),
+}
+
+/// The calibration curves loaded into a display's video card (its "gamma ramps") while the
+/// profile is in use. This is Apple's private `vcgt` tag, which is not in the specification
+/// but is written by most display calibration software.
+///
+/// Each curve maps an output value from 0.0 to 1.0 to the value sent to the display.
+#[derive(Debug, Clone, PartialEq)]
+pub enum VideoCardGamma {
+    /// Evenly spaced samples of the red, green, and blue curves, normalized to 0.0 to 1.0.
+    Table([Vec<f64>; 3]),
+    /// A `min + (max - min) * x ^ gamma` curve for each of red, green, and blue.
+    Formula([VideoCardGammaFormula; 3]),
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct VideoCardGammaFormula {
+    pub gamma: f64,
+    pub min: f64,
+    pub max: f64,
+}
+
+impl VideoCardGamma {
+    /// Applies the curve for `channel` (0 for red, 1 for green, 2 for blue) to `x`.
+    /// `x` is clamped to 0.0 to 1.0.
+    pub fn apply(&self, channel: usize, x: f64) -> f64 {
+        let x = x.clamp(0.0, 1.0);
+        match self {
+            VideoCardGamma::Table(tables) => {
+                let table = &tables[channel];
+                if table.len() < 2 {
+                    return table.first().copied().unwrap_or(x);
+                }
+                let position = x * (table.len() - 1) as f64;
+                let index = (position as usize).min(table.len() - 2);
+                let fraction = position - index as f64;
+                table[index] * (1.0 - fraction) + table[index + 1] * fraction
+            }
+            VideoCardGamma::Formula(formulas) => {
+                let VideoCardGammaFormula { gamma, min, max } = formulas[channel];
+                min + (max - min) * powf(x, gamma)
+            }
+        }
+    }
+}
+
 impl<'a> ICCParser<'a> {
     pub fn next_tag(&mut self) -> Result<Tag, ParseError> {
         if self.current_tag < self.tag_count {
@@ -53,6 +112,7 @@ impl<'a> ICCParser<'a> {
                 "gTRC" | "aagg" => TagType::GreenToneReproductionCurve,
                 "bTRC" | "aabg" => TagType::BlueToneReproductionCurve,
                 "chad" => TagType::ChromaticAdaptationMatrix,
+                "vcgt" => TagType::VideoCardGamma,
                 _ => TagType::Other(signature),
             };
             Ok(Tag {
@@ -66,14 +126,14 @@ impl<'a> ICCParser<'a> {
     }
 
     pub fn tag_data(&mut self, tag: Tag) -> Result<TagData, ParseError> {
-        Ok(self.parse_tag_data(tag.offset as usize, tag.size as usize)?)
+        self.parse_tag_data(tag.offset as usize, tag.size as usize)
     }
 
     pub(crate) fn parse_tag_info(
         &mut self,
         index: u32,
     ) -> Result<(ShortString, u32, u32), ParseError> {
-        self.i = (132 + index * 12) as usize; // Skip header + 4 bytes for tag
+        self.i = 132 + index as usize * 12; // Skip header + 4 bytes for tag
         let signature = self.read_short_string()?;
         let offset = self.read_u32()?;
         let size = self.read_u32()?;
@@ -99,7 +159,9 @@ impl<'a> ICCParser<'a> {
             "mluc" => MultiLocalizedStrings(self.parse_multi_localized_unicode(data_start)?),
             "XYZ " => XYZ(self.parse_xyz_data()?),
             "para" => ParametricCurve(self.parse_para_data()?),
+            "curv" => Curve(self.parse_curv_data()?),
             "sf32" => self.parse_sf32(size)?,
+            "vcgt" => VideoCardGamma(self.parse_vcgt_data()?),
             _ => Unknown,
         };
         self.i = old_i;
@@ -112,7 +174,7 @@ impl<'a> ICCParser<'a> {
         // Each tag has 8 bytes for the signature and reserved area.
         // If this is exactly 9 (as will be common for 3x3 matrices)
         // then parse / store the data in a convenient way.
-        if tag_length - 8 == 36 {
+        if tag_length.checked_sub(8) == Some(36) {
             Ok(TagData::Array9(self.read_s15_fixed_16_array_length_9()?))
         } else {
             Err(ParseError::UnimplementedInICCParser)
@@ -132,7 +194,8 @@ impl<'a> ICCParser<'a> {
         // record_size is always equal to 12
         let _record_size = self.read_u32()?;
 
-        let mut strings = Vec::with_capacity(number_of_records as usize);
+        // The record count isn't trusted for preallocation, reading each record checks the length.
+        let mut strings = Vec::new();
 
         for _ in 0..number_of_records {
             let language_code = self.read_bytes(2)?;
@@ -180,6 +243,91 @@ impl<'a> ICCParser<'a> {
         })
     }
 
+    /// Parse the video card gamma tag, which isn't in the specification. Its layout is described here:
+    /// https://developer.apple.com/library/archive/technotes/tn2007/tn2115.html
+    fn parse_vcgt_data(&mut self) -> Result<VideoCardGamma, ParseError> {
+        let gamma_type = self.read_u32()?;
+        match gamma_type {
+            0 => {
+                let channels = self.read_u16()? as usize;
+                let entry_count = self.read_u16()? as usize;
+                let entry_size = self.read_u16()?;
+                // A curve needs 2 entries to interpolate between.
+                if entry_count < 2 || !(channels == 1 || channels == 3) {
+                    return Err(ParseError::UnableToParse);
+                }
+                if !(entry_size == 1 || entry_size == 2) {
+                    return Err(ParseError::UnimplementedInICCParser);
+                }
+                // Check the tables fit before reading them.
+                let table_bytes = channels * entry_count * entry_size as usize;
+                if self.bytes.len().saturating_sub(self.i) < table_bytes {
+                    return Err(ParseError::UnexpectedEnd);
+                }
+                let mut read_entry = || -> Result<f64, ParseError> {
+                    match entry_size {
+                        1 => Ok(self.read_u8()? as f64 / 255.0),
+                        2 => Ok(self.read_u16()? as f64 / 65535.0),
+                        _ => Err(ParseError::UnimplementedInICCParser),
+                    }
+                };
+                let mut tables: [Vec<f64>; 3] = Default::default();
+                if channels == 1 {
+                    // A single curve is shared by all channels.
+                    for _ in 0..entry_count {
+                        tables[0].push(read_entry()?);
+                    }
+                    tables[1] = tables[0].clone();
+                    tables[2] = tables[0].clone();
+                } else {
+                    for table in tables.iter_mut() {
+                        for _ in 0..entry_count {
+                            table.push(read_entry()?);
+                        }
+                    }
+                }
+                Ok(VideoCardGamma::Table(tables))
+            }
+            1 => {
+                let mut read_formula = || -> Result<VideoCardGammaFormula, ParseError> {
+                    Ok(VideoCardGammaFormula {
+                        gamma: self.read_s15_fixed_16_number()?,
+                        min: self.read_s15_fixed_16_number()?,
+                        max: self.read_s15_fixed_16_number()?,
+                    })
+                };
+                Ok(VideoCardGamma::Formula([
+                    read_formula()?,
+                    read_formula()?,
+                    read_formula()?,
+                ]))
+            }
+            _ => Err(ParseError::UnableToParse),
+        }
+    }
+
+    /// Parse curve data.
+    /// See section 10.6 on page 53 of the specification.
+    fn parse_curv_data(&mut self) -> Result<Curve, ParseError> {
+        let count = self.read_u32()? as usize;
+        match count {
+            0 => Ok(Curve::Identity),
+            // A u8Fixed8Number
+            1 => Ok(Curve::Gamma(self.read_u16()? as f64 / 256.0)),
+            _ => {
+                // Read all the entries first so a count past the end of the profile fails before allocating.
+                let bytes =
+                    self.read_bytes(count.checked_mul(2).ok_or(ParseError::UnexpectedEnd)?)?;
+                Ok(Curve::Table(
+                    bytes
+                        .chunks_exact(2)
+                        .map(|v| u16::from_be_bytes([v[0], v[1]]) as f64 / 65535.0)
+                        .collect(),
+                ))
+            }
+        }
+    }
+
     /// Parse parametric curve data.
     /// See table 65 on page 69 of the specification.
     fn parse_para_data(&mut self) -> Result<ParametricCurve, ParseError> {
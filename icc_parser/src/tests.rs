@@ -1,3 +1,5 @@
+#![allow(unused_variables, unused_assignments, clippy::single_match)]
+
 use crate::*;
 use std::fs;
 
@@ -0,0 +1,67 @@
+//! Benchmarks of common conversions over buffers of colors.
+//!
+//! Compare the `fast-math` feature against the default by running:
+//! `cargo bench -p kcolor --bench conversions` and
+//! `cargo bench -p kcolor --bench conversions --features fast-math`
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+use kcolor::*;
+
+const COUNT: usize = 10_000;
+
+fn srgb_colors() -> Vec<(f64, f64, f64)> {
+    (0..COUNT)
+        .map(|i| {
+            let t = i as f64 / COUNT as f64;
+            (t, (t * 7.0) % 1.0, (t * 13.0) % 1.0)
+        })
+        .collect()
+}
+
+fn conversions(c: &mut Criterion) {
+    let srgb = srgb_colors();
+    let colors: Vec<Color> = srgb
+        .iter()
+        .map(|(r, g, b)| Color::new_srgb(*r, *g, *b, 1.0))
+        .collect();
+    let display_p3 = ColorSpace::from_standard(StandardColorSpace::DisplayP3);
+
+    c.bench_function("srgb_to_display_p3", |b| {
+        let converter = ColorSpaceConverter::new(&ColorSpace::SRGB, &display_p3);
+        b.iter(|| {
+            for rgb in &srgb {
+                black_box(converter.convert_encoded_color(black_box(rgb)));
+            }
+        })
+    });
+    c.bench_function("to_lab", |b| {
+        b.iter(|| {
+            for color in &colors {
+                black_box(black_box(color).to_lab());
+            }
+        })
+    });
+    c.bench_function("to_oklab", |b| {
+        b.iter(|| {
+            for color in &colors {
+                black_box(black_box(color).to_oklab());
+            }
+        })
+    });
+    c.bench_function("powf", |b| {
+        b.iter(|| {
+            for (r, _, _) in &srgb {
+                black_box(powf(black_box(*r), 2.4));
+            }
+        })
+    });
+    c.bench_function("cbrt", |b| {
+        b.iter(|| {
+            for (r, _, _) in &srgb {
+                black_box(cbrt(black_box(*r)));
+            }
+        })
+    });
+}
+
+criterion_group!(benches, conversions);
+criterion_main!(benches);
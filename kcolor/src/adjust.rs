@@ -0,0 +1,234 @@
+//! Operations that adjust the appearance of colors.
+use crate::white_points::*;
+use crate::*;
+
+impl Color {
+    /// Inverts the color's encoded RGB values in `color_space`, like a photographic negative.
+    /// RGB values are clipped to the color space before being inverted.
+    pub fn invert_in(&self, color_space: &ColorSpace) -> Color {
+        let (r, g, b, a) = color_space.color_to_rgba(self);
+        color_space.new_color(1.0 - r, 1.0 - g, 1.0 - b, a)
+    }
+
+    /// Inverts the color's linear RGB values in `color_space`.
+    /// Unlike `invert_in` this ignores the transfer function, so middle gray
+    /// in linear light (not middle gray in the encoded values) stays the same.
+    pub fn invert_linear_in(&self, color_space: &ColorSpace) -> Color {
+        let (r, g, b, a) = color_space.color_to_linear_rgba(self);
+        let rgb = color_space.to_XYZ * Vector3::new(1.0 - r, 1.0 - g, 1.0 - b);
+        Color::new_xyza(rgb.x, rgb.y, rgb.z, a)
+    }
+
+    /// Makes the color lighter by adding `amount` to its Oklch lightness,
+    /// then maps the result into the gamut of `color_space`.
+    /// Oklch lightness ranges from 0.0 to 1.0, so an `amount` of 0.1 is a noticeable step.
+    pub fn lighten(&self, amount: f64, color_space: &ColorSpace) -> Color {
+        let mut oklch = self.to_oklch();
+        oklch.L = (oklch.L + amount).clamp(0.0, 1.0);
+        color_space.gamut_map(&Color::from_oklch(oklch, self.a))
+    }
+
+    /// Makes the color darker by subtracting `amount` from its Oklch lightness,
+    /// then maps the result into the gamut of `color_space`.
+    pub fn darken(&self, amount: f64, color_space: &ColorSpace) -> Color {
+        self.lighten(-amount, color_space)
+    }
+
+    /// Makes the color more colorful by scaling its Oklch chroma by `1.0 + amount`,
+    /// then maps the result into the gamut of `color_space`.
+    /// Chroma can't go beyond what `color_space` can represent at the color's lightness and hue.
+    pub fn saturate(&self, amount: f64, color_space: &ColorSpace) -> Color {
+        let mut oklch = self.to_oklch();
+        oklch.C = (oklch.C * (1.0 + amount)).max(0.0);
+        color_space.gamut_map(&Color::from_oklch(oklch, self.a))
+    }
+
+    /// Makes the color less colorful by scaling its Oklch chroma by `1.0 - amount`,
+    /// then maps the result into the gamut of `color_space`.
+    /// An `amount` of 1.0 produces a gray of the same lightness.
+    pub fn desaturate(&self, amount: f64, color_space: &ColorSpace) -> Color {
+        self.saturate(-amount, color_space)
+    }
+
+    /// Makes muted colors more colorful while leaving already colorful colors and skin tones
+    /// mostly alone, then maps the result into the gamut of `color_space`.
+    /// Negative values of `amount` make colors less colorful in the same way.
+    ///
+    /// How muted a color is is measured relative to the most colorful color `color_space`
+    /// can represent with the same lightness and hue.
+    pub fn vibrance(&self, amount: f64, color_space: &ColorSpace) -> Color {
+        // Skin tones of all kinds cluster around this Oklch hue.
+        const SKIN_HUE: f64 = 50.0;
+        const SKIN_HUE_RANGE: f64 = 30.0;
+
+        let mut oklch = self.to_oklch();
+        let max_chroma = color_space.max_chroma(oklch.L, oklch.h);
+        if max_chroma <= 0.0 {
+            return *self;
+        }
+        let saturation = (oklch.C / max_chroma).clamp(0.0, 1.0);
+
+        // Fade the effect in as hues move away from skin tones.
+        let hue_distance = (float::rem_euclid(oklch.h - SKIN_HUE + 180.0, 360.0) - 180.0).abs();
+        let skin_protection = 1.0 - (1.0 - hue_distance / SKIN_HUE_RANGE).clamp(0.0, 1.0) * 0.5;
+
+        oklch.C = (oklch.C * (1.0 + amount * (1.0 - saturation) * skin_protection)).max(0.0);
+        color_space.gamut_map(&Color::from_oklch(oklch, self.a))
+    }
+
+    /// Adjusts the color's white balance, like the temperature and tint sliders of photo editors.
+    ///
+    /// The color is adapted as if the scene had been lit by a light `delta_kelvin` away from
+    /// 6504K (D65) and is corrected to look neutral under D65, so a positive `delta_kelvin`
+    /// makes the color warmer and a negative one makes it cooler.
+    /// `tint` offsets the light perpendicular to the daylight locus, measured as a
+    /// distance in the CIE 1960 UCS (like Duv). Positive values make the color more magenta
+    /// and negative values make it more green. 0.01 is a strong tint.
+    pub fn adjust_white_balance(&self, delta_kelvin: f64, tint: f64) -> Color {
+        let xyz = white_balance_adaptation(delta_kelvin, tint).convert(self.xyz());
+        Color::new_xyza(xyz.X, xyz.Y, xyz.Z, self.a)
+    }
+
+    /// Adjusts the color's exposure by `stops`, like a camera would.
+    /// Each stop doubles (or for negative values halves) the amount of light.
+    pub fn exposure(&self, stops: f64) -> Color {
+        let scale = powf(2.0, stops);
+        Color::new_xyza(self.X * scale, self.Y * scale, self.Z * scale, self.a)
+    }
+
+    /// Applies a gamma adjustment to the color's encoded RGB values in `color_space`.
+    /// Each value is raised to the power `1.0 / gamma`, so a `gamma` above 1.0 brightens
+    /// the midtones and a `gamma` below 1.0 darkens them, while black and white are unchanged.
+    pub fn gamma(&self, gamma: f64, color_space: &ColorSpace) -> Color {
+        let (r, g, b, a) = color_space.color_to_rgba_unclipped(self);
+        color_space.new_color(
+            apply_gamma(r, gamma),
+            apply_gamma(g, gamma),
+            apply_gamma(b, gamma),
+            a,
+        )
+    }
+
+    /// Removes the color's chromaticity while preserving its luminance (Y),
+    /// producing the gray that appears as bright as the color.
+    /// This is more accurate than averaging RGB values.
+    pub fn to_grayscale(&self) -> Color {
+        let white = D50_WHITE_POINT_2DEGREES.to_XYZ_with_luminance(self.Y);
+        Color::new_xyza(white.X, white.Y, white.Z, self.a)
+    }
+}
+
+impl ColorSpace {
+    /// Converts encoded RGB values in this color space to grayscale in place.
+    /// The luminance of each pixel is preserved, like `Color::to_grayscale`.
+    pub fn grayscale_slice(&self, pixels: &mut [[f64; 3]]) {
+        // The second row of the matrix calculates luminance from linear RGB.
+        let luminance = self.to_XYZ.row1();
+        for pixel in pixels.iter_mut() {
+            let linear = Vector3::new(
+                self.transfer_function.to_linear(pixel[0]),
+                self.transfer_function.to_linear(pixel[1]),
+                self.transfer_function.to_linear(pixel[2]),
+            );
+            // The white point of every color space has a luminance of 1.0,
+            // so equal linear RGB values equal to the luminance produce that gray.
+            let gray = self
+                .transfer_function
+                .from_linear(Vector3::dot(luminance, linear));
+            *pixel = [gray, gray, gray];
+        }
+    }
+
+    /// Adjusts the exposure of encoded RGB values in this color space in place.
+    /// See `Color::exposure`. Values are not clipped.
+    pub fn exposure_slice(&self, pixels: &mut [[f64; 3]], stops: f64) {
+        let scale = powf(2.0, stops);
+        for pixel in pixels.iter_mut() {
+            for value in pixel.iter_mut() {
+                *value = self
+                    .transfer_function
+                    .from_linear(self.transfer_function.to_linear(*value) * scale);
+            }
+        }
+    }
+
+    /// Applies a gamma adjustment to encoded RGB values in this color space in place.
+    /// See `Color::gamma`.
+    pub fn gamma_slice(&self, pixels: &mut [[f64; 3]], gamma: f64) {
+        for pixel in pixels.iter_mut() {
+            for value in pixel.iter_mut() {
+                *value = apply_gamma(*value, gamma);
+            }
+        }
+    }
+
+    /// Adjusts the white balance of encoded RGB values in this color space in place.
+    /// See `Color::adjust_white_balance` for what `delta_kelvin` and `tint` do.
+    /// Values are not clipped.
+    pub fn adjust_white_balance_slice(
+        &self,
+        pixels: &mut [[f64; 3]],
+        delta_kelvin: f64,
+        tint: f64,
+    ) {
+        let matrix =
+            self.from_XYZ * white_balance_adaptation(delta_kelvin, tint).inner_matrix * self.to_XYZ;
+        for pixel in pixels.iter_mut() {
+            let linear = Vector3::new(
+                self.transfer_function.to_linear(pixel[0]),
+                self.transfer_function.to_linear(pixel[1]),
+                self.transfer_function.to_linear(pixel[2]),
+            );
+            let rgb = matrix * linear;
+            *pixel = [
+                self.transfer_function.from_linear(rgb.x),
+                self.transfer_function.from_linear(rgb.y),
+                self.transfer_function.from_linear(rgb.z),
+            ];
+        }
+    }
+}
+
+/// Negative values are mirrored, like transfer functions.
+fn apply_gamma(value: f64, gamma: f64) -> f64 {
+    if value < 0.0 {
+        -powf(-value, 1.0 / gamma)
+    } else {
+        powf(value, 1.0 / gamma)
+    }
+}
+
+/// The adaptation from a light shifted by `delta_kelvin` and `tint` to D65.
+fn white_balance_adaptation(delta_kelvin: f64, tint: f64) -> ChromaticAdaptation {
+    const REFERENCE_TEMPERATURE: f64 = 6504.0;
+
+    // Tint is applied in the CIE 1960 UCS, where distances from the locus are measured.
+    fn to_uv(xyz: XYZ) -> (f64, f64) {
+        let d = xyz.X + 15.0 * xyz.Y + 3.0 * xyz.Z;
+        (4.0 * xyz.X / d, 6.0 * xyz.Y / d)
+    }
+
+    let temperature = REFERENCE_TEMPERATURE + delta_kelvin;
+    let (u, v) = to_uv(XYZ::from_cct(temperature));
+    let (u1, v1) = to_uv(XYZ::from_cct(temperature + 1.0));
+
+    // The direction perpendicular to the locus, pointing towards green.
+    let (du, dv) = (u1 - u, v1 - v);
+    let length = float::sqrt(du * du + dv * dv);
+    let (normal_u, normal_v) = if du < 0.0 {
+        (dv / length, -du / length)
+    } else {
+        (-dv / length, du / length)
+    };
+
+    // A positive tint corrects for a green light, which makes colors more magenta.
+    let u = u + normal_u * tint;
+    let v = v + normal_v * tint;
+    let d = 2.0 * u - 8.0 * v + 4.0;
+    let source = Chromaticity::new(3.0 * u / d, 2.0 * v / d);
+
+    ChromaticAdaptation::new(
+        source,
+        XYZ::from_cct(REFERENCE_TEMPERATURE).to_chromaticity(),
+    )
+}
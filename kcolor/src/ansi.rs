@@ -0,0 +1,88 @@
+//! Colors for terminals, with ANSI escape sequences.
+use crate::*;
+use alloc::format;
+use alloc::string::String;
+
+/// The default colors xterm uses for the 16 basic ANSI colors.
+/// Many terminals let users change these, so they're only an approximation.
+const ANSI_16: [u32; 16] = [
+    0x000000, 0xcd0000, 0x00cd00, 0xcdcd00, 0x0000ee, 0xcd00cd, 0x00cdcd, 0xe5e5e5, 0x7f7f7f,
+    0xff0000, 0x00ff00, 0xffff00, 0x5c5cff, 0xff00ff, 0x00ffff, 0xffffff,
+];
+
+/// The sRGB value of an index in the 256-color palette, excluding the first 16 colors.
+fn ansi_256_hex(index: u8) -> u32 {
+    const CUBE_LEVELS: [u32; 6] = [0, 95, 135, 175, 215, 255];
+    if index >= 232 {
+        let gray = 8 + 10 * (index as u32 - 232);
+        (gray << 16) | (gray << 8) | gray
+    } else {
+        let i = index as usize - 16;
+        (CUBE_LEVELS[i / 36] << 16) | (CUBE_LEVELS[(i / 6) % 6] << 8) | CUBE_LEVELS[i % 6]
+    }
+}
+
+fn closest(color: &Color, candidates: impl Iterator<Item = (u8, u32)>) -> u8 {
+    candidates
+        .map(|(index, hex)| {
+            (
+                index,
+                DeltaEOk.distance(color, &Color::from_hex_srgb(hex, 1.0)),
+            )
+        })
+        .min_by(|a, b| a.1.total_cmp(&b.1))
+        .map(|(index, _)| index)
+        .unwrap_or(0)
+}
+
+impl Color {
+    /// The index (0 to 15) of the closest of the 16 basic ANSI colors,
+    /// using xterm's default colors.
+    pub fn to_ansi_16(&self) -> u8 {
+        closest(
+            self,
+            ANSI_16.iter().enumerate().map(|(i, hex)| (i as u8, *hex)),
+        )
+    }
+
+    /// The index (16 to 255) of the closest color in the 256-color palette.
+    /// The first 16 colors are skipped because they vary between terminals.
+    pub fn to_ansi_256(&self) -> u8 {
+        closest(self, (16..=255).map(|i| (i, ansi_256_hex(i))))
+    }
+
+    /// The escape sequence that sets the terminal's text color to this color,
+    /// for terminals that support 24-bit color.
+    pub fn ansi_truecolor_foreground(&self) -> String {
+        let (r, g, b) = self.srgb_bytes();
+        format!("\x1b[38;2;{};{};{}m", r, g, b)
+    }
+
+    /// The escape sequence that sets the terminal's background color to this color,
+    /// for terminals that support 24-bit color.
+    pub fn ansi_truecolor_background(&self) -> String {
+        let (r, g, b) = self.srgb_bytes();
+        format!("\x1b[48;2;{};{};{}m", r, g, b)
+    }
+
+    /// The escape sequence that sets the terminal's text color to the closest
+    /// color in the 256-color palette.
+    pub fn ansi_256_foreground(&self) -> String {
+        format!("\x1b[38;5;{}m", self.to_ansi_256())
+    }
+
+    /// The escape sequence that sets the terminal's background color to the closest
+    /// color in the 256-color palette.
+    pub fn ansi_256_background(&self) -> String {
+        format!("\x1b[48;5;{}m", self.to_ansi_256())
+    }
+
+    fn srgb_bytes(&self) -> (u8, u8, u8) {
+        let (r, g, b, _) = ColorSpace::SRGB.gamut_map(self).to_srgb();
+        let to_byte = |v: f64| float::round(v * 255.0) as u8;
+        (to_byte(r), to_byte(g), to_byte(b))
+    }
+}
+
+/// The escape sequence that resets the terminal's colors.
+pub const ANSI_RESET: &str = "\x1b[0m";
@@ -0,0 +1,71 @@
+//! Averaging colors.
+//!
+//! Averaging encoded RGB values (such as sRGB) produces results that are too dark,
+//! so these functions average in XYZ, which is linear light, or in Oklab.
+use crate::*;
+
+impl Color {
+    /// The average of `colors` in XYZ, which matches physically mixing light.
+    /// Alpha is averaged separately.
+    /// Returns `None` if `colors` is empty.
+    pub fn average(colors: &[Color]) -> Option<Color> {
+        Self::weighted_average(colors.iter().map(|c| (*c, 1.0)))
+    }
+
+    /// The average of colors paired with weights in XYZ.
+    /// Returns `None` if there are no colors or the weights sum to 0.0.
+    pub fn weighted_average(colors: impl IntoIterator<Item = (Color, f64)>) -> Option<Color> {
+        let mut sum = Color::new_xyza(0.0, 0.0, 0.0, 0.0);
+        let mut total_weight = 0.0;
+        for (color, weight) in colors {
+            sum.X += color.X * weight;
+            sum.Y += color.Y * weight;
+            sum.Z += color.Z * weight;
+            sum.a += color.a * weight;
+            total_weight += weight;
+        }
+        if total_weight == 0.0 {
+            return None;
+        }
+        Some(Color::new_xyza(
+            sum.X / total_weight,
+            sum.Y / total_weight,
+            sum.Z / total_weight,
+            sum.a / total_weight,
+        ))
+    }
+
+    /// The average of `colors` in Oklab, which is closer to the color
+    /// a person would pick as representative of the colors.
+    /// Returns `None` if `colors` is empty.
+    pub fn average_oklab(colors: &[Color]) -> Option<Color> {
+        Self::weighted_average_oklab(colors.iter().map(|c| (*c, 1.0)))
+    }
+
+    /// The average of colors paired with weights in Oklab.
+    /// Returns `None` if there are no colors or the weights sum to 0.0.
+    pub fn weighted_average_oklab(colors: impl IntoIterator<Item = (Color, f64)>) -> Option<Color> {
+        let mut sum = Oklab::new(0.0, 0.0, 0.0);
+        let mut alpha = 0.0;
+        let mut total_weight = 0.0;
+        for (color, weight) in colors {
+            let oklab = color.to_oklab();
+            sum.L += oklab.L * weight;
+            sum.a += oklab.a * weight;
+            sum.b += oklab.b * weight;
+            alpha += color.a * weight;
+            total_weight += weight;
+        }
+        if total_weight == 0.0 {
+            return None;
+        }
+        Some(Color::from_oklab(
+            Oklab::new(
+                sum.L / total_weight,
+                sum.a / total_weight,
+                sum.b / total_weight,
+            ),
+            alpha / total_weight,
+        ))
+    }
+}
@@ -0,0 +1,202 @@
+//! A command line tool for quick color conversions and checks, similar to `transicc`.
+//!
+//! Color spaces are given by name (see `kcolor list`) or as a path to an ICC profile.
+use kcolor::*;
+use std::process::ExitCode;
+
+const USAGE: &str = "\
+Usage:
+  kcolor convert <from> <to> <r> <g> <b> [<a>]
+      Converts an RGB(A) color with components from 0.0 to 1.0 between color spaces.
+  kcolor parse <color>
+      Parses a hex color string, like #ff8000, as sRGB and describes it.
+  kcolor delta-e <color> <color>
+      Prints the difference between two hex colors with each delta E metric.
+  kcolor image <from> <to> <input.ppm> <output.ppm>
+      Converts a binary PPM (P6) image between color spaces.
+  kcolor list
+      Lists the names of the built in color spaces.
+
+Color spaces are a name from `kcolor list` or a path to an ICC profile.";
+
+fn main() -> ExitCode {
+    let args: Vec<String> = std::env::args().skip(1).collect();
+    let args: Vec<&str> = args.iter().map(|a| a.as_str()).collect();
+    let result = match args.as_slice() {
+        ["convert", from, to, rgba @ ..] if rgba.len() == 3 || rgba.len() == 4 => {
+            convert(from, to, rgba)
+        }
+        ["parse", color] => parse(color),
+        ["delta-e", a, b] => delta_e(a, b),
+        ["image", from, to, input, output] => image(from, to, input, output),
+        ["list"] => {
+            for standard in StandardColorSpace::ALL {
+                println!("{}", standard.name());
+            }
+            Ok(())
+        }
+        _ => Err(USAGE.to_string()),
+    };
+    match result {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(message) => {
+            eprintln!("{}", message);
+            ExitCode::FAILURE
+        }
+    }
+}
+
+fn color_space(name_or_path: &str) -> Result<ColorSpace, String> {
+    if let Some(color_space) = ColorSpace::from_name(name_or_path) {
+        return Ok(color_space);
+    }
+    let bytes = std::fs::read(name_or_path).map_err(|e| {
+        format!(
+            "'{}' isn't a color space name or a readable file: {}",
+            name_or_path, e
+        )
+    })?;
+    ColorSpace::from_icc_profile(&bytes)
+        .map_err(|e| format!("couldn't load the ICC profile '{}': {:?}", name_or_path, e))
+}
+
+fn number(text: &str) -> Result<f64, String> {
+    text.parse()
+        .map_err(|_| format!("'{}' isn't a number", text))
+}
+
+fn hex_color(text: &str) -> Result<Color, String> {
+    ColorSpace::SRGB
+        .new_color_from_hex_str(text)
+        .map_err(|e| format!("'{}': {}", text, e))
+}
+
+fn convert(from: &str, to: &str, rgba: &[&str]) -> Result<(), String> {
+    let (from, to) = (color_space(from)?, color_space(to)?);
+    let values = rgba
+        .iter()
+        .map(|v| number(v))
+        .collect::<Result<Vec<_>, _>>()?;
+    let alpha = values.get(3).copied().unwrap_or(1.0);
+    let color = from.new_color(values[0], values[1], values[2], alpha);
+    let (r, g, b, a) = to.color_to_rgba_unclipped(&color);
+    println!("{:.6} {:.6} {:.6} {:.6}", r, g, b, a);
+    if !to.contains(&color) {
+        println!("(out of gamut)");
+    }
+    Ok(())
+}
+
+fn parse(text: &str) -> Result<(), String> {
+    let color = hex_color(text)?;
+    let oklch = color.to_oklch();
+    let lab = color.to_lab();
+    println!("{}", color);
+    println!("oklch({:.4} {:.4} {:.2})", oklch.L, oklch.C, oklch.h);
+    println!("lab({:.4} {:.4} {:.4})", lab.L, lab.a, lab.b);
+    Ok(())
+}
+
+fn delta_e(a: &str, b: &str) -> Result<(), String> {
+    let (a, b) = (hex_color(a)?, hex_color(b)?);
+    println!("ΔE76:   {:.4}", DeltaE76.distance(&a, &b));
+    println!("ΔE2000: {:.4}", DeltaE2000.distance(&a, &b));
+    println!("ΔEOk:   {:.4}", DeltaEOk.distance(&a, &b));
+    Ok(())
+}
+
+fn image(from: &str, to: &str, input: &str, output: &str) -> Result<(), String> {
+    let (from, to) = (color_space(from)?, color_space(to)?);
+    let bytes = std::fs::read(input).map_err(|e| format!("couldn't read '{}': {}", input, e))?;
+    let mut image = Ppm::parse(&bytes)?;
+    let converter = ColorSpaceConverter::new(&from, &to);
+    let max = image.max as f64;
+    for pixel in image.samples.chunks_exact_mut(3) {
+        let (r, g, b) = converter.convert_encoded_color(&(
+            pixel[0] as f64 / max,
+            pixel[1] as f64 / max,
+            pixel[2] as f64 / max,
+        ));
+        for (sample, value) in pixel.iter_mut().zip([r, g, b]) {
+            *sample = (value.clamp(0.0, 1.0) * max).round() as u16;
+        }
+    }
+    std::fs::write(output, image.to_bytes())
+        .map_err(|e| format!("couldn't write '{}': {}", output, e))
+}
+
+/// A binary PPM (P6) image, the simplest format that's widely supported by image tools.
+struct Ppm {
+    width: usize,
+    height: usize,
+    max: u16,
+    samples: Vec<u16>,
+}
+
+impl Ppm {
+    fn parse(bytes: &[u8]) -> Result<Self, String> {
+        let invalid = || "the image isn't a binary PPM (P6) file".to_string();
+        let mut offset = 0;
+        let mut fields = Vec::new();
+        // The magic number, width, height, and maximum value are separated by whitespace
+        // and comments. A single whitespace character separates them from the samples.
+        while fields.len() < 4 {
+            while bytes.get(offset).is_some_and(|b| b.is_ascii_whitespace()) {
+                offset += 1;
+            }
+            if bytes.get(offset) == Some(&b'#') {
+                while bytes.get(offset).is_some_and(|b| *b != b'\n') {
+                    offset += 1;
+                }
+                continue;
+            }
+            let start = offset;
+            while bytes.get(offset).is_some_and(|b| !b.is_ascii_whitespace()) {
+                offset += 1;
+            }
+            if start == offset {
+                return Err(invalid());
+            }
+            fields.push(std::str::from_utf8(&bytes[start..offset]).map_err(|_| invalid())?);
+        }
+        offset += 1;
+
+        if fields[0] != "P6" {
+            return Err(invalid());
+        }
+        let parse = |field: &str| field.parse::<usize>().map_err(|_| invalid());
+        let (width, height, max) = (parse(fields[1])?, parse(fields[2])?, parse(fields[3])?);
+        if max == 0 || max > u16::MAX as usize {
+            return Err(invalid());
+        }
+        let bytes_per_sample = if max < 256 { 1 } else { 2 };
+        let data = bytes
+            .get(offset..offset + width * height * 3 * bytes_per_sample)
+            .ok_or_else(invalid)?;
+        let samples = if bytes_per_sample == 1 {
+            data.iter().map(|b| *b as u16).collect()
+        } else {
+            data.chunks_exact(2)
+                .map(|b| u16::from_be_bytes([b[0], b[1]]))
+                .collect()
+        };
+        Ok(Self {
+            width,
+            height,
+            max: max as u16,
+            samples,
+        })
+    }
+
+    fn to_bytes(&self) -> Vec<u8> {
+        let mut bytes = format!("P6\n{} {}\n{}\n", self.width, self.height, self.max).into_bytes();
+        for sample in &self.samples {
+            if self.max < 256 {
+                bytes.push(*sample as u8);
+            } else {
+                bytes.extend_from_slice(&sample.to_be_bytes());
+            }
+        }
+        bytes
+    }
+}
@@ -0,0 +1,171 @@
+//! Blend modes, as specified for CSS, SVG, and PDF:
+//! https://www.w3.org/TR/compositing-1/#blending
+use crate::*;
+
+/// How the colors of a source and backdrop are mixed where they overlap.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum BlendMode {
+    /// The source replaces the backdrop.
+    #[default]
+    Normal,
+    Multiply,
+    Screen,
+    Overlay,
+    Darken,
+    Lighten,
+    ColorDodge,
+    ColorBurn,
+    HardLight,
+    SoftLight,
+    Difference,
+    Exclusion,
+    /// The hue of the source with the saturation and luminosity of the backdrop.
+    Hue,
+    /// The saturation of the source with the hue and luminosity of the backdrop.
+    Saturation,
+    /// The hue and saturation of the source with the luminosity of the backdrop.
+    Color,
+    /// The luminosity of the source with the hue and saturation of the backdrop.
+    Luminosity,
+}
+
+impl BlendMode {
+    /// Blends encoded RGB values, ignoring alpha.
+    pub fn blend_rgb(&self, source: [f64; 3], backdrop: [f64; 3]) -> [f64; 3] {
+        let separable = |f: fn(f64, f64) -> f64| {
+            [
+                f(backdrop[0], source[0]),
+                f(backdrop[1], source[1]),
+                f(backdrop[2], source[2]),
+            ]
+        };
+        match self {
+            BlendMode::Normal => source,
+            BlendMode::Multiply => separable(|b, s| b * s),
+            BlendMode::Screen => separable(screen),
+            BlendMode::Overlay => separable(|b, s| hard_light(s, b)),
+            BlendMode::Darken => separable(f64::min),
+            BlendMode::Lighten => separable(f64::max),
+            BlendMode::ColorDodge => separable(|b, s| {
+                if b == 0.0 {
+                    0.0
+                } else if s >= 1.0 {
+                    1.0
+                } else {
+                    (b / (1.0 - s)).min(1.0)
+                }
+            }),
+            BlendMode::ColorBurn => separable(|b, s| {
+                if b >= 1.0 {
+                    1.0
+                } else if s <= 0.0 {
+                    0.0
+                } else {
+                    1.0 - ((1.0 - b) / s).min(1.0)
+                }
+            }),
+            BlendMode::HardLight => separable(hard_light),
+            BlendMode::SoftLight => separable(|b, s| {
+                if s <= 0.5 {
+                    b - (1.0 - 2.0 * s) * b * (1.0 - b)
+                } else {
+                    let d = if b <= 0.25 {
+                        ((16.0 * b - 12.0) * b + 4.0) * b
+                    } else {
+                        float::sqrt(b)
+                    };
+                    b + (2.0 * s - 1.0) * (d - b)
+                }
+            }),
+            BlendMode::Difference => separable(|b, s| (b - s).abs()),
+            BlendMode::Exclusion => separable(|b, s| b + s - 2.0 * b * s),
+            BlendMode::Hue => set_lum(set_sat(source, sat(backdrop)), lum(backdrop)),
+            BlendMode::Saturation => set_lum(set_sat(backdrop, sat(source)), lum(backdrop)),
+            BlendMode::Color => set_lum(source, lum(backdrop)),
+            BlendMode::Luminosity => set_lum(backdrop, lum(source)),
+        }
+    }
+}
+
+impl Color {
+    /// Blends the color with `backdrop` using `mode`, then composites the result over `backdrop`.
+    /// Blending is performed on the encoded RGB values of `color_space`, which are clipped
+    /// to its gamut. Use `ColorSpace::SRGB` to match CSS.
+    pub fn blend(&self, backdrop: &Color, mode: BlendMode, color_space: &ColorSpace) -> Color {
+        let (sr, sg, sb, sa) = color_space.color_to_rgba(self);
+        let (br, bg, bb, ba) = color_space.color_to_rgba(backdrop);
+        let blended = mode.blend_rgb([sr, sg, sb], [br, bg, bb]);
+
+        // Where the backdrop is transparent the source is unchanged.
+        let source = [
+            (1.0 - ba) * sr + ba * blended[0],
+            (1.0 - ba) * sg + ba * blended[1],
+            (1.0 - ba) * sb + ba * blended[2],
+        ];
+
+        // Source-over compositing of the encoded values.
+        let a = sa + ba * (1.0 - sa);
+        if a == 0.0 {
+            return Color::new_xyza(0.0, 0.0, 0.0, 0.0);
+        }
+        let composite = |s: f64, b: f64| (s * sa + b * ba * (1.0 - sa)) / a;
+        color_space.new_color(
+            composite(source[0], br),
+            composite(source[1], bg),
+            composite(source[2], bb),
+            a,
+        )
+    }
+}
+
+fn screen(b: f64, s: f64) -> f64 {
+    b + s - b * s
+}
+
+fn hard_light(b: f64, s: f64) -> f64 {
+    if s <= 0.5 {
+        b * 2.0 * s
+    } else {
+        screen(b, 2.0 * s - 1.0)
+    }
+}
+
+// The non-separable blend modes use these helpers from the specification.
+
+fn lum(c: [f64; 3]) -> f64 {
+    0.3 * c[0] + 0.59 * c[1] + 0.11 * c[2]
+}
+
+fn clip_color(c: [f64; 3]) -> [f64; 3] {
+    let l = lum(c);
+    let n = c[0].min(c[1]).min(c[2]);
+    let x = c[0].max(c[1]).max(c[2]);
+    let mut c = c;
+    if n < 0.0 {
+        c = c.map(|v| l + (v - l) * l / (l - n));
+    }
+    if x > 1.0 {
+        c = c.map(|v| l + (v - l) * (1.0 - l) / (x - l));
+    }
+    c
+}
+
+fn set_lum(c: [f64; 3], l: f64) -> [f64; 3] {
+    let d = l - lum(c);
+    clip_color(c.map(|v| v + d))
+}
+
+fn sat(c: [f64; 3]) -> f64 {
+    c[0].max(c[1]).max(c[2]) - c[0].min(c[1]).min(c[2])
+}
+
+fn set_sat(c: [f64; 3], s: f64) -> [f64; 3] {
+    let max = c[0].max(c[1]).max(c[2]);
+    let min = c[0].min(c[1]).min(c[2]);
+    if max > min {
+        c.map(|v| (v - min) * s / (max - min))
+    } else {
+        [0.0; 3]
+    }
+}
@@ -0,0 +1,322 @@
+use crate::*;
+
+/// The cone response model used to perform a chromatic adaptation.
+/// Bradford is the most commonly used and is what ICC profiles use.
+///
+/// See here for more info:
+/// http://www.brucelindbloom.com/index.html?Eqn_ChromAdapt.html
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum ChromaticAdaptationMethod {
+    #[default]
+    Bradford,
+    /// Von Kries using the Hunt-Pointer-Estevez cone response matrix
+    /// normalized to D65.
+    VonKries,
+    /// The transform from the CIECAM02 color appearance model.
+    Cat02,
+    /// The transform from the CAM16 color appearance model.
+    Cat16,
+    /// Scales the XYZ values directly, sometimes called "wrong Von Kries".
+    /// This is the least accurate method and is only provided for matching
+    /// older software that uses it.
+    XyzScaling,
+}
+
+impl ChromaticAdaptationMethod {
+    /// Returns matrices that convert XYZ values to LMS (Long Medium Short)
+    /// values measuring the response of cones, and back.
+    pub(crate) fn matrices(&self) -> (Matrix3x3, Matrix3x3) {
+        match self {
+            ChromaticAdaptationMethod::Bradford => {
+                // The Bradford matrix constants are found at the above link.
+                // The matrix is also available here: https://en.wikipedia.org/wiki/LMS_color_space
+                let matrix = Matrix3x3 {
+                    c0: Vector3 {
+                        x: 0.8951000,
+                        y: -0.7502000,
+                        z: 0.0389000,
+                    },
+                    c1: Vector3 {
+                        x: 0.2664000,
+                        y: 1.7135000,
+                        z: -0.0685000,
+                    },
+                    c2: Vector3 {
+                        x: -0.1614000,
+                        y: 0.0367000,
+                        z: 1.0296000,
+                    },
+                };
+
+                let inverse = Matrix3x3 {
+                    c0: Vector3 {
+                        x: 0.9869929,
+                        y: 0.4323053,
+                        z: -0.0085287,
+                    },
+                    c1: Vector3 {
+                        x: -0.1470543,
+                        y: 0.5183603,
+                        z: 0.0400428,
+                    },
+                    c2: Vector3 {
+                        x: 0.1599627,
+                        y: 0.0492912,
+                        z: 0.9684867,
+                    },
+                };
+                (matrix, inverse)
+            }
+            ChromaticAdaptationMethod::VonKries => {
+                // The Hunt-Pointer-Estevez matrix constants are found at the above link.
+                let matrix = Matrix3x3 {
+                    c0: Vector3 {
+                        x: 0.4002400,
+                        y: -0.2263000,
+                        z: 0.0000000,
+                    },
+                    c1: Vector3 {
+                        x: 0.7076000,
+                        y: 1.1653200,
+                        z: 0.0000000,
+                    },
+                    c2: Vector3 {
+                        x: -0.0808100,
+                        y: 0.0457000,
+                        z: 0.9182200,
+                    },
+                };
+
+                let inverse = Matrix3x3 {
+                    c0: Vector3 {
+                        x: 1.8599364,
+                        y: 0.3611914,
+                        z: 0.0000000,
+                    },
+                    c1: Vector3 {
+                        x: -1.1293816,
+                        y: 0.6388125,
+                        z: 0.0000000,
+                    },
+                    c2: Vector3 {
+                        x: 0.2198974,
+                        y: -0.0000064,
+                        z: 1.0890636,
+                    },
+                };
+                (matrix, inverse)
+            }
+            ChromaticAdaptationMethod::Cat02 => {
+                // https://en.wikipedia.org/wiki/CIECAM02#CAT02
+                let matrix = Matrix3x3 {
+                    c0: Vector3 {
+                        x: 0.7328,
+                        y: -0.7036,
+                        z: 0.0030,
+                    },
+                    c1: Vector3 {
+                        x: 0.4296,
+                        y: 1.6975,
+                        z: 0.0136,
+                    },
+                    c2: Vector3 {
+                        x: -0.1624,
+                        y: 0.0061,
+                        z: 0.9834,
+                    },
+                };
+
+                let inverse = Matrix3x3 {
+                    c0: Vector3 {
+                        x: 1.096124,
+                        y: 0.454369,
+                        z: -0.009628,
+                    },
+                    c1: Vector3 {
+                        x: -0.278869,
+                        y: 0.473533,
+                        z: -0.005698,
+                    },
+                    c2: Vector3 {
+                        x: 0.182745,
+                        y: 0.072098,
+                        z: 1.015326,
+                    },
+                };
+                (matrix, inverse)
+            }
+            ChromaticAdaptationMethod::Cat16 => {
+                // Li, C. et al. (2017) "Comprehensive color solutions: CAM16, CAT16, and CAM16-UCS"
+                let matrix = Matrix3x3 {
+                    c0: Vector3 {
+                        x: 0.401288,
+                        y: -0.250268,
+                        z: -0.002079,
+                    },
+                    c1: Vector3 {
+                        x: 0.650173,
+                        y: 1.204414,
+                        z: 0.048952,
+                    },
+                    c2: Vector3 {
+                        x: -0.051461,
+                        y: 0.045854,
+                        z: 0.953127,
+                    },
+                };
+
+                let inverse = Matrix3x3 {
+                    c0: Vector3 {
+                        x: 1.86206786,
+                        y: 0.38752654,
+                        z: -0.01584150,
+                    },
+                    c1: Vector3 {
+                        x: -1.01125463,
+                        y: 0.62144744,
+                        z: -0.03412294,
+                    },
+                    c2: Vector3 {
+                        x: 0.14918677,
+                        y: -0.00897398,
+                        z: 1.04996444,
+                    },
+                };
+                (matrix, inverse)
+            }
+            ChromaticAdaptationMethod::XyzScaling => {
+                let identity = Matrix3x3::from_columns(
+                    Vector3::new(1., 0., 0.),
+                    Vector3::new(0., 1., 0.),
+                    Vector3::new(0., 0., 1.),
+                );
+                (identity, identity)
+            }
+        }
+    }
+}
+
+/// Convert between XYZ color spaces with different white points.
+/// Wavelengths are perceived as one color in one lighting condition and a
+/// different color under a different lighting condition.
+/// Our eyes adjust to lighting and if a room has yellow-ish lighting
+/// (it has a yellow-ish whitepoint) then what appears white is produced
+/// with yellow-ish wavelenghts.
+///
+/// This function first converts to an intermediate space (LMS) that represents our eyes'
+/// cone responses using a transform chosen by `ChromaticAdaptationMethod`.
+///
+/// Then a conversion is performed from the LMS intermediate space back into XYZ.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ChromaticAdaptation {
+    pub(crate) inner_matrix: Matrix3x3,
+}
+
+impl ChromaticAdaptation {
+    /// Creates a chromatic adaptation using the Bradford method.
+    pub fn new(source_white_point: Chromaticity, destination_white_point: Chromaticity) -> Self {
+        Self::new_with_method(
+            source_white_point,
+            destination_white_point,
+            ChromaticAdaptationMethod::Bradford,
+        )
+    }
+
+    pub fn new_with_method(
+        source_white_point: Chromaticity,
+        destination_white_point: Chromaticity,
+        method: ChromaticAdaptationMethod,
+    ) -> Self {
+        Self::new_with_degree_of_adaptation(
+            source_white_point,
+            destination_white_point,
+            method,
+            1.0,
+        )
+    }
+
+    /// Creates a partial chromatic adaptation.
+    /// `degree_of_adaptation` (the 'D' factor from CIECAM02) ranges from 0.0 for
+    /// no adaptation to 1.0 for full adaptation to the destination white point.
+    /// Values in between model an observer that has only partially adapted,
+    /// such as when viewing a display under differently colored room lighting.
+    /// See `ChromaticAdaptation::degree_of_adaptation` to calculate it from viewing conditions.
+    pub fn new_with_degree_of_adaptation(
+        source_white_point: Chromaticity,
+        destination_white_point: Chromaticity,
+        method: ChromaticAdaptationMethod,
+        degree_of_adaptation: f64,
+    ) -> Self {
+        // Implemented using the techniques described here:
+        // http://www.brucelindbloom.com/index.html?Eqn_ChromAdapt.html
+
+        // To do math with the XYZ values convert them to Vector3s.
+        let source_white_point = source_white_point.to_XYZ().to_vector3();
+        let destination_white_point = destination_white_point.to_XYZ().to_vector3();
+
+        let (cone_response_matrix, cone_response_matrix_inverse) = method.matrices();
+
+        // "crs" stands for "Cone response of source white point"
+        // "crd" stands for "Cone response of destination white point"
+        // The xyz values correspond to the response of the three cones.
+        // These three responses are the "LMS" color space.
+        // "LMS" stands for "Long", "Medium", "Short" based on the wavelengths
+        // the three types of cones respond to.
+        let crs = cone_response_matrix * source_white_point;
+        let crd = cone_response_matrix * destination_white_point;
+
+        // Interpolate each cone's scale between 1.0 (no adaptation) and the full adaptation.
+        let d = degree_of_adaptation.clamp(0.0, 1.0);
+        let scale = |destination: f64, source: f64| d * (destination / source) + (1.0 - d);
+        let intermediate_matrix = Matrix3x3::from_columns(
+            Vector3::new(scale(crd.x, crs.x), 0., 0.),
+            Vector3::new(0., scale(crd.y, crs.y), 0.),
+            Vector3::new(0., 0., scale(crd.z, crs.z)),
+        );
+
+        let inner_matrix =
+            cone_response_matrix_inverse * intermediate_matrix * cone_response_matrix;
+
+        Self { inner_matrix }
+    }
+
+    /// Calculates the degree of adaptation ('D') using the CIECAM02 formula.
+    /// `surround` is 1.0 for an average surround, 0.9 for dim, and 0.8 for dark.
+    /// `adapting_luminance` is the luminance of the adapting field in cd/m^2.
+    pub fn degree_of_adaptation(surround: f64, adapting_luminance: f64) -> f64 {
+        let d = surround * (1.0 - (1.0 / 3.6) * float::exp((-adapting_luminance - 42.0) / 92.0));
+        d.clamp(0.0, 1.0)
+    }
+
+    /// Combines two adaptations into one that applies `self` and then `other`.
+    /// Useful for prebaking multi-step white point conversions into a single matrix.
+    pub fn then(&self, other: &ChromaticAdaptation) -> ChromaticAdaptation {
+        Self {
+            inner_matrix: other.inner_matrix * self.inner_matrix,
+        }
+    }
+
+    /// An adaptation that undoes this one.
+    pub fn inverse(&self) -> ChromaticAdaptation {
+        Self {
+            inner_matrix: self.inner_matrix.inverse(),
+        }
+    }
+
+    /// The matrix that is multiplied with XYZ values to perform the adaptation.
+    pub fn matrix(&self) -> Matrix3x3 {
+        self.inner_matrix
+    }
+
+    pub fn convert(&self, xyz: XYZ) -> XYZ {
+        let v = Vector3::new(xyz.X, xyz.Y, xyz.Z);
+        let v = self.inner_matrix * v;
+        XYZ {
+            X: v.x,
+            Y: v.y,
+            Z: v.z,
+        }
+    }
+}
@@ -1,8 +1,13 @@
 use crate::color_space::ColorSpace;
+use crate::Rgba8;
+use kcolor_types::{Chromaticity, XYZ};
 /// Colors are stored internally in XYZ CIE 1931 space.
 /// Alpha is provided purely for convenience, but is not adjusted by any of
 /// the code in this library.
-#[derive(Debug, Copy, Clone)]
+///
+/// `Display` and alternate `Debug` (`{:#?}`) also show the nearest sRGB color as hex.
+#[derive(Copy, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Color {
     pub(crate) X: f64,
     pub(crate) Y: f64,
@@ -11,6 +16,81 @@ pub struct Color {
 }
 
 impl Color {
+    // The XYZ values of these constants are the sRGB primaries (the columns of
+    // `ColorSpace::SRGB`'s matrix) and their sums.
+
+    /// Opaque black.
+    pub const BLACK: Color = Color {
+        X: 0.0,
+        Y: 0.0,
+        Z: 0.0,
+        a: 1.0,
+    };
+
+    /// Opaque sRGB white: `#ffffff`.
+    pub const WHITE: Color = Color {
+        X: 0.9642119840744972,
+        Y: 1.0000000361131431,
+        Z: 0.8251882305776286,
+        a: 1.0,
+    };
+
+    /// Fully transparent black.
+    pub const TRANSPARENT: Color = Color {
+        X: 0.0,
+        Y: 0.0,
+        Z: 0.0,
+        a: 0.0,
+    };
+
+    /// Opaque sRGB red: `#ff0000`.
+    pub const RED: Color = Color {
+        X: 0.4360219083775758,
+        Y: 0.2224751872467074,
+        Z: 0.013928117106761706,
+        a: 1.0,
+    };
+
+    /// Opaque sRGB green: `#00ff00`.
+    pub const GREEN: Color = Color {
+        X: 0.3851088006156898,
+        Y: 0.7169066518920372,
+        Z: 0.09710152837405213,
+        a: 1.0,
+    };
+
+    /// Opaque sRGB blue: `#0000ff`.
+    pub const BLUE: Color = Color {
+        X: 0.14308127508123153,
+        Y: 0.06061819697439862,
+        Z: 0.7141585850968147,
+        a: 1.0,
+    };
+
+    /// Opaque sRGB yellow: `#ffff00`.
+    pub const YELLOW: Color = Color {
+        X: 0.8211307089932656,
+        Y: 0.9393818391387445,
+        Z: 0.11102964548081383,
+        a: 1.0,
+    };
+
+    /// Opaque sRGB cyan: `#00ffff`.
+    pub const CYAN: Color = Color {
+        X: 0.5281900756969213,
+        Y: 0.7775248488664358,
+        Z: 0.8112601134708669,
+        a: 1.0,
+    };
+
+    /// Opaque sRGB magenta: `#ff00ff`.
+    pub const MAGENTA: Color = Color {
+        X: 0.5791031834588074,
+        Y: 0.283093384221106,
+        Z: 0.7280867022035764,
+        a: 1.0,
+    };
+
     pub fn new_srgb(r: f64, g: f64, b: f64, a: f64) -> Self {
         ColorSpace::SRGB.new_color(r, g, b, a)
     }
@@ -21,12 +101,17 @@ impl Color {
         Color { X, Y, Z, a }
     }
 
+    /// A new color from XYZ values relative to white point D50 2 degrees.
+    pub fn from_xyz(xyz: XYZ, alpha: f64) -> Self {
+        Color::new_xyza(xyz.X, xyz.Y, xyz.Z, alpha)
+    }
+
     pub fn from_hex_srgb(hex: u32, alpha: f64) -> Self {
         ColorSpace::SRGB.new_color_from_hex(hex, alpha)
     }
 
     pub fn from_bytes_srgb(r: u8, g: u8, b: u8, alpha: u8) -> Self {
-        ColorSpace::SRGB.new_color_from_bytes(r, g, b, alpha)
+        ColorSpace::SRGB.new_color_from_rgba8(Rgba8 { r, g, b, a: alpha })
     }
 
     pub fn from_f32_srgb(r: f64, g: f64, b: f64, alpha: f64) -> Self {
@@ -42,7 +127,7 @@ impl Color {
     }
 
     pub fn from_bytes_linear_srgb(r: u8, g: u8, b: u8, alpha: u8) -> Self {
-        ColorSpace::SRGB_LINEAR.new_color_from_bytes(r, g, b, alpha)
+        ColorSpace::SRGB_LINEAR.new_color_from_rgba8(Rgba8 { r, g, b, a: alpha })
     }
 
     pub fn from_f32_linear_srgb(r: f64, g: f64, b: f64, alpha: f64) -> Self {
@@ -73,6 +158,33 @@ impl Color {
         color_space.color_to_rgba_unclipped(self)
     }
 
+    /// The color as `f32` RGBA values in the color space, with the transfer function applied.
+    /// Suitable for directly passing to graphics APIs as a clear color or uniform.
+    pub fn to_rgba_f32_array(&self, color_space: &ColorSpace) -> [f32; 4] {
+        let (r, g, b, a) = color_space.color_to_rgba(self);
+        [r as f32, g as f32, b as f32, a as f32]
+    }
+
+    /// The color as linear `f32` RGBA values in the color space's primaries.
+    /// Use this when the graphics API applies the transfer function itself,
+    /// for example when rendering to an sRGB framebuffer.
+    pub fn to_linear_f32_array(&self, color_space: &ColorSpace) -> [f32; 4] {
+        let (r, g, b, a) = color_space.color_to_linear_rgba(self);
+        [r as f32, g as f32, b as f32, a as f32]
+    }
+
+    /// The color as 8-bit RGBA values in the color space, with the transfer function applied.
+    /// Use this for images and textures with an sRGB (or other encoded) format.
+    pub fn to_encoded_rgba8(&self, color_space: &ColorSpace) -> [u8; 4] {
+        color_space.color_to_rgba8(self)
+    }
+
+    /// The color as 8-bit linear RGBA values in the color space's primaries.
+    /// Use this for textures with a linear (UNORM) format.
+    pub fn to_linear_rgba8(&self, color_space: &ColorSpace) -> [u8; 4] {
+        color_space.color_to_linear_rgba8(self)
+    }
+
     pub fn black() -> Color {
         Color::new_xyza(0.0, 0.0, 0.0, 1.0)
     }
@@ -81,6 +193,60 @@ impl Color {
         Color::new_xyza(0.950, 1.0, 1.089, 1.0)
     }
 
+    pub fn alpha(&self) -> f64 {
+        self.a
+    }
+
+    /// The same color with a different alpha.
+    pub fn with_alpha(&self, alpha: f64) -> Color {
+        Color { a: alpha, ..*self }
+    }
+
+    /// Creates a color by transforming this color's XYZ values. Alpha is unchanged.
+    pub fn map_xyz(&self, f: impl FnOnce(XYZ) -> XYZ) -> Color {
+        let xyz = f(self.xyz());
+        Color::new_xyza(xyz.X, xyz.Y, xyz.Z, self.a)
+    }
+
+    /// The relative luminance (the Y of XYZ), where 1.0 is the luminance of white.
+    pub fn luminance(&self) -> f64 {
+        self.Y
+    }
+
+    /// The WCAG contrast ratio between two colors, from 1.0 to 21.0.
+    /// Alpha is ignored.
+    pub fn contrast_ratio(&self, other: &Color) -> f64 {
+        let (a, b) = (self.Y.max(0.0) + 0.05, other.Y.max(0.0) + 0.05);
+        if a > b {
+            a / b
+        } else {
+            b / a
+        }
+    }
+
+    /// The color's chromaticity, relative to D50 like all colors.
+    /// Black has no chromaticity and returns NaN values.
+    pub fn chromaticity(&self) -> Chromaticity {
+        self.xyz().to_chromaticity()
+    }
+
+    /// Returns true if none of the color's values are infinite or NaN.
+    pub fn is_finite(&self) -> bool {
+        self.X.is_finite() && self.Y.is_finite() && self.Z.is_finite() && self.a.is_finite()
+    }
+
+    /// Returns true if each of the XYZ and alpha values of the two colors differ by at most `epsilon`.
+    pub fn approx_eq(&self, other: &Color, epsilon: f64) -> bool {
+        (self.X - other.X).abs() <= epsilon
+            && (self.Y - other.Y).abs() <= epsilon
+            && (self.Z - other.Z).abs() <= epsilon
+            && (self.a - other.a).abs() <= epsilon
+    }
+
+    pub(crate) fn xyz(&self) -> XYZ {
+        XYZ::new(self.X, self.Y, self.Z)
+    }
+
     /// Interpolates between two colors in XYZ color space.
     pub fn interpolate(&self, b: &Color, amount: f64) -> Color {
         Color {
@@ -91,3 +257,120 @@ impl Color {
         }
     }
 }
+
+/// The nearest sRGB color as `#rrggbb`, followed by `(out of sRGB gamut)` if the color was gamut mapped.
+struct SrgbApproximation<'a>(&'a Color);
+
+impl core::fmt::Display for SrgbApproximation<'_> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        let srgb = ColorSpace::SRGB;
+        let [r, g, b, _] = srgb.color_to_rgba8(&srgb.gamut_map(self.0));
+        write!(f, "#{:02x}{:02x}{:02x}", r, g, b)?;
+        if !srgb.contains(self.0) {
+            write!(f, " (out of sRGB gamut)")?;
+        }
+        Ok(())
+    }
+}
+
+impl core::fmt::Debug for Color {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        let alternate = f.alternate();
+        let mut debug = f.debug_struct("Color");
+        debug
+            .field("X", &self.X)
+            .field("Y", &self.Y)
+            .field("Z", &self.Z)
+            .field("a", &self.a);
+        if alternate {
+            debug.field("srgb", &format_args!("{}", SrgbApproximation(self)));
+        }
+        debug.finish()
+    }
+}
+
+/// Formats the color as its XYZ values, alpha, and the nearest sRGB color, for example:
+/// `XYZ(0.4360, 0.2225, 0.0139) alpha 1 ≈ #ff0000`.
+/// The precision of the XYZ values can be changed, like `{:.6}`.
+impl core::fmt::Display for Color {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        let precision = f.precision().unwrap_or(4);
+        write!(
+            f,
+            "XYZ({:.*}, {:.*}, {:.*}) alpha {} ≈ {}",
+            precision,
+            self.X,
+            precision,
+            self.Y,
+            precision,
+            self.Z,
+            self.a,
+            SrgbApproximation(self)
+        )
+    }
+}
+
+/// The color's XYZ values relative to D50. Alpha is discarded.
+impl From<Color> for XYZ {
+    fn from(color: Color) -> XYZ {
+        color.xyz()
+    }
+}
+
+// Arithmetic operates on XYZ values, which are linear light, so adding colors
+// is like adding light and scaling a color changes its brightness.
+// Alpha is treated as a fourth component: it's added, subtracted, and scaled along with XYZ,
+// so dividing a sum of colors by their count averages their alpha too.
+
+impl core::ops::Add for Color {
+    type Output = Color;
+
+    fn add(self, other: Color) -> Color {
+        Color {
+            X: self.X + other.X,
+            Y: self.Y + other.Y,
+            Z: self.Z + other.Z,
+            a: self.a + other.a,
+        }
+    }
+}
+
+impl core::ops::AddAssign for Color {
+    fn add_assign(&mut self, other: Color) {
+        *self = *self + other;
+    }
+}
+
+impl core::ops::Sub for Color {
+    type Output = Color;
+
+    fn sub(self, other: Color) -> Color {
+        Color {
+            X: self.X - other.X,
+            Y: self.Y - other.Y,
+            Z: self.Z - other.Z,
+            a: self.a - other.a,
+        }
+    }
+}
+
+impl core::ops::Mul<f64> for Color {
+    type Output = Color;
+
+    fn mul(self, scale: f64) -> Color {
+        Color {
+            X: self.X * scale,
+            Y: self.Y * scale,
+            Z: self.Z * scale,
+            a: self.a * scale,
+        }
+    }
+}
+
+impl core::ops::Div<f64> for Color {
+    type Output = Color;
+
+    fn div(self, divisor: f64) -> Color {
+        self * (1.0 / divisor)
+    }
+}
@@ -0,0 +1,1215 @@
+//! The reflectances of the standard samples used to evaluate how lights render colors.
+//!
+//! The data is from the CIE's datasets, from 380 to 780 nanometers in 5 nanometer steps.
+
+// Some measured values happen to be close to constants like 1/π.
+#![allow(clippy::approx_constant)]
+
+/// The wavelengths, in nanometers, of the values in `TEST_COLOR_SAMPLES`
+/// and `COLOR_EVALUATION_SAMPLES`. These are 380 to 780 nanometers in 5 nanometer steps.
+pub const SAMPLE_WAVELENGTHS: [f64; 81] = {
+    let mut wavelengths = [0.0; 81];
+    let mut i = 0;
+    while i < 81 {
+        wavelengths[i] = 380.0 + 5.0 * i as f64;
+        i += 1;
+    }
+    wavelengths
+};
+
+/// The spectral reflectances of the 14 CIE test color samples (TCS01 to TCS14) used by
+/// `color_rendering_index`, from CIE 13.3, sampled at `SAMPLE_WAVELENGTHS`.
+///
+/// The first eight are moderately saturated colors used for the general index (Ra).
+/// TCS09 to TCS14 are saturated red, yellow, green, and blue, a light skin tone, and leaf green.
+#[rustfmt::skip]
+pub static TEST_COLOR_SAMPLES: [[f64; 81]; 14] = [
+    [
+        0.219, 0.239, 0.252, 0.256, 0.256, 0.254, 0.252, 0.248, 0.244, 0.24, 0.237, 0.232, 0.23,
+        0.226, 0.225, 0.222, 0.22, 0.218, 0.216, 0.214, 0.214, 0.214, 0.216, 0.218, 0.223, 0.225,
+        0.226, 0.226, 0.225, 0.225, 0.227, 0.23, 0.236, 0.245, 0.253, 0.262, 0.272, 0.283, 0.298,
+        0.318, 0.341, 0.367, 0.39, 0.409, 0.424, 0.435, 0.442, 0.448, 0.45, 0.451, 0.451, 0.451,
+        0.451, 0.451, 0.45, 0.45, 0.451, 0.451, 0.453, 0.454, 0.455, 0.457, 0.458, 0.46, 0.462,
+        0.463, 0.464, 0.465, 0.466, 0.466, 0.466, 0.466, 0.467, 0.467, 0.467, 0.467, 0.467, 0.467,
+        0.467, 0.467, 0.467,
+    ],
+    [
+        0.07, 0.079, 0.089, 0.101, 0.111, 0.116, 0.118, 0.12, 0.121, 0.122, 0.122, 0.122, 0.123,
+        0.124, 0.127, 0.128, 0.131, 0.134, 0.138, 0.143, 0.15, 0.159, 0.174, 0.19, 0.207, 0.225,
+        0.242, 0.253, 0.26, 0.264, 0.267, 0.269, 0.272, 0.276, 0.282, 0.289, 0.299, 0.309, 0.322,
+        0.329, 0.335, 0.339, 0.341, 0.341, 0.342, 0.342, 0.342, 0.341, 0.341, 0.339, 0.339, 0.338,
+        0.338, 0.337, 0.336, 0.335, 0.334, 0.332, 0.332, 0.331, 0.331, 0.33, 0.329, 0.328, 0.328,
+        0.327, 0.326, 0.325, 0.324, 0.324, 0.324, 0.323, 0.322, 0.321, 0.32, 0.318, 0.316, 0.315,
+        0.315, 0.314, 0.314,
+    ],
+    [
+        0.065, 0.068, 0.07, 0.072, 0.073, 0.073, 0.074, 0.074, 0.074, 0.073, 0.073, 0.073, 0.073,
+        0.073, 0.074, 0.075, 0.077, 0.08, 0.085, 0.094, 0.109, 0.126, 0.148, 0.172, 0.198, 0.221,
+        0.241, 0.26, 0.278, 0.302, 0.339, 0.37, 0.392, 0.399, 0.4, 0.393, 0.38, 0.365, 0.349, 0.332,
+        0.315, 0.299, 0.285, 0.272, 0.264, 0.257, 0.252, 0.247, 0.241, 0.235, 0.229, 0.224, 0.22,
+        0.217, 0.216, 0.216, 0.219, 0.224, 0.23, 0.238, 0.251, 0.269, 0.288, 0.312, 0.34, 0.366,
+        0.39, 0.412, 0.431, 0.447, 0.46, 0.472, 0.481, 0.488, 0.493, 0.497, 0.5, 0.502, 0.505, 0.51,
+        0.516,
+    ],
+    [
+        0.074, 0.083, 0.093, 0.105, 0.116, 0.121, 0.124, 0.126, 0.128, 0.131, 0.135, 0.139, 0.144,
+        0.151, 0.161, 0.172, 0.186, 0.205, 0.229, 0.254, 0.281, 0.308, 0.332, 0.352, 0.37, 0.383,
+        0.39, 0.394, 0.395, 0.392, 0.385, 0.377, 0.367, 0.354, 0.341, 0.327, 0.312, 0.296, 0.28,
+        0.263, 0.247, 0.229, 0.214, 0.198, 0.185, 0.175, 0.169, 0.164, 0.16, 0.156, 0.154, 0.152,
+        0.151, 0.149, 0.148, 0.148, 0.148, 0.149, 0.151, 0.154, 0.158, 0.162, 0.165, 0.168, 0.17,
+        0.171, 0.17, 0.168, 0.166, 0.164, 0.164, 0.165, 0.168, 0.172, 0.177, 0.181, 0.185, 0.189,
+        0.192, 0.194, 0.197,
+    ],
+    [
+        0.295, 0.306, 0.31, 0.312, 0.313, 0.315, 0.319, 0.322, 0.326, 0.33, 0.334, 0.339, 0.346,
+        0.352, 0.36, 0.369, 0.381, 0.394, 0.403, 0.41, 0.415, 0.418, 0.419, 0.417, 0.413, 0.409,
+        0.403, 0.396, 0.389, 0.381, 0.372, 0.363, 0.353, 0.342, 0.331, 0.32, 0.308, 0.296, 0.284,
+        0.271, 0.26, 0.247, 0.232, 0.22, 0.21, 0.2, 0.194, 0.189, 0.185, 0.183, 0.18, 0.177, 0.176,
+        0.175, 0.175, 0.175, 0.175, 0.177, 0.18, 0.183, 0.186, 0.189, 0.192, 0.195, 0.199, 0.2,
+        0.199, 0.198, 0.196, 0.195, 0.195, 0.196, 0.197, 0.2, 0.203, 0.205, 0.208, 0.212, 0.215,
+        0.217, 0.219,
+    ],
+    [
+        0.151, 0.203, 0.265, 0.339, 0.41, 0.464, 0.492, 0.508, 0.517, 0.524, 0.531, 0.538, 0.544,
+        0.551, 0.556, 0.556, 0.554, 0.549, 0.541, 0.531, 0.519, 0.504, 0.488, 0.469, 0.45, 0.431,
+        0.414, 0.395, 0.377, 0.358, 0.341, 0.325, 0.309, 0.293, 0.279, 0.265, 0.253, 0.241, 0.234,
+        0.227, 0.225, 0.222, 0.221, 0.22, 0.22, 0.22, 0.22, 0.22, 0.223, 0.227, 0.233, 0.239, 0.244,
+        0.251, 0.258, 0.263, 0.268, 0.273, 0.278, 0.281, 0.283, 0.286, 0.291, 0.296, 0.302, 0.313,
+        0.325, 0.338, 0.351, 0.364, 0.376, 0.389, 0.401, 0.413, 0.425, 0.436, 0.447, 0.458, 0.469,
+        0.477, 0.485,
+    ],
+    [
+        0.378, 0.459, 0.524, 0.546, 0.551, 0.555, 0.559, 0.56, 0.561, 0.558, 0.556, 0.551, 0.544,
+        0.535, 0.522, 0.506, 0.488, 0.469, 0.448, 0.429, 0.408, 0.385, 0.363, 0.341, 0.324, 0.311,
+        0.301, 0.291, 0.283, 0.273, 0.265, 0.26, 0.257, 0.257, 0.259, 0.26, 0.26, 0.258, 0.256,
+        0.254, 0.254, 0.259, 0.27, 0.284, 0.302, 0.324, 0.344, 0.362, 0.377, 0.389, 0.4, 0.41, 0.42,
+        0.429, 0.438, 0.445, 0.452, 0.457, 0.462, 0.466, 0.468, 0.47, 0.473, 0.477, 0.483, 0.489,
+        0.496, 0.503, 0.511, 0.518, 0.525, 0.532, 0.539, 0.546, 0.553, 0.559, 0.565, 0.57, 0.575,
+        0.578, 0.581,
+    ],
+    [
+        0.104, 0.129, 0.17, 0.24, 0.319, 0.416, 0.462, 0.482, 0.49, 0.488, 0.482, 0.473, 0.462,
+        0.45, 0.439, 0.426, 0.413, 0.397, 0.382, 0.366, 0.352, 0.337, 0.325, 0.31, 0.299, 0.289,
+        0.283, 0.276, 0.27, 0.262, 0.256, 0.251, 0.25, 0.251, 0.254, 0.258, 0.264, 0.269, 0.272,
+        0.274, 0.278, 0.284, 0.295, 0.316, 0.348, 0.384, 0.434, 0.482, 0.528, 0.568, 0.604, 0.629,
+        0.648, 0.663, 0.676, 0.685, 0.693, 0.7, 0.705, 0.709, 0.712, 0.715, 0.717, 0.719, 0.721,
+        0.72, 0.719, 0.722, 0.725, 0.727, 0.729, 0.73, 0.73, 0.73, 0.73, 0.73, 0.73, 0.73, 0.73,
+        0.73, 0.73,
+    ],
+    [
+        0.066, 0.062, 0.058, 0.055, 0.052, 0.052, 0.051, 0.05, 0.05, 0.049, 0.048, 0.047, 0.046,
+        0.044, 0.042, 0.041, 0.038, 0.035, 0.033, 0.031, 0.03, 0.029, 0.028, 0.028, 0.028, 0.029,
+        0.03, 0.03, 0.031, 0.031, 0.032, 0.032, 0.033, 0.034, 0.035, 0.037, 0.041, 0.044, 0.048,
+        0.052, 0.06, 0.076, 0.102, 0.136, 0.19, 0.256, 0.336, 0.418, 0.505, 0.581, 0.641, 0.682,
+        0.717, 0.74, 0.758, 0.77, 0.781, 0.79, 0.797, 0.803, 0.809, 0.814, 0.819, 0.824, 0.828,
+        0.83, 0.831, 0.833, 0.835, 0.836, 0.836, 0.837, 0.838, 0.839, 0.839, 0.839, 0.839, 0.839,
+        0.839, 0.839, 0.839,
+    ],
+    [
+        0.05, 0.054, 0.059, 0.063, 0.066, 0.067, 0.068, 0.069, 0.069, 0.07, 0.072, 0.073, 0.076,
+        0.078, 0.083, 0.088, 0.095, 0.103, 0.113, 0.125, 0.142, 0.162, 0.189, 0.219, 0.262, 0.305,
+        0.365, 0.416, 0.465, 0.509, 0.546, 0.581, 0.61, 0.634, 0.653, 0.666, 0.678, 0.687, 0.693,
+        0.698, 0.701, 0.704, 0.705, 0.705, 0.706, 0.707, 0.707, 0.707, 0.708, 0.708, 0.71, 0.711,
+        0.712, 0.714, 0.716, 0.718, 0.72, 0.722, 0.725, 0.729, 0.731, 0.735, 0.739, 0.742, 0.746,
+        0.748, 0.749, 0.751, 0.753, 0.754, 0.755, 0.755, 0.755, 0.755, 0.756, 0.757, 0.758, 0.759,
+        0.759, 0.759, 0.759,
+    ],
+    [
+        0.111, 0.121, 0.127, 0.129, 0.127, 0.121, 0.116, 0.112, 0.108, 0.105, 0.104, 0.104, 0.105,
+        0.106, 0.11, 0.115, 0.123, 0.134, 0.148, 0.167, 0.192, 0.219, 0.252, 0.291, 0.325, 0.347,
+        0.356, 0.353, 0.346, 0.333, 0.314, 0.294, 0.271, 0.248, 0.227, 0.206, 0.188, 0.17, 0.153,
+        0.138, 0.125, 0.114, 0.106, 0.1, 0.096, 0.092, 0.09, 0.087, 0.085, 0.082, 0.08, 0.079,
+        0.078, 0.078, 0.078, 0.078, 0.081, 0.083, 0.088, 0.093, 0.102, 0.112, 0.125, 0.141, 0.161,
+        0.182, 0.203, 0.223, 0.242, 0.257, 0.27, 0.282, 0.292, 0.302, 0.31, 0.314, 0.317, 0.323,
+        0.33, 0.334, 0.338,
+    ],
+    [
+        0.12, 0.103, 0.09, 0.082, 0.076, 0.068, 0.064, 0.065, 0.075, 0.093, 0.123, 0.16, 0.207,
+        0.256, 0.3, 0.331, 0.346, 0.347, 0.341, 0.328, 0.307, 0.282, 0.257, 0.23, 0.204, 0.178,
+        0.154, 0.129, 0.109, 0.09, 0.075, 0.062, 0.051, 0.041, 0.035, 0.029, 0.025, 0.022, 0.019,
+        0.017, 0.017, 0.017, 0.016, 0.016, 0.016, 0.016, 0.016, 0.016, 0.016, 0.016, 0.018, 0.018,
+        0.018, 0.018, 0.019, 0.02, 0.023, 0.024, 0.026, 0.03, 0.035, 0.043, 0.056, 0.074, 0.097,
+        0.128, 0.166, 0.21, 0.257, 0.305, 0.354, 0.401, 0.446, 0.485, 0.52, 0.551, 0.577, 0.599,
+        0.618, 0.633, 0.645,
+    ],
+    [
+        0.104, 0.127, 0.161, 0.211, 0.264, 0.313, 0.341, 0.352, 0.359, 0.361, 0.364, 0.365, 0.367,
+        0.369, 0.372, 0.374, 0.376, 0.379, 0.384, 0.389, 0.397, 0.405, 0.416, 0.429, 0.443, 0.454,
+        0.461, 0.466, 0.469, 0.471, 0.474, 0.476, 0.483, 0.49, 0.506, 0.526, 0.553, 0.582, 0.618,
+        0.651, 0.68, 0.701, 0.717, 0.729, 0.736, 0.742, 0.745, 0.747, 0.748, 0.748, 0.748, 0.748,
+        0.748, 0.748, 0.748, 0.748, 0.747, 0.747, 0.747, 0.747, 0.747, 0.747, 0.747, 0.746, 0.746,
+        0.746, 0.745, 0.744, 0.743, 0.744, 0.745, 0.748, 0.75, 0.75, 0.749, 0.748, 0.748, 0.747,
+        0.747, 0.747, 0.747,
+    ],
+    [
+        0.036, 0.036, 0.037, 0.038, 0.039, 0.039, 0.04, 0.041, 0.042, 0.042, 0.043, 0.044, 0.044,
+        0.045, 0.045, 0.046, 0.047, 0.048, 0.05, 0.052, 0.055, 0.057, 0.062, 0.067, 0.075, 0.083,
+        0.092, 0.1, 0.108, 0.121, 0.133, 0.142, 0.15, 0.154, 0.155, 0.152, 0.147, 0.14, 0.133,
+        0.125, 0.118, 0.112, 0.106, 0.101, 0.098, 0.095, 0.093, 0.09, 0.089, 0.087, 0.086, 0.085,
+        0.084, 0.084, 0.084, 0.084, 0.085, 0.087, 0.092, 0.096, 0.102, 0.11, 0.123, 0.137, 0.152,
+        0.169, 0.188, 0.207, 0.226, 0.243, 0.26, 0.277, 0.294, 0.31, 0.325, 0.339, 0.353, 0.366,
+        0.379, 0.39, 0.399,
+    ],
+];
+
+/// The spectral reflectances of the 99 color evaluation samples (CES) used by `tm30`,
+/// from ANSI/IES TM-30-20 and CIE 224:2017, sampled at `SAMPLE_WAVELENGTHS`.
+#[rustfmt::skip]
+pub static COLOR_EVALUATION_SAMPLES: [[f64; 81]; 99] = [
+    [
+        0.6359, 0.6359, 0.6359, 0.6359, 0.6359, 0.64015, 0.6454, 0.64888, 0.6507, 0.65147, 0.6508,
+        0.64823, 0.644, 0.63825, 0.6301, 0.61895, 0.6063, 0.59361, 0.5802, 0.56548, 0.5515, 0.54016,
+        0.53, 0.51942, 0.5095, 0.50163, 0.4958, 0.49171, 0.4894, 0.48884, 0.4894, 0.49068, 0.4938,
+        0.50011, 0.5103, 0.52488, 0.5442, 0.56832, 0.5963, 0.62679, 0.6578, 0.68743, 0.7149,
+        0.73974, 0.7617, 0.78072, 0.7972, 0.81156, 0.8239, 0.83427, 0.8428, 0.84971, 0.8556,
+        0.86098, 0.8657, 0.86955, 0.8728, 0.87578, 0.8785, 0.88094, 0.8833, 0.88579, 0.8884,
+        0.89091, 0.8926, 0.8926, 0.8926, 0.8926, 0.8926, 0.8926, 0.8926, 0.8926, 0.8926, 0.8926,
+        0.8926, 0.8926, 0.8926, 0.8926, 0.8926, 0.8926, 0.8926,
+    ],
+    [
+        0.2615, 0.2615, 0.2615, 0.2615, 0.2615, 0.26409, 0.267, 0.26834, 0.2684, 0.26779, 0.2663,
+        0.26349, 0.259, 0.25241, 0.2431, 0.23086, 0.2175, 0.20471, 0.1918, 0.17813, 0.1656, 0.15602,
+        0.1481, 0.14026, 0.1327, 0.12604, 0.1209, 0.11769, 0.1161, 0.11569, 0.1161, 0.1171, 0.1187,
+        0.12114, 0.1252, 0.13198, 0.1433, 0.16092, 0.1855, 0.21707, 0.2542, 0.29505, 0.3376,
+        0.37991, 0.4205, 0.45828, 0.4932, 0.52533, 0.554, 0.57872, 0.6003, 0.61963, 0.6367, 0.65151,
+        0.6651, 0.6785, 0.6916, 0.70422, 0.7171, 0.7305, 0.742, 0.74976, 0.7571, 0.76684, 0.7746,
+        0.7746, 0.7746, 0.7746, 0.7746, 0.7746, 0.7746, 0.7746, 0.7746, 0.7746, 0.7746, 0.7746,
+        0.7746, 0.7746, 0.7746, 0.7746, 0.7746,
+    ],
+    [
+        1e-05, 0.00663, 0.01694, 0.01773, 0.01167, 0.00511, 0.00379, 0.00874, 0.01888, 0.02972,
+        0.04199, 0.04896, 0.05457, 0.05674, 0.0541, 0.05487, 0.05908, 0.06075, 0.06259, 0.06304,
+        0.06069, 0.05967, 0.05944, 0.05601, 0.05392, 0.05202, 0.04895, 0.04762, 0.04647, 0.04398,
+        0.04102, 0.04046, 0.04187, 0.04197, 0.04123, 0.04185, 0.04305, 0.04484, 0.04429, 0.04632,
+        0.04914, 0.05151, 0.05305, 0.05463, 0.05863, 0.06604, 0.07427, 0.08516, 0.0979, 0.10928,
+        0.12064, 0.12749, 0.13322, 0.14094, 0.15003, 0.15656, 0.16207, 0.17095, 0.18004, 0.18664,
+        0.18611, 0.1865, 0.19529, 0.2021, 0.20393, 0.21328, 0.21966, 0.22617, 0.23281, 0.23959,
+        0.24651, 0.25356, 0.26073, 0.26804, 0.27548, 0.28305, 0.29074, 0.29855, 0.30648, 0.31452,
+        0.32268,
+    ],
+    [
+        0.47376, 0.47793, 0.46406, 0.44102, 0.42157, 0.40559, 0.3895, 0.37567, 0.36501, 0.35611,
+        0.34936, 0.342, 0.33382, 0.32411, 0.31282, 0.29816, 0.27982, 0.26042, 0.24078, 0.22206,
+        0.20462, 0.18884, 0.17488, 0.16337, 0.1556, 0.1519, 0.15143, 0.15412, 0.16036, 0.17075,
+        0.18586, 0.20558, 0.22892, 0.25477, 0.28272, 0.31202, 0.342, 0.37116, 0.39782, 0.42123,
+        0.44136, 0.45795, 0.47105, 0.48107, 0.48842, 0.49386, 0.49824, 0.50229, 0.50567, 0.50819,
+        0.51009, 0.51142, 0.51262, 0.51402, 0.51522, 0.51566, 0.51527, 0.5141, 0.51322, 0.51343,
+        0.51337, 0.51311, 0.51261, 0.51208, 0.51191, 0.51291, 0.51485, 0.51667, 0.51849, 0.52041,
+        0.52228, 0.52415, 0.52602, 0.52789, 0.52976, 0.53163, 0.5335, 0.53537, 0.53723, 0.5391,
+        0.54096,
+    ],
+    [
+        0.13351, 0.1354, 0.13565, 0.1354, 0.13579, 0.13757, 0.14001, 0.14195, 0.14194, 0.13887,
+        0.13327, 0.12595, 0.11738, 0.10794, 0.09819, 0.08868, 0.07978, 0.07182, 0.06508, 0.05976,
+        0.05586, 0.05323, 0.0514, 0.04991, 0.04869, 0.04783, 0.04761, 0.04831, 0.05011, 0.05302,
+        0.05654, 0.06008, 0.06335, 0.06628, 0.06946, 0.07376, 0.08059, 0.09115, 0.10539, 0.12247,
+        0.13976, 0.15471, 0.16698, 0.17763, 0.19118, 0.21263, 0.24557, 0.29221, 0.35063, 0.41726,
+        0.48613, 0.5514, 0.61007, 0.66051, 0.7037, 0.74105, 0.77303, 0.79995, 0.82248, 0.84133,
+        0.85712, 0.8705, 0.88222, 0.89292, 0.90256, 0.91091, 0.91776, 0.92304, 0.92737, 0.93156,
+        0.93639, 0.93985, 0.94353, 0.947, 0.95027, 0.95335, 0.95625, 0.95897, 0.96153, 0.96394,
+        0.9662,
+    ],
+    [
+        0.04525, 0.05337, 0.06285, 0.07388, 0.08249, 0.10382, 0.11965, 0.13053, 0.13699, 0.13975,
+        0.14015, 0.13946, 0.13788, 0.13541, 0.13217, 0.12835, 0.12428, 0.12021, 0.116, 0.11157,
+        0.10743, 0.10413, 0.10171, 0.09999, 0.09856, 0.09712, 0.096, 0.09563, 0.09619, 0.09777,
+        0.10033, 0.10388, 0.10861, 0.11472, 0.12221, 0.13104, 0.14123, 0.15275, 0.16518, 0.17796,
+        0.19031, 0.20157, 0.2116, 0.2203, 0.22708, 0.23139, 0.23328, 0.23318, 0.2323, 0.23215,
+        0.23457, 0.24132, 0.25369, 0.27287, 0.3003, 0.33695, 0.38151, 0.43149, 0.48165, 0.5272,
+        0.56789, 0.6043, 0.63589, 0.66181, 0.68123, 0.70786, 0.73017, 0.75138, 0.77145, 0.79034,
+        0.80806, 0.82462, 0.84003, 0.85433, 0.86754, 0.87973, 0.89094, 0.90122, 0.91063, 0.91923,
+        0.92706,
+    ],
+    [
+        0.0583, 0.0583, 0.0583, 0.0583, 0.0583, 0.05786, 0.0573, 0.05692, 0.0568, 0.05694, 0.0572,
+        0.05744, 0.0576, 0.05763, 0.0573, 0.05646, 0.0553, 0.05406, 0.0529, 0.05192, 0.0511,
+        0.05039, 0.0498, 0.04936, 0.049, 0.04866, 0.0484, 0.04828, 0.0482, 0.04807, 0.0479, 0.04775,
+        0.0477, 0.0478, 0.048, 0.04829, 0.0488, 0.04973, 0.0513, 0.0539, 0.0585, 0.06636, 0.0792,
+        0.09894, 0.1277, 0.16683, 0.2143, 0.26677, 0.319, 0.3658, 0.404, 0.43172, 0.4503, 0.46188,
+        0.4686, 0.4723, 0.4736, 0.47296, 0.4715, 0.4703, 0.4695, 0.46891, 0.4679, 0.46607, 0.4645,
+        0.4645, 0.4645, 0.4645, 0.4645, 0.4645, 0.4645, 0.4645, 0.4645, 0.4645, 0.4645, 0.4645,
+        0.4645, 0.4645, 0.4645, 0.4645, 0.4645,
+    ],
+    [
+        0.05818, 0.05961, 0.05984, 0.05941, 0.05886, 0.05858, 0.05836, 0.05797, 0.05769, 0.05777,
+        0.05795, 0.0579, 0.05764, 0.05729, 0.05707, 0.05715, 0.05725, 0.05711, 0.05685, 0.05663,
+        0.05647, 0.05633, 0.05609, 0.05569, 0.05529, 0.05505, 0.05496, 0.05495, 0.05491, 0.05477,
+        0.05459, 0.05446, 0.05441, 0.05442, 0.05444, 0.05444, 0.05458, 0.05501, 0.05571, 0.05682,
+        0.05915, 0.06379, 0.07216, 0.08577, 0.10608, 0.13417, 0.16962, 0.2113, 0.25684, 0.30366,
+        0.34959, 0.39266, 0.43125, 0.46417, 0.4916, 0.51406, 0.5322, 0.54676, 0.5587, 0.56897,
+        0.57812, 0.58662, 0.59483, 0.60303, 0.61109, 0.61878, 0.62574, 0.63173, 0.63709, 0.64228,
+        0.64779, 0.65285, 0.65804, 0.66319, 0.6683, 0.67337, 0.6784, 0.68339, 0.68834, 0.69325,
+        0.69811,
+    ],
+    [
+        0.04157, 0.04172, 0.04187, 0.04202, 0.04225, 0.04229, 0.04246, 0.04275, 0.04318, 0.04374,
+        0.04441, 0.04516, 0.04596, 0.04681, 0.04771, 0.04862, 0.04936, 0.04981, 0.05008, 0.05044,
+        0.05142, 0.0534, 0.05585, 0.05805, 0.05925, 0.05899, 0.05771, 0.05602, 0.0542, 0.0524,
+        0.0506, 0.04872, 0.04668, 0.04446, 0.04225, 0.04038, 0.03936, 0.0396, 0.04081, 0.04258,
+        0.04483, 0.0475, 0.05039, 0.05323, 0.05554, 0.05696, 0.05771, 0.05813, 0.05843, 0.0587,
+        0.05894, 0.05917, 0.05966, 0.06065, 0.06193, 0.0632, 0.0642, 0.06474, 0.06492, 0.06489,
+        0.06471, 0.06441, 0.06399, 0.06344, 0.06276, 0.06228, 0.06173, 0.06118, 0.06064, 0.0601,
+        0.05957, 0.05904, 0.05852, 0.058, 0.05748, 0.05697, 0.05646, 0.05596, 0.05546, 0.05496,
+        0.05447,
+    ],
+    [
+        0.19983, 0.19983, 0.19983, 0.19983, 0.19983, 0.20628, 0.21152, 0.20944, 0.20352, 0.1985,
+        0.19473, 0.19149, 0.18842, 0.18529, 0.18231, 0.17978, 0.17804, 0.17718, 0.17637, 0.17467,
+        0.17192, 0.16834, 0.16488, 0.1625, 0.16135, 0.1612, 0.16111, 0.16047, 0.16075, 0.16357,
+        0.16904, 0.17689, 0.18673, 0.1985, 0.21355, 0.23345, 0.25938, 0.29341, 0.34157, 0.4083,
+        0.48749, 0.57053, 0.64908, 0.71596, 0.7683, 0.80508, 0.82849, 0.84185, 0.84979, 0.85637,
+        0.86217, 0.86695, 0.87064, 0.87337, 0.87575, 0.87822, 0.88011, 0.88074, 0.88075, 0.88086,
+        0.88083, 0.88044, 0.8805, 0.88167, 0.88291, 0.88291, 0.88291, 0.88291, 0.88291, 0.88291,
+        0.88291, 0.88291, 0.88291, 0.88291, 0.88291, 0.88291, 0.88291, 0.88291, 0.88291, 0.88291,
+        0.88291,
+    ],
+    [
+        0.03951, 0.04449, 0.05006, 0.05629, 0.06081, 0.07233, 0.08032, 0.08522, 0.08742, 0.08748,
+        0.08644, 0.0852, 0.08358, 0.08121, 0.07806, 0.07423, 0.06998, 0.06554, 0.06091, 0.05612,
+        0.05174, 0.04833, 0.04593, 0.04441, 0.04337, 0.04243, 0.04169, 0.04131, 0.04139, 0.04207,
+        0.04366, 0.04679, 0.05302, 0.06419, 0.0822, 0.10861, 0.1434, 0.18552, 0.23131, 0.27651,
+        0.31696, 0.34933, 0.37353, 0.39051, 0.40201, 0.40996, 0.41601, 0.42172, 0.42833, 0.43684,
+        0.44755, 0.46045, 0.47495, 0.49066, 0.50855, 0.52962, 0.55369, 0.57982, 0.60514, 0.62726,
+        0.64752, 0.6676, 0.68675, 0.7036, 0.71681, 0.73386, 0.74863, 0.76285, 0.7765, 0.78958,
+        0.80209, 0.81403, 0.82541, 0.83623, 0.84651, 0.85625, 0.86547, 0.87419, 0.88242, 0.89018,
+        0.89748,
+    ],
+    [
+        0.24167, 0.21622, 0.18687, 0.16204, 0.14514, 0.12981, 0.11536, 0.10584, 0.1043, 0.09871,
+        0.09483, 0.09767, 0.09829, 0.09474, 0.09395, 0.09191, 0.08603, 0.08133, 0.07541, 0.06828,
+        0.06159, 0.05719, 0.05416, 0.05087, 0.04867, 0.04995, 0.05235, 0.05565, 0.0598, 0.06338,
+        0.07119, 0.08203, 0.09658, 0.11374, 0.1325, 0.15516, 0.18331, 0.21658, 0.25334, 0.29135,
+        0.33392, 0.37966, 0.42691, 0.47137, 0.5126, 0.54874, 0.5796, 0.60302, 0.6223, 0.63769,
+        0.64759, 0.65705, 0.66606, 0.67239, 0.67883, 0.67562, 0.67555, 0.67885, 0.67821, 0.67843,
+        0.68161, 0.68412, 0.68273, 0.68325, 0.68676, 0.68639, 0.68745, 0.68851, 0.68957, 0.69063,
+        0.69169, 0.69275, 0.6938, 0.69485, 0.6959, 0.69695, 0.69799, 0.69903, 0.70007, 0.70111,
+        0.70215,
+    ],
+    [
+        0.04337, 0.04333, 0.04328, 0.04324, 0.04327, 0.04311, 0.04307, 0.0432, 0.04357, 0.04419,
+        0.04496, 0.04572, 0.04625, 0.04641, 0.04644, 0.04655, 0.04635, 0.04557, 0.04496, 0.04556,
+        0.04863, 0.05497, 0.06332, 0.07191, 0.07909, 0.08373, 0.08654, 0.08841, 0.08912, 0.08836,
+        0.08654, 0.08414, 0.08118, 0.07767, 0.07423, 0.07149, 0.06947, 0.06819, 0.06818, 0.07069,
+        0.07949, 0.09754, 0.12216, 0.14933, 0.17516, 0.19672, 0.21476, 0.23037, 0.24254, 0.2503,
+        0.25485, 0.25781, 0.26021, 0.26266, 0.26487, 0.26641, 0.26735, 0.26785, 0.26795, 0.2677,
+        0.26735, 0.26713, 0.26696, 0.26668, 0.26616, 0.26598, 0.26566, 0.26535, 0.26503, 0.26471,
+        0.2644, 0.26408, 0.26376, 0.26345, 0.26313, 0.26282, 0.2625, 0.26219, 0.26187, 0.26156,
+        0.26124,
+    ],
+    [
+        0.3414, 0.3414, 0.3414, 0.3414, 0.3414, 0.34301, 0.3451, 0.34676, 0.3482, 0.34976, 0.3513,
+        0.35261, 0.3538, 0.35499, 0.3562, 0.35738, 0.3585, 0.35955, 0.3607, 0.36205, 0.3634,
+        0.36454, 0.3656, 0.36676, 0.3682, 0.37009, 0.3727, 0.37625, 0.3808, 0.38614, 0.3911,
+        0.39457, 0.3966, 0.39753, 0.3976, 0.39728, 0.3979, 0.40103, 0.4083, 0.42058, 0.4355,
+        0.45009, 0.4622, 0.47043, 0.4756, 0.47895, 0.4812, 0.48286, 0.4841, 0.48498, 0.4856,
+        0.48607, 0.4867, 0.48768, 0.4887, 0.48941, 0.4898, 0.48999, 0.4903, 0.49098, 0.4919,
+        0.49283, 0.4937, 0.49444, 0.4949, 0.4949, 0.4949, 0.4949, 0.4949, 0.4949, 0.4949, 0.4949,
+        0.4949, 0.4949, 0.4949, 0.4949, 0.4949, 0.4949, 0.4949, 0.4949, 0.4949,
+    ],
+    [
+        0.16992, 0.17295, 0.17602, 0.17913, 0.18141, 0.18593, 0.18883, 0.19137, 0.19479, 0.20029,
+        0.2089, 0.22103, 0.23492, 0.24851, 0.26083, 0.27135, 0.28027, 0.28799, 0.2948, 0.30093,
+        0.3066, 0.31208, 0.318, 0.32476, 0.33168, 0.33767, 0.34119, 0.34098, 0.33712, 0.33041,
+        0.32312, 0.31756, 0.31476, 0.31513, 0.3181, 0.32241, 0.3251, 0.32407, 0.32228, 0.32449,
+        0.33775, 0.36682, 0.40525, 0.44457, 0.47934, 0.50614, 0.52637, 0.54227, 0.55469, 0.56419,
+        0.57172, 0.5782, 0.58395, 0.58911, 0.59367, 0.59765, 0.6015, 0.60563, 0.60997, 0.61431,
+        0.61843, 0.62214, 0.62533, 0.62795, 0.62993, 0.63284, 0.63536, 0.63788, 0.64039, 0.64289,
+        0.64538, 0.64787, 0.65035, 0.65282, 0.65528, 0.65773, 0.66018, 0.66262, 0.66504, 0.66746,
+        0.66987,
+    ],
+    [
+        0.03466, 0.03537, 0.03609, 0.03682, 0.03767, 0.03829, 0.0391, 0.04003, 0.04104, 0.04212,
+        0.04349, 0.04531, 0.04737, 0.04944, 0.05166, 0.05419, 0.05696, 0.05989, 0.06299, 0.06628,
+        0.06972, 0.07323, 0.07667, 0.07996, 0.0832, 0.08647, 0.08973, 0.09289, 0.09576, 0.09824,
+        0.10056, 0.10293, 0.10535, 0.10777, 0.11015, 0.1125, 0.11485, 0.1173, 0.12026, 0.12415,
+        0.12934, 0.13622, 0.14527, 0.15696, 0.1714, 0.18852, 0.20764, 0.2281, 0.2497, 0.27223,
+        0.29483, 0.31669, 0.3378, 0.35841, 0.37905, 0.40003, 0.42039, 0.43917, 0.45673, 0.47371,
+        0.49042, 0.50703, 0.52319, 0.53846, 0.55239, 0.56804, 0.58283, 0.59748, 0.61196, 0.62624,
+        0.6403, 0.65413, 0.6677, 0.681, 0.69401, 0.70672, 0.71912, 0.73119, 0.74292, 0.75432,
+        0.76537,
+    ],
+    [
+        0.0308, 0.0331, 0.03556, 0.0382, 0.04031, 0.04443, 0.04751, 0.04967, 0.05105, 0.05186,
+        0.05253, 0.05346, 0.0547, 0.05623, 0.05805, 0.06016, 0.06249, 0.06492, 0.06732, 0.06952,
+        0.07145, 0.07305, 0.07441, 0.07559, 0.07648, 0.07701, 0.07747, 0.07817, 0.07924, 0.08086,
+        0.08358, 0.08811, 0.09531, 0.1058, 0.11916, 0.1345, 0.1504, 0.16541, 0.17859, 0.18928,
+        0.19761, 0.20396, 0.20914, 0.21381, 0.21771, 0.22041, 0.22166, 0.22146, 0.22077, 0.22081,
+        0.22303, 0.22881, 0.239, 0.25451, 0.27704, 0.308, 0.34672, 0.39138, 0.4373, 0.48016,
+        0.51979, 0.55666, 0.58977, 0.61775, 0.63924, 0.66823, 0.69292, 0.71656, 0.73905, 0.76037,
+        0.78045, 0.7993, 0.81691, 0.8333, 0.84849, 0.86252, 0.87545, 0.88732, 0.89819, 0.90812,
+        0.91717,
+    ],
+    [
+        0.0731, 0.0731, 0.0731, 0.0731, 0.0731, 0.07538, 0.0785, 0.0813, 0.0841, 0.08752, 0.0918,
+        0.09696, 0.1025, 0.10789, 0.1129, 0.11747, 0.1218, 0.12611, 0.1304, 0.13466, 0.139, 0.14355,
+        0.1484, 0.15359, 0.159, 0.16433, 0.1688, 0.17166, 0.1728, 0.17246, 0.1716, 0.17132, 0.1725,
+        0.17559, 0.1796, 0.18329, 0.1858, 0.18685, 0.1881, 0.1917, 0.1999, 0.21409, 0.2322, 0.2514,
+        0.2694, 0.2845, 0.2969, 0.3072, 0.3157, 0.32272, 0.3289, 0.33486, 0.3408, 0.34674, 0.3524,
+        0.35758, 0.3626, 0.36783, 0.3732, 0.37853, 0.3836, 0.38829, 0.393, 0.39783, 0.4012, 0.4012,
+        0.4012, 0.4012, 0.4012, 0.4012, 0.4012, 0.4012, 0.4012, 0.4012, 0.4012, 0.4012, 0.4012,
+        0.4012, 0.4012, 0.4012, 0.4012,
+    ],
+    [
+        0.1202, 0.1202, 0.1202, 0.1202, 0.1202, 0.12316, 0.1259, 0.12589, 0.1245, 0.12359, 0.1232,
+        0.12298, 0.123, 0.12334, 0.1238, 0.12417, 0.1245, 0.12484, 0.1251, 0.12518, 0.1251, 0.12501,
+        0.1256, 0.12801, 0.1348, 0.14805, 0.1664, 0.18711, 0.2052, 0.21662, 0.2233, 0.22905, 0.2393,
+        0.25936, 0.2925, 0.33928, 0.3914, 0.43925, 0.4769, 0.50097, 0.5146, 0.5221, 0.526, 0.5281,
+        0.529, 0.52902, 0.5286, 0.5281, 0.5275, 0.52676, 0.526, 0.52534, 0.5246, 0.52361, 0.5226,
+        0.52181, 0.5211, 0.52029, 0.5195, 0.51887, 0.5182, 0.51729, 0.5162, 0.51512, 0.5144, 0.5144,
+        0.5144, 0.51444, 0.5144, 0.5144, 0.5144, 0.5144, 0.5144, 0.5144, 0.5144, 0.5144, 0.5144,
+        0.5144, 0.5144, 0.5144, 0.5144,
+    ],
+    [
+        0.02509, 0.02701, 0.02908, 0.03129, 0.03197, 0.03714, 0.03952, 0.03916, 0.03614, 0.03094,
+        0.02572, 0.02242, 0.02035, 0.01859, 0.01787, 0.01943, 0.02472, 0.03577, 0.0565, 0.08996,
+        0.13394, 0.18288, 0.2232, 0.2432, 0.24664, 0.2407, 0.23085, 0.22122, 0.21248, 0.20455,
+        0.19769, 0.19234, 0.18925, 0.18913, 0.19252, 0.19974, 0.21059, 0.2247, 0.24147, 0.26062,
+        0.28317, 0.31075, 0.34583, 0.39045, 0.44412, 0.50458, 0.56506, 0.61852, 0.66127, 0.69166,
+        0.7127, 0.72818, 0.74021, 0.75035, 0.75977, 0.76939, 0.77972, 0.79098, 0.80256, 0.81379,
+        0.8244, 0.83423, 0.84297, 0.8503, 0.85588, 0.86331, 0.86964, 0.87572, 0.88156, 0.88715,
+        0.89252, 0.89765, 0.90257, 0.90728, 0.91178, 0.91609, 0.9202, 0.92413, 0.92788, 0.93146,
+        0.93487,
+    ],
+    [
+        0.12356, 0.13071, 0.13822, 0.14608, 0.14874, 0.16578, 0.17316, 0.17444, 0.17316, 0.1722,
+        0.17167, 0.17114, 0.17068, 0.17047, 0.17068, 0.1715, 0.17316, 0.17586, 0.17962, 0.1843,
+        0.18935, 0.19425, 0.19927, 0.20499, 0.21238, 0.22278, 0.23849, 0.26174, 0.2934, 0.33353,
+        0.38028, 0.43126, 0.48394, 0.53577, 0.58432, 0.62791, 0.66763, 0.70476, 0.73852, 0.76792,
+        0.79343, 0.81553, 0.83334, 0.84628, 0.85638, 0.86565, 0.87355, 0.87932, 0.88378, 0.88789,
+        0.89152, 0.89442, 0.89689, 0.89928, 0.90145, 0.90319, 0.90443, 0.90521, 0.90572, 0.90617,
+        0.90672, 0.90745, 0.9083, 0.90916, 0.90989, 0.91076, 0.91157, 0.91237, 0.91316, 0.91394,
+        0.91472, 0.9155, 0.91626, 0.91703, 0.91778, 0.91853, 0.91927, 0.92001, 0.92074, 0.92146,
+        0.92218,
+    ],
+    [
+        0.00012, 0.01062, 0.02084, 0.03107, 0.04157, 0.05232, 0.06229, 0.07033, 0.07607, 0.07961,
+        0.08233, 0.08564, 0.08976, 0.09456, 0.09986, 0.10554, 0.11177, 0.11877, 0.12662, 0.13529,
+        0.1444, 0.15365, 0.16337, 0.17397, 0.18569, 0.19876, 0.21356, 0.23039, 0.24906, 0.26935,
+        0.29154, 0.31594, 0.34258, 0.37131, 0.40142, 0.43235, 0.4645, 0.49808, 0.53141, 0.56281,
+        0.59236, 0.62034, 0.6462, 0.66928, 0.68945, 0.70682, 0.72194, 0.73531, 0.74695, 0.75689,
+        0.76575, 0.77407, 0.78137, 0.7872, 0.79221, 0.79723, 0.80262, 0.80842, 0.81375, 0.81785,
+        0.82133, 0.82495, 0.82862, 0.83218, 0.83587, 0.83979, 0.84305, 0.84486, 0.84588, 0.84712,
+        0.8496, 0.85043, 0.8519, 0.85335, 0.8548, 0.85624, 0.85766, 0.85907, 0.86047, 0.86186,
+        0.86323,
+    ],
+    [
+        0.37853, 0.40061, 0.41716, 0.43082, 0.44274, 0.45364, 0.46388, 0.47366, 0.48294, 0.49171,
+        0.50041, 0.50937, 0.51814, 0.5263, 0.53427, 0.5426, 0.55151, 0.56099, 0.57041, 0.57915,
+        0.58732, 0.59526, 0.60357, 0.61278, 0.62303, 0.63425, 0.64599, 0.65789, 0.67036, 0.68406,
+        0.69997, 0.71856, 0.73784, 0.75548, 0.77019, 0.78135, 0.78986, 0.79689, 0.8031, 0.80879,
+        0.81341, 0.8164, 0.81801, 0.81876, 0.81934, 0.82031, 0.82163, 0.82311, 0.82467, 0.82636,
+        0.82855, 0.83152, 0.83481, 0.83794, 0.84114, 0.84471, 0.84844, 0.85209, 0.85572, 0.85943,
+        0.86329, 0.86723, 0.87082, 0.87373, 0.87657, 0.87984, 0.88271, 0.88441, 0.88579, 0.8877,
+        0.88946, 0.8903, 0.89074, 0.89141, 0.892, 0.89212, 0.892, 0.89196, 0.892, 0.89204, 0.892,
+    ],
+    [
+        0.2515, 0.2515, 0.2515, 0.2515, 0.2515, 0.26605, 0.2812, 0.28563, 0.2838, 0.28249, 0.282,
+        0.28135, 0.281, 0.28161, 0.2832, 0.28567, 0.289, 0.2933, 0.2991, 0.30695, 0.317, 0.32939,
+        0.3447, 0.36343, 0.3854, 0.41039, 0.4386, 0.47037, 0.5062, 0.54626, 0.5894, 0.6341, 0.6788,
+        0.72176, 0.7606, 0.79314, 0.8187, 0.83727, 0.85, 0.8583, 0.8634, 0.86637, 0.8678, 0.86812,
+        0.8677, 0.86688, 0.8659, 0.86494, 0.8639, 0.86263, 0.861, 0.85898, 0.857, 0.85543, 0.8541,
+        0.85264, 0.8504, 0.84708, 0.8439, 0.84212, 0.8415, 0.84134, 0.8407, 0.83899, 0.8374, 0.8374,
+        0.8374, 0.8374, 0.8374, 0.8374, 0.8374, 0.8374, 0.8374, 0.8374, 0.8374, 0.8374, 0.8374,
+        0.8374, 0.8374, 0.8374, 0.8374,
+    ],
+    [
+        0.10281, 0.09292, 0.08404, 0.07621, 0.06814, 0.0624, 0.06158, 0.05854, 0.05464, 0.05502,
+        0.05832, 0.0584, 0.05462, 0.0529, 0.05304, 0.0521, 0.05075, 0.05128, 0.053, 0.05316,
+        0.05188, 0.0524, 0.0551, 0.05851, 0.06343, 0.07062, 0.08218, 0.10589, 0.14388, 0.19506,
+        0.25332, 0.3075, 0.35271, 0.38845, 0.41659, 0.43753, 0.45206, 0.46315, 0.47193, 0.479,
+        0.48644, 0.49282, 0.49629, 0.5002, 0.50456, 0.50626, 0.50779, 0.51042, 0.51055, 0.50984,
+        0.50953, 0.50935, 0.5127, 0.51496, 0.51194, 0.51094, 0.51107, 0.50985, 0.50758, 0.50201,
+        0.49937, 0.5013, 0.50444, 0.5139, 0.51821, 0.52735, 0.53455, 0.54175, 0.54893, 0.55608,
+        0.56322, 0.57032, 0.5774, 0.58445, 0.59146, 0.59844, 0.60537, 0.61227, 0.61911, 0.62592,
+        0.63267,
+    ],
+    [
+        0.1688, 0.1688, 0.1688, 0.1688, 0.1688, 0.16558, 0.1616, 0.159, 0.1581, 0.15881, 0.1613,
+        0.16571, 0.1717, 0.17895, 0.1876, 0.19777, 0.209, 0.22112, 0.2356, 0.25362, 0.2734, 0.29329,
+        0.315, 0.34048, 0.3692, 0.40017, 0.4329, 0.46717, 0.5032, 0.54088, 0.5784, 0.614, 0.6477,
+        0.67979, 0.7097, 0.73677, 0.7607, 0.78141, 0.7994, 0.81523, 0.8292, 0.84155, 0.8524,
+        0.86185, 0.8699, 0.87659, 0.8823, 0.88735, 0.8916, 0.89487, 0.8974, 0.89946, 0.9011,
+        0.90232, 0.9033, 0.90419, 0.905, 0.9057, 0.9063, 0.9068, 0.9072, 0.90752, 0.9079, 0.9084,
+        0.9088, 0.90888, 0.9088, 0.9088, 0.9088, 0.9088, 0.9088, 0.9088, 0.9088, 0.9088, 0.9088,
+        0.9088, 0.9088, 0.9088, 0.9088, 0.9088, 0.9088,
+    ],
+    [
+        0.06462, 0.065, 0.0662, 0.06758, 0.06839, 0.07185, 0.08117, 0.09389, 0.11434, 0.14548,
+        0.1798, 0.20758, 0.22587, 0.24072, 0.25486, 0.26272, 0.26805, 0.27812, 0.28681, 0.29048,
+        0.292, 0.29516, 0.30288, 0.31468, 0.32907, 0.34463, 0.35691, 0.36974, 0.38422, 0.39686,
+        0.40592, 0.41045, 0.41237, 0.41646, 0.42207, 0.42657, 0.42941, 0.4309, 0.43186, 0.43417,
+        0.43799, 0.44134, 0.44276, 0.4441, 0.44574, 0.44656, 0.44617, 0.44794, 0.44909, 0.44919,
+        0.45155, 0.45456, 0.45681, 0.45528, 0.45003, 0.44666, 0.44579, 0.44445, 0.4443, 0.44272,
+        0.44242, 0.44704, 0.45358, 0.46121, 0.46357, 0.47205, 0.47816, 0.48429, 0.49042, 0.49655,
+        0.50268, 0.50881, 0.51494, 0.52106, 0.52718, 0.53329, 0.53939, 0.54548, 0.55155, 0.55761,
+        0.56365,
+    ],
+    [
+        0.0458, 0.04489, 0.04394, 0.04317, 0.04467, 0.0465, 0.04793, 0.04699, 0.04621, 0.044,
+        0.04456, 0.04653, 0.04711, 0.04794, 0.04801, 0.04537, 0.04556, 0.04574, 0.04704, 0.04889,
+        0.04999, 0.05113, 0.05184, 0.05266, 0.0578, 0.06583, 0.07923, 0.09316, 0.10443, 0.11237,
+        0.11717, 0.11799, 0.11843, 0.11869, 0.11877, 0.1174, 0.11503, 0.12031, 0.12027, 0.12087,
+        0.12009, 0.12214, 0.12237, 0.12116, 0.11951, 0.11896, 0.11864, 0.11826, 0.11813, 0.11937,
+        0.11881, 0.11634, 0.11621, 0.11637, 0.11656, 0.11571, 0.11537, 0.11267, 0.1142, 0.11624,
+        0.11636, 0.1132, 0.11456, 0.11781, 0.11627, 0.11443, 0.11559, 0.11697, 0.11711, 0.11871,
+        0.11979, 0.11884, 0.1178, 0.11833, 0.1188, 0.11906, 0.1203, 0.12106, 0.12099, 0.1212,
+        0.12123,
+    ],
+    [
+        0.03981, 0.04155, 0.04336, 0.04525, 0.04798, 0.04887, 0.05115, 0.05478, 0.05972, 0.06611,
+        0.07483, 0.08683, 0.1027, 0.12281, 0.14711, 0.17523, 0.20611, 0.23842, 0.27043, 0.30066,
+        0.32892, 0.35533, 0.37997, 0.40328, 0.42723, 0.45369, 0.48277, 0.51378, 0.54473, 0.57367,
+        0.60006, 0.62365, 0.64365, 0.65956, 0.67224, 0.6828, 0.69184, 0.69975, 0.70674, 0.71305,
+        0.7191, 0.72521, 0.73114, 0.73663, 0.74196, 0.74751, 0.7535, 0.75999, 0.76667, 0.77308,
+        0.77882, 0.78351, 0.78709, 0.78962, 0.79148, 0.79321, 0.79597, 0.8006, 0.80638, 0.81236,
+        0.81832, 0.82419, 0.82976, 0.83482, 0.83915, 0.84423, 0.84885, 0.85335, 0.85775, 0.86203,
+        0.86621, 0.87028, 0.87424, 0.8781, 0.88185, 0.88551, 0.88906, 0.89252, 0.89589, 0.89916,
+        0.90233,
+    ],
+    [
+        0.03043, 0.03198, 0.03135, 0.02956, 0.02799, 0.02943, 0.03159, 0.03054, 0.03068, 0.0351,
+        0.04618, 0.05858, 0.07378, 0.09711, 0.12843, 0.16071, 0.18945, 0.21301, 0.23025, 0.2386,
+        0.24313, 0.2497, 0.25715, 0.26432, 0.27011, 0.27155, 0.272, 0.27353, 0.2763, 0.27819,
+        0.27862, 0.27889, 0.27955, 0.28173, 0.28524, 0.28833, 0.29177, 0.29664, 0.30154, 0.30507,
+        0.30871, 0.3134, 0.31903, 0.32509, 0.32974, 0.33388, 0.33894, 0.3422, 0.34451, 0.34661,
+        0.34779, 0.3504, 0.35438, 0.35485, 0.35338, 0.35403, 0.35561, 0.35852, 0.35971, 0.35739,
+        0.35449, 0.35373, 0.3554, 0.36075, 0.36514, 0.36959, 0.37419, 0.37882, 0.38348, 0.38815,
+        0.39284, 0.39756, 0.40229, 0.40704, 0.41181, 0.4166, 0.4214, 0.42622, 0.43105, 0.43589,
+        0.44074,
+    ],
+    [
+        0.04401, 0.04373, 0.04499, 0.04761, 0.05142, 0.05624, 0.06182, 0.06809, 0.07569, 0.08532,
+        0.09712, 0.11108, 0.1271, 0.14513, 0.16551, 0.1885, 0.21364, 0.24081, 0.27189, 0.30813,
+        0.34621, 0.38299, 0.42055, 0.46097, 0.50101, 0.53702, 0.56908, 0.59786, 0.6226, 0.64264,
+        0.65921, 0.67373, 0.68657, 0.6979, 0.70804, 0.71734, 0.72615, 0.73467, 0.74241, 0.74895,
+        0.75484, 0.7607, 0.76648, 0.7719, 0.77655, 0.78017, 0.78326, 0.78641, 0.78978, 0.79338,
+        0.79694, 0.80012, 0.80264, 0.80445, 0.8064, 0.80916, 0.8117, 0.81302, 0.81388, 0.81524,
+        0.81722, 0.81966, 0.82215, 0.82429, 0.82611, 0.82769, 0.82899, 0.82993, 0.83048, 0.8306,
+        0.83026, 0.8306, 0.83067, 0.83074, 0.83081, 0.83088, 0.83095, 0.83102, 0.83109, 0.83116,
+        0.83123,
+    ],
+    [
+        0.0429, 0.0429, 0.0429, 0.0429, 0.0429, 0.04206, 0.0411, 0.04069, 0.041, 0.04209, 0.044,
+        0.04679, 0.0505, 0.05522, 0.0613, 0.06913, 0.0789, 0.09077, 0.105, 0.1218, 0.1412, 0.16307,
+        0.1869, 0.21218, 0.2387, 0.26636, 0.2952, 0.32517, 0.3558, 0.38644, 0.4162, 0.44402, 0.4683,
+        0.48756, 0.5013, 0.50941, 0.5124, 0.51113, 0.5072, 0.50235, 0.498, 0.49512, 0.4931, 0.49093,
+        0.4876, 0.48248, 0.4763, 0.4703, 0.4663, 0.46623, 0.472, 0.48518, 0.5061, 0.53447, 0.5688,
+        0.60699, 0.6457, 0.68173, 0.7138, 0.74151, 0.7662, 0.78956, 0.8131, 0.83652, 0.8526, 0.8526,
+        0.8526, 0.8526, 0.8526, 0.8526, 0.8526, 0.8526, 0.8526, 0.8526, 0.8526, 0.8526, 0.8526,
+        0.8526, 0.8526, 0.8526, 0.8526,
+    ],
+    [
+        0.17315, 0.17955, 0.18551, 0.19147, 0.19787, 0.2047, 0.21023, 0.21321, 0.21595, 0.22112,
+        0.22921, 0.24063, 0.25764, 0.28283, 0.31841, 0.36487, 0.41631, 0.46632, 0.5129, 0.55466,
+        0.58809, 0.61048, 0.62424, 0.6331, 0.64079, 0.65018, 0.66068, 0.67101, 0.68048, 0.68881,
+        0.69672, 0.70489, 0.71287, 0.72001, 0.72588, 0.73048, 0.73522, 0.74123, 0.74699, 0.75091,
+        0.75383, 0.75692, 0.76021, 0.76361, 0.76763, 0.77265, 0.77797, 0.78271, 0.78627, 0.78844,
+        0.79028, 0.79278, 0.79528, 0.79706, 0.79877, 0.80118, 0.80412, 0.80718, 0.80995, 0.81215,
+        0.81403, 0.81593, 0.81807, 0.82055, 0.82326, 0.82593, 0.82804, 0.82924, 0.83027, 0.83213,
+        0.83581, 0.83693, 0.83904, 0.84114, 0.84321, 0.84525, 0.84728, 0.84929, 0.85127, 0.85323,
+        0.85517,
+    ],
+    [
+        0.04389, 0.04389, 0.04389, 0.04389, 0.04389, 0.04397, 0.04404, 0.04401, 0.044, 0.04411,
+        0.04433, 0.04464, 0.04511, 0.04589, 0.04721, 0.04923, 0.05164, 0.05427, 0.05796, 0.06361,
+        0.07121, 0.08063, 0.09207, 0.10573, 0.12138, 0.13906, 0.16048, 0.18727, 0.21932, 0.25474,
+        0.28642, 0.3078, 0.31975, 0.32497, 0.32621, 0.32579, 0.32446, 0.32253, 0.3202, 0.31762,
+        0.31486, 0.31189, 0.30849, 0.30443, 0.29971, 0.29443, 0.28917, 0.28446, 0.28043, 0.27707,
+        0.27414, 0.27141, 0.26885, 0.26649, 0.26449, 0.26303, 0.26213, 0.26186, 0.26248, 0.2641,
+        0.26609, 0.26786, 0.26981, 0.27228, 0.27417, 0.27417, 0.27417, 0.27417, 0.27417, 0.27417,
+        0.27417, 0.27417, 0.27417, 0.27417, 0.27417, 0.27417, 0.27417, 0.27417, 0.27417, 0.27417,
+        0.27417,
+    ],
+    [
+        0.05245, 0.05245, 0.05245, 0.05245, 0.05245, 0.05239, 0.05217, 0.0517, 0.05108, 0.05042,
+        0.0498, 0.04932, 0.04899, 0.04883, 0.04885, 0.04903, 0.04929, 0.0496, 0.05029, 0.05178,
+        0.0544, 0.05841, 0.06371, 0.06999, 0.07648, 0.08244, 0.08763, 0.09226, 0.09785, 0.1057,
+        0.11485, 0.12372, 0.13045, 0.13368, 0.13428, 0.13355, 0.13216, 0.13054, 0.12886, 0.1272,
+        0.12554, 0.12381, 0.12196, 0.11992, 0.11767, 0.11522, 0.11283, 0.11074, 0.10894, 0.10738,
+        0.10598, 0.10468, 0.10349, 0.10242, 0.10154, 0.10088, 0.10041, 0.10012, 0.10019, 0.10071,
+        0.10131, 0.10168, 0.10213, 0.10293, 0.10364, 0.10364, 0.10364, 0.10364, 0.10364, 0.10364,
+        0.10364, 0.10364, 0.10364, 0.10364, 0.10364, 0.10364, 0.10364, 0.10364, 0.10364, 0.10364,
+        0.10364,
+    ],
+    [
+        0.03431, 0.03707, 0.04283, 0.05358, 0.07506, 0.09776, 0.12729, 0.16371, 0.20727, 0.25618,
+        0.30712, 0.35739, 0.40413, 0.44397, 0.47549, 0.49917, 0.51519, 0.524, 0.52781, 0.5287,
+        0.52832, 0.52853, 0.52959, 0.53055, 0.53092, 0.53193, 0.53347, 0.53402, 0.53283, 0.53039,
+        0.5286, 0.52848, 0.5289, 0.52837, 0.527, 0.52649, 0.52843, 0.5321, 0.53516, 0.5367, 0.53798,
+        0.54007, 0.5431, 0.54624, 0.54817, 0.54899, 0.55035, 0.55266, 0.55518, 0.55746, 0.55801,
+        0.55702, 0.55717, 0.55869, 0.55967, 0.55989, 0.56082, 0.56192, 0.56165, 0.56041, 0.55912,
+        0.55486, 0.54941, 0.5433, 0.53563, 0.5263, 0.51786, 0.51116, 0.50353, 0.49575, 0.48819,
+        0.48065, 0.47311, 0.46558, 0.45806, 0.45057, 0.4431, 0.43562, 0.42817, 0.42076, 0.41339,
+    ],
+    [
+        0.05028, 0.05023, 0.05019, 0.05014, 0.05, 0.05009, 0.05, 0.04991, 0.05, 0.05039, 0.051,
+        0.05177, 0.053, 0.0549, 0.057, 0.05875, 0.06, 0.06073, 0.061, 0.06109, 0.062, 0.06453,
+        0.068, 0.07115, 0.072, 0.07038, 0.074, 0.08994, 0.115, 0.14309, 0.167, 0.18168, 0.192,
+        0.20293, 0.21, 0.20811, 0.199, 0.18615, 0.173, 0.16242, 0.155, 0.15106, 0.152, 0.15771,
+        0.161, 0.15559, 0.146, 0.13792, 0.131, 0.12386, 0.117, 0.11101, 0.105, 0.09848, 0.094,
+        0.09471, 0.1031, 0.1208, 0.1466, 0.17877, 0.2163, 0.25833, 0.3038, 0.35162, 0.4007, 0.45484,
+        0.50886, 0.56267, 0.61505, 0.66489, 0.7113, 0.75367, 0.79164, 0.82512, 0.85421, 0.87917,
+        0.90035, 0.91817, 0.93303, 0.94536, 0.95553,
+    ],
+    [
+        0.11323, 0.11525, 0.10979, 0.09541, 0.08689, 0.08224, 0.08142, 0.08681, 0.10151, 0.12741,
+        0.16431, 0.21014, 0.26265, 0.31889, 0.37622, 0.4306, 0.47844, 0.51829, 0.55029, 0.57599,
+        0.59562, 0.61049, 0.62077, 0.62698, 0.6327, 0.64011, 0.64719, 0.65171, 0.65373, 0.65447,
+        0.65538, 0.65739, 0.65954, 0.66027, 0.65923, 0.65779, 0.65799, 0.6594, 0.66049, 0.66094,
+        0.66122, 0.6619, 0.66315, 0.66458, 0.66544, 0.66585, 0.66684, 0.66881, 0.6706, 0.67138,
+        0.67119, 0.67049, 0.67041, 0.67108, 0.67106, 0.67024, 0.66944, 0.66846, 0.66851, 0.67028,
+        0.67149, 0.67258, 0.67428, 0.67638, 0.67857, 0.6819, 0.68592, 0.68952, 0.69328, 0.69711,
+        0.70083, 0.70452, 0.70819, 0.71183, 0.71544, 0.71903, 0.72258, 0.72615, 0.72967, 0.73316,
+        0.73661,
+    ],
+    [
+        0.04566, 0.04573, 0.0458, 0.04588, 0.04606, 0.04597, 0.04606, 0.04634, 0.04678, 0.04741,
+        0.04823, 0.04925, 0.05039, 0.0516, 0.05297, 0.05453, 0.05595, 0.05698, 0.05791, 0.05932,
+        0.06224, 0.06742, 0.07389, 0.08031, 0.08563, 0.08916, 0.0913, 0.09256, 0.09254, 0.09086,
+        0.088, 0.08461, 0.0811, 0.07777, 0.07461, 0.07165, 0.06935, 0.06811, 0.0677, 0.06784,
+        0.06863, 0.07018, 0.07224, 0.07444, 0.07646, 0.07805, 0.07924, 0.08017, 0.08089, 0.08147,
+        0.08192, 0.08233, 0.08295, 0.08401, 0.08532, 0.08661, 0.08759, 0.08807, 0.08821, 0.08823,
+        0.08821, 0.08818, 0.08811, 0.08796, 0.08769, 0.0876, 0.08743, 0.08727, 0.08711, 0.08694,
+        0.08678, 0.08662, 0.08645, 0.08629, 0.08613, 0.08597, 0.08581, 0.08565, 0.08549, 0.08533,
+        0.08517,
+    ],
+    [
+        0.075, 0.07502, 0.07503, 0.07504, 0.07522, 0.07498, 0.07502, 0.07534, 0.07595, 0.07683,
+        0.07801, 0.0795, 0.0813, 0.08347, 0.08625, 0.08964, 0.09243, 0.09371, 0.0948, 0.09794,
+        0.10655, 0.12302, 0.14427, 0.16617, 0.186, 0.20198, 0.21465, 0.22435, 0.22825, 0.22393,
+        0.21393, 0.20158, 0.18858, 0.17596, 0.16384, 0.15244, 0.14313, 0.13716, 0.13396, 0.13286,
+        0.13458, 0.13978, 0.14736, 0.15579, 0.16364, 0.1698, 0.17456, 0.17846, 0.18157, 0.18391,
+        0.18569, 0.18725, 0.1893, 0.19238, 0.1961, 0.19976, 0.20259, 0.204, 0.20434, 0.20413,
+        0.20352, 0.20254, 0.20115, 0.19927, 0.19682, 0.19524, 0.19333, 0.19144, 0.18956, 0.18769,
+        0.18584, 0.184, 0.18218, 0.18037, 0.17857, 0.17679, 0.17502, 0.17327, 0.17153, 0.16981,
+        0.16809,
+    ],
+    [
+        0.4098, 0.4098, 0.4098, 0.4098, 0.4098, 0.41775, 0.4269, 0.43209, 0.4368, 0.44546, 0.4577,
+        0.47231, 0.4895, 0.50977, 0.5333, 0.55996, 0.5888, 0.61854, 0.6475, 0.67396, 0.6965,
+        0.71398, 0.7262, 0.73336, 0.7364, 0.73638, 0.7342, 0.73061, 0.7261, 0.72102, 0.7155,
+        0.70967, 0.7039, 0.6986, 0.6941, 0.69062, 0.6881, 0.6863, 0.6846, 0.68243, 0.6799, 0.67729,
+        0.6749, 0.673, 0.6718, 0.67137, 0.6714, 0.67164, 0.6725, 0.67464, 0.6789, 0.68616, 0.6971,
+        0.71218, 0.731, 0.75284, 0.7764, 0.80025, 0.823, 0.84343, 0.8609, 0.87518, 0.8872, 0.89754,
+        0.9041, 0.9041, 0.9041, 0.9041, 0.9041, 0.9041, 0.9041, 0.9041, 0.9041, 0.9041, 0.9041,
+        0.9041, 0.9041, 0.9041, 0.9041, 0.9041, 0.9041,
+    ],
+    [
+        0.06598, 0.06537, 0.06477, 0.06418, 0.06374, 0.06292, 0.06235, 0.06213, 0.06235, 0.06307,
+        0.06414, 0.0654, 0.06682, 0.06845, 0.0705, 0.07328, 0.07725, 0.08275, 0.08966, 0.09786,
+        0.10793, 0.12099, 0.1398, 0.16761, 0.20821, 0.26399, 0.33133, 0.40352, 0.46746, 0.51124,
+        0.53408, 0.53861, 0.52991, 0.51304, 0.49059, 0.46466, 0.43777, 0.41211, 0.38793, 0.36527,
+        0.34523, 0.32877, 0.31534, 0.30404, 0.2941, 0.2848, 0.27563, 0.26625, 0.25696, 0.24839,
+        0.24177, 0.2384, 0.23909, 0.24436, 0.25398, 0.26709, 0.28089, 0.29267, 0.30194, 0.30939,
+        0.31803, 0.33103, 0.3498, 0.37529, 0.40848, 0.43191, 0.45996, 0.48826, 0.51664, 0.54491,
+        0.5729, 0.60042, 0.62733, 0.65347, 0.67872, 0.70296, 0.72611, 0.7481, 0.76889, 0.78845,
+        0.80677,
+    ],
+    [
+        0.0671, 0.0671, 0.0671, 0.0671, 0.0671, 0.06228, 0.0562, 0.05196, 0.0497, 0.04885, 0.0489,
+        0.0495, 0.0509, 0.05356, 0.0582, 0.06544, 0.0751, 0.08761, 0.1066, 0.13515, 0.171, 0.21203,
+        0.2619, 0.3233, 0.3891, 0.45024, 0.4997, 0.53263, 0.5507, 0.55722, 0.5555, 0.54854, 0.538,
+        0.52506, 0.5102, 0.49382, 0.4768, 0.46002, 0.4439, 0.42861, 0.4139, 0.39905, 0.382, 0.36163,
+        0.3419, 0.32717, 0.3184, 0.31512, 0.3144, 0.31336, 0.3117, 0.30968, 0.3073, 0.30349, 0.2931,
+        0.27208, 0.2448, 0.21777, 0.1976, 0.18894, 0.1885, 0.19437, 0.2181, 0.26567, 0.3073, 0.3073,
+        0.3073, 0.3073, 0.3073, 0.3073, 0.3073, 0.3073, 0.3073, 0.3073, 0.3073, 0.3073, 0.3073,
+        0.3073, 0.3073, 0.3073, 0.3073,
+    ],
+    [
+        0.0439, 0.0439, 0.0439, 0.0439, 0.0439, 0.04451, 0.0452, 0.04555, 0.0457, 0.04589, 0.0461,
+        0.04629, 0.0465, 0.04676, 0.047, 0.04714, 0.0472, 0.04724, 0.0474, 0.04779, 0.0483, 0.04879,
+        0.0493, 0.04986, 0.0504, 0.05082, 0.0511, 0.05122, 0.0512, 0.05107, 0.0509, 0.05076, 0.0506,
+        0.05036, 0.0501, 0.04987, 0.0496, 0.04922, 0.0489, 0.04879, 0.0488, 0.0488, 0.0488, 0.04882,
+        0.0488, 0.04868, 0.0486, 0.04868, 0.0489, 0.04918, 0.0495, 0.04984, 0.0502, 0.05057, 0.051,
+        0.0515, 0.052, 0.05242, 0.0527, 0.05278, 0.0527, 0.05252, 0.0523, 0.05211, 0.052, 0.052,
+        0.052, 0.052, 0.052, 0.052, 0.052, 0.052, 0.052, 0.052, 0.052, 0.052, 0.052, 0.052, 0.052,
+        0.052, 0.052,
+    ],
+    [
+        0.0554, 0.05519, 0.05609, 0.05723, 0.0576, 0.05773, 0.05841, 0.059, 0.06076, 0.06181,
+        0.06364, 0.06536, 0.06841, 0.07063, 0.07333, 0.07831, 0.0847, 0.09311, 0.10313, 0.11523,
+        0.13064, 0.14961, 0.17359, 0.20369, 0.23996, 0.27414, 0.30443, 0.32537, 0.33756, 0.34253,
+        0.34239, 0.33797, 0.33044, 0.32147, 0.31229, 0.29914, 0.28479, 0.27639, 0.26307, 0.24861,
+        0.23426, 0.21943, 0.20576, 0.19114, 0.1799, 0.17159, 0.16571, 0.16047, 0.15739, 0.15454,
+        0.15364, 0.15204, 0.15024, 0.15039, 0.15037, 0.15041, 0.15234, 0.15367, 0.15924, 0.16394,
+        0.16737, 0.16914, 0.1733, 0.17773, 0.17987, 0.1802, 0.18, 0.17773, 0.17586, 0.17644,
+        0.17847, 0.18116, 0.18747, 0.19526, 0.20319, 0.2093, 0.21407, 0.21864, 0.21997, 0.21784,
+        0.21926,
+    ],
+    [
+        0.1126, 0.1126, 0.1126, 0.1126, 0.1126, 0.11615, 0.1202, 0.12231, 0.1235, 0.12532, 0.128,
+        0.13152, 0.1362, 0.14245, 0.1507, 0.16141, 0.1752, 0.19261, 0.2137, 0.23822, 0.2651,
+        0.29289, 0.3195, 0.34297, 0.3624, 0.37735, 0.388, 0.39477, 0.3983, 0.39931, 0.3986, 0.39682,
+        0.394, 0.39003, 0.3849, 0.3787, 0.3719, 0.3649, 0.3575, 0.34938, 0.3403, 0.33, 0.3181,
+        0.30447, 0.2902, 0.27656, 0.2644, 0.25433, 0.2463, 0.24004, 0.2349, 0.23025, 0.226, 0.22218,
+        0.2189, 0.21633, 0.2147, 0.21422, 0.2148, 0.21628, 0.2184, 0.22098, 0.2242, 0.22799, 0.2308,
+        0.2308, 0.2308, 0.2308, 0.2308, 0.2308, 0.2308, 0.2308, 0.2308, 0.2308, 0.2308, 0.2308,
+        0.2308, 0.2308, 0.2308, 0.2308, 0.2308,
+    ],
+    [
+        0.1446, 0.1446, 0.1446, 0.1446, 0.1446, 0.14514, 0.1458, 0.14646, 0.1484, 0.15294, 0.1601,
+        0.1697, 0.182, 0.19737, 0.2162, 0.23886, 0.2656, 0.2965, 0.331, 0.368, 0.4048, 0.43811,
+        0.464, 0.47933, 0.4848, 0.48229, 0.4745, 0.46402, 0.4522, 0.43995, 0.4277, 0.41577, 0.4045,
+        0.39412, 0.3845, 0.37543, 0.3669, 0.35895, 0.3518, 0.34575, 0.3413, 0.3387, 0.337, 0.33506,
+        0.3322, 0.32808, 0.3232, 0.31848, 0.3156, 0.31636, 0.3221, 0.33395, 0.3525, 0.37809, 0.4104,
+        0.44884, 0.4922, 0.53897, 0.5869, 0.63375, 0.6779, 0.71834, 0.7558, 0.78958, 0.8115, 0.8115,
+        0.8115, 0.8115, 0.8115, 0.8115, 0.8115, 0.8115, 0.8115, 0.8115, 0.8115, 0.8115, 0.8115,
+        0.8115, 0.8115, 0.8115, 0.8115,
+    ],
+    [
+        0.08776, 0.08843, 0.08933, 0.09022, 0.09089, 0.09134, 0.09245, 0.09578, 0.10461, 0.12154,
+        0.14474, 0.17179, 0.20227, 0.23616, 0.27292, 0.31153, 0.3494, 0.38408, 0.41516, 0.44282,
+        0.46753, 0.48971, 0.50918, 0.52583, 0.54042, 0.55383, 0.56646, 0.57838, 0.58866, 0.59648,
+        0.60236, 0.60683, 0.60921, 0.60857, 0.60427, 0.59607, 0.5849, 0.57159, 0.55521, 0.53495,
+        0.51211, 0.48821, 0.46354, 0.43824, 0.41298, 0.38846, 0.36486, 0.34231, 0.32117, 0.30177,
+        0.2842, 0.26844, 0.25429, 0.24158, 0.2304, 0.2208, 0.21248, 0.20511, 0.19862, 0.193,
+        0.18827, 0.18444, 0.18137, 0.17893, 0.17713, 0.17599, 0.1755, 0.17564, 0.17652, 0.1783,
+        0.18111, 0.18243, 0.18437, 0.18633, 0.18831, 0.1903, 0.1923, 0.19433, 0.19637, 0.19842,
+        0.20049,
+    ],
+    [
+        0.00057, 0.00739, 0.01389, 0.02038, 0.0272, 0.03434, 0.04051, 0.04446, 0.04633, 0.04675,
+        0.04681, 0.04748, 0.04889, 0.05103, 0.05418, 0.05879, 0.06559, 0.07524, 0.08795, 0.10363,
+        0.12163, 0.14087, 0.15921, 0.17469, 0.18701, 0.19627, 0.20241, 0.20542, 0.20553, 0.20313,
+        0.19895, 0.19369, 0.18754, 0.18056, 0.17279, 0.16436, 0.15574, 0.14741, 0.13947, 0.13197,
+        0.12523, 0.11951, 0.11449, 0.10977, 0.10532, 0.1012, 0.0975, 0.09436, 0.0919, 0.09028,
+        0.0895, 0.08951, 0.09004, 0.09084, 0.09188, 0.09319, 0.09465, 0.09614, 0.09758, 0.09892,
+        0.10023, 0.10164, 0.10336, 0.10553, 0.10789, 0.11019, 0.11246, 0.11484, 0.11757, 0.1209,
+        0.12509, 0.12825, 0.13195, 0.13574, 0.13962, 0.14359, 0.14766, 0.15182, 0.15608, 0.16044,
+        0.16489,
+    ],
+    [
+        0.07883, 0.08225, 0.0858, 0.08949, 0.09339, 0.09727, 0.10142, 0.10575, 0.11016, 0.11458,
+        0.11919, 0.12413, 0.12931, 0.13467, 0.14052, 0.14714, 0.15412, 0.16118, 0.1693, 0.18,
+        0.1958, 0.21832, 0.24453, 0.27019, 0.29107, 0.30412, 0.31122, 0.31477, 0.31449, 0.30979,
+        0.30149, 0.29047, 0.27638, 0.25896, 0.23937, 0.21933, 0.20136, 0.18742, 0.17665, 0.1678,
+        0.16107, 0.15676, 0.15442, 0.15328, 0.15223, 0.1504, 0.14826, 0.14662, 0.14618, 0.14745,
+        0.15005, 0.15358, 0.15829, 0.16436, 0.17109, 0.17743, 0.18201, 0.18381, 0.18359, 0.1824,
+        0.18052, 0.17803, 0.17486, 0.17093, 0.16613, 0.16279, 0.15896, 0.15521, 0.15154, 0.14793,
+        0.1444, 0.14093, 0.13754, 0.13421, 0.13096, 0.12777, 0.12464, 0.12159, 0.11859, 0.11566,
+        0.1128,
+    ],
+    [
+        0.0995, 0.10406, 0.1088, 0.11373, 0.11889, 0.12416, 0.12971, 0.13543, 0.14122, 0.14703,
+        0.15303, 0.15937, 0.16583, 0.17226, 0.17913, 0.1868, 0.19471, 0.20244, 0.21118, 0.22283,
+        0.24056, 0.2664, 0.29663, 0.32597, 0.34883, 0.36119, 0.3657, 0.36586, 0.36203, 0.35401,
+        0.34248, 0.328, 0.30983, 0.28742, 0.26249, 0.23757, 0.21634, 0.20167, 0.19213, 0.18575,
+        0.1828, 0.18373, 0.18766, 0.19304, 0.19699, 0.19725, 0.19521, 0.19302, 0.19233, 0.19418,
+        0.19788, 0.20264, 0.2089, 0.21709, 0.22617, 0.23465, 0.24066, 0.24281, 0.24224, 0.24044,
+        0.23778, 0.23432, 0.22994, 0.2245, 0.21783, 0.2132, 0.20789, 0.20268, 0.19757, 0.19256,
+        0.18764, 0.18282, 0.1781, 0.17347, 0.16894, 0.1645, 0.16016, 0.15591, 0.15175, 0.14769,
+        0.14371,
+    ],
+    [
+        0.0586, 0.05842, 0.05824, 0.05806, 0.05802, 0.05765, 0.0575, 0.05753, 0.05771, 0.05801,
+        0.05853, 0.05937, 0.06059, 0.06229, 0.06471, 0.06784, 0.07048, 0.07175, 0.07306, 0.07679,
+        0.08687, 0.106, 0.13056, 0.15571, 0.17817, 0.19575, 0.20908, 0.21853, 0.22052, 0.21204,
+        0.1963, 0.1777, 0.159, 0.142, 0.12623, 0.11115, 0.0981, 0.08841, 0.08151, 0.0765, 0.07296,
+        0.07063, 0.06935, 0.06891, 0.06883, 0.06872, 0.06863, 0.06872, 0.06894, 0.06923, 0.06966,
+        0.0704, 0.07182, 0.07418, 0.07708, 0.07997, 0.08223, 0.08342, 0.08378, 0.08364, 0.08306,
+        0.082, 0.08048, 0.07849, 0.07605, 0.07441, 0.07251, 0.07065, 0.06884, 0.06707, 0.06535,
+        0.06366, 0.06202, 0.06041, 0.05885, 0.05732, 0.05583, 0.05438, 0.05296, 0.05158, 0.05023,
+    ],
+    [
+        0.0779, 0.0779, 0.0779, 0.0779, 0.0779, 0.0793, 0.0812, 0.08291, 0.0848, 0.08736, 0.0905,
+        0.09403, 0.098, 0.10263, 0.1086, 0.11658, 0.1267, 0.139, 0.1538, 0.17152, 0.1928, 0.21822,
+        0.2479, 0.28105, 0.3137, 0.34111, 0.3585, 0.36251, 0.3554, 0.34058, 0.3203, 0.29646, 0.2706,
+        0.24412, 0.2182, 0.19387, 0.1717, 0.15204, 0.1347, 0.1194, 0.1059, 0.09403, 0.0839, 0.07567,
+        0.0694, 0.06504, 0.0622, 0.06044, 0.0594, 0.05878, 0.0584, 0.05812, 0.0579, 0.05775, 0.0579,
+        0.05855, 0.0596, 0.06093, 0.0625, 0.06428, 0.0661, 0.0678, 0.0695, 0.07121, 0.0724, 0.0724,
+        0.0724, 0.0724, 0.0724, 0.0724, 0.0724, 0.0724, 0.0724, 0.0724, 0.0724, 0.0724, 0.0724,
+        0.0724, 0.0724, 0.0724, 0.0724,
+    ],
+    [
+        0.4719, 0.4719, 0.4719, 0.4719, 0.4719, 0.48883, 0.5116, 0.53114, 0.5488, 0.56754, 0.5862,
+        0.60333, 0.6204, 0.63907, 0.6589, 0.67891, 0.698, 0.71523, 0.7305, 0.74379, 0.7547, 0.76285,
+        0.7684, 0.77166, 0.773, 0.77289, 0.7722, 0.77164, 0.7709, 0.7695, 0.7673, 0.76421, 0.76,
+        0.75437, 0.7468, 0.73691, 0.7252, 0.71226, 0.6982, 0.6828, 0.6649, 0.64336, 0.618, 0.58959,
+        0.5617, 0.53802, 0.5199, 0.5078, 0.5009, 0.49798, 0.4976, 0.49839, 0.4995, 0.50065, 0.5032,
+        0.50874, 0.518, 0.53104, 0.5461, 0.56122, 0.5755, 0.58862, 0.6015, 0.61435, 0.6232, 0.6232,
+        0.6232, 0.6232, 0.6232, 0.6232, 0.6232, 0.6232, 0.6232, 0.6232, 0.6232, 0.6232, 0.6232,
+        0.6232, 0.6232, 0.6232, 0.6232,
+    ],
+    [
+        0.30073, 0.32661, 0.34992, 0.3723, 0.39416, 0.41553, 0.43632, 0.45652, 0.47668, 0.49725,
+        0.51775, 0.53774, 0.55789, 0.57898, 0.60125, 0.62458, 0.64804, 0.67049, 0.69066, 0.70754,
+        0.72132, 0.73246, 0.74115, 0.74752, 0.75151, 0.75312, 0.75259, 0.7501, 0.74537, 0.7381,
+        0.72853, 0.71689, 0.70284, 0.68608, 0.66694, 0.64607, 0.6248, 0.60444, 0.58562, 0.56856,
+        0.55242, 0.53611, 0.51865, 0.49975, 0.48196, 0.46791, 0.45788, 0.45146, 0.44792, 0.44644,
+        0.44631, 0.44699, 0.44862, 0.4516, 0.45656, 0.46396, 0.47319, 0.48341, 0.49376, 0.50347,
+        0.51212, 0.51931, 0.5245, 0.52735, 0.52854, 0.52899, 0.52954, 0.53109, 0.53478, 0.54158,
+        0.55168, 0.56548, 0.58514, 0.61045, 0.62991, 0.63369, 0.62991, 0.62865, 0.62991, 0.63117,
+        0.62991,
+    ],
+    [
+        0.2315, 0.2315, 0.2315, 0.2315, 0.2315, 0.25503, 0.28201, 0.29616, 0.30297, 0.3113, 0.32158,
+        0.33253, 0.34437, 0.35808, 0.37612, 0.40032, 0.42836, 0.45815, 0.49262, 0.53399, 0.57658,
+        0.61368, 0.64244, 0.66137, 0.67063, 0.67106, 0.66461, 0.65332, 0.63836, 0.62052, 0.59994,
+        0.57664, 0.55071, 0.52241, 0.49259, 0.46218, 0.43191, 0.40231, 0.37328, 0.34465, 0.31651,
+        0.28904, 0.26263, 0.23787, 0.21609, 0.19856, 0.18547, 0.17653, 0.17054, 0.16621, 0.16281,
+        0.15981, 0.15704, 0.15455, 0.15295, 0.15286, 0.15443, 0.15772, 0.16295, 0.16994, 0.17687,
+        0.18216, 0.18699, 0.19243, 0.19647, 0.19647, 0.19647, 0.19647, 0.19647, 0.19647, 0.19647,
+        0.19647, 0.19647, 0.19647, 0.19647, 0.19647, 0.19647, 0.19647, 0.19647, 0.19647, 0.19647,
+    ],
+    [
+        0.1347, 0.1347, 0.1347, 0.1347, 0.1347, 0.13984, 0.146, 0.14999, 0.1536, 0.15934, 0.1677,
+        0.17857, 0.1916, 0.20654, 0.2238, 0.24394, 0.2674, 0.29427, 0.3234, 0.35336, 0.3827,
+        0.40983, 0.4325, 0.4487, 0.458, 0.46055, 0.4571, 0.44861, 0.4362, 0.42088, 0.403, 0.38264,
+        0.3594, 0.33313, 0.3051, 0.27694, 0.2503, 0.22651, 0.2055, 0.18674, 0.1694, 0.15247, 0.1347,
+        0.11572, 0.099, 0.0879, 0.0816, 0.07827, 0.0763, 0.07448, 0.0729, 0.07173, 0.0701, 0.06692,
+        0.0613, 0.05304, 0.0444, 0.03781, 0.0339, 0.03263, 0.0332, 0.03593, 0.0465, 0.06786, 0.0866,
+        0.0866, 0.0866, 0.0866, 0.0866, 0.0866, 0.0866, 0.0866, 0.0866, 0.0866, 0.0866, 0.0866,
+        0.0866, 0.0866, 0.0866, 0.0866, 0.0866,
+    ],
+    [
+        0.10606, 0.11035, 0.11514, 0.1202, 0.12531, 0.13033, 0.1355, 0.14114, 0.14747, 0.15464,
+        0.16259, 0.17128, 0.18103, 0.19242, 0.20673, 0.22518, 0.24804, 0.27486, 0.30339, 0.33116,
+        0.35681, 0.37901, 0.39557, 0.40468, 0.4069, 0.40341, 0.39562, 0.38483, 0.37179, 0.35707,
+        0.34094, 0.32357, 0.3049, 0.28488, 0.2638, 0.24215, 0.22077, 0.20033, 0.18041, 0.16051,
+        0.14091, 0.12209, 0.10463, 0.08914, 0.07639, 0.06696, 0.06048, 0.05632, 0.05385, 0.05246,
+        0.0517, 0.05122, 0.05094, 0.05089, 0.05139, 0.05276, 0.05499, 0.05802, 0.06171, 0.06589,
+        0.0702, 0.07429, 0.07806, 0.08141, 0.08396, 0.08534, 0.08554, 0.08476, 0.08362, 0.08285,
+        0.08317, 0.08202, 0.08147, 0.08092, 0.08037, 0.07983, 0.07929, 0.07876, 0.07822, 0.07769,
+        0.07717,
+    ],
+    [
+        0.4345, 0.4345, 0.4345, 0.4345, 0.4345, 0.51357, 0.6042, 0.65038, 0.6645, 0.67117, 0.6756,
+        0.6783, 0.6805, 0.68332, 0.6869, 0.69119, 0.6965, 0.70332, 0.7126, 0.72513, 0.7405, 0.75796,
+        0.7765, 0.79473, 0.81, 0.8199, 0.8244, 0.82407, 0.8196, 0.8116, 0.8003, 0.78591, 0.7689,
+        0.74963, 0.7279, 0.7038, 0.6793, 0.65657, 0.6366, 0.6199, 0.6061, 0.59451, 0.5841, 0.57394,
+        0.564, 0.55461, 0.5468, 0.5414, 0.5377, 0.53473, 0.5319, 0.52908, 0.5275, 0.5284, 0.5316,
+        0.53599, 0.5382, 0.53583, 0.5326, 0.5326, 0.5353, 0.53839, 0.5372, 0.52892, 0.5205, 0.5205,
+        0.5205, 0.5205, 0.5205, 0.5205, 0.5205, 0.5205, 0.5205, 0.5205, 0.5205, 0.5205, 0.5205,
+        0.5205, 0.5205, 0.5205, 0.5205,
+    ],
+    [
+        0.4371, 0.47272, 0.50312, 0.53129, 0.55735, 0.58083, 0.60183, 0.62063, 0.63777, 0.65381,
+        0.66908, 0.6837, 0.69721, 0.70918, 0.71973, 0.72912, 0.73751, 0.745, 0.75159, 0.75729,
+        0.7622, 0.76636, 0.76953, 0.77145, 0.77193, 0.77082, 0.76796, 0.76324, 0.7568, 0.74895,
+        0.74036, 0.73159, 0.72237, 0.71241, 0.70206, 0.69189, 0.68268, 0.67515, 0.66956, 0.66589,
+        0.66336, 0.66107, 0.65854, 0.65553, 0.65249, 0.65002, 0.64874, 0.64903, 0.65032, 0.65199,
+        0.6543, 0.65764, 0.66202, 0.66738, 0.67372, 0.6809, 0.68803, 0.69419, 0.69911, 0.7028,
+        0.70579, 0.70842, 0.70991, 0.7096, 0.70853, 0.70807, 0.70918, 0.71261, 0.71859, 0.7271,
+        0.73766, 0.75043, 0.7685, 0.79265, 0.81153, 0.81521, 0.81153, 0.8103, 0.81153, 0.81276,
+        0.81153,
+    ],
+    [
+        0.28873, 0.30831, 0.3286, 0.34955, 0.36977, 0.39383, 0.41611, 0.43654, 0.45502, 0.47151,
+        0.48618, 0.49915, 0.51039, 0.51992, 0.52806, 0.53497, 0.53987, 0.5422, 0.54344, 0.54563,
+        0.55128, 0.562, 0.57549, 0.58884, 0.6007, 0.61031, 0.61757, 0.62184, 0.61965, 0.60824,
+        0.59038, 0.56965, 0.54731, 0.52399, 0.50027, 0.47704, 0.4566, 0.44088, 0.42892, 0.4193,
+        0.41175, 0.40621, 0.40242, 0.39998, 0.39815, 0.39634, 0.39478, 0.39387, 0.39388, 0.39495,
+        0.39686, 0.39943, 0.40282, 0.4071, 0.41175, 0.41604, 0.41909, 0.42028, 0.42008, 0.41917,
+        0.4178, 0.41607, 0.41393, 0.41127, 0.40798, 0.40562, 0.40289, 0.40017, 0.39746, 0.39475,
+        0.39205, 0.38936, 0.38667, 0.38399, 0.38132, 0.37865, 0.37599, 0.37334, 0.3707, 0.36806,
+        0.36544,
+    ],
+    [
+        0.17009, 0.1793, 0.1889, 0.19888, 0.20849, 0.22043, 0.23133, 0.24199, 0.25317, 0.26551,
+        0.27899, 0.29357, 0.30976, 0.32796, 0.34749, 0.36733, 0.38621, 0.40281, 0.416, 0.42493,
+        0.4299, 0.43128, 0.4289, 0.42262, 0.41302, 0.40101, 0.3882, 0.37623, 0.36636, 0.35908,
+        0.35246, 0.34451, 0.33558, 0.3266, 0.3187, 0.31303, 0.31076, 0.31238, 0.31572, 0.31825,
+        0.3187, 0.31651, 0.31274, 0.30881, 0.30579, 0.30449, 0.3048, 0.30627, 0.30778, 0.30843,
+        0.30877, 0.30964, 0.31175, 0.31579, 0.32267, 0.3333, 0.34848, 0.36893, 0.39515, 0.42758,
+        0.46663, 0.51216, 0.56194, 0.61321, 0.66321, 0.70794, 0.74967, 0.78723, 0.8205, 0.84956,
+        0.87463, 0.89604, 0.91415, 0.92936, 0.94204, 0.95256, 0.96125, 0.9684, 0.97427, 0.97907,
+        0.98299,
+    ],
+    [
+        0.02353, 0.02714, 0.03128, 0.03604, 0.03956, 0.0488, 0.05538, 0.05973, 0.06229, 0.06356,
+        0.06429, 0.06519, 0.0665, 0.06835, 0.0708, 0.07393, 0.07801, 0.08318, 0.08883, 0.09427,
+        0.09914, 0.10306, 0.10525, 0.10508, 0.10285, 0.09908, 0.09414, 0.08838, 0.08232, 0.07644,
+        0.0709, 0.06586, 0.06169, 0.05878, 0.05728, 0.05732, 0.05899, 0.06227, 0.0667, 0.07168,
+        0.07651, 0.08049, 0.08302, 0.08366, 0.08262, 0.08028, 0.07721, 0.07397, 0.0708, 0.06788,
+        0.06539, 0.06346, 0.06189, 0.06055, 0.05979, 0.05995, 0.06109, 0.06312, 0.06589, 0.06936,
+        0.07381, 0.07946, 0.08592, 0.09264, 0.09904, 0.10694, 0.11492, 0.12341, 0.13244, 0.14202,
+        0.15216, 0.1629, 0.17424, 0.18619, 0.19876, 0.21197, 0.2258, 0.24026, 0.25534, 0.27102,
+        0.2873,
+    ],
+    [
+        0.22326, 0.22326, 0.22326, 0.22326, 0.22326, 0.23893, 0.25743, 0.26842, 0.27548, 0.28427,
+        0.29488, 0.30635, 0.31906, 0.33377, 0.3515, 0.3731, 0.39842, 0.42668, 0.45565, 0.48271,
+        0.50514, 0.52064, 0.52858, 0.52895, 0.5225, 0.51016, 0.49295, 0.47185, 0.44761, 0.42095,
+        0.39281, 0.36403, 0.33487, 0.30556, 0.27667, 0.24893, 0.22344, 0.20115, 0.18221, 0.16643,
+        0.1531, 0.14145, 0.13099, 0.12147, 0.1132, 0.10657, 0.10171, 0.09859, 0.09675, 0.09567,
+        0.095, 0.09454, 0.0944, 0.09475, 0.09577, 0.09754, 0.09981, 0.10231, 0.10484, 0.10725,
+        0.10932, 0.11084, 0.11159, 0.11151, 0.11115, 0.11115, 0.11115, 0.11115, 0.11115, 0.11115,
+        0.11115, 0.11115, 0.11115, 0.11115, 0.11115, 0.11115, 0.11115, 0.11115, 0.11115, 0.11115,
+        0.11115,
+    ],
+    [
+        0.00602, 0.00905, 0.0136, 0.02039, 0.02949, 0.04609, 0.06811, 0.09024, 0.10713, 0.11533,
+        0.11875, 0.12207, 0.1255, 0.1285, 0.13206, 0.13733, 0.14467, 0.15417, 0.16581, 0.17902,
+        0.19103, 0.1988, 0.20027, 0.19443, 0.18339, 0.16987, 0.15589, 0.14313, 0.13275, 0.12537,
+        0.12004, 0.11552, 0.11101, 0.10609, 0.10157, 0.09857, 0.0981, 0.10068, 0.10475, 0.10827,
+        0.10922, 0.10612, 0.09949, 0.09047, 0.08072, 0.07169, 0.06355, 0.05616, 0.04935, 0.04295,
+        0.03694, 0.03133, 0.02631, 0.02207, 0.01867, 0.01613, 0.0143, 0.01301, 0.01211, 0.0115,
+        0.01112, 0.01094, 0.01092, 0.01106, 0.01132, 0.01139, 0.01154, 0.01169, 0.01184, 0.012,
+        0.01216, 0.01232, 0.01248, 0.01265, 0.01281, 0.01298, 0.01315, 0.01332, 0.0135, 0.01368,
+        0.01386,
+    ],
+    [
+        0.1249, 0.1249, 0.1249, 0.1249, 0.1249, 0.13231, 0.1413, 0.14721, 0.1518, 0.1578, 0.1655,
+        0.17467, 0.1854, 0.19798, 0.213, 0.23107, 0.2524, 0.2766, 0.3011, 0.32295, 0.3397, 0.34943,
+        0.3519, 0.34737, 0.3365, 0.32016, 0.2998, 0.27692, 0.2527, 0.2282, 0.2043, 0.18174, 0.1609,
+        0.14202, 0.1252, 0.11054, 0.0983, 0.08866, 0.0813, 0.07575, 0.0715, 0.06811, 0.0653, 0.0629,
+        0.0609, 0.05933, 0.0582, 0.05751, 0.0571, 0.0568, 0.0566, 0.0565, 0.0565, 0.05658, 0.0568,
+        0.0572, 0.0577, 0.05822, 0.0587, 0.0591, 0.0594, 0.05961, 0.0597, 0.05967, 0.0596, 0.0596,
+        0.0596, 0.0596, 0.0596, 0.0596, 0.0596, 0.0596, 0.0596, 0.0596, 0.0596, 0.0596, 0.0596,
+        0.0596, 0.0596, 0.0596, 0.0596,
+    ],
+    [
+        0.147, 0.147, 0.147, 0.147, 0.147, 0.15842, 0.1722, 0.18102, 0.1872, 0.19467, 0.2042,
+        0.21573, 0.2291, 0.24431, 0.262, 0.28281, 0.3068, 0.3333, 0.3594, 0.38192, 0.3989, 0.40878,
+        0.4103, 0.40282, 0.3879, 0.36754, 0.3433, 0.31661, 0.2889, 0.26145, 0.2351, 0.21043, 0.1876,
+        0.16666, 0.1477, 0.1309, 0.1167, 0.10545, 0.0968, 0.09021, 0.0851, 0.08095, 0.0774, 0.07421,
+        0.0714, 0.06907, 0.0674, 0.0665, 0.0661, 0.06589, 0.0657, 0.06547, 0.0654, 0.06568, 0.0663,
+        0.06714, 0.068, 0.06869, 0.0691, 0.0692, 0.0691, 0.06891, 0.0686, 0.06815, 0.0678, 0.0678,
+        0.0678, 0.0678, 0.0678, 0.0678, 0.0678, 0.0678, 0.0678, 0.0678, 0.0678, 0.0678, 0.0678,
+        0.0678, 0.0678, 0.0678, 0.0678,
+    ],
+    [
+        0.22149, 0.23594, 0.25104, 0.26676, 0.28245, 0.30036, 0.31769, 0.33467, 0.35149, 0.36826,
+        0.38457, 0.39997, 0.41415, 0.4269, 0.43836, 0.44837, 0.45536, 0.4581, 0.45804, 0.45745,
+        0.45897, 0.46435, 0.47144, 0.47732, 0.47999, 0.47816, 0.47257, 0.46378, 0.4497, 0.42856,
+        0.40271, 0.3752, 0.34809, 0.32279, 0.29914, 0.27704, 0.25793, 0.24313, 0.23196, 0.22349,
+        0.21784, 0.21519, 0.21485, 0.21593, 0.21763, 0.21934, 0.22104, 0.22281, 0.22444, 0.22571,
+        0.22681, 0.22807, 0.23021, 0.23373, 0.23804, 0.24224, 0.24535, 0.24669, 0.24669, 0.24599,
+        0.24484, 0.24338, 0.24154, 0.23921, 0.23629, 0.23428, 0.23192, 0.22958, 0.22726, 0.22495,
+        0.22267, 0.22039, 0.21814, 0.2159, 0.21368, 0.21147, 0.20928, 0.20711, 0.20495, 0.20282,
+        0.20069,
+    ],
+    [
+        0.13225, 0.13933, 0.14672, 0.15443, 0.1622, 0.17098, 0.17961, 0.18831, 0.19733, 0.20679,
+        0.2163, 0.2254, 0.23381, 0.24136, 0.24814, 0.25399, 0.25741, 0.25724, 0.25494, 0.25267,
+        0.25277, 0.25679, 0.26298, 0.26902, 0.27359, 0.27587, 0.27606, 0.27413, 0.26802, 0.25609,
+        0.24031, 0.22327, 0.2064, 0.19064, 0.176, 0.1626, 0.15168, 0.14438, 0.14004, 0.13802,
+        0.13932, 0.14481, 0.15313, 0.16242, 0.17116, 0.1782, 0.18373, 0.18816, 0.19156, 0.19396,
+        0.19558, 0.19682, 0.19847, 0.20117, 0.20444, 0.20756, 0.2098, 0.21071, 0.21063, 0.21003,
+        0.20908, 0.20784, 0.2063, 0.20442, 0.20218, 0.20051, 0.19863, 0.19676, 0.19491, 0.19307,
+        0.19124, 0.18942, 0.18762, 0.18584, 0.18406, 0.1823, 0.18055, 0.17882, 0.17709, 0.17538,
+        0.17369,
+    ],
+    [
+        0.31794, 0.34608, 0.37534, 0.40554, 0.43537, 0.46845, 0.49988, 0.5299, 0.55872, 0.58644,
+        0.61261, 0.63672, 0.65857, 0.67805, 0.69495, 0.7089, 0.71896, 0.72438, 0.72576, 0.72392,
+        0.71916, 0.71161, 0.70123, 0.688, 0.67187, 0.65288, 0.63147, 0.60796, 0.58201, 0.55343,
+        0.52327, 0.49283, 0.4632, 0.43523, 0.40899, 0.38458, 0.36303, 0.34528, 0.33078, 0.3188,
+        0.30924, 0.30208, 0.29698, 0.29355, 0.29142, 0.29027, 0.28997, 0.29041, 0.29121, 0.29206,
+        0.29307, 0.2945, 0.29688, 0.30057, 0.30502, 0.30941, 0.31264, 0.31392, 0.31378, 0.31299,
+        0.31182, 0.31034, 0.30852, 0.30629, 0.30358, 0.3016, 0.29933, 0.29708, 0.29484, 0.2926,
+        0.29038, 0.28817, 0.28597, 0.28377, 0.28159, 0.27942, 0.27726, 0.27511, 0.27296, 0.27083,
+        0.26871,
+    ],
+    [
+        0.0504, 0.0504, 0.0504, 0.0504, 0.0504, 0.05485, 0.0617, 0.06943, 0.0786, 0.09002, 0.1034,
+        0.11814, 0.1336, 0.14925, 0.1652, 0.18141, 0.1967, 0.20953, 0.2182, 0.2214, 0.2195, 0.21333,
+        0.2039, 0.19206, 0.178, 0.16197, 0.1452, 0.12916, 0.1153, 0.10467, 0.0969, 0.091, 0.085,
+        0.07718, 0.0677, 0.05734, 0.0473, 0.03901, 0.0343, 0.03478, 0.0408, 0.05134, 0.0612, 0.0654,
+        0.0641, 0.05901, 0.053, 0.04859, 0.0459, 0.04443, 0.0437, 0.04331, 0.0431, 0.04314, 0.0441,
+        0.04681, 0.052, 0.06016, 0.0708, 0.08316, 0.0963, 0.10963, 0.1242, 0.14004, 0.1514, 0.1514,
+        0.1514, 0.1514, 0.1514, 0.1514, 0.1514, 0.1514, 0.1514, 0.1514, 0.1514, 0.1514, 0.1514,
+        0.1514, 0.1514, 0.1514, 0.1514,
+    ],
+    [
+        0.37756, 0.39319, 0.40905, 0.42509, 0.44022, 0.45815, 0.47443, 0.48877, 0.50091, 0.51073,
+        0.51874, 0.52556, 0.53162, 0.53716, 0.54203, 0.54598, 0.54903, 0.55122, 0.55254, 0.553,
+        0.55264, 0.55149, 0.54955, 0.54681, 0.54326, 0.53894, 0.53389, 0.52809, 0.52121, 0.51298,
+        0.5039, 0.49461, 0.48566, 0.47742, 0.46979, 0.46263, 0.45609, 0.45034, 0.44537, 0.44115,
+        0.43795, 0.43598, 0.43496, 0.4346, 0.43496, 0.4361, 0.43774, 0.43955, 0.44114, 0.44227,
+        0.4431, 0.44397, 0.44537, 0.44767, 0.45052, 0.45332, 0.45526, 0.45577, 0.45536, 0.45475,
+        0.45433, 0.45436, 0.45475, 0.45534, 0.45598, 0.45652, 0.45709, 0.45767, 0.45825, 0.45883,
+        0.45941, 0.45998, 0.46056, 0.46114, 0.46172, 0.4623, 0.46288, 0.46346, 0.46404, 0.46462,
+        0.46519,
+    ],
+    [
+        0.1616, 0.18016, 0.20034, 0.22217, 0.23998, 0.2737, 0.29807, 0.32072, 0.3493, 0.38927,
+        0.43737, 0.48744, 0.53051, 0.55904, 0.57419, 0.57916, 0.57668, 0.56907, 0.55741, 0.54267,
+        0.52663, 0.51095, 0.49575, 0.48059, 0.46418, 0.44544, 0.42486, 0.40323, 0.38078, 0.35769,
+        0.33451, 0.31157, 0.28794, 0.26281, 0.23701, 0.21187, 0.18905, 0.16994, 0.15459, 0.14279,
+        0.13464, 0.13012, 0.12858, 0.12914, 0.13086, 0.13275, 0.13364, 0.13271, 0.13047, 0.12777,
+        0.1255, 0.12447, 0.1252, 0.12807, 0.13295, 0.13949, 0.14695, 0.15462, 0.16234, 0.17089,
+        0.18428, 0.20617, 0.23561, 0.27055, 0.30889, 0.3489, 0.39166, 0.43615, 0.48169, 0.52754,
+        0.57293, 0.61712, 0.65946, 0.6994, 0.73652, 0.77057, 0.8014, 0.829, 0.85348, 0.87497,
+        0.89371,
+    ],
+    [
+        0.03388, 0.03483, 0.03581, 0.03682, 0.03783, 0.03893, 0.04001, 0.04112, 0.0423, 0.0436,
+        0.04508, 0.04678, 0.04885, 0.05133, 0.05391, 0.05623, 0.05798, 0.05898, 0.05937, 0.05937,
+        0.05927, 0.05923, 0.05888, 0.05785, 0.05629, 0.05448, 0.05282, 0.05164, 0.05083, 0.05013,
+        0.04915, 0.04764, 0.04597, 0.04463, 0.04398, 0.04429, 0.04547, 0.04724, 0.04885, 0.04958,
+        0.04934, 0.04829, 0.04686, 0.04551, 0.04448, 0.04393, 0.04378, 0.0439, 0.04408, 0.0442,
+        0.04428, 0.04442, 0.04478, 0.04555, 0.04706, 0.04965, 0.05351, 0.0589, 0.06642, 0.07676,
+        0.09035, 0.10762, 0.12907, 0.15524, 0.18665, 0.22083, 0.26013, 0.30371, 0.35111, 0.40165,
+        0.45437, 0.50813, 0.56171, 0.61388, 0.66357, 0.70988, 0.7522, 0.79016, 0.82368, 0.85284,
+        0.87789,
+    ],
+    [
+        0.0509, 0.0509, 0.0509, 0.0509, 0.0509, 0.05866, 0.0691, 0.07815, 0.0867, 0.0965, 0.1076,
+        0.11962, 0.1322, 0.14512, 0.1587, 0.17307, 0.1869, 0.19843, 0.2055, 0.20636, 0.2012,
+        0.19094, 0.1774, 0.16234, 0.1465, 0.1304, 0.1148, 0.10054, 0.0886, 0.07973, 0.0736, 0.06947,
+        0.066, 0.06197, 0.0572, 0.05188, 0.0467, 0.04255, 0.0405, 0.04145, 0.0454, 0.05156, 0.0568,
+        0.05823, 0.0562, 0.05198, 0.0473, 0.04369, 0.0413, 0.03997, 0.0395, 0.0397, 0.0403, 0.04114,
+        0.0426, 0.04518, 0.0493, 0.05525, 0.0627, 0.07116, 0.08, 0.08885, 0.0984, 0.10867, 0.116,
+        0.116, 0.116, 0.116, 0.116, 0.116, 0.116, 0.116, 0.116, 0.116, 0.116, 0.116, 0.116, 0.116,
+        0.116, 0.116, 0.116,
+    ],
+    [
+        0.2886, 0.2886, 0.2886, 0.2886, 0.2886, 0.31111, 0.3396, 0.36066, 0.3777, 0.39675, 0.4175,
+        0.43808, 0.4568, 0.47192, 0.4812, 0.48293, 0.4779, 0.46766, 0.4543, 0.43959, 0.4236,
+        0.40603, 0.3869, 0.36621, 0.3435, 0.31848, 0.2919, 0.26503, 0.2402, 0.21929, 0.2015,
+        0.18521, 0.1684, 0.14935, 0.128, 0.10516, 0.0834, 0.06563, 0.0542, 0.05104, 0.0568, 0.07068,
+        0.0872, 0.10057, 0.1085, 0.11045, 0.1094, 0.10857, 0.1087, 0.10986, 0.1119, 0.11481, 0.1194,
+        0.12646, 0.1358, 0.14672, 0.1574, 0.16579, 0.1702, 0.16964, 0.1657, 0.15994, 0.1512,
+        0.13917, 0.1297, 0.1297, 0.1297, 0.1297, 0.1297, 0.1297, 0.1297, 0.1297, 0.1297, 0.1297,
+        0.1297, 0.1297, 0.1297, 0.1297, 0.1297, 0.1297, 0.1297,
+    ],
+    [
+        0.02805, 0.03537, 0.05422, 0.0783, 0.10593, 0.13046, 0.15591, 0.1814, 0.20586, 0.22735,
+        0.24649, 0.26234, 0.27425, 0.28313, 0.29062, 0.2948, 0.29517, 0.29327, 0.28875, 0.28176,
+        0.27334, 0.26346, 0.25141, 0.23809, 0.22535, 0.21297, 0.20098, 0.18934, 0.1775, 0.16598,
+        0.15478, 0.14464, 0.13534, 0.12632, 0.11734, 0.1087, 0.10113, 0.09491, 0.08967, 0.08526,
+        0.08169, 0.07914, 0.07764, 0.07707, 0.0773, 0.07825, 0.08023, 0.08357, 0.08886, 0.09681,
+        0.10784, 0.12216, 0.13992, 0.16133, 0.18574, 0.21193, 0.23877, 0.26496, 0.28852, 0.30737,
+        0.32249, 0.33247, 0.33782, 0.33897, 0.33667, 0.33133, 0.32425, 0.31788, 0.31165, 0.30494,
+        0.29854, 0.29222, 0.28597, 0.27981, 0.27373, 0.26774, 0.26182, 0.25589, 0.25007, 0.24437,
+        0.23878,
+    ],
+    [
+        0.12947, 0.14167, 0.15481, 0.16893, 0.1812, 0.20172, 0.21754, 0.23408, 0.25676, 0.2894,
+        0.32944, 0.37206, 0.40977, 0.43567, 0.4479, 0.44654, 0.43439, 0.41472, 0.39001, 0.36265,
+        0.3355, 0.31099, 0.28943, 0.27027, 0.2517, 0.23219, 0.21258, 0.19421, 0.17803, 0.16458,
+        0.1533, 0.14323, 0.13285, 0.12098, 0.10823, 0.0957, 0.08469, 0.07633, 0.07069, 0.06766,
+        0.06722, 0.06932, 0.07348, 0.079, 0.08479, 0.08966, 0.09254, 0.09274, 0.09095, 0.0882,
+        0.08559, 0.08415, 0.0845, 0.08701, 0.09155, 0.09774, 0.10495, 0.11256, 0.12024, 0.1285,
+        0.14079, 0.16039, 0.18676, 0.21842, 0.25388, 0.2922, 0.33405, 0.37868, 0.42547, 0.47364,
+        0.5223, 0.57054, 0.61747, 0.66232, 0.70442, 0.74331, 0.77869, 0.81043, 0.83857, 0.86323,
+        0.88465,
+    ],
+    [
+        0.56749, 0.57857, 0.58956, 0.60046, 0.60662, 0.62422, 0.63353, 0.63805, 0.64127, 0.64576,
+        0.65031, 0.65295, 0.65239, 0.64837, 0.64415, 0.64297, 0.64445, 0.64684, 0.64673, 0.64119,
+        0.63104, 0.61778, 0.60176, 0.5833, 0.56373, 0.54443, 0.526, 0.50863, 0.49145, 0.4737,
+        0.45601, 0.43928, 0.42404, 0.41066, 0.39922, 0.38961, 0.38145, 0.3742, 0.36715, 0.35995,
+        0.35394, 0.3506, 0.35017, 0.35247, 0.35672, 0.36207, 0.36794, 0.37408, 0.38115, 0.39035,
+        0.40398, 0.42415, 0.45114, 0.4846, 0.52342, 0.56583, 0.60811, 0.6465, 0.6791, 0.70496,
+        0.72516, 0.74134, 0.75525, 0.76868, 0.78344, 0.79525, 0.80745, 0.81908, 0.83016, 0.84069,
+        0.85069, 0.86016, 0.86913, 0.8776, 0.88559, 0.89313, 0.90023, 0.9069, 0.91317, 0.91906,
+        0.92458,
+    ],
+    [
+        0.31684, 0.34433, 0.36972, 0.39599, 0.42314, 0.45025, 0.47575, 0.49823, 0.51751, 0.53377,
+        0.54733, 0.55847, 0.5672, 0.5734, 0.57683, 0.57723, 0.57441, 0.56815, 0.55801, 0.5436,
+        0.52497, 0.50256, 0.4778, 0.45229, 0.4272, 0.40344, 0.3814, 0.36127, 0.34289, 0.32612,
+        0.31138, 0.29903, 0.28877, 0.28021, 0.27333, 0.2682, 0.26479, 0.26307, 0.26304, 0.26462,
+        0.26728, 0.27052, 0.27437, 0.2789, 0.2839, 0.28921, 0.29508, 0.30178, 0.30938, 0.31796,
+        0.32802, 0.34004, 0.35416, 0.37048, 0.38943, 0.41133, 0.43586, 0.4626, 0.49134, 0.52196,
+        0.55435, 0.58815, 0.62191, 0.65419, 0.68463, 0.71299, 0.73843, 0.76029, 0.77927, 0.79615,
+        0.81061, 0.82241, 0.83267, 0.84211, 0.84839, 0.84957, 0.84839, 0.848, 0.84839, 0.84878,
+        0.84839,
+    ],
+    [
+        0.12067, 0.17602, 0.23298, 0.27447, 0.30442, 0.32357, 0.33682, 0.34501, 0.34763, 0.35069,
+        0.355, 0.35271, 0.34519, 0.33814, 0.33335, 0.32585, 0.31343, 0.30296, 0.29343, 0.27907,
+        0.26179, 0.24644, 0.23007, 0.21652, 0.20356, 0.18994, 0.17481, 0.15838, 0.14046, 0.12315,
+        0.11069, 0.10618, 0.1031, 0.09997, 0.09749, 0.09121, 0.08211, 0.0744, 0.06799, 0.06738,
+        0.07171, 0.08124, 0.09024, 0.0963, 0.10233, 0.10517, 0.1001, 0.09536, 0.09978, 0.11368,
+        0.13297, 0.16167, 0.19616, 0.23898, 0.29173, 0.34345, 0.37835, 0.40403, 0.42472, 0.436,
+        0.44674, 0.45591, 0.46076, 0.46165, 0.46531, 0.47145, 0.47513, 0.47881, 0.4825, 0.48618,
+        0.48987, 0.49356, 0.49725, 0.50094, 0.50463, 0.50832, 0.51201, 0.5157, 0.51938, 0.52307,
+        0.52675,
+    ],
+    [
+        0.7365, 0.7365, 0.7365, 0.7365, 0.7365, 0.74377, 0.7532, 0.76059, 0.7666, 0.77259, 0.7779,
+        0.78171, 0.7845, 0.78682, 0.7882, 0.78804, 0.7862, 0.78261, 0.7771, 0.76964, 0.761, 0.75186,
+        0.7418, 0.73044, 0.7188, 0.70796, 0.6979, 0.68834, 0.679, 0.6698, 0.6615, 0.65484, 0.6497,
+        0.64587, 0.6438, 0.64381, 0.6451, 0.64674, 0.6483, 0.64956, 0.6507, 0.652, 0.6538, 0.65629,
+        0.6591, 0.66177, 0.6639, 0.66528, 0.6663, 0.66737, 0.6684, 0.66966, 0.6735, 0.68201, 0.6942,
+        0.70871, 0.7258, 0.74588, 0.7684, 0.79213, 0.8142, 0.83253, 0.8499, 0.86826, 0.8814, 0.8814,
+        0.8814, 0.8814, 0.8814, 0.8814, 0.8814, 0.8814, 0.8814, 0.8814, 0.8814, 0.8814, 0.8814,
+        0.8814, 0.8814, 0.8814, 0.8814,
+    ],
+    [
+        0.5957, 0.5957, 0.5957, 0.5957, 0.5957, 0.60396, 0.6132, 0.61739, 0.618, 0.61762, 0.6159,
+        0.61213, 0.6071, 0.60152, 0.5944, 0.58478, 0.5736, 0.56173, 0.5479, 0.53111, 0.5136,
+        0.49766, 0.4827, 0.46771, 0.453, 0.43916, 0.4267, 0.41592, 0.4064, 0.39769, 0.39, 0.38378,
+        0.3797, 0.37817, 0.3783, 0.37928, 0.3818, 0.38643, 0.3918, 0.39639, 0.4001, 0.40309, 0.4052,
+        0.4063, 0.4067, 0.40672, 0.4062, 0.40499, 0.4033, 0.40177, 0.4024, 0.40703, 0.4156, 0.42779,
+        0.4442, 0.46526, 0.4898, 0.51664, 0.5461, 0.57781, 0.607, 0.63023, 0.6536, 0.68162, 0.7029,
+        0.7029, 0.7029, 0.7029, 0.7029, 0.7029, 0.7029, 0.7029, 0.7029, 0.7029, 0.7029, 0.7029,
+        0.7029, 0.7029, 0.7029, 0.7029, 0.7029,
+    ],
+    [
+        0.03375, 0.03555, 0.03745, 0.03943, 0.04138, 0.04379, 0.04605, 0.0483, 0.05071, 0.05334,
+        0.05597, 0.05829, 0.06004, 0.06105, 0.06153, 0.06171, 0.06143, 0.06054, 0.05935, 0.05833,
+        0.05816, 0.05926, 0.06103, 0.06267, 0.06351, 0.06315, 0.06193, 0.06027, 0.05806, 0.05512,
+        0.0517, 0.04814, 0.04456, 0.04107, 0.03781, 0.03496, 0.03285, 0.03177, 0.03156, 0.03212,
+        0.03394, 0.03742, 0.04208, 0.04716, 0.0517, 0.05496, 0.05726, 0.05907, 0.06044, 0.06132,
+        0.06183, 0.06214, 0.06252, 0.06316, 0.06391, 0.06457, 0.065, 0.06512, 0.065, 0.06478,
+        0.06451, 0.06423, 0.06391, 0.06349, 0.06292, 0.06257, 0.06214, 0.06171, 0.06128, 0.06085,
+        0.06043, 0.06001, 0.05959, 0.05918, 0.05877, 0.05836, 0.05795, 0.05755, 0.05715, 0.05675,
+        0.05635,
+    ],
+    [
+        0.01425, 0.01292, 0.01731, 0.02444, 0.03831, 0.0494, 0.06324, 0.07955, 0.09523, 0.11233,
+        0.13166, 0.1495, 0.1582, 0.16275, 0.17107, 0.17496, 0.17395, 0.1737, 0.17216, 0.16727,
+        0.16007, 0.15576, 0.14927, 0.14321, 0.13436, 0.12644, 0.12123, 0.11599, 0.11004, 0.104,
+        0.09716, 0.09359, 0.09213, 0.09261, 0.09066, 0.08902, 0.08907, 0.08792, 0.08794, 0.08588,
+        0.08463, 0.08725, 0.09088, 0.09758, 0.10274, 0.10874, 0.10959, 0.11171, 0.11603, 0.12276,
+        0.12916, 0.13826, 0.14464, 0.15049, 0.16785, 0.19265, 0.20784, 0.22794, 0.25255, 0.26974,
+        0.28737, 0.30481, 0.3163, 0.32417, 0.33878, 0.34908, 0.36054, 0.37215, 0.38392, 0.39582,
+        0.40785, 0.41999, 0.43223, 0.44455, 0.45694, 0.46938, 0.48186, 0.49436, 0.50687, 0.51938,
+        0.53185,
+    ],
+    [
+        0.50252, 0.514, 0.52546, 0.53689, 0.54636, 0.56056, 0.57138, 0.57981, 0.58686, 0.59284,
+        0.5952, 0.59176, 0.58448, 0.57575, 0.56542, 0.55287, 0.53821, 0.52174, 0.50366, 0.48437,
+        0.46484, 0.44597, 0.42761, 0.40934, 0.39068, 0.37145, 0.35265, 0.33549, 0.32078, 0.3089,
+        0.29884, 0.28927, 0.27879, 0.26664, 0.25456, 0.24483, 0.23947, 0.23969, 0.24364, 0.24875,
+        0.25258, 0.25331, 0.25139, 0.24833, 0.24771, 0.25339, 0.26836, 0.29491, 0.33339, 0.38325,
+        0.44221, 0.5067, 0.56969, 0.62438, 0.66837, 0.70087, 0.72318, 0.73733, 0.74601, 0.75185,
+        0.75644, 0.76102, 0.76637, 0.77313, 0.78195, 0.78747, 0.79409, 0.80056, 0.80687, 0.81303,
+        0.81904, 0.8249, 0.8306, 0.83616, 0.84157, 0.84683, 0.85195, 0.85693, 0.86177, 0.86647,
+        0.87103,
+    ],
+    [
+        0.10613, 0.11586, 0.12635, 0.13764, 0.14596, 0.16476, 0.17753, 0.18653, 0.19401, 0.20193,
+        0.21099, 0.22114, 0.23055, 0.237, 0.23849, 0.23384, 0.22469, 0.21322, 0.20076, 0.18829,
+        0.17614, 0.16448, 0.1534, 0.1429, 0.13255, 0.12197, 0.1112, 0.10054, 0.09085, 0.08296,
+        0.07705, 0.07288, 0.06921, 0.06482, 0.05957, 0.05375, 0.04826, 0.04403, 0.0414, 0.04111,
+        0.04617, 0.05927, 0.07933, 0.10352, 0.1255, 0.13984, 0.14804, 0.15301, 0.15638, 0.15927,
+        0.16204, 0.16492, 0.1682, 0.17215, 0.17684, 0.182, 0.18627, 0.1883, 0.18796, 0.18571,
+        0.18319, 0.18185, 0.1812, 0.18026, 0.17803, 0.17751, 0.17632, 0.17513, 0.17395, 0.17278,
+        0.17161, 0.17045, 0.16929, 0.16814, 0.167, 0.16586, 0.16473, 0.16361, 0.16249, 0.16138,
+        0.16027,
+    ],
+    [
+        0.4264, 0.44033, 0.45435, 0.46845, 0.4749, 0.50054, 0.51283, 0.51584, 0.51363, 0.50938,
+        0.5028, 0.49313, 0.48116, 0.46804, 0.45465, 0.44164, 0.42903, 0.41662, 0.40401, 0.39068,
+        0.37591, 0.35912, 0.34056, 0.32103, 0.30254, 0.28662, 0.27176, 0.25598, 0.23869, 0.22042,
+        0.20493, 0.1958, 0.19252, 0.19315, 0.19401, 0.1919, 0.18706, 0.18151, 0.18071, 0.18983,
+        0.2094, 0.23795, 0.27076, 0.30302, 0.33272, 0.35883, 0.38147, 0.40076, 0.41563, 0.42508,
+        0.42953, 0.4299, 0.42774, 0.42497, 0.42427, 0.42833, 0.43926, 0.45877, 0.48761, 0.52562,
+        0.56992, 0.61695, 0.66286, 0.70375, 0.73574, 0.77275, 0.80334, 0.83071, 0.85496, 0.87625,
+        0.89481, 0.91086, 0.92467, 0.93649, 0.94656, 0.95511, 0.96235, 0.96846, 0.9736, 0.97793,
+        0.98156,
+    ],
+    [
+        0.07275, 0.08208, 0.09249, 0.10408, 0.11546, 0.13191, 0.14757, 0.16011, 0.16724, 0.16828,
+        0.16927, 0.17537, 0.1816, 0.1823, 0.17906, 0.17458, 0.16856, 0.16017, 0.1494, 0.13679,
+        0.12423, 0.11336, 0.10344, 0.09358, 0.08459, 0.07741, 0.07195, 0.06776, 0.0642, 0.06078,
+        0.05768, 0.05526, 0.05381, 0.05358, 0.05483, 0.05763, 0.06145, 0.06564, 0.06961, 0.07304,
+        0.07664, 0.0812, 0.08693, 0.09386, 0.10181, 0.11045, 0.11913, 0.12714, 0.13391, 0.13922,
+        0.1439, 0.14909, 0.15603, 0.16583, 0.17906, 0.19607, 0.21697, 0.2417, 0.26986, 0.30107,
+        0.33549, 0.37309, 0.41253, 0.45219, 0.49039, 0.53155, 0.57117, 0.60989, 0.64728, 0.68294,
+        0.71658, 0.74797, 0.77696, 0.8035, 0.82757, 0.84925, 0.86864, 0.88587, 0.9011, 0.91449,
+        0.92621,
+    ],
+    [
+        0.2617, 0.2617, 0.2617, 0.2617, 0.2617, 0.27271, 0.2838, 0.28598, 0.2822, 0.27711, 0.2703,
+        0.26063, 0.249, 0.23663, 0.2239, 0.21107, 0.1987, 0.18718, 0.1759, 0.16417, 0.152, 0.13979,
+        0.1287, 0.11977, 0.1126, 0.10642, 0.1002, 0.09321, 0.086, 0.07945, 0.0743, 0.07114, 0.0699,
+        0.07019, 0.0709, 0.07109, 0.0711, 0.07175, 0.0745, 0.08084, 0.0919, 0.10811, 0.1276,
+        0.14791, 0.1665, 0.1813, 0.1923, 0.20011, 0.2057, 0.20999, 0.2134, 0.21626, 0.2189, 0.22164,
+        0.2246, 0.22768, 0.2301, 0.23137, 0.2329, 0.23606, 0.2401, 0.24393, 0.2472, 0.24973, 0.2512,
+        0.2512, 0.2512, 0.2512, 0.2512, 0.2512, 0.2512, 0.2512, 0.2512, 0.2512, 0.2512, 0.2512,
+        0.2512, 0.2512, 0.2512, 0.2512, 0.2512,
+    ],
+    [
+        0.02966, 0.03379, 0.04777, 0.07917, 0.13075, 0.17691, 0.22905, 0.28446, 0.34279, 0.4011,
+        0.45479, 0.5015, 0.53963, 0.56762, 0.58708, 0.60061, 0.6085, 0.61064, 0.60879, 0.60406,
+        0.59734, 0.59009, 0.58275, 0.57511, 0.56692, 0.55949, 0.55297, 0.54597, 0.53793, 0.52943,
+        0.52207, 0.51669, 0.51244, 0.50807, 0.50371, 0.50097, 0.50109, 0.50342, 0.50601, 0.50809,
+        0.51072, 0.51513, 0.52154, 0.52925, 0.53705, 0.54511, 0.55458, 0.56537, 0.57661, 0.58749,
+        0.59586, 0.60146, 0.60694, 0.61324, 0.61886, 0.62331, 0.6279, 0.6315, 0.63258, 0.63234,
+        0.63235, 0.62884, 0.62321, 0.61633, 0.60749, 0.59715, 0.5883, 0.58146, 0.57354, 0.56538,
+        0.55745, 0.54949, 0.54151, 0.5335, 0.52548, 0.51744, 0.5094, 0.50135, 0.49329, 0.48523,
+        0.47718,
+    ],
+    [
+        0.00526, 0.02448, 0.05165, 0.07373, 0.0967, 0.12048, 0.14705, 0.17355, 0.19903, 0.22337,
+        0.24497, 0.26321, 0.27932, 0.29203, 0.2999, 0.3027, 0.30154, 0.29826, 0.29386, 0.28892,
+        0.28247, 0.27445, 0.26514, 0.25488, 0.24532, 0.23679, 0.22756, 0.21696, 0.20634, 0.19676,
+        0.18816, 0.18045, 0.1734, 0.16725, 0.16263, 0.16, 0.15893, 0.15868, 0.15918, 0.16048,
+        0.16283, 0.16661, 0.17222, 0.18041, 0.19233, 0.20953, 0.2335, 0.26384, 0.2985, 0.33518,
+        0.37373, 0.41399, 0.45456, 0.49236, 0.52439, 0.55001, 0.57049, 0.58799, 0.6057, 0.62287,
+        0.63523, 0.64717, 0.65931, 0.67212, 0.68575, 0.7006, 0.71303, 0.72275, 0.7337, 0.74439,
+        0.75455, 0.76442, 0.77403, 0.78335, 0.79239, 0.80115, 0.80963, 0.81816, 0.82632, 0.83411,
+        0.84154,
+    ],
+    [
+        0.23077, 0.24352, 0.25636, 0.2692, 0.28195, 0.2946, 0.30754, 0.3213, 0.33662, 0.35357,
+        0.36925, 0.38105, 0.39054, 0.39975, 0.40839, 0.41539, 0.41877, 0.41719, 0.41283, 0.40771,
+        0.39971, 0.38682, 0.37157, 0.357, 0.34356, 0.33108, 0.31952, 0.30897, 0.30003, 0.29316,
+        0.28788, 0.28365, 0.28066, 0.27914, 0.2788, 0.27935, 0.28107, 0.28419, 0.28827, 0.29295,
+        0.29889, 0.30683, 0.3167, 0.32832, 0.34189, 0.35764, 0.37558, 0.39567, 0.41783, 0.44204,
+        0.4684, 0.49679, 0.52606, 0.55502, 0.58337, 0.61088, 0.63663, 0.65968, 0.67984, 0.69709,
+        0.71148, 0.72321, 0.73309, 0.74189, 0.74957, 0.75581, 0.76013, 0.76226, 0.76306, 0.76369,
+        0.7653, 0.7657, 0.76658, 0.76746, 0.76833, 0.7692, 0.77007, 0.77094, 0.77181, 0.77267,
+        0.77353,
+    ],
+    [
+        0.06102, 0.06701, 0.07354, 0.08065, 0.0881, 0.09694, 0.10607, 0.11496, 0.12312, 0.13016,
+        0.13629, 0.14162, 0.14547, 0.14718, 0.147, 0.14528, 0.1418, 0.13637, 0.12955, 0.12194,
+        0.11362, 0.10476, 0.09637, 0.08937, 0.08341, 0.07795, 0.07299, 0.06865, 0.06493, 0.06185,
+        0.05952, 0.058, 0.05727, 0.05727, 0.05788, 0.05896, 0.06033, 0.06187, 0.0637, 0.06599,
+        0.06891, 0.07264, 0.07748, 0.08381, 0.09218, 0.10311, 0.11679, 0.13341, 0.15364, 0.17791,
+        0.2053, 0.23477, 0.26624, 0.29975, 0.33484, 0.37096, 0.40773, 0.44475, 0.48134, 0.51686,
+        0.55127, 0.58455, 0.6163, 0.646, 0.67316, 0.70121, 0.72707, 0.75148, 0.77439, 0.79576,
+        0.81558, 0.83389, 0.85071, 0.8661, 0.88012, 0.89286, 0.9044, 0.91481, 0.92418, 0.93259,
+        0.94014,
+    ],
+    [
+        0.07372, 0.09065, 0.111, 0.13525, 0.16428, 0.19678, 0.23541, 0.27695, 0.31816, 0.35643,
+        0.39144, 0.42267, 0.44627, 0.45905, 0.4639, 0.46464, 0.46288, 0.45926, 0.45279, 0.44269,
+        0.43067, 0.41862, 0.40662, 0.39437, 0.38166, 0.36832, 0.35445, 0.34032, 0.32703, 0.31562,
+        0.30624, 0.2988, 0.29299, 0.28848, 0.28494, 0.28227, 0.28117, 0.28238, 0.28596, 0.29206,
+        0.30186, 0.31662, 0.33692, 0.36293, 0.39399, 0.42915, 0.46746, 0.5075, 0.54614, 0.58068,
+        0.61187, 0.64119, 0.66965, 0.69796, 0.72611, 0.75367, 0.779, 0.80048, 0.81742, 0.82988,
+        0.83974, 0.84881, 0.85666, 0.86235, 0.86492, 0.87151, 0.87627, 0.88089, 0.88535, 0.88966,
+        0.89384, 0.89787, 0.90177, 0.90553, 0.90917, 0.91267, 0.91606, 0.91932, 0.92247, 0.92551,
+        0.92844,
+    ],
+    [
+        0.33851, 0.36145, 0.38504, 0.40918, 0.43394, 0.45859, 0.48408, 0.50808, 0.5283, 0.5434,
+        0.55592, 0.5681, 0.57702, 0.5796, 0.57732, 0.57245, 0.56571, 0.55731, 0.54695, 0.53443,
+        0.52046, 0.50593, 0.49141, 0.47729, 0.46359, 0.45025, 0.4372, 0.42459, 0.41355, 0.40517,
+        0.39949, 0.39625, 0.39511, 0.39575, 0.39816, 0.40244, 0.40897, 0.418, 0.42915, 0.44206,
+        0.45737, 0.47574, 0.49692, 0.52053, 0.54655, 0.575, 0.60596, 0.63909, 0.67241, 0.70387,
+        0.73294, 0.75943, 0.78318, 0.80405, 0.82201, 0.83704, 0.84892, 0.85751, 0.86339, 0.86749,
+        0.87174, 0.87761, 0.88387, 0.8886, 0.88988, 0.89554, 0.89928, 0.9029, 0.9064, 0.90979,
+        0.91307, 0.91624, 0.9193, 0.92226, 0.92513, 0.92789, 0.93056, 0.93314, 0.93563, 0.93803,
+        0.94035,
+    ],
+    [
+        0.1766, 0.1766, 0.1766, 0.1766, 0.1766, 0.19451, 0.2147, 0.22438, 0.2275, 0.23063, 0.2342,
+        0.23707, 0.2381, 0.23625, 0.2309, 0.22182, 0.2098, 0.19595, 0.1817, 0.16814, 0.1548,
+        0.14093, 0.1264, 0.11163, 0.0988, 0.08993, 0.0848, 0.08234, 0.0803, 0.07659, 0.071, 0.06414,
+        0.0582, 0.05623, 0.0633, 0.08396, 0.1187, 0.16498, 0.2121, 0.24927, 0.2735, 0.28466, 0.2861,
+        0.28181, 0.2747, 0.26721, 0.2609, 0.25685, 0.255, 0.25496, 0.2562, 0.25835, 0.262, 0.26778,
+        0.2757, 0.28525, 0.2945, 0.30142, 0.3049, 0.3045, 0.3014, 0.29673, 0.2897, 0.28021, 0.2728,
+        0.2728, 0.2728, 0.2728, 0.2728, 0.2728, 0.2728, 0.2728, 0.2728, 0.2728, 0.2728, 0.2728,
+        0.2728, 0.2728, 0.2728, 0.2728, 0.2728,
+    ],
+    [
+        0.3104, 0.3104, 0.3104, 0.3104, 0.3104, 0.32028, 0.33277, 0.34184, 0.34836, 0.35424,
+        0.35855, 0.35975, 0.3565, 0.34789, 0.3344, 0.31699, 0.29728, 0.27687, 0.25673, 0.23768,
+        0.22049, 0.20561, 0.1923, 0.1797, 0.16765, 0.15642, 0.14727, 0.14136, 0.13849, 0.13788,
+        0.13781, 0.13679, 0.13518, 0.13405, 0.13543, 0.14163, 0.15519, 0.17835, 0.2121, 0.25629,
+        0.30765, 0.36205, 0.4152, 0.46276, 0.50047, 0.52541, 0.54006, 0.54796, 0.55154, 0.55303,
+        0.55484, 0.55912, 0.56677, 0.57838, 0.59454, 0.61548, 0.63996, 0.66655, 0.69439, 0.72255,
+        0.74925, 0.77306, 0.79489, 0.81494, 0.82814, 0.82814, 0.82814, 0.82814, 0.82814, 0.82814,
+        0.82814, 0.82814, 0.82814, 0.82814, 0.82814, 0.82814, 0.82814, 0.82814, 0.82814, 0.82814,
+        0.82814,
+    ],
+    [
+        0.2092, 0.2092, 0.2092, 0.2092, 0.2092, 0.22044, 0.2319, 0.23457, 0.2316, 0.22794, 0.2234,
+        0.21695, 0.2092, 0.201, 0.1926, 0.18414, 0.1758, 0.16773, 0.1598, 0.15183, 0.1438, 0.13581,
+        0.1284, 0.12204, 0.1165, 0.11137, 0.1061, 0.10032, 0.0944, 0.08894, 0.0847, 0.08227, 0.0815,
+        0.08193, 0.0827, 0.08309, 0.0834, 0.08441, 0.0877, 0.09508, 0.1083, 0.12914, 0.1595, 0.2002,
+        0.2475, 0.29719, 0.3477, 0.39782, 0.4451, 0.48712, 0.5228, 0.55159, 0.5737, 0.58975, 0.6013,
+        0.60992, 0.6162, 0.62057, 0.6238, 0.62656, 0.6288, 0.63041, 0.6317, 0.63294, 0.6338, 0.6338,
+        0.6338, 0.6338, 0.6338, 0.6338, 0.6338, 0.6338, 0.6338, 0.6338, 0.6338, 0.6338, 0.6338,
+        0.6338, 0.6338, 0.6338, 0.6338,
+    ],
+];
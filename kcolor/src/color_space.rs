@@ -1,3 +1,4 @@
+pub use crate::chromatic_adaptation::*;
 use crate::white_points::*;
 use crate::Color;
 use kcolor_types::*;
@@ -9,6 +10,10 @@ pub struct ColorSpace {
     pub(crate) to_XYZ: Matrix3x3,
     pub(crate) from_XYZ: Matrix3x3,
     pub(crate) transfer_function: TransferFunction,
+    /// The white point the color space was specified with, before adaptation to D50.
+    pub(crate) white_point: Chromaticity,
+    /// The method used to adapt the white point to D50.
+    pub(crate) adaptation_method: ChromaticAdaptationMethod,
 }
 
 impl ColorSpace {
@@ -24,18 +29,45 @@ impl ColorSpace {
     /// Color spaces have different transfer functions.
     /// More info:
     /// https://en.wikipedia.org/wiki/CIE_1931_color_space#CIE_xy_chromaticity_diagram_and_the_CIE_xyY_color_space
+    ///
+    /// The white point is adapted to D50 with the Bradford method.
     pub fn new(
         red_primary: Chromaticity,
         green_primary: Chromaticity,
         blue_primary: Chromaticity,
         white_point: Chromaticity,
         transfer_function: TransferFunction,
+    ) -> Self {
+        Self::new_with_adaptation_method(
+            red_primary,
+            green_primary,
+            blue_primary,
+            white_point,
+            transfer_function,
+            ChromaticAdaptationMethod::default(),
+        )
+    }
+
+    /// The same as `ColorSpace::new` but the white point is adapted to D50 using
+    /// `adaptation_method`. This is useful to exactly match the numbers produced by other
+    /// software that uses a different method.
+    pub fn new_with_adaptation_method(
+        red_primary: Chromaticity,
+        green_primary: Chromaticity,
+        blue_primary: Chromaticity,
+        white_point: Chromaticity,
+        transfer_function: TransferFunction,
+        adaptation_method: ChromaticAdaptationMethod,
     ) -> Self {
         // Reference:
         // http://www.brucelindbloom.com/index.html?Eqn_RGB_XYZ_Matrix.html
 
-        let white_point_adaptation =
-            ChromaticAdaptation::new(white_point, D50_WHITE_POINT_2DEGREES);
+        let white_point_chromaticity = white_point;
+        let white_point_adaptation = ChromaticAdaptation::new_with_method(
+            white_point,
+            D50_WHITE_POINT_2DEGREES,
+            adaptation_method,
+        );
 
         // Convert the chromaticities to XYZ values and then calculate their
         // relative relation to the white point.
@@ -58,7 +90,11 @@ impl ColorSpace {
         let sb = white_point_adaptation.inner_matrix * sb;
 
         // The primaries should all be shifted to be relative to D50.
-        Self::new_xyz_d50(sr, sg, sb, transfer_function)
+        Self {
+            white_point: white_point_chromaticity,
+            adaptation_method,
+            ..Self::new_xyz_d50(sr, sg, sb, transfer_function)
+        }
     }
 
     /// Create a new color profile with XYZ coordinates for the primaries.
@@ -81,9 +117,85 @@ impl ColorSpace {
             to_XYZ,
             from_XYZ,
             transfer_function,
+            white_point: D50_WHITE_POINT_2DEGREES,
+            adaptation_method: ChromaticAdaptationMethod::Bradford,
         }
     }
 
+    /// The white point the color space was created with.
+    /// Color spaces created from XYZ primaries, such as ICC profiles, return D50.
+    pub fn white_point(&self) -> Chromaticity {
+        self.white_point
+    }
+
+    /// The chromaticity of the red primary relative to the color space's white point.
+    pub fn red_primary(&self) -> Chromaticity {
+        self.primary(self.to_XYZ.c0)
+    }
+
+    /// The chromaticity of the green primary relative to the color space's white point.
+    pub fn green_primary(&self) -> Chromaticity {
+        self.primary(self.to_XYZ.c1)
+    }
+
+    /// The chromaticity of the blue primary relative to the color space's white point.
+    pub fn blue_primary(&self) -> Chromaticity {
+        self.primary(self.to_XYZ.c2)
+    }
+
+    pub fn transfer_function(&self) -> &TransferFunction {
+        &self.transfer_function
+    }
+
+    /// The matrix that converts linear RGB values in this color space to XYZ values relative to D50.
+    /// The matrix is stored in columns and is applied as `to_XYZ_matrix() * rgb`.
+    pub fn to_XYZ_matrix(&self) -> Matrix3x3 {
+        self.to_XYZ
+    }
+
+    /// The matrix that converts XYZ values relative to D50 to linear RGB values in this color space.
+    pub fn from_XYZ_matrix(&self) -> Matrix3x3 {
+        self.from_XYZ
+    }
+
+    /// The method used to adapt the color space's white point to D50.
+    pub fn adaptation_method(&self) -> ChromaticAdaptationMethod {
+        self.adaptation_method
+    }
+
+    /// Returns true if the color spaces have the same primaries, white point, and transfer function,
+    /// within `epsilon`.
+    ///
+    /// Chromaticities are compared rather than matrices, so color spaces calculated
+    /// in slightly different ways still compare as equal. Transfer functions are compared by
+    /// sampling them, so equivalent curves with different parameters also compare as equal.
+    pub fn approx_eq(&self, other: &ColorSpace, epsilon: f64) -> bool {
+        let close = |a: Chromaticity, b: Chromaticity| {
+            (a.x - b.x).abs() <= epsilon && (a.y - b.y).abs() <= epsilon
+        };
+        close(self.white_point(), other.white_point())
+            && close(self.red_primary(), other.red_primary())
+            && close(self.green_primary(), other.green_primary())
+            && close(self.blue_primary(), other.blue_primary())
+            && (0..=32).all(|i| {
+                let x = i as f64 / 32.0;
+                (self.transfer_function.to_linear(x) - other.transfer_function.to_linear(x)).abs()
+                    <= epsilon
+            })
+    }
+
+    /// Primaries are stored adapted to D50, so undo that adaptation to recover
+    /// the chromaticity they were specified with.
+    fn primary(&self, d50_primary: Vector3) -> Chromaticity {
+        let adaptation = ChromaticAdaptation::new_with_method(
+            D50_WHITE_POINT_2DEGREES,
+            self.white_point,
+            self.adaptation_method,
+        );
+        (adaptation.inner_matrix * XYZ::new(d50_primary.x, d50_primary.y, d50_primary.z))
+            .to_chromaticity()
+    }
+
     /// Creates a color with the specified RGB values for the color space
     pub fn new_color(&self, r: f64, g: f64, b: f64, a: f64) -> Color {
         let rgb = Vector3::new(r, g, b);
@@ -111,6 +223,9 @@ impl ColorSpace {
 
     /// Creates a new color from the hex values of a number.
     /// Alpha is transparency
+    ///
+    /// Note that blue comes before green.
+    #[deprecated(note = "takes blue before green, use `new_color_from_rgba8` instead")]
     pub fn new_color_from_bytes(&self, r: u8, b: u8, g: u8, alpha: u8) -> Color {
         let r = r as f64 / 255.0;
         let g = g as f64 / 255.0;
@@ -123,12 +238,7 @@ impl ColorSpace {
     /// RGB values outside of 0.0 to 1.0 will be clipped.
     pub fn color_to_rgba(&self, color: &Color) -> (f64, f64, f64, f64) {
         let (r, g, b, a) = self.color_to_rgba_unclipped(color);
-        (
-            r.max(0.0).min(1.0),
-            g.max(0.0).min(1.0),
-            b.max(0.0).min(1.0),
-            a,
-        )
+        (r.clamp(0.0, 1.0), g.clamp(0.0, 1.0), b.clamp(0.0, 1.0), a)
     }
 
     /// Gets the RGBA values for the color as expressed in this color space
@@ -144,118 +254,67 @@ impl ColorSpace {
         );
         (rgb.x, rgb.y, rgb.z, color.a)
     }
+
+    /// Gets the RGBA values for the color in this color space's primaries
+    /// without applying the transfer function.
+    /// RGB values outside of 0.0 to 1.0 will be clipped.
+    pub fn color_to_linear_rgba(&self, color: &Color) -> (f64, f64, f64, f64) {
+        let XYZ = Vector3::new(color.X, color.Y, color.Z);
+        let rgb = self.from_XYZ * XYZ;
+        (
+            rgb.x.clamp(0.0, 1.0),
+            rgb.y.clamp(0.0, 1.0),
+            rgb.z.clamp(0.0, 1.0),
+            color.a,
+        )
+    }
 }
 
 /// If frequent color space conversions are to be performed, use this.
+#[derive(Debug, Clone)]
 pub struct ColorSpaceConverter {
-    conversion_matrix: Matrix3x3,
+    pub(crate) from_transfer_function: TransferFunction,
+    pub(crate) conversion_matrix: Matrix3x3,
+    pub(crate) to_transfer_function: TransferFunction,
 }
 
 impl ColorSpaceConverter {
     pub fn new(from: &ColorSpace, to: &ColorSpace) -> Self {
         Self {
+            from_transfer_function: from.transfer_function.clone(),
             conversion_matrix: to.from_XYZ * from.to_XYZ,
+            to_transfer_function: to.transfer_function.clone(),
         }
     }
 
+    /// The matrix that converts linear RGB values in the `from` color space
+    /// to linear RGB values in the `to` color space.
+    pub fn conversion_matrix(&self) -> Matrix3x3 {
+        self.conversion_matrix
+    }
+
+    /// Converts linear RGB values in the `from` color space to linear RGB values
+    /// in the `to` color space. Only the conversion matrix is applied, not the transfer
+    /// functions, so use `convert_encoded_color` for encoded values. Values are not clipped.
     pub fn convert_color(&self, color: &(f64, f64, f64)) -> (f64, f64, f64) {
         let color = Vector3::new(color.0, color.1, color.2);
         let color = self.conversion_matrix * color;
         (color.x, color.y, color.z)
     }
-}
-
-/// Convert between XYZ color spaces with different white points.
-/// Wavelengths are perceived as one color in one lighting condition and a
-/// different color under a different lighting condition.
-/// Our eyes adjust to lighting and if a room has yellow-ish lighting
-/// (it has a yellow-ish whitepoint) then what appears white is produced
-/// with yellow-ish wavelenghts.
-///
-/// This function first converts to an intermediate space (LMS) that represents our eyes'
-/// cone responses using a Bradford transform.
-///
-/// Then a conversion is performed from the LMS intermediate space back into XYZ.
-#[derive(Debug, Clone, PartialEq)]
-pub struct ChromaticAdaptation {
-    pub(crate) inner_matrix: Matrix3x3,
-}
-
-impl ChromaticAdaptation {
-    pub fn new(source_white_point: Chromaticity, destination_white_point: Chromaticity) -> Self {
-        // Implemented using the techniques described here:
-        // http://www.brucelindbloom.com/index.html?Eqn_ChromAdapt.html
-
-        // To do math with the XYZ values convert them to Vector3s.
-        let source_white_point = source_white_point.to_XYZ().to_vector3();
-        let destination_white_point = destination_white_point.to_XYZ().to_vector3();
-
-        // The Bradford matrix constants are found at the above link.
-        // The matrix is also available here: https://en.wikipedia.org/wiki/LMS_color_space
-        // These matrices convert XYZ values to LMS (Long Medium Short) values measuring the response of cones.
-        let bradford_matrix = Matrix3x3 {
-            c0: Vector3 {
-                x: 0.8951000,
-                y: -0.7502000,
-                z: 0.0389000,
-            },
-            c1: Vector3 {
-                x: 0.2664000,
-                y: 1.7135000,
-                z: -0.0685000,
-            },
-            c2: Vector3 {
-                x: -0.1614000,
-                y: 0.0367000,
-                z: 1.0296000,
-            },
-        };
-
-        let bradford_matrix_inverse = Matrix3x3 {
-            c0: Vector3 {
-                x: 0.9869929,
-                y: 0.4323053,
-                z: -0.0085287,
-            },
-            c1: Vector3 {
-                x: -0.1470543,
-                y: 0.5183603,
-                z: 0.0400428,
-            },
-            c2: Vector3 {
-                x: 0.1599627,
-                y: 0.0492912,
-                z: 0.9684867,
-            },
-        };
 
-        // "crs" stands for "Cone response of source white point"
-        // "crd" stands for "Cone response of destination white point"
-        // The xyz values correspond to the response of the three cones.
-        // These three responses are the "LMS" color space.
-        // "LMS" stands for "Long", "Medium", "Short" based on the wavelengths
-        // the three types of cones respond to.
-        let crs = bradford_matrix * source_white_point;
-        let crd = bradford_matrix * destination_white_point;
-
-        let intermediate_matrix = Matrix3x3::from_columns(
-            Vector3::new(crd.x / crs.x, 0., 0.),
-            Vector3::new(0., crd.y / crs.y, 0.),
-            Vector3::new(0., 0., crd.z / crs.z),
+    /// Converts encoded RGB values in the `from` color space to encoded RGB values
+    /// in the `to` color space, applying both transfer functions. Values are not clipped.
+    pub fn convert_encoded_color(&self, color: &(f64, f64, f64)) -> (f64, f64, f64) {
+        let color = Vector3::new(
+            self.from_transfer_function.to_linear(color.0),
+            self.from_transfer_function.to_linear(color.1),
+            self.from_transfer_function.to_linear(color.2),
         );
-
-        let inner_matrix = bradford_matrix_inverse * intermediate_matrix * bradford_matrix;
-
-        Self { inner_matrix }
-    }
-
-    pub fn convert(&self, xyz: XYZ) -> XYZ {
-        let v = Vector3::new(xyz.X, xyz.Y, xyz.Z);
-        let v = self.inner_matrix * v;
-        XYZ {
-            X: v.x,
-            Y: v.y,
-            Z: v.z,
-        }
+        let color = self.conversion_matrix * color;
+        (
+            self.to_transfer_function.from_linear(color.x),
+            self.to_transfer_function.from_linear(color.y),
+            self.to_transfer_function.from_linear(color.z),
+        )
     }
 }
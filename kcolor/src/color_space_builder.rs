@@ -0,0 +1,147 @@
+use crate::*;
+
+/// Builds a `ColorSpace` from named settings, validating them instead of producing
+/// a color space full of NaNs.
+///
+/// ```
+/// # use kcolor::*;
+/// # use kcolor::white_points::*;
+/// let color_space = ColorSpace::builder()
+///     .primaries(
+///         Chromaticity::new(0.64, 0.33),
+///         Chromaticity::new(0.30, 0.60),
+///         Chromaticity::new(0.15, 0.06),
+///     )
+///     .white_point(D65_WHITE_POINT_2DEGREES)
+///     .transfer_function(SRGB_TRANSFER_FUNCTION)
+///     .build()
+///     .unwrap();
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct ColorSpaceBuilder {
+    primaries: [Option<Chromaticity>; 3],
+    white_point: Option<Chromaticity>,
+    transfer_function: TransferFunction,
+    adaptation_method: ChromaticAdaptationMethod,
+}
+
+/// The error returned by `ColorSpaceBuilder::build`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ColorSpaceError {
+    /// A primary wasn't set.
+    MissingPrimary,
+    /// The white point wasn't set.
+    MissingWhitePoint,
+    /// A primary or the white point has a chromaticity with a y of 0.0 or less,
+    /// or with values that aren't finite.
+    InvalidChromaticity,
+    /// The primaries are on a line, so they can't describe a range of colors.
+    CollinearPrimaries,
+}
+
+impl core::fmt::Display for ColorSpaceError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        let message = match self {
+            ColorSpaceError::MissingPrimary => "a primary is missing",
+            ColorSpaceError::MissingWhitePoint => "the white point is missing",
+            ColorSpaceError::InvalidChromaticity => "invalid chromaticity",
+            ColorSpaceError::CollinearPrimaries => "the primaries are collinear",
+        };
+        f.write_str(message)
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for ColorSpaceError {}
+
+impl ColorSpace {
+    pub fn builder() -> ColorSpaceBuilder {
+        ColorSpaceBuilder::default()
+    }
+}
+
+impl Default for ColorSpaceBuilder {
+    fn default() -> Self {
+        Self {
+            primaries: [None; 3],
+            white_point: None,
+            transfer_function: TransferFunction::None,
+            adaptation_method: ChromaticAdaptationMethod::default(),
+        }
+    }
+}
+
+impl ColorSpaceBuilder {
+    pub fn red_primary(mut self, primary: Chromaticity) -> Self {
+        self.primaries[0] = Some(primary);
+        self
+    }
+
+    pub fn green_primary(mut self, primary: Chromaticity) -> Self {
+        self.primaries[1] = Some(primary);
+        self
+    }
+
+    pub fn blue_primary(mut self, primary: Chromaticity) -> Self {
+        self.primaries[2] = Some(primary);
+        self
+    }
+
+    /// Sets the red, green, and blue primaries.
+    pub fn primaries(self, red: Chromaticity, green: Chromaticity, blue: Chromaticity) -> Self {
+        self.red_primary(red)
+            .green_primary(green)
+            .blue_primary(blue)
+    }
+
+    pub fn white_point(mut self, white_point: Chromaticity) -> Self {
+        self.white_point = Some(white_point);
+        self
+    }
+
+    /// Sets the white point from XYZ values. Only the chromaticity is used.
+    pub fn white_point_xyz(self, white_point: XYZ) -> Self {
+        self.white_point(white_point.to_chromaticity())
+    }
+
+    /// Sets the transfer function. By default colors are linear (`TransferFunction::None`).
+    pub fn transfer_function(mut self, transfer_function: TransferFunction) -> Self {
+        self.transfer_function = transfer_function;
+        self
+    }
+
+    /// Sets the method used to adapt the white point to D50. By default this is Bradford.
+    pub fn adaptation_method(mut self, adaptation_method: ChromaticAdaptationMethod) -> Self {
+        self.adaptation_method = adaptation_method;
+        self
+    }
+
+    pub fn build(self) -> Result<ColorSpace, ColorSpaceError> {
+        let [red, green, blue] = self.primaries;
+        let (red, green, blue) = match (red, green, blue) {
+            (Some(red), Some(green), Some(blue)) => (red, green, blue),
+            _ => return Err(ColorSpaceError::MissingPrimary),
+        };
+        let white_point = self.white_point.ok_or(ColorSpaceError::MissingWhitePoint)?;
+
+        let valid = |c: &Chromaticity| c.x.is_finite() && c.y.is_finite() && c.y > 0.0;
+        if ![red, green, blue, white_point].iter().all(valid) {
+            return Err(ColorSpaceError::InvalidChromaticity);
+        }
+
+        // Twice the area of the triangle formed by the primaries.
+        let area = (green.x - red.x) * (blue.y - red.y) - (blue.x - red.x) * (green.y - red.y);
+        if area.abs() < 1e-9 {
+            return Err(ColorSpaceError::CollinearPrimaries);
+        }
+
+        Ok(ColorSpace::new_with_adaptation_method(
+            red,
+            green,
+            blue,
+            white_point,
+            self.transfer_function,
+            self.adaptation_method,
+        ))
+    }
+}
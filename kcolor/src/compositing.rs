@@ -0,0 +1,161 @@
+//! Alpha compositing.
+//!
+//! The Porter-Duff operators composite `self` (the source) with a backdrop (the destination).
+//! Colors are composited in XYZ, which is linear light, so results are physically sensible
+//! and don't depend on any color space's transfer function.
+//! Reference: https://www.w3.org/TR/compositing-1/#porterduffcompositingoperators
+use crate::*;
+use alloc::vec::Vec;
+
+impl Color {
+    /// Multiplies the color's XYZ values by its alpha.
+    /// This is premultiplication in linear light, which is what compositing
+    /// and GPU blending expect.
+    ///
+    /// The returned color's values only make sense to code expecting premultiplied values.
+    /// Use `unpremultiply` to undo this.
+    pub fn premultiply(&self) -> Color {
+        Color::new_xyza(self.X * self.a, self.Y * self.a, self.Z * self.a, self.a)
+    }
+
+    /// Places the color over `backdrop`. This is the usual way to draw a translucent color.
+    pub fn over(&self, backdrop: &Color) -> Color {
+        self.porter_duff(backdrop, 1.0, 1.0 - self.a)
+    }
+
+    /// Places `backdrop` over the color.
+    pub fn destination_over(&self, backdrop: &Color) -> Color {
+        self.porter_duff(backdrop, 1.0 - backdrop.a, 1.0)
+    }
+
+    /// Keeps the part of the color that overlaps `backdrop`.
+    pub fn source_in(&self, backdrop: &Color) -> Color {
+        self.porter_duff(backdrop, backdrop.a, 0.0)
+    }
+
+    /// Keeps the part of `backdrop` that overlaps the color.
+    pub fn destination_in(&self, backdrop: &Color) -> Color {
+        self.porter_duff(backdrop, 0.0, self.a)
+    }
+
+    /// Keeps the part of the color that doesn't overlap `backdrop`.
+    pub fn source_out(&self, backdrop: &Color) -> Color {
+        self.porter_duff(backdrop, 1.0 - backdrop.a, 0.0)
+    }
+
+    /// Keeps the part of `backdrop` that doesn't overlap the color.
+    pub fn destination_out(&self, backdrop: &Color) -> Color {
+        self.porter_duff(backdrop, 0.0, 1.0 - self.a)
+    }
+
+    /// Places the part of the color that overlaps `backdrop` over `backdrop`.
+    pub fn source_atop(&self, backdrop: &Color) -> Color {
+        self.porter_duff(backdrop, backdrop.a, 1.0 - self.a)
+    }
+
+    /// Places the part of `backdrop` that overlaps the color over the color.
+    pub fn destination_atop(&self, backdrop: &Color) -> Color {
+        self.porter_duff(backdrop, 1.0 - backdrop.a, self.a)
+    }
+
+    /// Keeps the parts of the color and `backdrop` that don't overlap.
+    pub fn xor(&self, backdrop: &Color) -> Color {
+        self.porter_duff(backdrop, 1.0 - backdrop.a, 1.0 - self.a)
+    }
+
+    /// Adds the color and `backdrop` together. Alpha is clamped to 1.0.
+    pub fn plus(&self, backdrop: &Color) -> Color {
+        let mut color = self.porter_duff(backdrop, 1.0, 1.0);
+        if color.a > 1.0 {
+            color = color.premultiply();
+            color.a = 1.0;
+        }
+        color
+    }
+
+    /// The general Porter-Duff operator, where `source_factor` and `backdrop_factor`
+    /// are the fractions of the source and backdrop that are kept.
+    fn porter_duff(&self, backdrop: &Color, source_factor: f64, backdrop_factor: f64) -> Color {
+        let source = self.premultiply();
+        let backdrop = backdrop.premultiply();
+        Color::new_xyza(
+            source.X * source_factor + backdrop.X * backdrop_factor,
+            source.Y * source_factor + backdrop.Y * backdrop_factor,
+            source.Z * source_factor + backdrop.Z * backdrop_factor,
+            source.a * source_factor + backdrop.a * backdrop_factor,
+        )
+        .unpremultiply()
+    }
+
+    /// Divides the color's XYZ values by its alpha, undoing `premultiply`.
+    /// A fully transparent color has lost its original values, so it returns transparent black.
+    pub fn unpremultiply(&self) -> Color {
+        if self.a == 0.0 {
+            Color::new_xyza(0.0, 0.0, 0.0, 0.0)
+        } else {
+            Color::new_xyza(self.X / self.a, self.Y / self.a, self.Z / self.a, self.a)
+        }
+    }
+}
+
+impl ColorSpace {
+    /// Gets the RGBA values for the color as expressed in this color space,
+    /// with the RGB values multiplied by alpha after the transfer function is applied.
+    /// This is how premultiplied images are usually stored.
+    /// RGB values outside of 0.0 to 1.0 will be clipped.
+    /// A fully transparent color returns all zeros.
+    pub fn color_to_rgba_premultiplied(&self, color: &Color) -> (f64, f64, f64, f64) {
+        let (r, g, b, a) = self.color_to_rgba(color);
+        (r * a, g * a, b * a, a)
+    }
+
+    /// Gets the linear RGBA values for the color in this color space's primaries,
+    /// with the RGB values multiplied by alpha.
+    /// This is what GPU blending expects when rendering to a linear or sRGB framebuffer.
+    /// RGB values outside of 0.0 to 1.0 will be clipped.
+    /// A fully transparent color returns all zeros.
+    pub fn color_to_linear_rgba_premultiplied(&self, color: &Color) -> (f64, f64, f64, f64) {
+        let (r, g, b, a) = self.color_to_linear_rgba(color);
+        (r * a, g * a, b * a, a)
+    }
+
+    /// Creates a color from premultiplied RGBA values encoded in this color space,
+    /// as returned by `color_to_rgba_premultiplied`.
+    /// A fully transparent color has no RGB values to recover, so it returns transparent black.
+    pub fn new_color_premultiplied(&self, r: f64, g: f64, b: f64, a: f64) -> Color {
+        if a == 0.0 {
+            Color::new_xyza(0.0, 0.0, 0.0, 0.0)
+        } else {
+            self.new_color(r / a, g / a, b / a, a)
+        }
+    }
+}
+
+/// Calculates an alpha mask that removes pixels close to `key`, like a green screen.
+/// `pixels` are RGB values encoded in `color_space` and closeness is measured with `metric`.
+///
+/// Pixels within `tolerance` of the key get an alpha of 0.0 and pixels further than
+/// `tolerance + softness` get an alpha of 1.0. Alpha ramps linearly in between,
+/// which softens the edges of the mask.
+pub fn chroma_key_mask(
+    pixels: &[[f64; 3]],
+    color_space: &ColorSpace,
+    key: &Color,
+    tolerance: f64,
+    softness: f64,
+    metric: &impl ColorDistance,
+) -> Vec<f64> {
+    pixels
+        .iter()
+        .map(|[r, g, b]| {
+            let distance = metric.distance(&color_space.new_color(*r, *g, *b, 1.0), key);
+            if distance <= tolerance {
+                0.0
+            } else if distance >= tolerance + softness {
+                1.0
+            } else {
+                (distance - tolerance) / softness
+            }
+        })
+        .collect()
+}
@@ -1,3 +1,4 @@
+use crate::white_points::*;
 use crate::*;
 
 impl ColorSpace {
@@ -45,6 +46,8 @@ impl ColorSpace {
             },
         },
         transfer_function: SRGB_TRANSFER_FUNCTION,
+        white_point: D65_WHITE_POINT_2DEGREES,
+        adaptation_method: ChromaticAdaptationMethod::Bradford,
     };
 
     /// Exact same as the above SRGB space, except with a linear transfer function.
@@ -84,6 +87,8 @@ impl ColorSpace {
             },
         },
         transfer_function: TransferFunction::None,
+        white_point: D65_WHITE_POINT_2DEGREES,
+        adaptation_method: ChromaticAdaptationMethod::Bradford,
     };
 }
 
@@ -92,6 +97,6 @@ pub const SRGB_TRANSFER_FUNCTION: TransferFunction =
         gamma: 2.4,
         a: 0.94786729857,
         b: 0.05213270142,
-        c: 1.0 / 12.0, // 1.0 / 12.0
+        c: 1.0 / 12.92,
         d: 0.04045,
     });
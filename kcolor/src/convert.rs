@@ -0,0 +1,116 @@
+use crate::typed::{EncodedColor, LinearColor, Space};
+use crate::*;
+
+/// A type that represents a color and can be converted to and from `Color`.
+///
+/// Types without alpha convert to opaque colors and discard alpha when converted from a `Color`.
+/// Implement this for your own color types to make them work with `ConvertColor`.
+pub trait ColorRepresentation: Sized {
+    fn to_color(&self) -> Color;
+    fn from_color(color: &Color) -> Self;
+}
+
+/// Converts between any two color representations, going through `Color`.
+///
+/// Generic code can accept any color type and convert it to the representation it works in:
+///
+/// ```
+/// # use kcolor::*;
+/// fn lightness<C: ConvertColor<Oklab>>(color: &C) -> f64 {
+///     color.convert_color().L
+/// }
+/// assert_eq!(lightness(&Color::BLACK), 0.0);
+/// assert!(lightness(&Lab::new(50.0, 0.0, 0.0)) > 0.0);
+/// ```
+pub trait ConvertColor<Target> {
+    fn convert_color(&self) -> Target;
+}
+
+impl<Source: ColorRepresentation, Target: ColorRepresentation> ConvertColor<Target> for Source {
+    fn convert_color(&self) -> Target {
+        Target::from_color(&self.to_color())
+    }
+}
+
+impl ColorRepresentation for Color {
+    fn to_color(&self) -> Color {
+        *self
+    }
+
+    fn from_color(color: &Color) -> Self {
+        *color
+    }
+}
+
+/// XYZ values relative to D50.
+impl ColorRepresentation for XYZ {
+    fn to_color(&self) -> Color {
+        Color::from_xyz(*self, 1.0)
+    }
+
+    fn from_color(color: &Color) -> Self {
+        color.xyz()
+    }
+}
+
+/// Lab relative to D50.
+impl ColorRepresentation for Lab {
+    fn to_color(&self) -> Color {
+        Color::from_lab(*self, 1.0)
+    }
+
+    fn from_color(color: &Color) -> Self {
+        color.to_lab()
+    }
+}
+
+/// Luv relative to D50.
+impl ColorRepresentation for Luv {
+    fn to_color(&self) -> Color {
+        Color::from_luv(*self, 1.0)
+    }
+
+    fn from_color(color: &Color) -> Self {
+        color.to_luv()
+    }
+}
+
+impl ColorRepresentation for Oklab {
+    fn to_color(&self) -> Color {
+        Color::from_oklab(*self, 1.0)
+    }
+
+    fn from_color(color: &Color) -> Self {
+        color.to_oklab()
+    }
+}
+
+impl ColorRepresentation for Oklch {
+    fn to_color(&self) -> Color {
+        Color::from_oklch(*self, 1.0)
+    }
+
+    fn from_color(color: &Color) -> Self {
+        color.to_oklch()
+    }
+}
+
+impl<S: Space> ColorRepresentation for EncodedColor<S> {
+    fn to_color(&self) -> Color {
+        EncodedColor::to_color(self)
+    }
+
+    fn from_color(color: &Color) -> Self {
+        EncodedColor::from_color(color)
+    }
+}
+
+impl<S: Space> ColorRepresentation for LinearColor<S> {
+    fn to_color(&self) -> Color {
+        LinearColor::to_color(self)
+    }
+
+    fn from_color(color: &Color) -> Self {
+        LinearColor::from_color(color)
+    }
+}
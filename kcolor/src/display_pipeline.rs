@@ -0,0 +1,112 @@
+use crate::tonemap::{ToneMapper, ToneMapping};
+use crate::*;
+
+/// How colors outside of the output color space's gamut are brought within it.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum GamutMapping {
+    /// Clip each RGB value to 0.0 to 1.0. Fast, but shifts hue and lightness.
+    Clip,
+    /// Reduce Oklch chroma while preserving lightness and hue (see `ColorSpace::gamut_map`).
+    #[default]
+    Chroma,
+}
+
+/// Converts colors for display by applying, in order:
+/// tone mapping, chromatic adaptation, gamut mapping, and the output color space's encoding.
+///
+/// Getting the order of these steps wrong is an easy mistake: tone mapping must happen before
+/// gamut mapping, or bright colors are desaturated to white, and gamut mapping must happen
+/// in the output's white point, or it maps to the wrong gamut.
+///
+/// ```
+/// # use kcolor::*;
+/// # use kcolor::tonemap::ToneMapping;
+/// let pipeline = DisplayPipeline::new(ColorSpace::SRGB)
+///     .tone_mapping(ToneMapping::Reinhard)
+///     .gamut_mapping(GamutMapping::Chroma);
+/// let (r, g, b, a) = pipeline.apply(&Color::new_linear_srgb(4.0, 2.0, 0.5, 1.0));
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct DisplayPipeline {
+    tone_mapper: Option<ToneMapper>,
+    chromatic_adaptation: Option<ChromaticAdaptation>,
+    gamut_mapping: GamutMapping,
+    output: ColorSpace,
+    /// The chromatic adaptation followed by the output's conversion from XYZ.
+    output_matrix: Matrix3x3,
+}
+
+impl DisplayPipeline {
+    /// A pipeline that encodes colors in `output`, gamut mapping them with `GamutMapping::Chroma`.
+    pub fn new(output: ColorSpace) -> Self {
+        Self {
+            tone_mapper: None,
+            chromatic_adaptation: None,
+            gamut_mapping: GamutMapping::default(),
+            output_matrix: output.from_XYZ_matrix(),
+            output,
+        }
+    }
+
+    /// Tone map colors before any other step.
+    pub fn tone_mapping(mut self, tone_mapping: ToneMapping) -> Self {
+        self.tone_mapper = Some(ToneMapper::new(tone_mapping));
+        self
+    }
+
+    /// Adapt colors after tone mapping and before gamut mapping.
+    /// Adaptations are combined if this is called more than once.
+    pub fn chromatic_adaptation(mut self, adaptation: ChromaticAdaptation) -> Self {
+        let adaptation = match self.chromatic_adaptation {
+            Some(existing) => existing.then(&adaptation),
+            None => adaptation,
+        };
+        self.output_matrix = self.output.from_XYZ_matrix() * adaptation.matrix();
+        self.chromatic_adaptation = Some(adaptation);
+        self
+    }
+
+    pub fn gamut_mapping(mut self, gamut_mapping: GamutMapping) -> Self {
+        self.gamut_mapping = gamut_mapping;
+        self
+    }
+
+    /// The color space colors are encoded in.
+    pub fn output(&self) -> &ColorSpace {
+        &self.output
+    }
+
+    /// Converts the color to RGBA values in the output color space.
+    /// The RGB values are within 0.0 to 1.0.
+    pub fn apply(&self, color: &Color) -> (f64, f64, f64, f64) {
+        let mut color = *color;
+        if let Some(tone_mapper) = &self.tone_mapper {
+            color = tone_mapper.map(&color);
+        }
+        match self.gamut_mapping {
+            GamutMapping::Clip => {
+                let rgb = self.output_matrix * color.xyz().to_vector3();
+                let transfer_function = self.output.transfer_function();
+                let encode = |x: f64| transfer_function.from_linear(x).clamp(0.0, 1.0);
+                (encode(rgb.x), encode(rgb.y), encode(rgb.z), color.a)
+            }
+            GamutMapping::Chroma => {
+                if let Some(adaptation) = &self.chromatic_adaptation {
+                    let xyz = adaptation.convert(color.xyz());
+                    color = Color::new_xyza(xyz.X, xyz.Y, xyz.Z, color.a);
+                }
+                self.output.color_to_rgba(&self.output.gamut_map(&color))
+            }
+        }
+    }
+
+    /// Converts RGB values in the `input` color space to RGB values in the output color space, in place.
+    pub fn apply_slice(&self, pixels: &mut [[f64; 3]], input: &ColorSpace) {
+        for pixel in pixels.iter_mut() {
+            let color = input.new_color(pixel[0], pixel[1], pixel[2], 1.0);
+            let (r, g, b, _) = self.apply(&color);
+            *pixel = [r, g, b];
+        }
+    }
+}
@@ -0,0 +1,204 @@
+//! Getting the ICC profile of the user's display from the operating system,
+//! enabled with the `display-profile` feature.
+//!
+//! Supported platforms:
+//! - Windows, with `GetICMProfileW`.
+//! - macOS, with the ColorSync profile of the main display from CoreGraphics.
+//! - Linux and BSDs with X11, from the `_ICC_PROFILE` property of the root window, which
+//!   color managers like colord set. Wayland sessions are supported through XWayland when
+//!   the compositor sets that property; Wayland color management protocols aren't supported.
+//!
+//! Only the main (or first) display is queried.
+use crate::*;
+use alloc::vec::Vec;
+
+#[derive(Debug)]
+pub enum DisplayProfileError {
+    /// Getting display profiles isn't supported on this platform.
+    Unsupported,
+    /// The display has no profile, or the display couldn't be accessed.
+    NotFound,
+    /// The profile was found, but couldn't be read.
+    Io(std::io::Error),
+    /// The profile was read, but couldn't be loaded as a color space.
+    ICCProfile(ICCProfileError),
+}
+
+/// The bytes of the main display's ICC profile.
+pub fn display_icc_profile() -> Result<Vec<u8>, DisplayProfileError> {
+    platform::display_icc_profile()
+}
+
+impl ColorSpace {
+    /// The color space of the main display, from its ICC profile.
+    /// Colors converted to this color space look as intended on the user's actual display.
+    pub fn from_display_profile() -> Result<ColorSpace, DisplayProfileError> {
+        ColorSpace::from_icc_profile(&display_icc_profile()?)
+            .map_err(DisplayProfileError::ICCProfile)
+    }
+}
+
+#[cfg(windows)]
+mod platform {
+    use super::*;
+    use std::ffi::c_void;
+    use std::os::windows::ffi::OsStringExt;
+
+    #[link(name = "user32")]
+    extern "system" {
+        fn GetDC(window: *mut c_void) -> *mut c_void;
+        fn ReleaseDC(window: *mut c_void, device_context: *mut c_void) -> i32;
+    }
+
+    #[link(name = "gdi32")]
+    extern "system" {
+        fn GetICMProfileW(
+            device_context: *mut c_void,
+            length: *mut u32,
+            file_name: *mut u16,
+        ) -> i32;
+    }
+
+    pub(super) fn display_icc_profile() -> Result<Vec<u8>, DisplayProfileError> {
+        let path = unsafe {
+            // The device context of the whole screen.
+            let device_context = GetDC(core::ptr::null_mut());
+            if device_context.is_null() {
+                return Err(DisplayProfileError::NotFound);
+            }
+            // The first call gets the length of the path, including the null terminator.
+            let mut length = 0;
+            GetICMProfileW(device_context, &mut length, core::ptr::null_mut());
+            let mut path = alloc::vec![0u16; length as usize];
+            let found =
+                length > 0 && GetICMProfileW(device_context, &mut length, path.as_mut_ptr()) != 0;
+            ReleaseDC(core::ptr::null_mut(), device_context);
+            if !found {
+                return Err(DisplayProfileError::NotFound);
+            }
+            path.truncate(path.iter().position(|c| *c == 0).unwrap_or(path.len()));
+            std::ffi::OsString::from_wide(&path)
+        };
+        std::fs::read(path).map_err(DisplayProfileError::Io)
+    }
+}
+
+#[cfg(target_os = "macos")]
+mod platform {
+    use super::*;
+    use std::ffi::c_void;
+
+    #[link(name = "CoreGraphics", kind = "framework")]
+    extern "C" {
+        fn CGMainDisplayID() -> u32;
+        fn CGDisplayCopyColorSpace(display: u32) -> *const c_void;
+        fn CGColorSpaceCopyICCData(color_space: *const c_void) -> *const c_void;
+    }
+
+    #[link(name = "CoreFoundation", kind = "framework")]
+    extern "C" {
+        fn CFDataGetLength(data: *const c_void) -> isize;
+        fn CFDataGetBytePtr(data: *const c_void) -> *const u8;
+        fn CFRelease(object: *const c_void);
+    }
+
+    pub(super) fn display_icc_profile() -> Result<Vec<u8>, DisplayProfileError> {
+        unsafe {
+            let color_space = CGDisplayCopyColorSpace(CGMainDisplayID());
+            if color_space.is_null() {
+                return Err(DisplayProfileError::NotFound);
+            }
+            let data = CGColorSpaceCopyICCData(color_space);
+            CFRelease(color_space);
+            if data.is_null() {
+                return Err(DisplayProfileError::NotFound);
+            }
+            let bytes =
+                core::slice::from_raw_parts(CFDataGetBytePtr(data), CFDataGetLength(data) as usize)
+                    .to_vec();
+            CFRelease(data);
+            Ok(bytes)
+        }
+    }
+}
+
+#[cfg(all(unix, not(target_os = "macos"), not(target_os = "android")))]
+mod platform {
+    use super::*;
+    use std::os::raw::{c_char, c_int, c_long, c_uchar, c_ulong, c_void};
+
+    #[link(name = "X11")]
+    extern "C" {
+        fn XOpenDisplay(name: *const c_char) -> *mut c_void;
+        fn XCloseDisplay(display: *mut c_void) -> c_int;
+        fn XDefaultRootWindow(display: *mut c_void) -> c_ulong;
+        fn XInternAtom(display: *mut c_void, name: *const c_char, only_if_exists: c_int)
+            -> c_ulong;
+        #[allow(clippy::too_many_arguments)]
+        fn XGetWindowProperty(
+            display: *mut c_void,
+            window: c_ulong,
+            property: c_ulong,
+            offset: c_long,
+            length: c_long,
+            delete: c_int,
+            requested_type: c_ulong,
+            actual_type: *mut c_ulong,
+            actual_format: *mut c_int,
+            item_count: *mut c_ulong,
+            bytes_after: *mut c_ulong,
+            value: *mut *mut c_uchar,
+        ) -> c_int;
+        fn XFree(data: *mut c_void) -> c_int;
+    }
+
+    pub(super) fn display_icc_profile() -> Result<Vec<u8>, DisplayProfileError> {
+        unsafe {
+            // Connects to the display named by the `DISPLAY` environment variable.
+            let display = XOpenDisplay(core::ptr::null());
+            if display.is_null() {
+                return Err(DisplayProfileError::NotFound);
+            }
+            let atom = XInternAtom(display, b"_ICC_PROFILE\0".as_ptr() as *const c_char, 1);
+            let mut bytes = None;
+            if atom != 0 {
+                let (mut actual_type, mut actual_format) = (0, 0);
+                let (mut item_count, mut bytes_after) = (0, 0);
+                let mut value = core::ptr::null_mut();
+                // The length is in 32 bit units. `AnyPropertyType` is 0 and `Success` is 0.
+                let status = XGetWindowProperty(
+                    display,
+                    XDefaultRootWindow(display),
+                    atom,
+                    0,
+                    c_long::MAX / 4,
+                    0,
+                    0,
+                    &mut actual_type,
+                    &mut actual_format,
+                    &mut item_count,
+                    &mut bytes_after,
+                    &mut value,
+                );
+                if status == 0 && !value.is_null() {
+                    if actual_format == 8 && item_count > 0 {
+                        bytes =
+                            Some(core::slice::from_raw_parts(value, item_count as usize).to_vec());
+                    }
+                    XFree(value as *mut c_void);
+                }
+            }
+            XCloseDisplay(display);
+            bytes.ok_or(DisplayProfileError::NotFound)
+        }
+    }
+}
+
+#[cfg(not(any(windows, target_os = "macos", all(unix, not(target_os = "android")))))]
+mod platform {
+    use super::*;
+
+    pub(super) fn display_icc_profile() -> Result<Vec<u8>, DisplayProfileError> {
+        Err(DisplayProfileError::Unsupported)
+    }
+}
@@ -0,0 +1,214 @@
+//! Metrics for how different two colors look, often called delta E (ΔE).
+use crate::*;
+use alloc::vec::Vec;
+
+/// A metric for how different two colors look.
+/// Larger values mean the colors are more different and identical colors have a distance of 0.0.
+pub trait ColorDistance {
+    fn distance(&self, a: &Color, b: &Color) -> f64;
+
+    /// The coordinates of the color in the space this metric is measured in.
+    /// Distances should roughly follow straight line distances between coordinates,
+    /// which lets spatial structures like `PaletteIndex` quickly find close colors.
+    fn coordinates(&self, color: &Color) -> [f64; 3];
+
+    /// The largest straight line distance between the coordinates of two colors
+    /// that are `distance` apart according to this metric.
+    /// This must not be too small or spatial searches will miss colors.
+    fn coordinate_bound(&self, distance: f64) -> f64 {
+        distance
+    }
+}
+
+/// The original CIE 1976 delta E: the straight line distance between colors in CIELAB (D50).
+/// A difference of about 2.3 is just noticeable.
+/// It overstates differences between saturated colors.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Default)]
+pub struct DeltaE76;
+
+/// The CIEDE2000 delta E, which corrects the perceptual non-uniformity of CIELAB (D50).
+/// This is the most accurate CIE metric, but it's much slower to calculate than the others.
+/// A difference of 1.0 is about just noticeable.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Default)]
+pub struct DeltaE2000;
+
+/// The straight line distance between colors in Oklab.
+/// It's about as accurate as CIEDE2000 for most colors and much cheaper to calculate.
+/// A difference of about 0.02 is just noticeable.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Default)]
+pub struct DeltaEOk;
+
+impl ColorDistance for DeltaE76 {
+    fn distance(&self, a: &Color, b: &Color) -> f64 {
+        a.to_lab().delta_e_76(&b.to_lab())
+    }
+
+    fn coordinates(&self, color: &Color) -> [f64; 3] {
+        let lab = color.to_lab();
+        [lab.L, lab.a, lab.b]
+    }
+}
+
+impl ColorDistance for DeltaE2000 {
+    fn distance(&self, a: &Color, b: &Color) -> f64 {
+        a.to_lab().delta_e_2000(&b.to_lab())
+    }
+
+    fn coordinates(&self, color: &Color) -> [f64; 3] {
+        let lab = color.to_lab();
+        [lab.L, lab.a, lab.b]
+    }
+
+    fn coordinate_bound(&self, distance: f64) -> f64 {
+        // CIEDE2000 divides differences by weights that reach about 7.0 for the most
+        // saturated colors, so Lab distances can be that many times larger.
+        distance * 8.0
+    }
+}
+
+impl ColorDistance for DeltaEOk {
+    fn distance(&self, a: &Color, b: &Color) -> f64 {
+        let a = a.to_oklab();
+        let b = b.to_oklab();
+        let (dl, da, db) = (a.L - b.L, a.a - b.a, a.b - b.b);
+        float::sqrt(dl * dl + da * da + db * db)
+    }
+
+    fn coordinates(&self, color: &Color) -> [f64; 3] {
+        let oklab = color.to_oklab();
+        [oklab.L, oklab.a, oklab.b]
+    }
+}
+
+impl Lab {
+    /// The CIE 1976 delta E between two Lab values.
+    pub fn delta_e_76(&self, other: &Lab) -> f64 {
+        let (dl, da, db) = (self.L - other.L, self.a - other.a, self.b - other.b);
+        float::sqrt(dl * dl + da * da + db * db)
+    }
+
+    /// The CIEDE2000 delta E between two Lab values.
+    ///
+    /// Implemented as described here:
+    /// http://www2.ece.rochester.edu/~gsharma/ciede2000/ciede2000noteCRNA.pdf
+    pub fn delta_e_2000(&self, other: &Lab) -> f64 {
+        let pow7 = |x: f64| {
+            let x2 = x * x;
+            x2 * x2 * x2 * x
+        };
+        // 25^7
+        const POW_25_7: f64 = 6103515625.0;
+
+        let c1 = float::sqrt(self.a * self.a + self.b * self.b);
+        let c2 = float::sqrt(other.a * other.a + other.b * other.b);
+        let c_mean = (c1 + c2) / 2.0;
+        let g = 0.5 * (1.0 - float::sqrt(pow7(c_mean) / (pow7(c_mean) + POW_25_7)));
+
+        let a1 = self.a * (1.0 + g);
+        let a2 = other.a * (1.0 + g);
+        let c1 = float::sqrt(a1 * a1 + self.b * self.b);
+        let c2 = float::sqrt(a2 * a2 + other.b * other.b);
+
+        let hue = |b: f64, a: f64| {
+            if a == 0.0 && b == 0.0 {
+                0.0
+            } else {
+                float::rem_euclid(float::atan2(b, a).to_degrees(), 360.0)
+            }
+        };
+        let h1 = hue(self.b, a1);
+        let h2 = hue(other.b, a2);
+
+        let delta_l = other.L - self.L;
+        let delta_c = c2 - c1;
+        let delta_h = if c1 * c2 == 0.0 {
+            0.0
+        } else if (h2 - h1).abs() <= 180.0 {
+            h2 - h1
+        } else if h2 - h1 > 180.0 {
+            h2 - h1 - 360.0
+        } else {
+            h2 - h1 + 360.0
+        };
+        let delta_h = 2.0 * float::sqrt(c1 * c2) * float::sin((delta_h / 2.0).to_radians());
+
+        let l_mean = (self.L + other.L) / 2.0;
+        let c_mean = (c1 + c2) / 2.0;
+        let h_mean = if c1 * c2 == 0.0 {
+            h1 + h2
+        } else if (h1 - h2).abs() <= 180.0 {
+            (h1 + h2) / 2.0
+        } else if h1 + h2 < 360.0 {
+            (h1 + h2 + 360.0) / 2.0
+        } else {
+            (h1 + h2 - 360.0) / 2.0
+        };
+
+        let t = 1.0 - 0.17 * float::cos((h_mean - 30.0).to_radians())
+            + 0.24 * float::cos((2.0 * h_mean).to_radians())
+            + 0.32 * float::cos((3.0 * h_mean + 6.0).to_radians())
+            - 0.20 * float::cos((4.0 * h_mean - 63.0).to_radians());
+        let delta_theta = 30.0 * float::exp(-((h_mean - 275.0) / 25.0) * ((h_mean - 275.0) / 25.0));
+        let r_c = 2.0 * float::sqrt(pow7(c_mean) / (pow7(c_mean) + POW_25_7));
+        let l_offset = (l_mean - 50.0) * (l_mean - 50.0);
+        let s_l = 1.0 + 0.015 * l_offset / float::sqrt(20.0 + l_offset);
+        let s_c = 1.0 + 0.045 * c_mean;
+        let s_h = 1.0 + 0.015 * c_mean * t;
+        let r_t = -float::sin((2.0 * delta_theta).to_radians()) * r_c;
+
+        let l = delta_l / s_l;
+        let c = delta_c / s_c;
+        let h = delta_h / s_h;
+        float::sqrt(l * l + c * c + h * h + r_t * c * h)
+    }
+}
+
+/// The distance between each pair of pixels in two buffers of RGB values in `color_space`,
+/// such as a reference image and the output of a codec or pipeline.
+/// Alpha is ignored.
+///
+/// Panics if the buffers have different lengths.
+pub fn delta_e_map(
+    a: &[[f64; 3]],
+    b: &[[f64; 3]],
+    color_space: &ColorSpace,
+    metric: &impl ColorDistance,
+) -> Vec<f64> {
+    assert_eq!(a.len(), b.len(), "The buffers must be the same length");
+    a.iter()
+        .zip(b)
+        .map(|([r0, g0, b0], [r1, g1, b1])| {
+            metric.distance(
+                &color_space.new_color(*r0, *g0, *b0, 1.0),
+                &color_space.new_color(*r1, *g1, *b1, 1.0),
+            )
+        })
+        .collect()
+}
+
+/// Summary statistics of a map of distances, as returned by [`delta_e_map`].
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct DeltaEStats {
+    pub mean: f64,
+    /// 95% of distances are less than or equal to this.
+    pub percentile_95: f64,
+    pub max: f64,
+}
+
+impl DeltaEStats {
+    /// Returns `None` if `distances` is empty.
+    pub fn from_distances(distances: &[f64]) -> Option<Self> {
+        if distances.is_empty() {
+            return None;
+        }
+        let mut sorted = distances.to_vec();
+        sorted.sort_by(|a, b| a.total_cmp(b));
+        // The nearest-rank percentile.
+        let rank = (sorted.len() * 95).div_ceil(100);
+        Some(Self {
+            mean: sorted.iter().sum::<f64>() / sorted.len() as f64,
+            percentile_95: sorted[rank.max(1) - 1],
+            max: sorted[sorted.len() - 1],
+        })
+    }
+}
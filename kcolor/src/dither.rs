@@ -0,0 +1,176 @@
+//! Dithering: remapping images to a limited set of colors while preserving the appearance of
+//! smooth gradients.
+use crate::*;
+use alloc::vec::Vec;
+
+/// The pattern used to spread the error of each pixel to the pixels after it.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Default)]
+pub enum ErrorDiffusion {
+    /// The classic and most common pattern.
+    #[default]
+    FloydSteinberg,
+    /// Only spreads 3/4 of the error, which increases contrast and reduces noise.
+    /// Used by the original Macintosh.
+    Atkinson,
+    /// Jarvis, Judice, and Ninke's pattern spreads the error further,
+    /// which is slower but produces smoother results.
+    JarvisJudiceNinke,
+}
+
+impl ErrorDiffusion {
+    /// The offsets (x, y) and weights of the pixels the error is spread to, and the total weight.
+    fn kernel(&self) -> (&'static [(isize, usize, f64)], f64) {
+        match self {
+            ErrorDiffusion::FloydSteinberg => {
+                (&[(1, 0, 7.0), (-1, 1, 3.0), (0, 1, 5.0), (1, 1, 1.0)], 16.0)
+            }
+            ErrorDiffusion::Atkinson => (
+                &[
+                    (1, 0, 1.0),
+                    (2, 0, 1.0),
+                    (-1, 1, 1.0),
+                    (0, 1, 1.0),
+                    (1, 1, 1.0),
+                    (0, 2, 1.0),
+                ],
+                8.0,
+            ),
+            ErrorDiffusion::JarvisJudiceNinke => (
+                &[
+                    (1, 0, 7.0),
+                    (2, 0, 5.0),
+                    (-2, 1, 3.0),
+                    (-1, 1, 5.0),
+                    (0, 1, 7.0),
+                    (1, 1, 5.0),
+                    (2, 1, 3.0),
+                    (-2, 2, 1.0),
+                    (-1, 2, 3.0),
+                    (0, 2, 5.0),
+                    (1, 2, 3.0),
+                    (2, 2, 1.0),
+                ],
+                48.0,
+            ),
+        }
+    }
+}
+
+/// Remaps an image to the colors of `palette` with error diffusion dithering,
+/// returning the palette index of each pixel.
+///
+/// `pixels` are encoded RGB values in `color_space`, stored row by row with `width` pixels per row.
+/// The error between each pixel and its palette color is measured in the linear RGB values of
+/// `color_space` and spread to the pixels after it, so gradients keep the right brightness.
+pub fn error_diffusion<M: ColorDistance>(
+    pixels: &[[f64; 3]],
+    width: usize,
+    color_space: &ColorSpace,
+    palette: &PaletteIndex<M>,
+    pattern: ErrorDiffusion,
+) -> Vec<usize> {
+    let (kernel, total_weight) = pattern.kernel();
+    let palette_linear: Vec<Vector3> = palette
+        .palette()
+        .iter()
+        .map(|color| color_space.from_XYZ * Vector3::new(color.X, color.Y, color.Z))
+        .collect();
+
+    let mut linear: Vec<Vector3> = pixels
+        .iter()
+        .map(|pixel| {
+            let tf = &color_space.transfer_function;
+            Vector3::new(
+                tf.to_linear(pixel[0]),
+                tf.to_linear(pixel[1]),
+                tf.to_linear(pixel[2]),
+            )
+        })
+        .collect();
+
+    let height = pixels.len().checked_div(width).unwrap_or(0);
+    let mut indices = Vec::with_capacity(pixels.len());
+    for y in 0..height {
+        for x in 0..width {
+            // Accumulated error can push values outside the color space,
+            // which are clipped to find the closest palette color.
+            let value = linear[y * width + x];
+            let clipped = Vector3::new(
+                value.x.clamp(0.0, 1.0),
+                value.y.clamp(0.0, 1.0),
+                value.z.clamp(0.0, 1.0),
+            );
+            let xyz = color_space.to_XYZ * clipped;
+            let index = palette
+                .nearest(&Color::new_xyza(xyz.x, xyz.y, xyz.z, 1.0))
+                .unwrap_or(0);
+            indices.push(index);
+
+            let error = match palette_linear.get(index) {
+                Some(chosen) => value - *chosen,
+                None => continue,
+            };
+            for (dx, dy, weight) in kernel {
+                let (nx, ny) = (x as isize + dx, y + dy);
+                if nx < 0 || nx as usize >= width || ny >= height {
+                    continue;
+                }
+                let neighbor = &mut linear[ny * width + nx as usize];
+                *neighbor = *neighbor + error * (weight / total_weight);
+            }
+        }
+    }
+    indices
+}
+
+/// The threshold for each position in an ordered dithering matrix of size `size` × `size`,
+/// from 0 to `size * size - 1`, and the size of the matrix.
+/// `size` is rounded up to a power of two.
+fn bayer_matrix(size: usize) -> (Vec<usize>, usize) {
+    let mut matrix = alloc::vec![0];
+    let mut n = 1;
+    while n < size {
+        // Each level of the matrix is built from four offset copies of the previous level.
+        let mut next = alloc::vec![0; 4 * n * n];
+        for y in 0..n {
+            for x in 0..n {
+                let value = 4 * matrix[y * n + x];
+                next[y * 2 * n + x] = value;
+                next[y * 2 * n + x + n] = value + 2;
+                next[(y + n) * 2 * n + x] = value + 3;
+                next[(y + n) * 2 * n + x + n] = value + 1;
+            }
+        }
+        matrix = next;
+        n *= 2;
+    }
+    (matrix, n)
+}
+
+/// Reduces each channel of an image to `levels` evenly spaced values with ordered (Bayer) dithering.
+///
+/// For example levels of `[32, 64, 32]` prepare an image for RGB565 output.
+/// `pixels` are encoded RGB values from 0.0 to 1.0, stored row by row with `width` pixels per row,
+/// and are replaced in place with the reduced values.
+/// `matrix_size` is rounded up to a power of two. Larger matrices produce more
+/// in-between shades, but the pattern is more visible. 4 or 8 is typical.
+///
+/// Unlike error diffusion every pixel is processed independently, so the results
+/// are deterministic, stable between animation frames, and can be computed in parallel.
+pub fn ordered(pixels: &mut [[f64; 3]], width: usize, levels: [u32; 3], matrix_size: usize) {
+    let (matrix, n) = bayer_matrix(matrix_size);
+    let cells = (n * n) as f64;
+
+    for (i, pixel) in pixels.iter_mut().enumerate() {
+        let (x, y) = match width {
+            0 => (0, 0),
+            width => (i % width, i / width),
+        };
+        // An offset from -0.5 to 0.5 of a step.
+        let threshold = (matrix[(y % n) * n + x % n] as f64 + 0.5) / cells - 0.5;
+        for (value, levels) in pixel.iter_mut().zip(levels) {
+            let steps = levels.max(2) as f64 - 1.0;
+            *value = (float::round(*value * steps + threshold) / steps).clamp(0.0, 1.0);
+        }
+    }
+}
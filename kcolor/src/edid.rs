@@ -0,0 +1,189 @@
+//! Parsing a display's EDID (Extended Display Identification Data) to describe its colors,
+//! for when a display has no ICC profile.
+//!
+//! EDID chromaticities are reported by the manufacturer, usually from the panel's design
+//! rather than measurement, so color spaces made from them are approximate.
+use crate::*;
+
+/// The color information from an EDID.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Edid {
+    pub red_primary: Chromaticity,
+    pub green_primary: Chromaticity,
+    pub blue_primary: Chromaticity,
+    pub white_point: Chromaticity,
+    /// The display's gamma, if the EDID specifies one.
+    pub gamma: Option<f64>,
+    /// Whether the display's default color space is sRGB.
+    pub srgb_default: bool,
+    /// The HDR static metadata from a CTA-861 extension block, if there is one.
+    pub hdr: Option<HdrStaticMetadata>,
+}
+
+/// The HDR capabilities of a display, from a CTA-861 HDR static metadata data block.
+/// Luminances are in cd/m².
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct HdrStaticMetadata {
+    /// The display supports traditional gamma with SDR luminance.
+    pub sdr: bool,
+    /// The display supports traditional gamma with HDR luminance.
+    pub traditional_hdr: bool,
+    /// The display supports the SMPTE ST 2084 perceptual quantizer (PQ).
+    pub pq: bool,
+    /// The display supports hybrid log-gamma (HLG).
+    pub hlg: bool,
+    /// The luminance of the brightest content the display is designed for.
+    pub max_luminance: Option<f64>,
+    /// The average luminance of the brightest frame the display is designed for.
+    pub max_frame_average_luminance: Option<f64>,
+    /// The luminance of the darkest content the display is designed for.
+    pub min_luminance: Option<f64>,
+}
+
+#[derive(Debug)]
+pub enum EdidError {
+    /// The data doesn't start with the EDID header.
+    InvalidHeader,
+    /// The data is shorter than a block it declares.
+    UnexpectedEnd,
+    /// A block's checksum doesn't match.
+    InvalidChecksum,
+}
+
+const HEADER: [u8; 8] = [0x00, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0x00];
+
+impl Edid {
+    /// Parses the base EDID block and any CTA-861 extension blocks that follow it.
+    pub fn parse(bytes: &[u8]) -> Result<Self, EdidError> {
+        let base = block(bytes, 0)?;
+        if base[..8] != HEADER {
+            return Err(EdidError::InvalidHeader);
+        }
+
+        // Chromaticities are 10 bit values: the high 8 bits are in bytes 27 to 34 and
+        // the low 2 bits are packed into bytes 25 and 26.
+        let low_bits = (base[25] as u16) << 8 | base[26] as u16;
+        let coordinate = |i: usize| {
+            let low = (low_bits >> (14 - i * 2)) & 0b11;
+            ((base[27 + i] as u16) << 2 | low) as f64 / 1024.0
+        };
+        let chromaticity = |i: usize| Chromaticity::new(coordinate(i * 2), coordinate(i * 2 + 1));
+
+        let mut hdr = None;
+        let extension_count = base[126] as usize;
+        for index in 1..=extension_count {
+            let extension = block(bytes, index)?;
+            // CTA-861 extensions have a tag of 0x02.
+            if extension[0] == 0x02 {
+                hdr = hdr.or_else(|| parse_hdr_static_metadata(extension));
+            }
+        }
+
+        Ok(Self {
+            red_primary: chromaticity(0),
+            green_primary: chromaticity(1),
+            blue_primary: chromaticity(2),
+            white_point: chromaticity(3),
+            // 0xff means gamma is defined in an extension block, which isn't supported.
+            gamma: if base[23] == 0xff {
+                None
+            } else {
+                Some((base[23] as f64 + 100.0) / 100.0)
+            },
+            srgb_default: base[24] & 0b100 != 0,
+            hdr,
+        })
+    }
+
+    /// An approximate color space for the display in SDR mode.
+    /// Displays that default to sRGB use the sRGB transfer function,
+    /// others use their gamma, or 2.2 if they don't specify one.
+    pub fn color_space(&self) -> ColorSpace {
+        let transfer_function = if self.srgb_default {
+            SRGB_TRANSFER_FUNCTION
+        } else {
+            TransferFunction::ParametricCurve(ParametricCurve::Function3 {
+                gamma: self.gamma.unwrap_or(2.2),
+                a: 1.0,
+                b: 0.0,
+                c: 0.0,
+                d: 0.0,
+            })
+        };
+        self.color_space_with_transfer_function(transfer_function)
+    }
+
+    /// An approximate color space for the display in HDR10 mode, using the perceptual quantizer.
+    /// Returns `None` if the display doesn't support PQ.
+    /// Linear values of 1.0 are `reference_white` cd/m², like `ColorSpace::rec2100_pq`.
+    pub fn pq_color_space(&self, reference_white: f64) -> Option<ColorSpace> {
+        if !self.hdr?.pq {
+            return None;
+        }
+        Some(self.color_space_with_transfer_function(TransferFunction::Pq { reference_white }))
+    }
+
+    fn color_space_with_transfer_function(
+        &self,
+        transfer_function: TransferFunction,
+    ) -> ColorSpace {
+        ColorSpace::new(
+            self.red_primary,
+            self.green_primary,
+            self.blue_primary,
+            self.white_point,
+            transfer_function,
+        )
+    }
+}
+
+/// Gets the 128 byte block at `index` and checks its checksum.
+fn block(bytes: &[u8], index: usize) -> Result<&[u8], EdidError> {
+    let block = bytes
+        .get(index * 128..(index + 1) * 128)
+        .ok_or(EdidError::UnexpectedEnd)?;
+    if block.iter().fold(0u8, |sum, b| sum.wrapping_add(*b)) != 0 {
+        return Err(EdidError::InvalidChecksum);
+    }
+    Ok(block)
+}
+
+/// Finds the HDR static metadata data block within a CTA-861 extension block.
+fn parse_hdr_static_metadata(extension: &[u8]) -> Option<HdrStaticMetadata> {
+    // Data blocks are between byte 4 and the offset of the detailed timing descriptors.
+    let end = (extension[2] as usize).clamp(4, 127);
+    let mut offset = 4;
+    while offset < end {
+        let tag = extension[offset] >> 5;
+        let length = (extension[offset] & 0b11111) as usize;
+        let payload = extension.get(offset + 1..offset + 1 + length)?;
+        offset += 1 + length;
+
+        // An extended tag (7) with the extended tag code for HDR static metadata (6).
+        if tag != 7 || length < 3 || payload[0] != 6 {
+            continue;
+        }
+        let eotfs = payload[1];
+        // Luminances are coded as 50 * 2^(value / 32).
+        let luminance = |i: usize| {
+            payload
+                .get(i)
+                .filter(|v| **v != 0)
+                .map(|v| 50.0 * powf(2.0, *v as f64 / 32.0))
+        };
+        let max_luminance = luminance(3);
+        return Some(HdrStaticMetadata {
+            sdr: eotfs & 0b1 != 0,
+            traditional_hdr: eotfs & 0b10 != 0,
+            pq: eotfs & 0b100 != 0,
+            hlg: eotfs & 0b1000 != 0,
+            max_luminance,
+            max_frame_average_luminance: luminance(4),
+            min_luminance: payload.get(5).and_then(|v| {
+                let max = max_luminance?;
+                Some(max * powf(*v as f64 / 255.0, 2.0) / 100.0)
+            }),
+        });
+    }
+    None
+}
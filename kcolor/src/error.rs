@@ -0,0 +1,108 @@
+use crate::*;
+
+/// The error returned by kcolor's fallible constructors and parsers.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Error {
+    /// A hex color string isn't 3, 4, 6, or 8 hexadecimal digits, optionally starting with `#`.
+    InvalidHex,
+    /// A chromaticity has a y of 0.0 or less, or values that aren't finite.
+    InvalidChromaticity,
+    /// The primaries of a color space are on a line, so they can't describe a range of colors.
+    CollinearPrimaries,
+    /// A matrix can't be inverted.
+    SingularMatrix,
+    /// A required setting of a `ColorSpaceBuilder` wasn't set.
+    MissingSetting,
+    /// A color string couldn't be understood, even by `Color::parse_lossy`.
+    UnrecognizedColor,
+    /// A `YCbCrFormat` bit depth isn't from 8 to 16.
+    InvalidBitDepth,
+    /// Too few color samples were provided to calculate a color rendering metric.
+    NotEnoughSamples,
+}
+
+impl core::fmt::Display for Error {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        let message = match self {
+            Error::InvalidHex => "invalid hex color",
+            Error::InvalidChromaticity => "invalid chromaticity",
+            Error::CollinearPrimaries => "the primaries are collinear",
+            Error::SingularMatrix => "the matrix can't be inverted",
+            Error::MissingSetting => "a required color space setting is missing",
+            Error::UnrecognizedColor => "unrecognized color",
+            Error::InvalidBitDepth => "the bit depth must be from 8 to 16",
+            Error::NotEnoughSamples => "not enough color samples",
+        };
+        f.write_str(message)
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for Error {}
+
+impl From<ColorSpaceError> for Error {
+    fn from(error: ColorSpaceError) -> Self {
+        match error {
+            ColorSpaceError::MissingPrimary | ColorSpaceError::MissingWhitePoint => {
+                Error::MissingSetting
+            }
+            ColorSpaceError::InvalidChromaticity => Error::InvalidChromaticity,
+            ColorSpaceError::CollinearPrimaries => Error::CollinearPrimaries,
+        }
+    }
+}
+
+impl ColorSpace {
+    /// The same as `ColorSpace::new`, but returns an error instead of a color space
+    /// that produces NaNs if the primaries or white point are invalid.
+    pub fn try_new(
+        red_primary: Chromaticity,
+        green_primary: Chromaticity,
+        blue_primary: Chromaticity,
+        white_point: Chromaticity,
+        transfer_function: TransferFunction,
+    ) -> Result<Self, Error> {
+        Ok(ColorSpace::builder()
+            .primaries(red_primary, green_primary, blue_primary)
+            .white_point(white_point)
+            .transfer_function(transfer_function)
+            .build()?)
+    }
+
+    /// The same as `ColorSpace::new_xyz_d50`, but returns an error if the primaries
+    /// can't be inverted to convert colors back to the color space.
+    pub fn try_new_xyz_d50(
+        red_primary: XYZ,
+        green_primary: XYZ,
+        blue_primary: XYZ,
+        transfer_function: TransferFunction,
+    ) -> Result<Self, Error> {
+        let to_xyz = Matrix3x3::from_columns_xyz(red_primary, green_primary, blue_primary);
+        to_xyz.try_inverse().ok_or(Error::SingularMatrix)?;
+        Ok(ColorSpace::new_xyz_d50(
+            red_primary,
+            green_primary,
+            blue_primary,
+            transfer_function,
+        ))
+    }
+
+    /// Parses a CSS style hex color string in this color space: `#rgb`, `#rgba`, `#rrggbb`,
+    /// or `#rrggbbaa`. The `#` is optional.
+    pub fn new_color_from_hex_str(&self, hex: &str) -> Result<Color, Error> {
+        let digits = hex.trim().trim_start_matches('#');
+        if !digits.chars().all(|c| c.is_ascii_hexdigit()) {
+            return Err(Error::InvalidHex);
+        }
+        let digit = |i: usize| u8::from_str_radix(&digits[i..i + 1], 16).unwrap();
+        let byte = |i: usize| u8::from_str_radix(&digits[i * 2..i * 2 + 2], 16).unwrap();
+        let rgba = match digits.len() {
+            3 => [digit(0) * 17, digit(1) * 17, digit(2) * 17, 255],
+            4 => [digit(0) * 17, digit(1) * 17, digit(2) * 17, digit(3) * 17],
+            6 => [byte(0), byte(1), byte(2), 255],
+            8 => [byte(0), byte(1), byte(2), byte(3)],
+            _ => return Err(Error::InvalidHex),
+        };
+        Ok(self.new_color_from_rgba8_array(rgba))
+    }
+}
@@ -0,0 +1,180 @@
+//! A C interface to kColor, enabled with the `ffi` feature.
+//!
+//! Build a C library from it with:
+//! `cargo rustc -p kcolor --features ffi --crate-type cdylib`
+//! A header can be generated with `cbindgen`.
+//!
+//! Color spaces and converters are heap allocated and returned as opaque pointers.
+//! They must be released with `kcolor_color_space_free` and `kcolor_converter_free`.
+use crate::*;
+use alloc::boxed::Box;
+use core::ptr;
+
+/// Transfer function kinds accepted by `kcolor_color_space_new`.
+pub const KCOLOR_TRANSFER_FUNCTION_NONE: u32 = 0;
+/// An ICC parametric curve of type 3 with parameters `[gamma, a, b, c, d]`. Used by sRGB.
+pub const KCOLOR_TRANSFER_FUNCTION_PARAMETRIC_3: u32 = 3;
+/// An ICC parametric curve of type 4 with parameters `[gamma, a, b, c, d, e, f]`.
+pub const KCOLOR_TRANSFER_FUNCTION_PARAMETRIC_4: u32 = 4;
+
+/// Creates a new color space from chromaticities.
+/// Returns null if `transfer_function` is not a supported kind.
+///
+/// # Safety
+/// `chromaticities` must point to 8 values: the x and y of the red, green,
+/// and blue primaries followed by the white point.
+/// `parameters` must point to as many values as the transfer function kind requires,
+/// or may be null for `KCOLOR_TRANSFER_FUNCTION_NONE`.
+#[no_mangle]
+pub unsafe extern "C" fn kcolor_color_space_new(
+    chromaticities: *const f64,
+    transfer_function: u32,
+    parameters: *const f64,
+) -> *mut ColorSpace {
+    let c = core::slice::from_raw_parts(chromaticities, 8);
+    let transfer_function = match transfer_function {
+        KCOLOR_TRANSFER_FUNCTION_NONE => TransferFunction::None,
+        KCOLOR_TRANSFER_FUNCTION_PARAMETRIC_3 => {
+            let p = core::slice::from_raw_parts(parameters, 5);
+            TransferFunction::ParametricCurve(ParametricCurve::Function3 {
+                gamma: p[0],
+                a: p[1],
+                b: p[2],
+                c: p[3],
+                d: p[4],
+            })
+        }
+        KCOLOR_TRANSFER_FUNCTION_PARAMETRIC_4 => {
+            let p = core::slice::from_raw_parts(parameters, 7);
+            TransferFunction::ParametricCurve(ParametricCurve::Function4 {
+                gamma: p[0],
+                a: p[1],
+                b: p[2],
+                c: p[3],
+                d: p[4],
+                e: p[5],
+                f: p[6],
+            })
+        }
+        _ => return ptr::null_mut(),
+    };
+    Box::into_raw(Box::new(ColorSpace::new(
+        Chromaticity::new(c[0], c[1]),
+        Chromaticity::new(c[2], c[3]),
+        Chromaticity::new(c[4], c[5]),
+        Chromaticity::new(c[6], c[7]),
+        transfer_function,
+    )))
+}
+
+/// Creates a new sRGB color space.
+#[no_mangle]
+pub extern "C" fn kcolor_color_space_srgb() -> *mut ColorSpace {
+    Box::into_raw(Box::new(ColorSpace::SRGB))
+}
+
+/// Creates a new linear sRGB color space.
+#[no_mangle]
+pub extern "C" fn kcolor_color_space_srgb_linear() -> *mut ColorSpace {
+    Box::into_raw(Box::new(ColorSpace::SRGB_LINEAR))
+}
+
+/// Creates a color space from the bytes of an ICC profile.
+/// Returns null if the profile could not be parsed or is unsupported.
+///
+/// # Safety
+/// `bytes` must point to `length` readable bytes.
+#[no_mangle]
+pub unsafe extern "C" fn kcolor_color_space_from_icc_profile(
+    bytes: *const u8,
+    length: usize,
+) -> *mut ColorSpace {
+    let bytes = core::slice::from_raw_parts(bytes, length);
+    // Profiles come from untrusted files, and a panic can't unwind into C.
+    match std::panic::catch_unwind(|| ColorSpace::from_icc_profile(bytes)) {
+        Ok(Ok(color_space)) => Box::into_raw(Box::new(color_space)),
+        _ => ptr::null_mut(),
+    }
+}
+
+/// Frees a color space. Passing null does nothing.
+///
+/// # Safety
+/// `color_space` must have been returned by one of the `kcolor_color_space_*`
+/// functions and must not be used afterwards.
+#[no_mangle]
+pub unsafe extern "C" fn kcolor_color_space_free(color_space: *mut ColorSpace) {
+    if !color_space.is_null() {
+        drop(Box::from_raw(color_space));
+    }
+}
+
+/// Converts a single RGBA color from one color space to another.
+/// The output is not clipped.
+///
+/// # Safety
+/// `from` and `to` must be valid color spaces.
+/// `rgba_in` must point to 4 readable values and `rgba_out` to 4 writable values.
+#[no_mangle]
+pub unsafe extern "C" fn kcolor_convert_color(
+    from: *const ColorSpace,
+    to: *const ColorSpace,
+    rgba_in: *const f64,
+    rgba_out: *mut f64,
+) {
+    let rgba_in = core::slice::from_raw_parts(rgba_in, 4);
+    let color = (*from).new_color(rgba_in[0], rgba_in[1], rgba_in[2], rgba_in[3]);
+    let (r, g, b, a) = (*to).color_to_rgba_unclipped(&color);
+    let rgba_out = core::slice::from_raw_parts_mut(rgba_out, 4);
+    rgba_out.copy_from_slice(&[r, g, b, a]);
+}
+
+/// Creates a converter for repeatedly converting colors between two color spaces.
+///
+/// # Safety
+/// `from` and `to` must be valid color spaces.
+#[no_mangle]
+pub unsafe extern "C" fn kcolor_converter_new(
+    from: *const ColorSpace,
+    to: *const ColorSpace,
+) -> *mut ColorSpaceConverter {
+    Box::into_raw(Box::new(ColorSpaceConverter::new(&*from, &*to)))
+}
+
+/// Frees a converter. Passing null does nothing.
+///
+/// # Safety
+/// `converter` must have been returned by `kcolor_converter_new`
+/// and must not be used afterwards.
+#[no_mangle]
+pub unsafe extern "C" fn kcolor_converter_free(converter: *mut ColorSpaceConverter) {
+    if !converter.is_null() {
+        drop(Box::from_raw(converter));
+    }
+}
+
+/// Converts a buffer of `f32` pixels in place.
+/// Each pixel is `channels` values long and the first three are converted as RGB.
+/// Additional channels, such as alpha, are left untouched.
+/// The output is not clipped.
+///
+/// # Safety
+/// `converter` must be a valid converter, `channels` must be at least 3,
+/// and `pixels` must point to `pixel_count * channels` writable values.
+#[no_mangle]
+pub unsafe extern "C" fn kcolor_converter_convert_f32_buffer(
+    converter: *const ColorSpaceConverter,
+    pixels: *mut f32,
+    pixel_count: usize,
+    channels: usize,
+) {
+    let converter = &*converter;
+    let pixels = core::slice::from_raw_parts_mut(pixels, pixel_count * channels);
+    for pixel in pixels.chunks_exact_mut(channels) {
+        let (r, g, b) =
+            converter.convert_encoded_color(&(pixel[0] as f64, pixel[1] as f64, pixel[2] as f64));
+        pixel[0] = r as f32;
+        pixel[1] = g as f32;
+        pixel[2] = b as f32;
+    }
+}
@@ -0,0 +1,64 @@
+use crate::*;
+
+// How far outside of 0.0 to 1.0 a linear RGB value can be and still be considered in gamut.
+// This prevents rounding errors from causing colors to be reported as out of gamut.
+const GAMUT_EPSILON: f64 = 0.000001;
+
+impl ColorSpace {
+    /// Returns true if the color can be represented in this color space
+    /// without its RGB values going outside of 0.0 to 1.0.
+    pub fn contains(&self, color: &Color) -> bool {
+        let rgb = self.from_XYZ * color.xyz().to_vector3();
+        let in_range = |v: f64| (-GAMUT_EPSILON..=1.0 + GAMUT_EPSILON).contains(&v);
+        in_range(rgb.x) && in_range(rgb.y) && in_range(rgb.z)
+    }
+
+    /// Finds a color within this color space's gamut that is perceptually close to `color`.
+    ///
+    /// Colors already within the gamut are returned unchanged.
+    /// Otherwise the Oklch chroma of the color is reduced, preserving lightness and hue,
+    /// until the color fits. Colors brighter than white or darker than black become white or black.
+    pub fn gamut_map(&self, color: &Color) -> Color {
+        if self.contains(color) {
+            return *color;
+        }
+
+        let oklch = color.to_oklch();
+        if oklch.L >= 1.0 {
+            return self.new_color(1.0, 1.0, 1.0, color.a);
+        }
+        if oklch.L <= 0.0 {
+            return self.new_color(0.0, 0.0, 0.0, color.a);
+        }
+
+        let chroma = self.max_chroma_below(oklch.L, oklch.h, oklch.C);
+
+        // Clip away any remaining error so the result is exactly within the gamut.
+        let mapped = Color::from_oklch(Oklch::new(oklch.L, chroma, oklch.h), color.a);
+        let (r, g, b, a) = self.color_to_rgba(&mapped);
+        self.new_color(r, g, b, a)
+    }
+
+    /// The largest Oklch chroma a color with the given Oklch lightness and hue
+    /// can have while staying within this color space's gamut.
+    pub fn max_chroma(&self, lightness: f64, hue: f64) -> f64 {
+        // No real color space has colors with an Oklch chroma near 1.0.
+        self.max_chroma_below(lightness, hue, 1.0)
+    }
+
+    fn max_chroma_below(&self, lightness: f64, hue: f64, upper_bound: f64) -> f64 {
+        // Binary search for the largest chroma that's within the gamut.
+        let mut low = 0.0;
+        let mut high = upper_bound;
+        for _ in 0..24 {
+            let chroma = (low + high) / 2.0;
+            let candidate = Color::from_oklch(Oklch::new(lightness, chroma, hue), 1.0);
+            if self.contains(&candidate) {
+                low = chroma;
+            } else {
+                high = chroma;
+            }
+        }
+        low
+    }
+}
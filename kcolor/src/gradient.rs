@@ -0,0 +1,261 @@
+//! Gradients: colors that vary smoothly between stops, mapping data values to them,
+//! and exporting them to CSS and SVG.
+use crate::*;
+use alloc::format;
+use alloc::string::String;
+use alloc::vec::Vec;
+
+/// The color space colors are mixed in between a gradient's stops.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum GradientInterpolation {
+    /// Mixes in XYZ, which matches physically mixing light.
+    Xyz,
+    /// Mixes in Oklab, which changes at a perceptually even rate and avoids muddy middles.
+    #[default]
+    Oklab,
+    /// Mixes lightness, chroma, and hue in Oklch, going the shorter way around the
+    /// color wheel. This keeps colors saturated but can pass through unrelated hues.
+    Oklch,
+}
+
+/// How data values are scaled to positions along a gradient by `Gradient::map_values`.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum ValueScale {
+    #[default]
+    Linear,
+    /// Logarithmic scaling, for values spanning many orders of magnitude.
+    /// Values that aren't greater than 0.0 are out of range.
+    Log,
+}
+
+/// A list of colors at positions from 0.0 to 1.0 that are blended between.
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Gradient {
+    stops: Vec<(f64, Color)>,
+    interpolation: GradientInterpolation,
+    value_scale: ValueScale,
+    under_color: Option<Color>,
+    over_color: Option<Color>,
+}
+
+impl Gradient {
+    /// Creates a gradient from colors paired with their positions, which are sorted.
+    pub fn new(stops: impl IntoIterator<Item = (f64, Color)>) -> Self {
+        let mut stops: Vec<(f64, Color)> = stops.into_iter().collect();
+        stops.sort_by(|a, b| a.0.total_cmp(&b.0));
+        Self {
+            stops,
+            interpolation: GradientInterpolation::default(),
+            value_scale: ValueScale::default(),
+            under_color: None,
+            over_color: None,
+        }
+    }
+
+    /// Creates a gradient with the colors spread evenly from 0.0 to 1.0.
+    pub fn evenly_spaced(colors: &[Color]) -> Self {
+        let last = colors.len().saturating_sub(1).max(1) as f64;
+        Self::new(
+            colors
+                .iter()
+                .enumerate()
+                .map(|(i, color)| (i as f64 / last, *color)),
+        )
+    }
+
+    /// Sets the color space colors are mixed in. Defaults to `GradientInterpolation::Oklab`.
+    pub fn interpolation(mut self, interpolation: GradientInterpolation) -> Self {
+        self.interpolation = interpolation;
+        self
+    }
+
+    /// Sets how `map_values` scales values. Defaults to `ValueScale::Linear`.
+    pub fn value_scale(mut self, value_scale: ValueScale) -> Self {
+        self.value_scale = value_scale;
+        self
+    }
+
+    /// The color `map_values` uses for values below the minimum,
+    /// instead of the color of the first stop.
+    pub fn under_color(mut self, color: Color) -> Self {
+        self.under_color = Some(color);
+        self
+    }
+
+    /// The color `map_values` uses for values above the maximum,
+    /// instead of the color of the last stop.
+    pub fn over_color(mut self, color: Color) -> Self {
+        self.over_color = Some(color);
+        self
+    }
+
+    pub fn stops(&self) -> &[(f64, Color)] {
+        &self.stops
+    }
+
+    /// The color at `position`. Positions outside the stops get the nearest stop's color.
+    /// A gradient without stops, or a NaN `position`, is transparent.
+    pub fn sample(&self, position: f64) -> Color {
+        let (first, last) = match (self.stops.first(), self.stops.last()) {
+            (Some(first), Some(last)) if !position.is_nan() => (first, last),
+            _ => return Color::TRANSPARENT,
+        };
+        if position <= first.0 {
+            return first.1;
+        }
+        if position >= last.0 {
+            return last.1;
+        }
+        // There's always a later stop unless the last stop's position is NaN.
+        let next = match self.stops.iter().position(|(p, _)| *p > position) {
+            Some(next) => next,
+            None => return last.1,
+        };
+        let (p0, c0) = self.stops[next - 1];
+        let (p1, c1) = self.stops[next];
+        self.mix(&c0, &c1, (position - p0) / (p1 - p0))
+    }
+
+    fn mix(&self, a: &Color, b: &Color, amount: f64) -> Color {
+        let lerp = |a: f64, b: f64| (b - a) * amount + a;
+        let alpha = lerp(a.a, b.a);
+        match self.interpolation {
+            GradientInterpolation::Xyz => a.interpolate(b, amount),
+            GradientInterpolation::Oklab => {
+                let (a, b) = (a.to_oklab(), b.to_oklab());
+                Color::from_oklab(
+                    Oklab::new(lerp(a.L, b.L), lerp(a.a, b.a), lerp(a.b, b.b)),
+                    alpha,
+                )
+            }
+            GradientInterpolation::Oklch => {
+                let (a, b) = (a.to_oklch(), b.to_oklch());
+                // Take the shorter way around the color wheel.
+                let difference = float::rem_euclid(b.h - a.h + 180.0, 360.0) - 180.0;
+                let h = float::rem_euclid(a.h + difference * amount, 360.0);
+                Color::from_oklch(Oklch::new(lerp(a.L, b.L), lerp(a.C, b.C), h), alpha)
+            }
+        }
+    }
+
+    /// Maps data values to colors for false coloring, where `min` maps to the start of
+    /// the gradient and `max` to the end, scaled according to `value_scale`.
+    ///
+    /// Values out of range use `under_color` and `over_color` if they're set.
+    /// NaN values are transparent.
+    pub fn map_values(&self, values: &[f64], min: f64, max: f64) -> Vec<Color> {
+        let (start, end) = match (self.stops.first(), self.stops.last()) {
+            (Some(first), Some(last)) => (first.0, last.0),
+            _ => return values.iter().map(|_| Color::TRANSPARENT).collect(),
+        };
+        let to_unit = |v: f64| match self.value_scale {
+            ValueScale::Linear => (v - min) / (max - min),
+            ValueScale::Log if v <= 0.0 => f64::NEG_INFINITY,
+            ValueScale::Log => float::ln(v / min) / float::ln(max / min),
+        };
+        values
+            .iter()
+            .map(|v| {
+                let t = to_unit(*v);
+                if v.is_nan() {
+                    Color::TRANSPARENT
+                } else if t < 0.0 {
+                    self.under_color.unwrap_or_else(|| self.sample(start))
+                } else if t > 1.0 {
+                    self.over_color.unwrap_or_else(|| self.sample(end))
+                } else {
+                    self.sample(start + t * (end - start))
+                }
+            })
+            .collect()
+    }
+}
+
+impl Gradient {
+    /// The gradient's stops with `samples_per_segment` evenly spaced stops between each pair,
+    /// so formats that can only blend in sRGB closely follow the intended blend.
+    pub fn resampled_stops(&self, samples_per_segment: usize) -> Vec<(f64, Color)> {
+        let mut stops = Vec::new();
+        for pair in self.stops.windows(2) {
+            let (p0, p1) = (pair[0].0, pair[1].0);
+            for i in 0..=samples_per_segment {
+                let p = p0 + (p1 - p0) * i as f64 / (samples_per_segment + 1) as f64;
+                stops.push((p, self.mix(&pair[0].1, &pair[1].1, (p - p0) / (p1 - p0))));
+            }
+        }
+        stops.extend(self.stops.last());
+        stops
+    }
+
+    /// A CSS `linear-gradient()` that interpolates in the same space as this gradient,
+    /// using the CSS Color 4 `in <color space>` syntax with `oklch()` colors.
+    /// `direction` is a CSS direction like `"to right"` or `"45deg"`.
+    ///
+    /// Older browsers don't support this syntax, see `to_css_srgb`.
+    pub fn to_css(&self, direction: &str) -> String {
+        let space = match self.interpolation {
+            GradientInterpolation::Xyz => "xyz-d50",
+            GradientInterpolation::Oklab => "oklab",
+            GradientInterpolation::Oklch => "oklch",
+        };
+        let mut css = format!("linear-gradient({} in {}", direction, space);
+        for (position, color) in &self.stops {
+            let oklch = color.to_oklch();
+            css += &format!(
+                ", oklch({} {} {}",
+                number(oklch.L, 4),
+                number(oklch.C, 4),
+                number(oklch.h, 2)
+            );
+            if color.a < 1.0 {
+                css += &format!(" / {}", number(color.a, 4));
+            }
+            css += &format!(") {}%", number(position * 100.0, 2));
+        }
+        css + ")"
+    }
+
+    /// A CSS `linear-gradient()` with sRGB hex colors that works in all browsers.
+    /// Browsers blend these in sRGB, so stops are resampled with `samples_per_segment`
+    /// to approximate the intended blend. Colors are gamut mapped to sRGB.
+    pub fn to_css_srgb(&self, direction: &str, samples_per_segment: usize) -> String {
+        let mut css = format!("linear-gradient({}", direction);
+        for (position, color) in self.resampled_stops(samples_per_segment) {
+            css += &format!(
+                ", {} {}%",
+                ColorSpace::SRGB.color_to_hex_string(&color, color.a < 1.0),
+                number(position * 100.0, 2)
+            );
+        }
+        css + ")"
+    }
+
+    /// An SVG `<linearGradient>` element with the given `id`, going left to right.
+    /// SVG blends in sRGB, so stops are resampled with `samples_per_segment`
+    /// to approximate the intended blend. Colors are gamut mapped to sRGB.
+    pub fn to_svg(&self, id: &str, samples_per_segment: usize) -> String {
+        let mut svg = format!("<linearGradient id=\"{}\">\n", id);
+        for (position, color) in self.resampled_stops(samples_per_segment) {
+            svg += &format!(
+                "  <stop offset=\"{}%\" stop-color=\"{}\"",
+                number(position * 100.0, 2),
+                ColorSpace::SRGB.color_to_hex_string(&color, false)
+            );
+            if color.a < 1.0 {
+                svg += &format!(" stop-opacity=\"{}\"", number(color.a, 4));
+            }
+            svg += "/>\n";
+        }
+        svg + "</linearGradient>"
+    }
+}
+
+/// Formats `value` rounded to `decimals` places, without trailing zeros.
+fn number(value: f64, decimals: i32) -> String {
+    let scale = float::powf(10.0, decimals as f64);
+    // Adding 0.0 turns -0.0 into 0.0.
+    format!("{}", float::round(value * scale) / scale + 0.0)
+}
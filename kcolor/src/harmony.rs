@@ -0,0 +1,125 @@
+//! Color harmonies: sets of colors with hues evenly spaced around the color wheel.
+//!
+//! Hues are rotated in Oklch so the spacing is perceptually even, and the lightness and
+//! chroma of the base color are kept. Results are gamut mapped to the requested color space.
+//! The first color of each set is the (gamut mapped) base color.
+use crate::*;
+
+/// Rotates the hue of `color` by each of `offsets` degrees.
+fn rotate_hues<const N: usize>(
+    color: &Color,
+    offsets: [f64; N],
+    color_space: &ColorSpace,
+) -> [Color; N] {
+    let oklch = color.to_oklch();
+    offsets.map(|offset| {
+        let h = float::rem_euclid(oklch.h + offset, 360.0);
+        color_space.gamut_map(&Color::from_oklch(Oklch::new(oklch.L, oklch.C, h), color.a))
+    })
+}
+
+/// The base color and the color opposite it on the color wheel.
+pub fn complementary(color: &Color, color_space: &ColorSpace) -> [Color; 2] {
+    rotate_hues(color, [0.0, 180.0], color_space)
+}
+
+/// The base color and the two colors on either side of its complement, 150 degrees away.
+pub fn split_complementary(color: &Color, color_space: &ColorSpace) -> [Color; 3] {
+    rotate_hues(color, [0.0, 150.0, 210.0], color_space)
+}
+
+/// The base color and its two neighbors, 30 degrees away on either side.
+pub fn analogous(color: &Color, color_space: &ColorSpace) -> [Color; 3] {
+    rotate_hues(color, [0.0, 30.0, -30.0], color_space)
+}
+
+/// Three colors evenly spaced around the color wheel.
+pub fn triadic(color: &Color, color_space: &ColorSpace) -> [Color; 3] {
+    rotate_hues(color, [0.0, 120.0, 240.0], color_space)
+}
+
+/// Two pairs of complementary colors forming a rectangle on the color wheel.
+pub fn tetradic(color: &Color, color_space: &ColorSpace) -> [Color; 4] {
+    rotate_hues(color, [0.0, 60.0, 180.0, 240.0], color_space)
+}
+
+/// An endless, deterministic sequence of colors where each new color is as different as
+/// possible from the ones before it, for chart series and debug visualizations.
+///
+/// Each color's Oklch hue is 1/φ (the golden ratio) of a turn from the previous color's,
+/// which spreads any number of hues evenly around the color wheel. Lightness also varies,
+/// within a range that keeps colors readable, so that later colors with similar hues
+/// can still be told apart. Colors are gamut mapped to the color space.
+///
+/// ```
+/// # use kcolor::*;
+/// let series: Vec<Color> = harmony::DistinctColors::new(&ColorSpace::SRGB).take(8).collect();
+/// ```
+#[derive(Debug, Clone)]
+pub struct DistinctColors {
+    color_space: ColorSpace,
+    index: u64,
+    hue_offset: f64,
+    lightness: (f64, f64),
+    chroma: f64,
+}
+
+impl DistinctColors {
+    /// A sequence with lightness from 0.55 to 0.8 and a chroma of 0.15,
+    /// which is readable on both light and dark backgrounds.
+    pub fn new(color_space: &ColorSpace) -> Self {
+        Self {
+            color_space: color_space.clone(),
+            index: 0,
+            hue_offset: 0.0,
+            lightness: (0.55, 0.8),
+            chroma: 0.15,
+        }
+    }
+
+    /// Starts the sequence at a hue chosen by `seed`, so different seeds produce different
+    /// sequences. The same seed always produces the same sequence.
+    pub fn with_seed(mut self, seed: u64) -> Self {
+        // SplitMix64, so nearby seeds produce unrelated hues.
+        let mut z = seed.wrapping_add(0x9E3779B97F4A7C15);
+        z = (z ^ (z >> 30)).wrapping_mul(0xBF58476D1CE4E5B9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94D049BB133111EB);
+        z ^= z >> 31;
+        self.hue_offset = (z >> 11) as f64 / (1u64 << 53) as f64;
+        self
+    }
+
+    /// Limits the Oklch lightness of the colors to `min..=max`.
+    pub fn lightness_range(mut self, min: f64, max: f64) -> Self {
+        self.lightness = (min, max);
+        self
+    }
+
+    /// Sets the Oklch chroma of the colors. Colors that would be outside of the
+    /// color space's gamut have their chroma reduced.
+    pub fn chroma(mut self, chroma: f64) -> Self {
+        self.chroma = chroma;
+        self
+    }
+}
+
+impl Iterator for DistinctColors {
+    type Item = Color;
+
+    fn next(&mut self) -> Option<Color> {
+        const INVERSE_GOLDEN_RATIO: f64 = 0.618_033_988_749_895;
+        // The second value of the R2 low discrepancy sequence, which is independent
+        // of the golden ratio so lightness and hue don't repeat together.
+        const LIGHTNESS_STEP: f64 = 0.569_840_290_998_053;
+
+        let i = self.index as f64;
+        self.index += 1;
+        let hue = float::rem_euclid(self.hue_offset + i * INVERSE_GOLDEN_RATIO, 1.0) * 360.0;
+        let t = float::rem_euclid(0.5 + i * LIGHTNESS_STEP, 1.0);
+        let (min, max) = self.lightness;
+        let lightness = min + (max - min) * t;
+
+        let color = Color::from_oklch(Oklch::new(lightness, self.chroma, hue), 1.0);
+        Some(self.color_space.gamut_map(&color))
+    }
+}
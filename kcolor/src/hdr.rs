@@ -0,0 +1,131 @@
+//! High dynamic range (HDR) colors.
+//!
+//! `Color`s are usually relative: a luminance (Y) of 1.0 is diffuse (paper) white.
+//! HDR formats like HDR10 instead encode absolute luminance in cd/m² (nits), so colors can be
+//! converted to absolute colors, whose Y is in cd/m², given how bright diffuse white should be.
+use crate::white_points::*;
+use crate::*;
+
+/// The luminance in cd/m² of diffuse white in HDR content, from ITU-R BT.2408.
+pub const HDR_REFERENCE_WHITE: f64 = 203.0;
+
+/// The red, green, and blue primaries of ITU-R BT.2020 and BT.2100, used by HDR video.
+pub(crate) const REC2020_PRIMARIES: [Chromaticity; 3] = [
+    Chromaticity { x: 0.708, y: 0.292 },
+    Chromaticity { x: 0.170, y: 0.797 },
+    Chromaticity { x: 0.131, y: 0.046 },
+];
+
+impl Color {
+    /// Converts a relative color, where 1.0 is diffuse white, to an absolute color,
+    /// whose XYZ values are in cd/m², where diffuse white is `reference_white` cd/m².
+    pub fn relative_to_absolute(&self, reference_white: f64) -> Color {
+        Color::new_xyza(
+            self.X * reference_white,
+            self.Y * reference_white,
+            self.Z * reference_white,
+            self.a,
+        )
+    }
+
+    /// Converts an absolute color, whose XYZ values are in cd/m², to a relative color,
+    /// where 1.0 is diffuse white and diffuse white is `reference_white` cd/m².
+    pub fn absolute_to_relative(&self, reference_white: f64) -> Color {
+        self.relative_to_absolute(1.0 / reference_white)
+    }
+
+    /// Converts HLG scene light to absolute display light with the HLG opto-optical transfer
+    /// function (OOTF) from ITU-R BT.2100, for a display whose peak luminance is
+    /// `peak_luminance` cd/m². The XYZ values of the returned color are in cd/m².
+    ///
+    /// Scene light is what `ColorSpace::rec2100_hlg` decodes, where 1.0 is the brightest
+    /// the signal can encode. The OOTF applies the system gamma (see `hlg_system_gamma`)
+    /// to luminance, so brighter displays get more contrast and the same content looks
+    /// consistent on SDR and HDR displays.
+    /// Use `absolute_to_relative` with the display's reference white to get a relative color.
+    pub fn hlg_ootf(&self, peak_luminance: f64) -> Color {
+        let gamma = hlg_system_gamma(peak_luminance);
+        let luminance = hlg_scene_luminance(self);
+        let scale = if luminance > 0.0 {
+            peak_luminance * powf(luminance, gamma - 1.0)
+        } else {
+            0.0
+        };
+        Color::new_xyza(self.X * scale, self.Y * scale, self.Z * scale, self.a)
+    }
+
+    /// The inverse of `hlg_ootf`: converts absolute display light in cd/m² to HLG scene light
+    /// for a display whose peak luminance is `peak_luminance` cd/m².
+    pub fn hlg_inverse_ootf(&self, peak_luminance: f64) -> Color {
+        let gamma = hlg_system_gamma(peak_luminance);
+        let display_luminance = hlg_scene_luminance(self) / peak_luminance;
+        let scale = if display_luminance > 0.0 {
+            powf(display_luminance, (1.0 - gamma) / gamma) / peak_luminance
+        } else {
+            0.0
+        };
+        Color::new_xyza(self.X * scale, self.Y * scale, self.Z * scale, self.a)
+    }
+}
+
+/// The HLG system gamma for a display whose peak luminance is `peak_luminance` cd/m².
+/// This is 1.2 for the nominal 1000 cd/m² display.
+///
+/// The formula from ITU-R BT.2100 is used from 400 to 2000 cd/m², and the extended formula
+/// from ITU-R BT.2390 is used outside of that range.
+pub fn hlg_system_gamma(peak_luminance: f64) -> f64 {
+    if (400.0..=2000.0).contains(&peak_luminance) {
+        1.2 + 0.42 * float::ln(peak_luminance / 1000.0) / core::f64::consts::LN_10
+    } else {
+        1.2 * powf(
+            1.111,
+            float::ln(peak_luminance / 1000.0) / core::f64::consts::LN_2,
+        )
+    }
+}
+
+/// The luminance HLG uses for the OOTF, calculated from linear BT.2020 RGB values.
+fn hlg_scene_luminance(color: &Color) -> f64 {
+    let (r, g, b, _) = rec2020_linear().color_to_rgba_unclipped(color);
+    0.2627 * r + 0.6780 * g + 0.0593 * b
+}
+
+fn rec2020_linear() -> ColorSpace {
+    ColorSpace::new(
+        REC2020_PRIMARIES[0],
+        REC2020_PRIMARIES[1],
+        REC2020_PRIMARIES[2],
+        D65_WHITE_POINT_2DEGREES,
+        TransferFunction::None,
+    )
+}
+
+impl ColorSpace {
+    /// The ITU-R BT.2100 color space with the perceptual quantizer (PQ) transfer function,
+    /// as used by HDR10. Linear values of 1.0 are `reference_white` cd/m².
+    ///
+    /// Use `HDR_REFERENCE_WHITE` for relative colors, where 1.0 is diffuse white,
+    /// or 1.0 for absolute colors (see `Color::relative_to_absolute`).
+    pub fn rec2100_pq(reference_white: f64) -> ColorSpace {
+        ColorSpace::new(
+            REC2020_PRIMARIES[0],
+            REC2020_PRIMARIES[1],
+            REC2020_PRIMARIES[2],
+            D65_WHITE_POINT_2DEGREES,
+            TransferFunction::Pq { reference_white },
+        )
+    }
+
+    /// The ITU-R BT.2100 color space with the hybrid log-gamma (HLG) transfer function.
+    /// Linear values are scene light from 0.0 to 1.0; use `Color::hlg_ootf` to convert
+    /// colors to display light.
+    pub fn rec2100_hlg() -> ColorSpace {
+        ColorSpace::new(
+            REC2020_PRIMARIES[0],
+            REC2020_PRIMARIES[1],
+            REC2020_PRIMARIES[2],
+            D65_WHITE_POINT_2DEGREES,
+            TransferFunction::Hlg,
+        )
+    }
+}
@@ -8,13 +8,60 @@ pub enum ICCProfileError {
     UnsupportedICCProfile,
 }
 impl ColorSpace {
-    /// This function does not handle all ICC profiles, or even most.
-    /// Presently it only handles RGB profiles that define
-    /// primaries, a white point, and a transfer function
+    /// This function does not handle all ICC profiles.
+    /// Presently it only handles RGB profiles that define primaries, a white point,
+    /// and `para` or `curv` tone reproduction curves, like most display profiles.
     pub fn from_icc_profile(bytes: &[u8]) -> Result<Self, ICCProfileError> {
+        Self::parse_icc_profile(bytes).map(|(color_space, _)| color_space)
+    }
+
+    /// Like `from_icc_profile`, but if the profile has video card gamma (`vcgt`)
+    /// calibration curves they're applied with `with_video_card_gamma`.
+    ///
+    /// Use this for profiles that describe a display's native response while the operating
+    /// system loads the calibration curves, so the color space matches what the display shows.
+    /// Profiles made by measuring a display with its calibration already loaded
+    /// include the curves in their response, so use `from_icc_profile` for those.
+    pub fn from_icc_profile_with_calibration(bytes: &[u8]) -> Result<Self, ICCProfileError> {
+        let (color_space, video_card_gamma) = Self::parse_icc_profile(bytes)?;
+        Ok(match video_card_gamma {
+            Some(video_card_gamma) => color_space.with_video_card_gamma(&video_card_gamma),
+            None => color_space,
+        })
+    }
+
+    /// The video card gamma (`vcgt`) calibration curves of an ICC profile, if it has them.
+    pub fn video_card_gamma_from_icc_profile(
+        bytes: &[u8],
+    ) -> Result<Option<VideoCardGamma>, ICCProfileError> {
+        Self::parse_icc_profile(bytes).map(|(_, video_card_gamma)| video_card_gamma)
+    }
+
+    /// A color space for a display whose input passes through calibration curves
+    /// (such as those loaded from a `vcgt` tag) before reaching a display that behaves like `self`.
+    ///
+    /// A color space has a single transfer function, so the red, green, and blue curves
+    /// are averaged. Differences between the curves, which adjust the white point, are lost.
+    pub fn with_video_card_gamma(&self, video_card_gamma: &VideoCardGamma) -> ColorSpace {
+        const SIZE: usize = 1024;
+        let decode = (0..SIZE)
+            .map(|i| {
+                let x = i as f64 / (SIZE - 1) as f64;
+                let calibrated = (0..3).map(|c| video_card_gamma.apply(c, x)).sum::<f64>() / 3.0;
+                self.transfer_function.to_linear(calibrated)
+            })
+            .collect();
+        let lut = TransferFunctionLut::from_decode(decode, SIZE).expect("SIZE is at least 2");
+        ColorSpace {
+            transfer_function: TransferFunction::Sampled(lut),
+            ..self.clone()
+        }
+    }
+
+    fn parse_icc_profile(bytes: &[u8]) -> Result<(Self, Option<VideoCardGamma>), ICCProfileError> {
         use ICCProfileError::*;
 
-        let mut parser = ICCParser::new(bytes).unwrap();
+        let mut parser = ICCParser::new(bytes).map_err(ParseError)?;
 
         let mut red_primary = None;
         let mut blue_primary = None;
@@ -28,68 +75,72 @@ impl ColorSpace {
         let mut green_tone_reproduction_curve = None;
 
         let mut chromatic_adaptation = None;
+        let mut video_card_gamma = None;
         while let Ok(tag) = parser.next_tag() {
             match tag.tag_type {
-                TagType::RedPrimary => match parser.tag_data(tag).map_err(|p| ParseError(p))? {
-                    TagData::XYZ(x) => red_primary = Some(x),
-                    _ => {}
-                },
-                TagType::GreenPrimary => match parser.tag_data(tag).map_err(|p| ParseError(p))? {
-                    TagData::XYZ(x) => green_primary = Some(x),
-                    _ => {}
-                },
-                TagType::BluePrimary => match parser.tag_data(tag).map_err(|p| ParseError(p))? {
-                    TagData::XYZ(x) => blue_primary = Some(x),
-                    _ => {}
-                },
-                TagType::WhitePoint => match parser.tag_data(tag).map_err(|p| ParseError(p))? {
-                    TagData::XYZ(x) => _white_point = Some(x),
-                    _ => {}
-                },
-                TagType::RedToneReproductionCurve => {
-                    match parser.tag_data(tag).map_err(|p| ParseError(p))? {
-                        TagData::ParametricCurve(x) => red_tone_reproduction_curve = Some(x),
-                        _ => {}
+                TagType::RedPrimary => {
+                    if let TagData::XYZ(x) = parser.tag_data(tag).map_err(ParseError)? {
+                        red_primary = Some(x)
                     }
                 }
-                TagType::GreenToneReproductionCurve => {
-                    match parser.tag_data(tag).map_err(|p| ParseError(p))? {
-                        TagData::ParametricCurve(x) => green_tone_reproduction_curve = Some(x),
-                        _ => {}
+                TagType::GreenPrimary => {
+                    if let TagData::XYZ(x) = parser.tag_data(tag).map_err(ParseError)? {
+                        green_primary = Some(x)
                     }
                 }
-                TagType::BlueToneReproductionCurve => {
-                    match parser.tag_data(tag).map_err(|p| ParseError(p))? {
-                        TagData::ParametricCurve(x) => blue_tone_reproduction_curve = Some(x),
-                        _ => {}
+                TagType::BluePrimary => {
+                    if let TagData::XYZ(x) = parser.tag_data(tag).map_err(ParseError)? {
+                        blue_primary = Some(x)
+                    }
+                }
+                TagType::WhitePoint => {
+                    if let TagData::XYZ(x) = parser.tag_data(tag).map_err(ParseError)? {
+                        _white_point = Some(x)
                     }
                 }
+                TagType::RedToneReproductionCurve => {
+                    red_tone_reproduction_curve =
+                        tone_reproduction_curve(parser.tag_data(tag).map_err(ParseError)?);
+                }
+                TagType::GreenToneReproductionCurve => {
+                    green_tone_reproduction_curve =
+                        tone_reproduction_curve(parser.tag_data(tag).map_err(ParseError)?);
+                }
+                TagType::BlueToneReproductionCurve => {
+                    blue_tone_reproduction_curve =
+                        tone_reproduction_curve(parser.tag_data(tag).map_err(ParseError)?);
+                }
                 TagType::ChromaticAdaptationMatrix => {
-                    match parser.tag_data(tag).map_err(|p| ParseError(p))? {
-                        TagData::Array9(x) => chromatic_adaptation = Some(x),
-                        _ => {}
+                    if let TagData::Array9(x) = parser.tag_data(tag).map_err(ParseError)? {
+                        chromatic_adaptation = Some(x)
+                    }
+                }
+                TagType::VideoCardGamma => {
+                    if let TagData::VideoCardGamma(x) = parser.tag_data(tag).map_err(ParseError)? {
+                        video_card_gamma = Some(x)
                     }
                 }
                 _ => {}
             }
         }
 
-        let red_primary = red_primary.map_or(Err(UnsupportedICCProfile), |p| Ok(p))?;
-        let green_primary = green_primary.map_or(Err(UnsupportedICCProfile), |p| Ok(p))?;
-        let blue_primary = blue_primary.map_or(Err(UnsupportedICCProfile), |p| Ok(p))?;
+        let red_primary = red_primary.ok_or(UnsupportedICCProfile)?;
+        let green_primary = green_primary.ok_or(UnsupportedICCProfile)?;
+        let blue_primary = blue_primary.ok_or(UnsupportedICCProfile)?;
 
         //let white_point = white_point.map_or(Err(UnsupportedICCProfile), |p| Ok(p))?;
 
         let red_tone_reproduction_curve =
-            red_tone_reproduction_curve.map_or(Err(UnsupportedICCProfile), |p| Ok(p))?;
+            red_tone_reproduction_curve.ok_or(UnsupportedICCProfile)?;
         let _green_tone_reproduction_curve =
-            green_tone_reproduction_curve.map_or(Err(UnsupportedICCProfile), |p| Ok(p))?;
+            green_tone_reproduction_curve.ok_or(UnsupportedICCProfile)?;
         let _blue_tone_reproduction_curve =
-            blue_tone_reproduction_curve.map_or(Err(UnsupportedICCProfile), |p| Ok(p))?;
-        let _c = chromatic_adaptation.map_or(Err(UnsupportedICCProfile), |p| Ok(p))?;
+            blue_tone_reproduction_curve.ok_or(UnsupportedICCProfile)?;
 
         // The chromatic adaptation matrix describes how to convert an XYZ
         // color from the white point to the native (D50) color space.
+        // It isn't used yet, and V2 profiles usually don't have one.
+        let _c = chromatic_adaptation;
 
         /*
         let chromatic_adaptation = Matrix3x3::from_columns(
@@ -102,11 +153,31 @@ impl ColorSpace {
 
         // It's incorrect to use only one tone reproduction curve, but for now it's ok
         // Most of the time tone reproduction curves are shared between red, green, and blue.
-        Ok(ColorSpace::new_xyz_d50(
+        let color_space = ColorSpace::new_xyz_d50(
             red_primary,
             green_primary,
             blue_primary,
-            TransferFunction::ParametricCurve(red_tone_reproduction_curve),
-        ))
+            red_tone_reproduction_curve,
+        );
+        Ok((color_space, video_card_gamma))
+    }
+}
+
+/// The transfer function of a `para` or `curv` tone reproduction curve tag.
+fn tone_reproduction_curve(data: TagData) -> Option<TransferFunction> {
+    match data {
+        TagData::ParametricCurve(curve) => Some(TransferFunction::ParametricCurve(curve)),
+        TagData::Curve(Curve::Identity) => Some(TransferFunction::None),
+        TagData::Curve(Curve::Gamma(gamma)) => Some(TransferFunction::ParametricCurve(
+            ParametricCurve::Function0 { gamma },
+        )),
+        TagData::Curve(Curve::Table(table)) => {
+            // Invert short tables into more entries so encoding stays accurate.
+            let encode_size = table.len().max(1024);
+            TransferFunctionLut::from_decode(table, encode_size)
+                .ok()
+                .map(TransferFunction::Sampled)
+        }
+        _ => None,
     }
 }
@@ -0,0 +1,256 @@
+use crate::white_points::*;
+use crate::*;
+
+// Constants from here:
+// http://www.brucelindbloom.com/index.html?LContinuity.html
+const EPSILON: f64 = 216.0 / 24389.0;
+const KAPPA: f64 = 24389.0 / 27.0;
+
+/// A color in the CIE 1976 L*a*b* color space:
+/// https://en.wikipedia.org/wiki/CIELAB_color_space
+///
+/// `L` is lightness from 0.0 to 100.0.
+/// `a` and `b` are how green/red and blue/yellow the color is.
+/// Lab values are relative to a reference white, usually D50.
+#[derive(Debug, Copy, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Lab {
+    pub L: f64,
+    pub a: f64,
+    pub b: f64,
+}
+
+/// A color in the CIE 1976 L*u*v* color space:
+/// https://en.wikipedia.org/wiki/CIELUV
+///
+/// `L` is lightness from 0.0 to 100.0.
+/// Luv values are relative to a reference white, usually D50.
+#[derive(Debug, Copy, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Luv {
+    pub L: f64,
+    pub u: f64,
+    pub v: f64,
+}
+
+impl Lab {
+    pub fn new(L: f64, a: f64, b: f64) -> Self {
+        Self { L, a, b }
+    }
+
+    /// Converts an XYZ value to Lab relative to `reference_white`.
+    /// The XYZ value and reference white should share the same observer and illuminant.
+    /// http://www.brucelindbloom.com/index.html?Eqn_XYZ_to_Lab.html
+    pub fn from_XYZ(xyz: XYZ, reference_white: XYZ) -> Self {
+        let f = |t: f64| {
+            if t > EPSILON {
+                cbrt(t)
+            } else {
+                (KAPPA * t + 16.0) / 116.0
+            }
+        };
+        let fx = f(xyz.X / reference_white.X);
+        let fy = f(xyz.Y / reference_white.Y);
+        let fz = f(xyz.Z / reference_white.Z);
+        Self {
+            L: 116.0 * fy - 16.0,
+            a: 500.0 * (fx - fy),
+            b: 200.0 * (fy - fz),
+        }
+    }
+
+    /// Converts an XYZ value measured with `observer`, such as a spectrophotometer reading
+    /// for the 10 degree observer, to Lab relative to that observer's D50 white point.
+    /// `Color` always stores 2 degree XYZ, so use `Color::to_lab` for colors instead.
+    pub fn from_XYZ_for_observer(xyz: XYZ, observer: Observer) -> Self {
+        Self::from_XYZ(xyz, observer.d50_white_point().to_XYZ())
+    }
+
+    /// Converts Lab relative to the D50 white point of `observer` to an XYZ value
+    /// for that observer.
+    pub fn to_XYZ_for_observer(&self, observer: Observer) -> XYZ {
+        self.to_XYZ(observer.d50_white_point().to_XYZ())
+    }
+
+    /// Converts Lab relative to `reference_white` to an XYZ value.
+    /// http://www.brucelindbloom.com/index.html?Eqn_Lab_to_XYZ.html
+    pub fn to_XYZ(&self, reference_white: XYZ) -> XYZ {
+        let fy = (self.L + 16.0) / 116.0;
+        let fx = self.a / 500.0 + fy;
+        let fz = fy - self.b / 200.0;
+        let f_inverse = |f: f64| {
+            let f3 = f * f * f;
+            if f3 > EPSILON {
+                f3
+            } else {
+                (116.0 * f - 16.0) / KAPPA
+            }
+        };
+        let yr = if self.L > KAPPA * EPSILON {
+            fy * fy * fy
+        } else {
+            self.L / KAPPA
+        };
+        XYZ::new(
+            f_inverse(fx) * reference_white.X,
+            yr * reference_white.Y,
+            f_inverse(fz) * reference_white.Z,
+        )
+    }
+}
+
+/// The u' and v' chromaticity coordinates used by Luv.
+fn uv_prime(xyz: XYZ) -> (f64, f64) {
+    let denominator = xyz.X + 15.0 * xyz.Y + 3.0 * xyz.Z;
+    if denominator == 0.0 {
+        return (0.0, 0.0);
+    }
+    (4.0 * xyz.X / denominator, 9.0 * xyz.Y / denominator)
+}
+
+impl Luv {
+    pub fn new(L: f64, u: f64, v: f64) -> Self {
+        Self { L, u, v }
+    }
+
+    /// Converts an XYZ value to Luv relative to `reference_white`.
+    /// http://www.brucelindbloom.com/index.html?Eqn_XYZ_to_Luv.html
+    pub fn from_XYZ(xyz: XYZ, reference_white: XYZ) -> Self {
+        let yr = xyz.Y / reference_white.Y;
+        let L = if yr > EPSILON {
+            116.0 * cbrt(yr) - 16.0
+        } else {
+            KAPPA * yr
+        };
+        let (u_prime, v_prime) = uv_prime(xyz);
+        let (u_prime_white, v_prime_white) = uv_prime(reference_white);
+        if L == 0.0 {
+            return Self::new(0.0, 0.0, 0.0);
+        }
+        Self {
+            L,
+            u: 13.0 * L * (u_prime - u_prime_white),
+            v: 13.0 * L * (v_prime - v_prime_white),
+        }
+    }
+
+    /// Converts an XYZ value measured with `observer` to Luv relative to that
+    /// observer's D50 white point.
+    /// `Color` always stores 2 degree XYZ, so use `Color::to_luv` for colors instead.
+    pub fn from_XYZ_for_observer(xyz: XYZ, observer: Observer) -> Self {
+        Self::from_XYZ(xyz, observer.d50_white_point().to_XYZ())
+    }
+
+    /// Converts Luv relative to the D50 white point of `observer` to an XYZ value
+    /// for that observer.
+    pub fn to_XYZ_for_observer(&self, observer: Observer) -> XYZ {
+        self.to_XYZ(observer.d50_white_point().to_XYZ())
+    }
+
+    /// Converts Luv relative to `reference_white` to an XYZ value.
+    /// http://www.brucelindbloom.com/index.html?Eqn_Luv_to_XYZ.html
+    pub fn to_XYZ(&self, reference_white: XYZ) -> XYZ {
+        if self.L <= 0.0 {
+            return XYZ::new(0.0, 0.0, 0.0);
+        }
+        let y = if self.L > KAPPA * EPSILON {
+            let f = (self.L + 16.0) / 116.0;
+            f * f * f
+        } else {
+            self.L / KAPPA
+        } * reference_white.Y;
+        let (u_prime_white, v_prime_white) = uv_prime(reference_white);
+        let u_prime = self.u / (13.0 * self.L) + u_prime_white;
+        let v_prime = self.v / (13.0 * self.L) + v_prime_white;
+        XYZ::new(
+            y * 9.0 * u_prime / (4.0 * v_prime),
+            y,
+            y * (12.0 - 3.0 * u_prime - 20.0 * v_prime) / (4.0 * v_prime),
+        )
+    }
+}
+
+impl Color {
+    /// Converts the color to Lab relative to D50. Alpha is discarded.
+    pub fn to_lab(&self) -> Lab {
+        Lab::from_XYZ(self.xyz(), D50_WHITE_POINT_2DEGREES.to_XYZ())
+    }
+
+    /// Creates a color from Lab values relative to D50.
+    pub fn from_lab(lab: Lab, alpha: f64) -> Color {
+        let xyz = lab.to_XYZ(D50_WHITE_POINT_2DEGREES.to_XYZ());
+        Color::new_xyza(xyz.X, xyz.Y, xyz.Z, alpha)
+    }
+
+    /// Converts the color to Luv relative to D50. Alpha is discarded.
+    pub fn to_luv(&self) -> Luv {
+        Luv::from_XYZ(self.xyz(), D50_WHITE_POINT_2DEGREES.to_XYZ())
+    }
+
+    /// Creates a color from Luv values relative to D50.
+    pub fn from_luv(luv: Luv, alpha: f64) -> Color {
+        let xyz = luv.to_XYZ(D50_WHITE_POINT_2DEGREES.to_XYZ());
+        Color::new_xyza(xyz.X, xyz.Y, xyz.Z, alpha)
+    }
+
+    /// Converts the color to Lab relative to `reference_white`.
+    /// The color is first adapted from D50 to `reference_white` with a Bradford transform,
+    /// so the results match instruments that report Lab relative to another illuminant, such as D65.
+    pub fn to_lab_with_reference_white(&self, reference_white: Chromaticity) -> Lab {
+        let xyz =
+            ChromaticAdaptation::new(D50_WHITE_POINT_2DEGREES, reference_white).convert(self.xyz());
+        Lab::from_XYZ(xyz, reference_white.to_XYZ())
+    }
+
+    /// Creates a color from Lab values relative to `reference_white`.
+    pub fn from_lab_with_reference_white(
+        lab: Lab,
+        alpha: f64,
+        reference_white: Chromaticity,
+    ) -> Color {
+        let xyz = lab.to_XYZ(reference_white.to_XYZ());
+        let xyz = ChromaticAdaptation::new(reference_white, D50_WHITE_POINT_2DEGREES).convert(xyz);
+        Color::new_xyza(xyz.X, xyz.Y, xyz.Z, alpha)
+    }
+
+    /// Converts the color to Luv relative to `reference_white`.
+    /// The color is first adapted from D50 to `reference_white` with a Bradford transform.
+    pub fn to_luv_with_reference_white(&self, reference_white: Chromaticity) -> Luv {
+        let xyz =
+            ChromaticAdaptation::new(D50_WHITE_POINT_2DEGREES, reference_white).convert(self.xyz());
+        Luv::from_XYZ(xyz, reference_white.to_XYZ())
+    }
+
+    /// Creates a color from Luv values relative to `reference_white`.
+    pub fn from_luv_with_reference_white(
+        luv: Luv,
+        alpha: f64,
+        reference_white: Chromaticity,
+    ) -> Color {
+        let xyz = luv.to_XYZ(reference_white.to_XYZ());
+        let xyz = ChromaticAdaptation::new(reference_white, D50_WHITE_POINT_2DEGREES).convert(xyz);
+        Color::new_xyza(xyz.X, xyz.Y, xyz.Z, alpha)
+    }
+}
+
+impl ColorSpace {
+    /// Converts the color to Lab relative to this color space's white point.
+    pub fn color_to_lab(&self, color: &Color) -> Lab {
+        color.to_lab_with_reference_white(self.white_point)
+    }
+
+    /// Creates a color from Lab values relative to this color space's white point.
+    pub fn new_color_from_lab(&self, lab: Lab, alpha: f64) -> Color {
+        Color::from_lab_with_reference_white(lab, alpha, self.white_point)
+    }
+
+    /// Converts the color to Luv relative to this color space's white point.
+    pub fn color_to_luv(&self, color: &Color) -> Luv {
+        color.to_luv_with_reference_white(self.white_point)
+    }
+
+    /// Creates a color from Luv values relative to this color space's white point.
+    pub fn new_color_from_luv(&self, luv: Luv, alpha: f64) -> Color {
+        Color::from_luv_with_reference_white(luv, alpha, self.white_point)
+    }
+}
@@ -1,14 +1,28 @@
 //! This library is incomplete and undertested. Don't use it yet.
-//
+//!
+//! `kcolor` is `no_std` compatible. Disable the default `std` feature and
+//! enable the `libm` feature to use it without the standard library.
+#![cfg_attr(not(feature = "std"), no_std)]
+
+extern crate alloc;
+
 // Non-snake case is allowed because XYZ and xyY are traditionally
 // capitalized a specific way.
+mod chromatic_adaptation;
 #[allow(non_snake_case)]
 mod color;
+mod color_samples;
 #[allow(non_snake_case)]
 pub mod color_space;
 
 pub use color::Color;
+#[allow(non_snake_case)]
+mod lab;
+#[allow(non_snake_case)]
+mod oklab;
 pub use color_space::*;
+pub use lab::{Lab, Luv};
+pub use oklab::{Oklab, Oklch};
 
 pub use kcolor_types::*;
 
@@ -17,7 +31,78 @@ pub mod white_points;
 
 pub use constant_color_spaces::*;
 
+mod adjust;
+pub mod ansi;
+mod average;
+mod blend;
+pub use blend::BlendMode;
+mod color_space_builder;
+pub use color_space_builder::{ColorSpaceBuilder, ColorSpaceError};
+mod compositing;
+pub use compositing::chroma_key_mask;
+mod convert;
+pub use convert::{ColorRepresentation, ConvertColor};
+mod display_pipeline;
+pub use display_pipeline::{DisplayPipeline, GamutMapping};
+mod distance;
+pub mod dither;
+pub mod edid;
+pub use distance::{delta_e_map, ColorDistance, DeltaE2000, DeltaE76, DeltaEOk, DeltaEStats};
+mod error;
+pub use error::Error;
+mod gamut;
+pub mod gradient;
+pub mod harmony;
+mod hdr;
+pub use hdr::{hlg_system_gamma, HDR_REFERENCE_WHITE};
 mod icc;
+pub use icc::ICCProfileError;
+pub use icc_parser::{VideoCardGamma, VideoCardGammaFormula};
+pub mod lut;
+pub mod lut_file;
+pub mod packed;
+pub mod palette_file;
+mod parse_lossy;
+pub use parse_lossy::ParseAssumption;
+mod palette_index;
+pub use palette_index::PaletteIndex;
+pub mod quantize;
+
+mod registry;
+pub use registry::ColorSpaceRegistry;
+mod rgba;
+pub use rgba::{Rgba, Rgba8};
+mod sort;
+pub use sort::SortKey;
+pub mod spectral;
+mod standard_color_space;
+pub use standard_color_space::StandardColorSpace;
+pub mod testing;
+pub mod tonemap;
+pub mod typed;
+pub mod white_balance;
+pub mod working_space;
+pub mod ycbcr;
+
+#[cfg(feature = "rand")]
+pub mod random;
+
+pub mod shader;
+
+#[cfg(feature = "display-profile")]
+pub mod display_profile;
+
+#[cfg(feature = "ffi")]
+pub mod ffi;
+
+#[cfg(feature = "wasm")]
+pub mod wasm;
+
+#[cfg(feature = "python")]
+pub mod python;
+
+#[cfg(feature = "serde")]
+mod serialization;
 
 #[cfg(test)]
 mod tests;
@@ -0,0 +1,411 @@
+//! Lookup tables (LUTs) that bake color conversions into tables that video tools and GPUs
+//! can apply in real time.
+#[cfg(feature = "serde")]
+use crate::lut_file::LutFileError;
+use crate::*;
+use alloc::vec::Vec;
+
+/// A 3D lookup table: a cube of RGB output values sampled on an evenly spaced grid of RGB inputs.
+///
+/// Entries are stored with red changing fastest, then green, then blue,
+/// the same order as `.cube` files and 3D textures.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(try_from = "Lut3dFields"))]
+pub struct Lut3d {
+    pub(crate) size: usize,
+    pub(crate) entries: Vec<[f64; 3]>,
+    pub(crate) domain_min: [f64; 3],
+    pub(crate) domain_max: [f64; 3],
+}
+
+/// The unvalidated form of `Lut3d` that's deserialized.
+#[cfg(feature = "serde")]
+#[derive(serde::Deserialize)]
+struct Lut3dFields {
+    size: usize,
+    entries: Vec<[f64; 3]>,
+    domain_min: [f64; 3],
+    domain_max: [f64; 3],
+}
+
+#[cfg(feature = "serde")]
+impl core::convert::TryFrom<Lut3dFields> for Lut3d {
+    type Error = LutFileError;
+    fn try_from(fields: Lut3dFields) -> Result<Self, Self::Error> {
+        let length = cube(fields.size).ok_or(LutFileError::InvalidSize)?;
+        if fields.size < 2 {
+            return Err(LutFileError::InvalidSize);
+        }
+        if fields.entries.len() != length {
+            return Err(LutFileError::WrongEntryCount);
+        }
+        Ok(Self {
+            size: fields.size,
+            entries: fields.entries,
+            domain_min: fields.domain_min,
+            domain_max: fields.domain_max,
+        })
+    }
+}
+
+/// The number of entries in a 3D LUT with `size` samples along each side,
+/// or `None` if that overflows.
+pub(crate) fn cube(size: usize) -> Option<usize> {
+    size.checked_mul(size)?.checked_mul(size)
+}
+
+impl Lut3d {
+    /// Creates a LUT with `size` samples along each side.
+    /// Returns `None` if `size` is less than 2 or there aren't `size³` entries.
+    pub fn new(size: usize, entries: Vec<[f64; 3]>) -> Option<Self> {
+        if size < 2 || Some(entries.len()) != cube(size) {
+            return None;
+        }
+        Some(Self {
+            size,
+            entries,
+            domain_min: [0.0; 3],
+            domain_max: [1.0; 3],
+        })
+    }
+
+    /// Creates a LUT by sampling `f` at each point of the grid.
+    pub fn from_fn(size: usize, mut f: impl FnMut([f64; 3]) -> [f64; 3]) -> Self {
+        let size = size.max(2);
+        let step = 1.0 / (size - 1) as f64;
+        let mut entries = Vec::with_capacity(size * size * size);
+        for b in 0..size {
+            for g in 0..size {
+                for r in 0..size {
+                    entries.push(f([r as f64 * step, g as f64 * step, b as f64 * step]));
+                }
+            }
+        }
+        Self {
+            size,
+            entries,
+            domain_min: [0.0; 3],
+            domain_max: [1.0; 3],
+        }
+    }
+
+    /// The number of samples along each side of the cube.
+    pub fn size(&self) -> usize {
+        self.size
+    }
+
+    pub fn entries(&self) -> &[[f64; 3]] {
+        &self.entries
+    }
+
+    /// The input values that map to the first and last samples of each side of the cube.
+    /// By default this is 0.0 to 1.0.
+    pub fn domain(&self) -> ([f64; 3], [f64; 3]) {
+        (self.domain_min, self.domain_max)
+    }
+
+    pub fn set_domain(&mut self, min: [f64; 3], max: [f64; 3]) {
+        self.domain_min = min;
+        self.domain_max = max;
+    }
+
+    /// The entry at the grid coordinates.
+    pub fn entry(&self, r: usize, g: usize, b: usize) -> [f64; 3] {
+        self.entries[r + self.size * (g + self.size * b)]
+    }
+
+    /// Looks up RGB values with tetrahedral interpolation.
+    /// Inputs outside of the domain are clamped.
+    ///
+    /// Each cell of the cube is split into six tetrahedra along its neutral (gray) diagonal and
+    /// only the four corners of the tetrahedron containing the input are interpolated.
+    /// Unlike trilinear interpolation grays only depend on gray entries, and saturated
+    /// gradients don't posterize.
+    pub fn apply(&self, rgb: [f64; 3]) -> [f64; 3] {
+        let ([r, g, b], [fr, fg, fb]) = self.locate(rgb);
+        let corner = |dr: usize, dg: usize, db: usize| self.entry(r + dr, g + dg, b + db);
+
+        // The corners visited walking from the cell's first corner to its last,
+        // along the axes in order of decreasing fraction.
+        let (weights, path) = if fr > fg {
+            if fg > fb {
+                ([fr, fg, fb], [corner(1, 0, 0), corner(1, 1, 0)])
+            } else if fr > fb {
+                ([fr, fb, fg], [corner(1, 0, 0), corner(1, 0, 1)])
+            } else {
+                ([fb, fr, fg], [corner(0, 0, 1), corner(1, 0, 1)])
+            }
+        } else if fb > fg {
+            ([fb, fg, fr], [corner(0, 0, 1), corner(0, 1, 1)])
+        } else if fb > fr {
+            ([fg, fb, fr], [corner(0, 1, 0), corner(0, 1, 1)])
+        } else {
+            ([fg, fr, fb], [corner(0, 1, 0), corner(1, 1, 0)])
+        };
+        let corners = [corner(0, 0, 0), path[0], path[1], corner(1, 1, 1)];
+
+        let mut result = corners[0];
+        for (step, weight) in weights.iter().enumerate() {
+            for i in 0..3 {
+                result[i] += weight * (corners[step + 1][i] - corners[step][i]);
+            }
+        }
+        result
+    }
+
+    /// Looks up RGB values with trilinear interpolation, which blends all eight corners of the
+    /// cell containing the input. This matches LUT implementations that use 3D texture sampling.
+    pub fn apply_trilinear(&self, rgb: [f64; 3]) -> [f64; 3] {
+        let (index, fraction) = self.locate(rgb);
+        let mut result = [0.0; 3];
+        for corner in 0..8 {
+            let mut weight = 1.0;
+            let mut coordinates = index;
+            for axis in 0..3 {
+                if corner & (1 << axis) == 0 {
+                    weight *= 1.0 - fraction[axis];
+                } else {
+                    weight *= fraction[axis];
+                    coordinates[axis] += 1;
+                }
+            }
+            let entry = self.entry(coordinates[0], coordinates[1], coordinates[2]);
+            for i in 0..3 {
+                result[i] += entry[i] * weight;
+            }
+        }
+        result
+    }
+
+    /// The grid coordinates of the first corner of the cell containing `rgb`,
+    /// and how far `rgb` is across the cell along each axis.
+    fn locate(&self, rgb: [f64; 3]) -> ([usize; 3], [f64; 3]) {
+        let max = (self.size - 1) as f64;
+        let mut index = [0; 3];
+        let mut fraction = [0.0; 3];
+        for i in 0..3 {
+            let position = normalize(rgb[i], self.domain_min[i], self.domain_max[i]) * max;
+            // The last cell is used for 1.0 so `index + 1` stays within the cube.
+            let cell = float::floor(position).min(max - 1.0);
+            index[i] = cell as usize;
+            fraction[i] = position - cell;
+        }
+        (index, fraction)
+    }
+
+    /// Creates a LUT from the pixels of a HALD CLUT image, the image based LUT format used by
+    /// ImageMagick, GIMP, and darktable. `pixels` are the image's RGB values from 0.0 to 1.0,
+    /// stored row by row with `width` pixels per row.
+    ///
+    /// A HALD image of level `L` is `L³` pixels wide and tall and stores a cube with `L²`
+    /// samples along each side, in the same order as `entries`.
+    /// Returns `None` if the image doesn't have the dimensions of a HALD image.
+    pub fn from_hald(pixels: Vec<[f64; 3]>, width: usize) -> Option<Self> {
+        let level = (1..=width).take_while(|l| l * l * l <= width).last()?;
+        if level * level * level != width || pixels.len() != width * width {
+            return None;
+        }
+        Self::new(level * level, pixels)
+    }
+
+    /// The LUT as a HALD CLUT image of `level`, returned as RGB values
+    /// stored row by row and the image's width (which is also its height).
+    /// The LUT is resampled, so any level can be used. Level 8 (a 512x512 image) is typical.
+    pub fn to_hald(&self, level: usize) -> (Vec<[f64; 3]>, usize) {
+        let level = level.max(2);
+        let resampled = Lut3d::from_fn(level * level, |rgb| {
+            let mut input = [0.0; 3];
+            for i in 0..3 {
+                input[i] = self.domain_min[i] + rgb[i] * (self.domain_max[i] - self.domain_min[i]);
+            }
+            self.apply(input)
+        });
+        (resampled.entries, level * level * level)
+    }
+
+    /// Looks up RGB values in place with tetrahedral interpolation.
+    pub fn apply_slice(&self, pixels: &mut [[f64; 3]]) {
+        for pixel in pixels.iter_mut() {
+            *pixel = self.apply(*pixel);
+        }
+    }
+
+    /// Looks up RGB values in place with trilinear interpolation.
+    pub fn apply_trilinear_slice(&self, pixels: &mut [[f64; 3]]) {
+        for pixel in pixels.iter_mut() {
+            *pixel = self.apply_trilinear(*pixel);
+        }
+    }
+}
+
+/// A 1D lookup table: a separate curve for each of red, green, and blue,
+/// sampled at evenly spaced inputs.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(try_from = "Lut1dFields"))]
+pub struct Lut1d {
+    pub(crate) entries: Vec<[f64; 3]>,
+    pub(crate) domain_min: [f64; 3],
+    pub(crate) domain_max: [f64; 3],
+}
+
+/// The unvalidated form of `Lut1d` that's deserialized.
+#[cfg(feature = "serde")]
+#[derive(serde::Deserialize)]
+struct Lut1dFields {
+    entries: Vec<[f64; 3]>,
+    domain_min: [f64; 3],
+    domain_max: [f64; 3],
+}
+
+#[cfg(feature = "serde")]
+impl core::convert::TryFrom<Lut1dFields> for Lut1d {
+    type Error = LutFileError;
+    fn try_from(fields: Lut1dFields) -> Result<Self, Self::Error> {
+        if fields.entries.len() < 2 {
+            return Err(LutFileError::InvalidSize);
+        }
+        Ok(Self {
+            entries: fields.entries,
+            domain_min: fields.domain_min,
+            domain_max: fields.domain_max,
+        })
+    }
+}
+
+impl Lut1d {
+    /// Creates a LUT from its samples. Returns `None` if there are fewer than 2 entries.
+    pub fn new(entries: Vec<[f64; 3]>) -> Option<Self> {
+        if entries.len() < 2 {
+            return None;
+        }
+        Some(Self {
+            entries,
+            domain_min: [0.0; 3],
+            domain_max: [1.0; 3],
+        })
+    }
+
+    /// Creates a LUT with `size` entries by sampling `f` at evenly spaced inputs from 0.0 to 1.0.
+    pub fn from_fn(size: usize, mut f: impl FnMut(f64) -> [f64; 3]) -> Self {
+        let size = size.max(2);
+        let step = 1.0 / (size - 1) as f64;
+        Self {
+            entries: (0..size).map(|i| f(i as f64 * step)).collect(),
+            domain_min: [0.0; 3],
+            domain_max: [1.0; 3],
+        }
+    }
+
+    pub fn size(&self) -> usize {
+        self.entries.len()
+    }
+
+    pub fn entries(&self) -> &[[f64; 3]] {
+        &self.entries
+    }
+
+    /// The input values that map to the first and last entries. By default this is 0.0 to 1.0.
+    pub fn domain(&self) -> ([f64; 3], [f64; 3]) {
+        (self.domain_min, self.domain_max)
+    }
+
+    pub fn set_domain(&mut self, min: [f64; 3], max: [f64; 3]) {
+        self.domain_min = min;
+        self.domain_max = max;
+    }
+
+    /// Looks up each of the RGB values with linear interpolation.
+    /// Inputs outside of the domain are clamped.
+    pub fn apply(&self, rgb: [f64; 3]) -> [f64; 3] {
+        let max = (self.entries.len() - 1) as f64;
+        let mut result = [0.0; 3];
+        for i in 0..3 {
+            let position = normalize(rgb[i], self.domain_min[i], self.domain_max[i]) * max;
+            let cell = float::floor(position).min(max - 1.0);
+            let fraction = position - cell;
+            let index = cell as usize;
+            result[i] =
+                self.entries[index][i] * (1.0 - fraction) + self.entries[index + 1][i] * fraction;
+        }
+        result
+    }
+
+    /// Looks up RGB values in place.
+    pub fn apply_slice(&self, pixels: &mut [[f64; 3]]) {
+        for pixel in pixels.iter_mut() {
+            *pixel = self.apply(*pixel);
+        }
+    }
+}
+
+/// Maps `value` from `min..max` to 0.0 to 1.0, clamping values outside of the range.
+fn normalize(value: f64, min: f64, max: f64) -> f64 {
+    ((value - min) / (max - min)).clamp(0.0, 1.0)
+}
+
+/// The identity HALD CLUT image of `level`, returned as RGB values stored row by row and
+/// the image's width (which is also its height).
+/// Edit the image in any photo editor and load the result with `Lut3d::from_hald`
+/// to capture the edit as a LUT.
+pub fn hald_identity(level: usize) -> (Vec<[f64; 3]>, usize) {
+    let level = level.max(2);
+    let lut = Lut3d::from_fn(level * level, |rgb| rgb);
+    (lut.entries, level * level * level)
+}
+
+/// Samples the conversion of RGB values from one color space to another, including
+/// their transfer functions, into a `Lut3d` with `size` samples along each side.
+/// Output values are not clipped.
+pub fn generate_lut3d(from: &ColorSpace, to: &ColorSpace, size: usize) -> Lut3d {
+    let converter = ColorSpaceConverter::new(from, to);
+    Lut3d::from_fn(size, |rgb| {
+        let (r, g, b) = converter.convert_encoded_color(&(rgb[0], rgb[1], rgb[2]));
+        [r, g, b]
+    })
+}
+
+/// Samples the conversion of RGB values from one color space to another into a 1D 'shaper' LUT
+/// and a 3D LUT, which are applied one after the other. Write them together with
+/// `lut_file::CubeFile` to export a LUT for tools like DaVinci Resolve.
+///
+/// The shaper LUT re-encodes inputs with the `shaper` transfer function, so the 3D LUT's
+/// samples are spaced evenly in `shaper`'s encoding instead of `from`'s. For example use
+/// the sRGB or PQ transfer function as the shaper when `from` is linear, so dark colors
+/// aren't squeezed into the first few samples of the cube.
+pub fn generate_lut3d_with_shaper(
+    from: &ColorSpace,
+    to: &ColorSpace,
+    shaper: &TransferFunction,
+    shaper_size: usize,
+    size: usize,
+) -> (Lut1d, Lut3d) {
+    let shaper_lut = Lut1d::from_fn(shaper_size, |x| {
+        [shaper.from_linear(from.transfer_function.to_linear(x)); 3]
+    });
+    let lut = Lut3d::from_fn(size, |rgb| {
+        let linear = Vector3::new(
+            shaper.to_linear(rgb[0]),
+            shaper.to_linear(rgb[1]),
+            shaper.to_linear(rgb[2]),
+        );
+        let xyz = from.to_XYZ * linear;
+        let (r, g, b, _) = to.color_to_rgba_unclipped(&Color::new_xyza(xyz.x, xyz.y, xyz.z, 1.0));
+        [r, g, b]
+    });
+    (shaper_lut, lut)
+}
+
+/// Samples a `DisplayPipeline` applied to RGB values in `from` into a `Lut3d`,
+/// including its tone mapping and gamut mapping.
+pub fn generate_lut3d_with_pipeline(
+    from: &ColorSpace,
+    pipeline: &DisplayPipeline,
+    size: usize,
+) -> Lut3d {
+    Lut3d::from_fn(size, |rgb| {
+        let (r, g, b, _) = pipeline.apply(&from.new_color(rgb[0], rgb[1], rgb[2], 1.0));
+        [r, g, b]
+    })
+}
@@ -0,0 +1,208 @@
+//! Reading and writing `.cube` LUT files, the format used by Adobe and DaVinci Resolve.
+//!
+//! A file contains a 1D LUT, a 3D LUT, or (as written by Resolve) a 1D 'shaper' LUT
+//! that's applied before a 3D LUT.
+use crate::lut::{cube, Lut1d, Lut3d};
+use alloc::format;
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
+
+/// The contents of a `.cube` file.
+#[derive(Debug, Clone, Default, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct CubeFile {
+    pub title: Option<String>,
+    pub lut_1d: Option<Lut1d>,
+    pub lut_3d: Option<Lut3d>,
+}
+
+/// The error returned when reading a `.cube` file fails.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LutFileError {
+    /// Neither `LUT_1D_SIZE` nor `LUT_3D_SIZE` was specified, or a size is invalid.
+    InvalidSize,
+    /// A keyword's value or an entry couldn't be parsed.
+    InvalidNumber,
+    /// The number of entries doesn't match the sizes of the LUTs.
+    WrongEntryCount,
+}
+
+impl core::fmt::Display for LutFileError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        let message = match self {
+            LutFileError::InvalidSize => "invalid LUT size",
+            LutFileError::InvalidNumber => "a number couldn't be parsed",
+            LutFileError::WrongEntryCount => "the number of entries doesn't match the LUT size",
+        };
+        f.write_str(message)
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for LutFileError {}
+
+impl CubeFile {
+    /// Reads a `.cube` file.
+    ///
+    /// `DOMAIN_MIN` and `DOMAIN_MAX` apply to every LUT in the file,
+    /// and `LUT_1D_INPUT_RANGE` and `LUT_3D_INPUT_RANGE` apply to only one of them.
+    /// Unknown keywords are ignored.
+    pub fn from_cube(text: &str) -> Result<Self, LutFileError> {
+        let mut title = None;
+        let mut size_1d = None;
+        let mut size_3d = None;
+        let mut domain = ([0.0; 3], [1.0; 3]);
+        let mut range_1d = None;
+        let mut range_3d = None;
+        let mut entries = Vec::new();
+
+        for line in text.lines() {
+            let line = line.trim();
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+            let mut parts = line.split_whitespace();
+            let keyword = parts.next().unwrap_or("");
+            if !keyword.starts_with(|c: char| c.is_ascii_alphabetic()) {
+                entries.push(parse_triple(line.split_whitespace())?);
+                continue;
+            }
+            match keyword {
+                "TITLE" => {
+                    let value = line["TITLE".len()..].trim();
+                    title = Some(value.trim_matches('"').to_string());
+                }
+                "LUT_1D_SIZE" => size_1d = Some(parse_size(parts.next())?),
+                "LUT_3D_SIZE" => size_3d = Some(parse_size(parts.next())?),
+                "DOMAIN_MIN" => domain.0 = parse_triple(parts)?,
+                "DOMAIN_MAX" => domain.1 = parse_triple(parts)?,
+                "LUT_1D_INPUT_RANGE" => range_1d = Some(parse_range(parts)?),
+                "LUT_3D_INPUT_RANGE" => range_3d = Some(parse_range(parts)?),
+                _ => {}
+            }
+        }
+
+        if size_1d.is_none() && size_3d.is_none() {
+            return Err(LutFileError::InvalidSize);
+        }
+        let length_1d = size_1d.unwrap_or(0);
+        let length_3d = match size_3d {
+            Some(s) => cube(s).ok_or(LutFileError::InvalidSize)?,
+            None => 0,
+        };
+        let length = length_1d
+            .checked_add(length_3d)
+            .ok_or(LutFileError::InvalidSize)?;
+        if entries.len() != length {
+            return Err(LutFileError::WrongEntryCount);
+        }
+
+        // The 1D LUT's entries come first.
+        let entries_3d = entries.split_off(length_1d);
+        let lut_1d = match size_1d {
+            Some(_) => {
+                let mut lut = Lut1d::new(entries).ok_or(LutFileError::InvalidSize)?;
+                let (min, max) = range_1d.unwrap_or(domain);
+                lut.set_domain(min, max);
+                Some(lut)
+            }
+            None => None,
+        };
+        let lut_3d = match size_3d {
+            Some(size) => {
+                let mut lut = Lut3d::new(size, entries_3d).ok_or(LutFileError::InvalidSize)?;
+                let (min, max) = range_3d.unwrap_or(domain);
+                lut.set_domain(min, max);
+                Some(lut)
+            }
+            None => None,
+        };
+
+        Ok(Self {
+            title,
+            lut_1d,
+            lut_3d,
+        })
+    }
+
+    /// Writes a `.cube` file.
+    ///
+    /// A file with a single LUT specifies its domain with `DOMAIN_MIN` and `DOMAIN_MAX`.
+    /// A file with both LUTs uses `LUT_1D_INPUT_RANGE` and `LUT_3D_INPUT_RANGE` like Resolve,
+    /// which only support the same range for each channel, so the red channel's is written.
+    pub fn to_cube(&self) -> String {
+        let mut text = String::new();
+        if let Some(title) = &self.title {
+            text += &format!("TITLE \"{}\"\n", title);
+        }
+
+        let both = self.lut_1d.is_some() && self.lut_3d.is_some();
+        let write_domain = |text: &mut String, keyword: &str, (min, max): ([f64; 3], [f64; 3])| {
+            if min == [0.0; 3] && max == [1.0; 3] {
+                return;
+            }
+            if both {
+                *text += &format!("LUT_{}_INPUT_RANGE {:.6} {:.6}\n", keyword, min[0], max[0]);
+            } else {
+                *text += &format!("DOMAIN_MIN {:.6} {:.6} {:.6}\n", min[0], min[1], min[2]);
+                *text += &format!("DOMAIN_MAX {:.6} {:.6} {:.6}\n", max[0], max[1], max[2]);
+            }
+        };
+        if let Some(lut) = &self.lut_1d {
+            text += &format!("LUT_1D_SIZE {}\n", lut.size());
+            write_domain(&mut text, "1D", lut.domain());
+        }
+        if let Some(lut) = &self.lut_3d {
+            text += &format!("LUT_3D_SIZE {}\n", lut.size());
+            write_domain(&mut text, "3D", lut.domain());
+        }
+
+        let entries_1d = self.lut_1d.iter().flat_map(|l| l.entries());
+        let entries_3d = self.lut_3d.iter().flat_map(|l| l.entries());
+        for entry in entries_1d.chain(entries_3d) {
+            text += &format!("{:.6} {:.6} {:.6}\n", entry[0], entry[1], entry[2]);
+        }
+        text
+    }
+
+    /// Applies the 1D LUT and then the 3D LUT to RGB values.
+    pub fn apply(&self, rgb: [f64; 3]) -> [f64; 3] {
+        let mut rgb = rgb;
+        if let Some(lut) = &self.lut_1d {
+            rgb = lut.apply(rgb);
+        }
+        if let Some(lut) = &self.lut_3d {
+            rgb = lut.apply(rgb);
+        }
+        rgb
+    }
+}
+
+fn parse_size(value: Option<&str>) -> Result<usize, LutFileError> {
+    value
+        .and_then(|v| v.parse().ok())
+        .ok_or(LutFileError::InvalidSize)
+}
+
+fn parse_triple<'a>(mut parts: impl Iterator<Item = &'a str>) -> Result<[f64; 3], LutFileError> {
+    let mut value = || -> Result<f64, LutFileError> {
+        parts
+            .next()
+            .and_then(|p| p.parse().ok())
+            .ok_or(LutFileError::InvalidNumber)
+    };
+    Ok([value()?, value()?, value()?])
+}
+
+fn parse_range<'a>(
+    mut parts: impl Iterator<Item = &'a str>,
+) -> Result<([f64; 3], [f64; 3]), LutFileError> {
+    let mut value = || -> Result<f64, LutFileError> {
+        parts
+            .next()
+            .and_then(|p| p.parse().ok())
+            .ok_or(LutFileError::InvalidNumber)
+    };
+    let (min, max) = (value()?, value()?);
+    Ok(([min; 3], [max; 3]))
+}
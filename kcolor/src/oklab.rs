@@ -0,0 +1,186 @@
+use crate::*;
+
+/// A color in the Oklab perceptual color space:
+/// https://bottosson.github.io/posts/oklab/
+///
+/// `L` is perceived lightness from 0.0 to 1.0.
+/// `a` and `b` are how green/red and blue/yellow the color is.
+/// Equal distances in Oklab roughly correspond to equal perceived differences.
+#[derive(Debug, Copy, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Oklab {
+    pub L: f64,
+    pub a: f64,
+    pub b: f64,
+}
+
+/// Oklab expressed in polar coordinates.
+///
+/// `L` is the same as Oklab's `L`.
+/// `C` is chroma, how colorful the color is.
+/// `h` is the hue angle in degrees from 0.0 to 360.0.
+#[derive(Debug, Copy, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Oklch {
+    pub L: f64,
+    pub C: f64,
+    pub h: f64,
+}
+
+// Oklab is defined relative to D65, but colors are stored relative to D50.
+// This is the Oklab "M1" matrix multiplied by a Bradford adaptation from D50 to D65.
+// Calculated with this library.
+const XYZ_D50_TO_LMS: Matrix3x3 = Matrix3x3 {
+    c0: Vector3 {
+        x: 0.7706912491345352,
+        y: 0.005646865609173702,
+        z: 0.046368967339148276,
+    },
+    c1: Vector3 {
+        x: 0.3492271280487907,
+        y: 0.937067308269944,
+        z: 0.25290092867806085,
+    },
+    c2: Vector3 {
+        x: -0.11203284871143494,
+        y: 0.06969107971236932,
+        z: 0.8516457582735214,
+    },
+};
+
+const LMS_TO_XYZ_D50: Matrix3x3 = Matrix3x3 {
+    c0: Vector3 {
+        x: 1.2886302187706518,
+        y: -0.002604962188927688,
+        z: -0.06938759994467772,
+    },
+    c1: Vector3 {
+        x: -0.5378772986941894,
+        y: 1.0923470131448443,
+        z: -0.29509306742381797,
+    },
+    c2: Vector3 {
+        x: 0.21353263640031026,
+        y: -0.08973059908896272,
+        z: 1.1892170590393205,
+    },
+};
+
+// The Oklab "M2" matrix and its inverse.
+const LMS_TO_OKLAB: Matrix3x3 = Matrix3x3 {
+    c0: Vector3 {
+        x: 0.2104542553,
+        y: 1.9779984951,
+        z: 0.0259040371,
+    },
+    c1: Vector3 {
+        x: 0.793617785,
+        y: -2.428592205,
+        z: 0.7827717662,
+    },
+    c2: Vector3 {
+        x: -0.0040720468,
+        y: 0.4505937099,
+        z: -0.808675766,
+    },
+};
+
+const OKLAB_TO_LMS: Matrix3x3 = Matrix3x3 {
+    c0: Vector3 {
+        x: 0.9999999984505198,
+        y: 1.0000000088817607,
+        z: 1.0000000546724108,
+    },
+    c1: Vector3 {
+        x: 0.3963377921737678,
+        y: -0.10556134232365633,
+        z: -0.08948418209496575,
+    },
+    c2: Vector3 {
+        x: 0.21580375806075877,
+        y: -0.06385417477170588,
+        z: -1.2914855378640917,
+    },
+};
+
+impl Oklab {
+    pub fn new(L: f64, a: f64, b: f64) -> Self {
+        Self { L, a, b }
+    }
+
+    /// Converts XYZ values relative to D50 to Oklab.
+    pub fn from_XYZ(xyz: XYZ) -> Self {
+        let lms = XYZ_D50_TO_LMS * xyz.to_vector3();
+        // The `fast-math` `cbrt` didn't measurably speed this up in the `conversions` benchmark.
+        let lms = Vector3::new(float::cbrt(lms.x), float::cbrt(lms.y), float::cbrt(lms.z));
+        let lab = LMS_TO_OKLAB * lms;
+        Self {
+            L: lab.x,
+            a: lab.y,
+            b: lab.z,
+        }
+    }
+
+    /// Converts Oklab to XYZ values relative to D50.
+    pub fn to_XYZ(&self) -> XYZ {
+        let lms = OKLAB_TO_LMS * Vector3::new(self.L, self.a, self.b);
+        let lms = Vector3::new(
+            lms.x * lms.x * lms.x,
+            lms.y * lms.y * lms.y,
+            lms.z * lms.z * lms.z,
+        );
+        let xyz = LMS_TO_XYZ_D50 * lms;
+        XYZ::new(xyz.x, xyz.y, xyz.z)
+    }
+}
+
+impl Oklch {
+    pub fn new(L: f64, C: f64, h: f64) -> Self {
+        Self { L, C, h }
+    }
+}
+
+impl From<Oklab> for Oklch {
+    fn from(oklab: Oklab) -> Self {
+        let h = float::atan2(oklab.b, oklab.a).to_degrees();
+        Self {
+            L: oklab.L,
+            C: float::sqrt(oklab.a * oklab.a + oklab.b * oklab.b),
+            h: if h < 0.0 { h + 360.0 } else { h },
+        }
+    }
+}
+
+impl From<Oklch> for Oklab {
+    fn from(oklch: Oklch) -> Self {
+        let h = oklch.h.to_radians();
+        Self {
+            L: oklch.L,
+            a: oklch.C * float::cos(h),
+            b: oklch.C * float::sin(h),
+        }
+    }
+}
+
+impl Color {
+    /// Converts the color to Oklab. Alpha is discarded.
+    pub fn to_oklab(&self) -> Oklab {
+        Oklab::from_XYZ(self.xyz())
+    }
+
+    /// Creates a color from Oklab values.
+    pub fn from_oklab(oklab: Oklab, alpha: f64) -> Color {
+        let xyz = oklab.to_XYZ();
+        Color::new_xyza(xyz.X, xyz.Y, xyz.Z, alpha)
+    }
+
+    /// Converts the color to Oklch. Alpha is discarded.
+    pub fn to_oklch(&self) -> Oklch {
+        self.to_oklab().into()
+    }
+
+    /// Creates a color from Oklch values.
+    pub fn from_oklch(oklch: Oklch, alpha: f64) -> Color {
+        Self::from_oklab(oklch.into(), alpha)
+    }
+}
@@ -0,0 +1,205 @@
+//! Packed pixel formats: 16-bit formats used by embedded displays and older GPUs,
+//! and 10-bit formats used by HDR swapchains and video.
+//!
+//! Values are encoded RGB(A) values from 0.0 to 1.0.
+//!
+//! To dither a whole image, reduce it with `dither::ordered` (with levels of `[32, 64, 32]`
+//! for RGB565 or `[16, 16, 16]` for RGBA4444) and then pack each pixel.
+//! To dither a single color, pass an offset (the dithering 'hook') from -0.5 to 0.5 of a step,
+//! such as a value from a Bayer matrix or blue noise texture, to the `_dithered` functions.
+use crate::*;
+
+/// Packs RGB values into a 16-bit value with 5 bits of red, 6 bits of green, and 5 bits of blue.
+pub fn pack_rgb565(rgb: [f64; 3]) -> u16 {
+    pack_rgb565_dithered(rgb, 0.0)
+}
+
+/// Packs RGB values into RGB565, offsetting each value by `offset` of a step before rounding.
+pub fn pack_rgb565_dithered(rgb: [f64; 3], offset: f64) -> u16 {
+    let r = quantize(rgb[0], 31, offset);
+    let g = quantize(rgb[1], 63, offset);
+    let b = quantize(rgb[2], 31, offset);
+    (r << 11) | (g << 5) | b
+}
+
+pub fn unpack_rgb565(packed: u16) -> [f64; 3] {
+    [
+        ((packed >> 11) & 0x1F) as f64 / 31.0,
+        ((packed >> 5) & 0x3F) as f64 / 63.0,
+        (packed & 0x1F) as f64 / 31.0,
+    ]
+}
+
+/// Packs RGBA values into a 16-bit value with 4 bits for each channel.
+pub fn pack_rgba4444(rgba: [f64; 4]) -> u16 {
+    pack_rgba4444_dithered(rgba, 0.0)
+}
+
+/// Packs RGBA values into RGBA4444, offsetting each RGB value by `offset` of a step before rounding.
+/// Alpha is not dithered.
+pub fn pack_rgba4444_dithered(rgba: [f64; 4], offset: f64) -> u16 {
+    let r = quantize(rgba[0], 15, offset);
+    let g = quantize(rgba[1], 15, offset);
+    let b = quantize(rgba[2], 15, offset);
+    let a = quantize(rgba[3], 15, 0.0);
+    (r << 12) | (g << 8) | (b << 4) | a
+}
+
+pub fn unpack_rgba4444(packed: u16) -> [f64; 4] {
+    [
+        ((packed >> 12) & 0xF) as f64 / 15.0,
+        ((packed >> 8) & 0xF) as f64 / 15.0,
+        ((packed >> 4) & 0xF) as f64 / 15.0,
+        (packed & 0xF) as f64 / 15.0,
+    ]
+}
+
+/// Packs RGBA values into a 32-bit value with 10 bits for each of red, green, and blue
+/// and 2 bits of alpha. Red is stored in the least significant bits, matching
+/// `DXGI_FORMAT_R10G10B10A2_UNORM`, `VK_FORMAT_A2B10G10R10_UNORM_PACK32`, and `GL_RGB10_A2`.
+///
+/// Combine with `ColorSpace::rec2100_pq` to produce pixels for an HDR10 swapchain.
+pub fn pack_rgb10a2(rgba: [f64; 4]) -> u32 {
+    let r = quantize(rgba[0], 1023, 0.0) as u32;
+    let g = quantize(rgba[1], 1023, 0.0) as u32;
+    let b = quantize(rgba[2], 1023, 0.0) as u32;
+    let a = quantize(rgba[3], 3, 0.0) as u32;
+    r | (g << 10) | (b << 20) | (a << 30)
+}
+
+pub fn unpack_rgb10a2(packed: u32) -> [f64; 4] {
+    [
+        (packed & 0x3FF) as f64 / 1023.0,
+        ((packed >> 10) & 0x3FF) as f64 / 1023.0,
+        ((packed >> 20) & 0x3FF) as f64 / 1023.0,
+        (packed >> 30) as f64 / 3.0,
+    ]
+}
+
+/// Packs a value into a P010 style 16-bit word, where a 10-bit value is stored in the
+/// most significant bits and the low 6 bits are zero.
+/// P010 and P210 video frames store each of their samples this way.
+pub fn pack_p010(value: f64) -> u16 {
+    quantize(value, 1023, 0.0) << 6
+}
+
+/// Unpacks a P010 style 16-bit word. The low 6 bits are ignored.
+pub fn unpack_p010(word: u16) -> f64 {
+    (word >> 6) as f64 / 1023.0
+}
+
+/// Packs linear RGB values into the RGB9E5 shared exponent format, where red, green, and blue
+/// have 9-bit mantissas (stored from the least significant bits) and share a 5-bit exponent.
+/// It stores HDR values from 0.0 to 65408.0 in 32 bits, with about 3 significant digits
+/// for the largest channel.
+///
+/// Negative values become 0.0 and values above the maximum are clamped.
+/// Implemented as described by the `EXT_texture_shared_exponent` OpenGL extension.
+pub fn pack_rgb9e5(rgb: [f64; 3]) -> u32 {
+    const MAX: f64 = 511.0 / 512.0 * 65536.0;
+    let clamp = |v: f64| if v > 0.0 { v.min(MAX) } else { 0.0 };
+    let (r, g, b) = (clamp(rgb[0]), clamp(rgb[1]), clamp(rgb[2]));
+    let max = r.max(g).max(b);
+
+    let mut exponent = (floor_log2(max).max(-RGB9E5_BIAS - 1) + 1 + RGB9E5_BIAS) as u32;
+    let mut denominator = exp2(exponent as i32 - RGB9E5_BIAS - RGB9E5_MANTISSA_BITS);
+    // Rounding can overflow the mantissa, in which case the next exponent is needed.
+    if float::floor(max / denominator + 0.5) >= 512.0 {
+        denominator *= 2.0;
+        exponent += 1;
+    }
+
+    let mantissa = |v: f64| (float::floor(v / denominator + 0.5) as u32).min(511);
+    mantissa(r) | (mantissa(g) << 9) | (mantissa(b) << 18) | (exponent << 27)
+}
+
+/// Unpacks RGB9E5 values to linear RGB values.
+pub fn unpack_rgb9e5(packed: u32) -> [f64; 3] {
+    let scale = exp2((packed >> 27) as i32 - RGB9E5_BIAS - RGB9E5_MANTISSA_BITS);
+    [
+        (packed & 0x1FF) as f64 * scale,
+        ((packed >> 9) & 0x1FF) as f64 * scale,
+        ((packed >> 18) & 0x1FF) as f64 * scale,
+    ]
+}
+
+const RGB9E5_BIAS: i32 = 15;
+const RGB9E5_MANTISSA_BITS: i32 = 9;
+
+/// `floor(log2(x))` for positive normal numbers, read exactly from the float's exponent.
+fn floor_log2(x: f64) -> i32 {
+    ((x.to_bits() >> 52) & 0x7FF) as i32 - 1023
+}
+
+/// `2^exponent`, constructed exactly.
+fn exp2(exponent: i32) -> f64 {
+    f64::from_bits(((exponent + 1023) as u64) << 52)
+}
+
+fn quantize(value: f64, max: u16, offset: f64) -> u16 {
+    let max = max as f64;
+    float::round(value * max + offset).clamp(0.0, max) as u16
+}
+
+impl ColorSpace {
+    /// The color as RGB565 in this color space. Alpha is discarded.
+    pub fn color_to_rgb565(&self, color: &Color) -> u16 {
+        self.color_to_rgb565_dithered(color, 0.0)
+    }
+
+    /// The color as RGB565 in this color space, offset by `offset` of a step before rounding.
+    pub fn color_to_rgb565_dithered(&self, color: &Color, offset: f64) -> u16 {
+        let (r, g, b, _) = self.color_to_rgba(color);
+        pack_rgb565_dithered([r, g, b], offset)
+    }
+
+    /// Creates an opaque color from RGB565 values expressed in this color space.
+    pub fn new_color_from_rgb565(&self, packed: u16) -> Color {
+        let [r, g, b] = unpack_rgb565(packed);
+        self.new_color(r, g, b, 1.0)
+    }
+
+    /// The color as RGBA4444 in this color space.
+    pub fn color_to_rgba4444(&self, color: &Color) -> u16 {
+        self.color_to_rgba4444_dithered(color, 0.0)
+    }
+
+    /// The color as RGBA4444 in this color space, offset by `offset` of a step before rounding.
+    pub fn color_to_rgba4444_dithered(&self, color: &Color, offset: f64) -> u16 {
+        let (r, g, b, a) = self.color_to_rgba(color);
+        pack_rgba4444_dithered([r, g, b, a], offset)
+    }
+
+    /// Creates a color from RGBA4444 values expressed in this color space.
+    pub fn new_color_from_rgba4444(&self, packed: u16) -> Color {
+        let [r, g, b, a] = unpack_rgba4444(packed);
+        self.new_color(r, g, b, a)
+    }
+
+    /// The color as RGB10A2 in this color space. See `pack_rgb10a2` for the bit layout.
+    pub fn color_to_rgb10a2(&self, color: &Color) -> u32 {
+        let (r, g, b, a) = self.color_to_rgba(color);
+        pack_rgb10a2([r, g, b, a])
+    }
+
+    /// Creates a color from RGB10A2 values expressed in this color space.
+    pub fn new_color_from_rgb10a2(&self, packed: u32) -> Color {
+        let [r, g, b, a] = unpack_rgb10a2(packed);
+        self.new_color(r, g, b, a)
+    }
+
+    /// The color as RGB9E5 in this color space's primaries, without the transfer function.
+    /// Values are not limited to 0.0 to 1.0. See `pack_rgb9e5` for details.
+    pub fn color_to_rgb9e5(&self, color: &Color) -> u32 {
+        let rgb = self.from_XYZ * color.xyz().to_vector3();
+        pack_rgb9e5([rgb.x, rgb.y, rgb.z])
+    }
+
+    /// Creates an opaque color from RGB9E5 values in this color space's primaries.
+    /// The values are linear, so the transfer function is not applied.
+    pub fn new_color_from_rgb9e5(&self, packed: u32) -> Color {
+        let [r, g, b] = unpack_rgb9e5(packed);
+        let xyz = self.to_XYZ * Vector3::new(r, g, b);
+        Color::new_xyza(xyz.x, xyz.y, xyz.z, 1.0)
+    }
+}
@@ -0,0 +1,341 @@
+//! Reading and writing palette files used by design tools:
+//! GIMP palettes (.gpl), Adobe Color Swatch files (.aco), and Adobe Swatch Exchange files (.ase).
+//!
+//! With the `serde` feature `Palette` can also be (de)serialized with any serde format, such as JSON.
+use crate::*;
+use alloc::format;
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
+
+/// A named list of colors.
+#[derive(Debug, Clone, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Palette {
+    pub name: Option<String>,
+    pub entries: Vec<PaletteEntry>,
+}
+
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct PaletteEntry {
+    pub name: Option<String>,
+    pub color: Color,
+}
+
+/// The error returned when reading a palette file fails.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PaletteFileError {
+    /// The file doesn't start with the header of the expected format.
+    InvalidHeader,
+    /// The file ends partway through a color.
+    UnexpectedEnd,
+    /// A color couldn't be parsed.
+    InvalidColor,
+    /// The file uses a color model, like CMYK, that can't be converted without a color profile.
+    UnsupportedColorModel,
+}
+
+impl core::fmt::Display for PaletteFileError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        let message = match self {
+            PaletteFileError::InvalidHeader => "the file doesn't have the expected header",
+            PaletteFileError::UnexpectedEnd => "the file ends unexpectedly",
+            PaletteFileError::InvalidColor => "a color couldn't be parsed",
+            PaletteFileError::UnsupportedColorModel => "unsupported color model",
+        };
+        f.write_str(message)
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for PaletteFileError {}
+
+impl Palette {
+    /// Reads a GIMP palette. Colors are sRGB.
+    pub fn from_gpl(text: &str) -> Result<Self, PaletteFileError> {
+        let mut lines = text.lines();
+        if lines.next().map(|l| l.trim()) != Some("GIMP Palette") {
+            return Err(PaletteFileError::InvalidHeader);
+        }
+        let mut palette = Palette::default();
+        for line in lines {
+            let line = line.trim();
+            if line.is_empty() || line.starts_with('#') || line.starts_with("Columns:") {
+                continue;
+            }
+            if let Some(name) = line.strip_prefix("Name:") {
+                palette.name = Some(name.trim().to_string());
+                continue;
+            }
+            let mut parts = line.split_whitespace();
+            let mut channel = || -> Result<u8, PaletteFileError> {
+                parts
+                    .next()
+                    .and_then(|p| p.parse().ok())
+                    .ok_or(PaletteFileError::InvalidColor)
+            };
+            let (r, g, b) = (channel()?, channel()?, channel()?);
+            let name: Vec<&str> = parts.collect();
+            palette.entries.push(PaletteEntry {
+                name: if name.is_empty() {
+                    None
+                } else {
+                    Some(name.join(" "))
+                },
+                color: ColorSpace::SRGB.new_color(
+                    r as f64 / 255.0,
+                    g as f64 / 255.0,
+                    b as f64 / 255.0,
+                    1.0,
+                ),
+            });
+        }
+        Ok(palette)
+    }
+
+    /// Writes a GIMP palette. Colors are clipped to sRGB.
+    pub fn to_gpl(&self) -> String {
+        let mut text = String::from("GIMP Palette\n");
+        if let Some(name) = &self.name {
+            text += &format!("Name: {}\n", name);
+        }
+        text += "#\n";
+        for entry in &self.entries {
+            let [r, g, b] = srgb_bytes(&entry.color);
+            text += &format!(
+                "{:3} {:3} {:3}\t{}\n",
+                r,
+                g,
+                b,
+                entry.name.as_deref().unwrap_or("Untitled")
+            );
+        }
+        text
+    }
+
+    /// Reads an Adobe Color Swatch file, as used by Photoshop.
+    /// RGB colors are sRGB and Lab colors are relative to D50.
+    pub fn from_aco(bytes: &[u8]) -> Result<Self, PaletteFileError> {
+        let mut reader = Reader { bytes, offset: 0 };
+        let mut palette = Palette::default();
+
+        // Version 1 has no names. It's usually followed by version 2, which does.
+        for _ in 0..2 {
+            if reader.offset == bytes.len() && !palette.entries.is_empty() {
+                break;
+            }
+            let version = reader.u16()?;
+            if version != 1 && version != 2 {
+                return Err(PaletteFileError::InvalidHeader);
+            }
+            let count = reader.u16()?;
+            let mut entries = Vec::with_capacity(count as usize);
+            for _ in 0..count {
+                let model = reader.u16()?;
+                let values = [reader.u16()?, reader.u16()?, reader.u16()?, reader.u16()?];
+                let color = match model {
+                    0 => ColorSpace::SRGB.new_color(
+                        values[0] as f64 / 65535.0,
+                        values[1] as f64 / 65535.0,
+                        values[2] as f64 / 65535.0,
+                        1.0,
+                    ),
+                    7 => Color::from_lab(
+                        Lab::new(
+                            values[0] as f64 / 100.0,
+                            values[1] as i16 as f64 / 100.0,
+                            values[2] as i16 as f64 / 100.0,
+                        ),
+                        1.0,
+                    ),
+                    _ => return Err(PaletteFileError::UnsupportedColorModel),
+                };
+                let name = if version == 2 {
+                    let length = reader.u32()? as usize;
+                    Some(reader.utf16(length)?)
+                } else {
+                    None
+                };
+                entries.push(PaletteEntry { name, color });
+            }
+            palette.entries = entries;
+        }
+        Ok(palette)
+    }
+
+    /// Writes an Adobe Color Swatch file with sRGB colors, including both the
+    /// version 1 and version 2 (named) sections.
+    pub fn to_aco(&self) -> Vec<u8> {
+        let mut bytes = Vec::new();
+        for version in 1..=2u16 {
+            bytes.extend_from_slice(&version.to_be_bytes());
+            bytes.extend_from_slice(&(self.entries.len() as u16).to_be_bytes());
+            for entry in &self.entries {
+                let (r, g, b, _) = entry.color.to_srgb();
+                bytes.extend_from_slice(&0u16.to_be_bytes());
+                for value in [r, g, b, 0.0] {
+                    bytes.extend_from_slice(&(float::round(value * 65535.0) as u16).to_be_bytes());
+                }
+                if version == 2 {
+                    let name = utf16_with_null(entry.name.as_deref().unwrap_or(""));
+                    bytes.extend_from_slice(&(name.len() as u32).to_be_bytes());
+                    for unit in name {
+                        bytes.extend_from_slice(&unit.to_be_bytes());
+                    }
+                }
+            }
+        }
+        bytes
+    }
+
+    /// Reads an Adobe Swatch Exchange file, as used by Illustrator and InDesign.
+    /// RGB and gray colors are sRGB and Lab colors are relative to D50.
+    /// The name of the first group is used as the palette's name.
+    pub fn from_ase(bytes: &[u8]) -> Result<Self, PaletteFileError> {
+        let mut reader = Reader { bytes, offset: 0 };
+        if reader.take(4)? != b"ASEF" {
+            return Err(PaletteFileError::InvalidHeader);
+        }
+        let _version = (reader.u16()?, reader.u16()?);
+        let block_count = reader.u32()?;
+
+        let mut palette = Palette::default();
+        for _ in 0..block_count {
+            let block_type = reader.u16()?;
+            let length = reader.u32()? as usize;
+            let mut block = Reader {
+                bytes: reader.take(length)?,
+                offset: 0,
+            };
+            match block_type {
+                // Group start
+                0xc001 => {
+                    let name_length = block.u16()? as usize;
+                    let name = block.utf16(name_length)?;
+                    palette.name.get_or_insert(name);
+                }
+                // Color entry
+                0x0001 => {
+                    let name_length = block.u16()? as usize;
+                    let name = block.utf16(name_length)?;
+                    let color = match block.take(4)? {
+                        b"RGB " => ColorSpace::SRGB.new_color(
+                            block.f32()?,
+                            block.f32()?,
+                            block.f32()?,
+                            1.0,
+                        ),
+                        b"LAB " => Color::from_lab(
+                            Lab::new(block.f32()? * 100.0, block.f32()?, block.f32()?),
+                            1.0,
+                        ),
+                        b"Gray" => {
+                            let gray = block.f32()?;
+                            ColorSpace::SRGB.new_color(gray, gray, gray, 1.0)
+                        }
+                        _ => return Err(PaletteFileError::UnsupportedColorModel),
+                    };
+                    palette.entries.push(PaletteEntry {
+                        name: Some(name),
+                        color,
+                    });
+                }
+                // Group end and unknown blocks
+                _ => {}
+            }
+        }
+        Ok(palette)
+    }
+
+    /// Writes an Adobe Swatch Exchange file with sRGB colors.
+    pub fn to_ase(&self) -> Vec<u8> {
+        let mut bytes = b"ASEF".to_vec();
+        bytes.extend_from_slice(&1u16.to_be_bytes());
+        bytes.extend_from_slice(&0u16.to_be_bytes());
+        bytes.extend_from_slice(&(self.entries.len() as u32).to_be_bytes());
+        for entry in &self.entries {
+            let name = utf16_with_null(entry.name.as_deref().unwrap_or(""));
+            let mut block = Vec::new();
+            block.extend_from_slice(&(name.len() as u16).to_be_bytes());
+            for unit in name {
+                block.extend_from_slice(&unit.to_be_bytes());
+            }
+            block.extend_from_slice(b"RGB ");
+            let (r, g, b, _) = entry.color.to_srgb();
+            for value in [r, g, b] {
+                block.extend_from_slice(&(value as f32).to_be_bytes());
+            }
+            // A normal (not global or spot) color.
+            block.extend_from_slice(&2u16.to_be_bytes());
+
+            bytes.extend_from_slice(&1u16.to_be_bytes());
+            bytes.extend_from_slice(&(block.len() as u32).to_be_bytes());
+            bytes.extend_from_slice(&block);
+        }
+        bytes
+    }
+}
+
+fn srgb_bytes(color: &Color) -> [u8; 3] {
+    let (r, g, b, _) = color.to_srgb();
+    [r, g, b].map(|v| float::round(v * 255.0) as u8)
+}
+
+fn utf16_with_null(text: &str) -> Vec<u16> {
+    let mut units: Vec<u16> = text.encode_utf16().collect();
+    units.push(0);
+    units
+}
+
+/// Reads big endian values from a byte slice.
+struct Reader<'a> {
+    bytes: &'a [u8],
+    offset: usize,
+}
+
+impl<'a> Reader<'a> {
+    fn take(&mut self, length: usize) -> Result<&'a [u8], PaletteFileError> {
+        let end = self
+            .offset
+            .checked_add(length)
+            .ok_or(PaletteFileError::UnexpectedEnd)?;
+        let bytes = self
+            .bytes
+            .get(self.offset..end)
+            .ok_or(PaletteFileError::UnexpectedEnd)?;
+        self.offset = end;
+        Ok(bytes)
+    }
+
+    fn u16(&mut self) -> Result<u16, PaletteFileError> {
+        let bytes = self.take(2)?;
+        Ok(u16::from_be_bytes([bytes[0], bytes[1]]))
+    }
+
+    fn u32(&mut self) -> Result<u32, PaletteFileError> {
+        let bytes = self.take(4)?;
+        Ok(u32::from_be_bytes([bytes[0], bytes[1], bytes[2], bytes[3]]))
+    }
+
+    fn f32(&mut self) -> Result<f64, PaletteFileError> {
+        Ok(f32::from_bits(self.u32()?) as f64)
+    }
+
+    /// Reads `length` UTF-16 code units, dropping the terminating null.
+    fn utf16(&mut self, length: usize) -> Result<String, PaletteFileError> {
+        // Read the bytes first so a corrupt length fails instead of allocating.
+        let bytes = self.take(
+            length
+                .checked_mul(2)
+                .ok_or(PaletteFileError::UnexpectedEnd)?,
+        )?;
+        let mut units: Vec<u16> = bytes
+            .chunks_exact(2)
+            .map(|pair| u16::from_be_bytes([pair[0], pair[1]]))
+            .collect();
+        if units.last() == Some(&0) {
+            units.pop();
+        }
+        String::from_utf16(&units).map_err(|_| PaletteFileError::InvalidColor)
+    }
+}
@@ -0,0 +1,153 @@
+use crate::*;
+use alloc::vec::Vec;
+
+/// Finds the closest color in a palette, for example to remap an image to a fixed palette.
+///
+/// The palette is stored in a k-d tree built from the coordinates of `metric`,
+/// so each lookup takes O(log n) time for a palette of n colors.
+///
+/// Metrics that aren't straight line distances between their coordinates, like `DeltaE2000`,
+/// are supported by comparing every palette color within the metric's `coordinate_bound`
+/// of the closest coordinates, which makes lookups slower.
+#[derive(Debug, Clone)]
+pub struct PaletteIndex<M: ColorDistance> {
+    metric: M,
+    palette: Vec<Color>,
+    coordinates: Vec<[f64; 3]>,
+    nodes: Vec<KdNode>,
+}
+
+#[derive(Debug, Clone)]
+struct KdNode {
+    /// The index of the palette color at this node.
+    index: usize,
+    axis: usize,
+    left: Option<usize>,
+    right: Option<usize>,
+}
+
+impl<M: ColorDistance> PaletteIndex<M> {
+    pub fn new(palette: &[Color], metric: M) -> Self {
+        let coordinates: Vec<[f64; 3]> = palette.iter().map(|c| metric.coordinates(c)).collect();
+        let mut index = Self {
+            metric,
+            palette: palette.to_vec(),
+            coordinates,
+            nodes: Vec::with_capacity(palette.len()),
+        };
+        let mut indices: Vec<usize> = (0..palette.len()).collect();
+        index.build(&mut indices, 0);
+        index
+    }
+
+    pub fn palette(&self) -> &[Color] {
+        &self.palette
+    }
+
+    /// The index of the palette color closest to `color`.
+    /// Returns `None` if the palette is empty.
+    pub fn nearest(&self, color: &Color) -> Option<usize> {
+        if self.nodes.is_empty() {
+            return None;
+        }
+        let target = self.metric.coordinates(color);
+
+        let mut best = (f64::INFINITY, 0);
+        self.nearest_in(0, &target, &mut best);
+
+        // Compare every palette color that could be closer according to the metric.
+        let closest_distance = self.metric.distance(&self.palette[best.1], color);
+        let radius = self.metric.coordinate_bound(closest_distance);
+        let mut candidates = alloc::vec![best.1];
+        self.within(0, &target, radius * radius, &mut candidates);
+        candidates
+            .into_iter()
+            .map(|i| (self.metric.distance(&self.palette[i], color), i))
+            .min_by(|a, b| a.0.total_cmp(&b.0))
+            .map(|(_, i)| i)
+    }
+
+    /// The palette color closest to `color`.
+    pub fn nearest_color(&self, color: &Color) -> Option<Color> {
+        self.nearest(color).map(|i| self.palette[i])
+    }
+
+    /// Builds the subtree containing `indices` and returns its node.
+    fn build(&mut self, indices: &mut [usize], depth: usize) -> Option<usize> {
+        if indices.is_empty() {
+            return None;
+        }
+        let axis = depth % 3;
+        let coordinates = &self.coordinates;
+        indices.sort_by(|a, b| coordinates[*a][axis].total_cmp(&coordinates[*b][axis]));
+        let median = indices.len() / 2;
+
+        let node = self.nodes.len();
+        self.nodes.push(KdNode {
+            index: indices[median],
+            axis,
+            left: None,
+            right: None,
+        });
+        let (left, rest) = indices.split_at_mut(median);
+        self.nodes[node].left = self.build(left, depth + 1);
+        self.nodes[node].right = self.build(&mut rest[1..], depth + 1);
+        Some(node)
+    }
+
+    fn nearest_in(&self, node: usize, target: &[f64; 3], best: &mut (f64, usize)) {
+        let KdNode {
+            index,
+            axis,
+            left,
+            right,
+        } = self.nodes[node];
+        let distance = distance_squared(&self.coordinates[index], target);
+        if distance < best.0 {
+            *best = (distance, index);
+        }
+
+        let offset = target[axis] - self.coordinates[index][axis];
+        let (near, far) = if offset < 0.0 {
+            (left, right)
+        } else {
+            (right, left)
+        };
+        if let Some(near) = near {
+            self.nearest_in(near, target, best);
+        }
+        if let Some(far) = far {
+            if offset * offset < best.0 {
+                self.nearest_in(far, target, best);
+            }
+        }
+    }
+
+    fn within(&self, node: usize, target: &[f64; 3], radius_squared: f64, out: &mut Vec<usize>) {
+        let KdNode {
+            index,
+            axis,
+            left,
+            right,
+        } = self.nodes[node];
+        if distance_squared(&self.coordinates[index], target) <= radius_squared {
+            out.push(index);
+        }
+        let offset = target[axis] - self.coordinates[index][axis];
+        if let Some(left) = left {
+            if offset < 0.0 || offset * offset <= radius_squared {
+                self.within(left, target, radius_squared, out);
+            }
+        }
+        if let Some(right) = right {
+            if offset >= 0.0 || offset * offset <= radius_squared {
+                self.within(right, target, radius_squared, out);
+            }
+        }
+    }
+}
+
+fn distance_squared(a: &[f64; 3], b: &[f64; 3]) -> f64 {
+    let (d0, d1, d2) = (a[0] - b[0], a[1] - b[1], a[2] - b[2]);
+    d0 * d0 + d1 * d1 + d2 * d2
+}
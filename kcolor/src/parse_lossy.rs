@@ -0,0 +1,158 @@
+//! A forgiving color parser for user facing inputs, which accepts common
+//! non-standard ways of writing colors and reports what it assumed.
+use crate::working_space::default_color_space;
+use crate::*;
+use alloc::string::String;
+use alloc::vec::Vec;
+
+/// Something `Color::parse_lossy` assumed to understand a color string.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum ParseAssumption {
+    /// Hex digits without a leading `#` were read as a hex color.
+    MissingHash,
+    /// A `0x` prefix was read as `#`.
+    HexPrefix0x,
+    /// Numbers without a function name, or a function name without parentheses
+    /// like `rgb 255 0 0`, were read as `rgb()`.
+    ImplicitRgb,
+    /// Commas were read as decimal separators, like `0,5` for `0.5`.
+    DecimalComma,
+    /// RGB values were all 1.0 or less, with some fractional, and were read as
+    /// ranging from 0.0 to 1.0 instead of 0 to 255.
+    UnitRange,
+}
+
+impl Color {
+    /// Parses a color string leniently, returning the color and a list of what was assumed
+    /// to understand it. Colors are encoded in the default color space
+    /// (see `working_space::default_color_space`).
+    ///
+    /// This accepts standard hex colors and `rgb()`/`rgba()` with values from 0 to 255,
+    /// as well as inputs that are common from users and other tools:
+    /// hex without `#` (`ff8000`) or with `0x` (`0xff8000`), `rgb` without parentheses
+    /// (`rgb 255 128 0`) or without a name (`255, 128, 0`), values from 0.0 to 1.0,
+    /// percentages, and decimal commas (`0,5; 0,25; 1`).
+    pub fn parse_lossy(text: &str) -> Result<(Color, Vec<ParseAssumption>), Error> {
+        let mut assumptions = Vec::new();
+        let text = text.trim();
+        let color_space = default_color_space();
+
+        // Hex colors.
+        let hex = if let Some(hex) = text.strip_prefix('#') {
+            Some(hex)
+        } else if let Some(hex) = text.strip_prefix("0x").or_else(|| text.strip_prefix("0X")) {
+            assumptions.push(ParseAssumption::HexPrefix0x);
+            Some(hex)
+        } else if matches!(text.len(), 3 | 4 | 6 | 8)
+            && text.chars().all(|c| c.is_ascii_hexdigit())
+            // Three or four plain digits are more likely to be a typo'd number than hex.
+            && !text.chars().all(|c| c.is_ascii_digit())
+        {
+            assumptions.push(ParseAssumption::MissingHash);
+            Some(text)
+        } else {
+            None
+        };
+        if let Some(hex) = hex {
+            let color = color_space
+                .new_color_from_hex_str(hex)
+                .map_err(|_| Error::UnrecognizedColor)?;
+            return Ok((color, assumptions));
+        }
+
+        // Function syntax, with or without the name and parentheses.
+        let lowercase = text.to_ascii_lowercase();
+        let mut arguments = lowercase.as_str();
+        let name = arguments
+            .strip_prefix("rgba")
+            .or_else(|| arguments.strip_prefix("rgb"));
+        match name {
+            Some(rest) => arguments = rest.trim(),
+            None => assumptions.push(ParseAssumption::ImplicitRgb),
+        }
+        match arguments
+            .strip_prefix('(')
+            .and_then(|a| a.strip_suffix(')'))
+        {
+            Some(inner) => arguments = inner,
+            None if name.is_some() => assumptions.push(ParseAssumption::ImplicitRgb),
+            None => {}
+        }
+
+        let values = split_values(arguments, &mut assumptions)?;
+        if values.len() != 3 && values.len() != 4 {
+            return Err(Error::UnrecognizedColor);
+        }
+
+        // Percentages are always relative to 1.0. Other RGB values are 0 to 255
+        // unless they all look like they're from 0.0 to 1.0.
+        let rgb = &values[..3];
+        let unit_range = rgb.iter().all(|(v, percent)| *percent || *v <= 1.0)
+            && rgb
+                .iter()
+                .any(|(v, percent)| !*percent && *v != float::floor(*v));
+        if unit_range {
+            assumptions.push(ParseAssumption::UnitRange);
+        }
+        let channel = |(v, percent): (f64, bool)| {
+            if percent || unit_range {
+                v
+            } else {
+                v / 255.0
+            }
+        };
+        // Alpha is from 0.0 to 1.0, or a percentage.
+        let alpha = values.get(3).map_or(1.0, |(v, _)| *v);
+        if values.iter().any(|(v, _)| !v.is_finite() || *v < 0.0) {
+            return Err(Error::UnrecognizedColor);
+        }
+
+        let color = color_space.new_color(
+            channel(values[0]),
+            channel(values[1]),
+            channel(values[2]),
+            alpha,
+        );
+        Ok((color, assumptions))
+    }
+}
+
+/// Splits the arguments of a color into numbers, each paired with whether it was a percentage
+/// (which is divided by 100).
+fn split_values(
+    arguments: &str,
+    assumptions: &mut Vec<ParseAssumption>,
+) -> Result<Vec<(f64, bool)>, Error> {
+    // Commas are decimal separators when values are separated by semicolons,
+    // or by whitespace with commas inside values, like `0,5 0,25 1`.
+    let whitespace_separated: Vec<&str> = arguments
+        .split(|c: char| c.is_whitespace() || c == ';' || c == '/')
+        .map(|v| v.trim_end_matches(','))
+        .filter(|v| !v.is_empty())
+        .collect();
+    let decimal_comma = arguments.contains(';')
+        || whitespace_separated.len() >= 3 && whitespace_separated.iter().any(|v| v.contains(','));
+
+    let values: Vec<String> = if decimal_comma {
+        assumptions.push(ParseAssumption::DecimalComma);
+        whitespace_separated
+            .iter()
+            .map(|v| v.replace(',', "."))
+            .collect()
+    } else {
+        arguments
+            .split(|c: char| c.is_whitespace() || c == ',' || c == '/')
+            .filter(|v| !v.is_empty())
+            .map(String::from)
+            .collect()
+    };
+
+    values
+        .iter()
+        .map(|v| match v.strip_suffix('%') {
+            Some(percent) => percent.parse::<f64>().map(|p| (p / 100.0, true)),
+            None => v.parse::<f64>().map(|v| (v, false)),
+        })
+        .collect::<Result<_, _>>()
+        .map_err(|_| Error::UnrecognizedColor)
+}
@@ -0,0 +1,189 @@
+//! A Python extension module, enabled with the `python` feature.
+//!
+//! Build and install it into the current Python environment with:
+//! `maturin develop -m kcolor/Cargo.toml --features python`
+//!
+//! ```python
+//! import kcolor
+//! srgb = kcolor.ColorSpace.from_name("srgb")
+//! p3 = kcolor.ColorSpace.from_name("display-p3")
+//! print(p3.color_to_rgba(srgb.new_color(1.0, 0.0, 0.0)))
+//! ```
+use crate::*;
+use alloc::format;
+use alloc::string::{String, ToString};
+use pyo3::exceptions::PyValueError;
+use pyo3::prelude::*;
+
+#[pyclass(name = "ColorSpace", frozen)]
+#[derive(Clone)]
+pub struct PyColorSpace(ColorSpace);
+
+#[pymethods]
+impl PyColorSpace {
+    /// A built in color space by name, like "srgb" or "display-p3".
+    #[staticmethod]
+    fn from_name(name: &str) -> PyResult<Self> {
+        ColorSpace::from_name(name)
+            .map(PyColorSpace)
+            .ok_or_else(|| PyValueError::new_err(format!("unknown color space '{}'", name)))
+    }
+
+    /// A color space from the bytes of an ICC profile.
+    #[staticmethod]
+    fn from_icc_profile(bytes: &[u8]) -> PyResult<Self> {
+        ColorSpace::from_icc_profile(bytes)
+            .map(PyColorSpace)
+            .map_err(|e| PyValueError::new_err(format!("{:?}", e)))
+    }
+
+    /// The names of the built in color spaces.
+    #[staticmethod]
+    fn names() -> Vec<&'static str> {
+        StandardColorSpace::ALL.iter().map(|s| s.name()).collect()
+    }
+
+    #[pyo3(signature = (r, g, b, a = 1.0))]
+    fn new_color(&self, r: f64, g: f64, b: f64, a: f64) -> PyColor {
+        PyColor(self.0.new_color(r, g, b, a))
+    }
+
+    fn new_color_from_hex(&self, hex: &str) -> PyResult<PyColor> {
+        self.0
+            .new_color_from_hex_str(hex)
+            .map(PyColor)
+            .map_err(|e| PyValueError::new_err(e.to_string()))
+    }
+
+    /// The color's RGBA values in this color space, clipped to 0.0 to 1.0.
+    fn color_to_rgba(&self, color: &PyColor) -> (f64, f64, f64, f64) {
+        self.0.color_to_rgba(&color.0)
+    }
+
+    /// The color's RGBA values in this color space, which may be outside 0.0 to 1.0.
+    fn color_to_rgba_unclipped(&self, color: &PyColor) -> (f64, f64, f64, f64) {
+        self.0.color_to_rgba_unclipped(&color.0)
+    }
+
+    fn color_to_hex(&self, color: &PyColor, include_alpha: bool) -> String {
+        self.0.color_to_hex_string(&color.0, include_alpha)
+    }
+
+    fn contains(&self, color: &PyColor) -> bool {
+        self.0.contains(&color.0)
+    }
+
+    fn gamut_map(&self, color: &PyColor) -> PyColor {
+        PyColor(self.0.gamut_map(&color.0))
+    }
+
+    /// A converter from RGB values in this color space to RGB values in `to`.
+    fn converter(&self, to: &PyColorSpace) -> PyConverter {
+        PyConverter(ColorSpaceConverter::new(&self.0, &to.0))
+    }
+}
+
+#[pyclass(name = "Converter", frozen)]
+pub struct PyConverter(ColorSpaceConverter);
+
+#[pymethods]
+impl PyConverter {
+    /// Converts one RGB color. The result isn't clipped.
+    fn convert(&self, rgb: (f64, f64, f64)) -> (f64, f64, f64) {
+        self.0.convert_encoded_color(&rgb)
+    }
+
+    /// Converts a list of RGB colors. The results aren't clipped.
+    fn convert_many(&self, colors: Vec<(f64, f64, f64)>) -> Vec<(f64, f64, f64)> {
+        colors
+            .iter()
+            .map(|rgb| self.0.convert_encoded_color(rgb))
+            .collect()
+    }
+}
+
+#[pyclass(name = "Color", frozen)]
+#[derive(Clone)]
+pub struct PyColor(Color);
+
+#[pymethods]
+impl PyColor {
+    #[staticmethod]
+    #[pyo3(signature = (l, c, h, alpha = 1.0))]
+    fn from_oklch(l: f64, c: f64, h: f64, alpha: f64) -> Self {
+        PyColor(Color::from_oklch(Oklch::new(l, c, h), alpha))
+    }
+
+    #[staticmethod]
+    #[pyo3(signature = (l, a, b, alpha = 1.0))]
+    fn from_lab(l: f64, a: f64, b: f64, alpha: f64) -> Self {
+        PyColor(Color::from_lab(Lab::new(l, a, b), alpha))
+    }
+
+    /// XYZ values relative to D50.
+    #[staticmethod]
+    #[pyo3(signature = (x, y, z, alpha = 1.0))]
+    fn from_xyz(x: f64, y: f64, z: f64, alpha: f64) -> Self {
+        PyColor(Color::new_xyza(x, y, z, alpha))
+    }
+
+    fn to_xyz(&self) -> (f64, f64, f64) {
+        let xyz = self.0.xyz();
+        (xyz.X, xyz.Y, xyz.Z)
+    }
+
+    fn to_oklch(&self) -> (f64, f64, f64) {
+        let oklch = self.0.to_oklch();
+        (oklch.L, oklch.C, oklch.h)
+    }
+
+    fn to_oklab(&self) -> (f64, f64, f64) {
+        let oklab = self.0.to_oklab();
+        (oklab.L, oklab.a, oklab.b)
+    }
+
+    fn to_lab(&self) -> (f64, f64, f64) {
+        let lab = self.0.to_lab();
+        (lab.L, lab.a, lab.b)
+    }
+
+    #[getter]
+    fn alpha(&self) -> f64 {
+        self.0.alpha()
+    }
+
+    fn __str__(&self) -> String {
+        self.0.to_string()
+    }
+
+    fn __repr__(&self) -> String {
+        format!("{:?}", self.0)
+    }
+}
+
+#[pyfunction]
+fn delta_e_76(a: &PyColor, b: &PyColor) -> f64 {
+    DeltaE76.distance(&a.0, &b.0)
+}
+
+#[pyfunction]
+fn delta_e_2000(a: &PyColor, b: &PyColor) -> f64 {
+    DeltaE2000.distance(&a.0, &b.0)
+}
+
+#[pyfunction]
+fn delta_e_ok(a: &PyColor, b: &PyColor) -> f64 {
+    DeltaEOk.distance(&a.0, &b.0)
+}
+
+#[pymodule]
+#[pyo3(name = "kcolor")]
+fn python_module(module: &Bound<'_, PyModule>) -> PyResult<()> {
+    module.add_class::<PyColorSpace>()?;
+    module.add_class::<PyConverter>()?;
+    module.add_class::<PyColor>()?;
+    module.add_function(wrap_pyfunction!(delta_e_76, module)?)?;
+    module.add_function(wrap_pyfunction!(delta_e_2000, module)?)?;
+    module.add_function(wrap_pyfunction!(delta_e_ok, module)?)?;
+    Ok(())
+}
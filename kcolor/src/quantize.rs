@@ -0,0 +1,439 @@
+//! Color quantization: reducing many colors to a small palette of representative colors.
+//! This is useful for exporting indexed images (like GIF and PNG8) and extracting themes from images.
+use crate::*;
+use alloc::vec::Vec;
+
+/// The space colors are compared and averaged in while quantizing.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Default)]
+pub enum QuantizeSpace {
+    /// XYZ, which is linear light. Averages match physically mixing the colors.
+    Linear,
+    /// Oklab, where distances roughly match perceived differences.
+    /// This usually produces palettes that look closer to the original colors.
+    #[default]
+    Oklab,
+}
+
+impl QuantizeSpace {
+    pub(crate) fn to_point(self, color: &Color) -> [f64; 3] {
+        match self {
+            QuantizeSpace::Linear => [color.X, color.Y, color.Z],
+            QuantizeSpace::Oklab => {
+                let oklab = color.to_oklab();
+                [oklab.L, oklab.a, oklab.b]
+            }
+        }
+    }
+
+    pub(crate) fn point_to_color(self, point: [f64; 3], alpha: f64) -> Color {
+        match self {
+            QuantizeSpace::Linear => Color::new_xyza(point[0], point[1], point[2], alpha),
+            QuantizeSpace::Oklab => {
+                Color::from_oklab(Oklab::new(point[0], point[1], point[2]), alpha)
+            }
+        }
+    }
+}
+
+/// Reduces `colors` to at most `count` representative colors with the median cut algorithm.
+///
+/// The colors are repeatedly split in half along the axis of `space` with the widest range,
+/// and each resulting group is replaced with its average.
+/// Fewer than `count` colors are returned if there aren't enough distinct colors.
+pub fn median_cut(colors: &[Color], count: usize, space: QuantizeSpace) -> Vec<Color> {
+    let points: Vec<([f64; 3], f64)> = colors
+        .iter()
+        .map(|color| (space.to_point(color), color.a))
+        .collect();
+
+    let mut boxes: Vec<Vec<([f64; 3], f64)>> = Vec::new();
+    if !points.is_empty() && count > 0 {
+        boxes.push(points);
+    }
+
+    while boxes.len() < count {
+        // Split the box with the widest range along any axis.
+        let widest = boxes
+            .iter()
+            .enumerate()
+            .map(|(i, b)| (i, widest_axis(b)))
+            .filter(|(_, (_, range))| *range > 0.0)
+            .max_by(|(_, (_, a)), (_, (_, b))| a.total_cmp(b));
+        let (index, (axis, _)) = match widest {
+            Some(widest) => widest,
+            // Every box contains a single distinct color.
+            None => break,
+        };
+
+        let mut split = boxes.swap_remove(index);
+        split.sort_by(|a, b| a.0[axis].total_cmp(&b.0[axis]));
+        let upper = split.split_off(split.len() / 2);
+        boxes.push(split);
+        boxes.push(upper);
+    }
+
+    boxes
+        .iter()
+        .map(|b| {
+            let mut sum = [0.0; 3];
+            let mut alpha = 0.0;
+            for (point, a) in b {
+                sum[0] += point[0];
+                sum[1] += point[1];
+                sum[2] += point[2];
+                alpha += a;
+            }
+            let n = b.len() as f64;
+            space.point_to_color([sum[0] / n, sum[1] / n, sum[2] / n], alpha / n)
+        })
+        .collect()
+}
+
+/// The axis with the widest range of values and that range.
+fn widest_axis(points: &[([f64; 3], f64)]) -> (usize, f64) {
+    (0..3)
+        .map(|axis| {
+            let mut min = f64::INFINITY;
+            let mut max = f64::NEG_INFINITY;
+            for (point, _) in points {
+                min = min.min(point[axis]);
+                max = max.max(point[axis]);
+            }
+            (axis, max - min)
+        })
+        .max_by(|a, b| a.1.total_cmp(&b.1))
+        .unwrap()
+}
+
+/// Reduces `colors` to at most `count` representative colors with k-means clustering.
+///
+/// Colors are assigned to the closest palette color as measured by `metric`, and each
+/// palette color is moved to the Oklab average of its colors, until the palette stops changing.
+/// The palette starts from the result of `median_cut` in Oklab, so the output is deterministic
+/// and better than `median_cut` alone, at the cost of more time.
+pub fn k_means(colors: &[Color], count: usize, metric: &impl ColorDistance) -> Vec<Color> {
+    const MAX_ITERATIONS: usize = 32;
+
+    let mut palette = median_cut(colors, count, QuantizeSpace::Oklab);
+    let mut assignments = alloc::vec![usize::MAX; colors.len()];
+
+    for _ in 0..MAX_ITERATIONS {
+        let mut changed = false;
+        for (color, assignment) in colors.iter().zip(assignments.iter_mut()) {
+            let closest = closest_index(&palette, color, metric);
+            if closest != *assignment {
+                *assignment = closest;
+                changed = true;
+            }
+        }
+        if !changed {
+            break;
+        }
+
+        let mut sums = alloc::vec![([0.0; 3], 0.0, 0usize); palette.len()];
+        for (color, assignment) in colors.iter().zip(assignments.iter()) {
+            let point = QuantizeSpace::Oklab.to_point(color);
+            let sum = &mut sums[*assignment];
+            sum.0[0] += point[0];
+            sum.0[1] += point[1];
+            sum.0[2] += point[2];
+            sum.1 += color.a;
+            sum.2 += 1;
+        }
+        for (palette_color, (point, alpha, n)) in palette.iter_mut().zip(sums) {
+            // A palette color without any colors stays where it is.
+            if n > 0 {
+                let n = n as f64;
+                *palette_color = QuantizeSpace::Oklab
+                    .point_to_color([point[0] / n, point[1] / n, point[2] / n], alpha / n);
+            }
+        }
+    }
+    palette
+}
+
+fn closest_index(palette: &[Color], color: &Color, metric: &impl ColorDistance) -> usize {
+    palette
+        .iter()
+        .map(|p| metric.distance(p, color))
+        .enumerate()
+        .min_by(|a, b| a.1.total_cmp(&b.1))
+        .map(|(i, _)| i)
+        .unwrap_or(0)
+}
+
+/// Reduces a stream of colors to at most `max_colors` representative colors with an octree.
+///
+/// Colors can be added a few at a time, for example while an image is being decoded,
+/// and only the tree (not the colors) is kept in memory.
+/// Colors are positioned in the tree by their encoded RGB values in a color space
+/// and palette colors are averaged in XYZ.
+///
+/// Once all colors are added call `finish` to get the palette and look up the palette index
+/// of each color, which can be done in a second streaming pass.
+#[derive(Debug, Clone)]
+pub struct OctreeQuantizer {
+    color_space: ColorSpace,
+    max_colors: usize,
+    nodes: Vec<OctreeNode>,
+    free_nodes: Vec<usize>,
+    /// Nodes with children at each level, the candidates for merging.
+    reducible: [Vec<usize>; OCTREE_DEPTH],
+    leaf_count: usize,
+}
+
+const OCTREE_DEPTH: usize = 8;
+const NO_CHILD: usize = usize::MAX;
+
+#[derive(Debug, Clone)]
+struct OctreeNode {
+    children: [usize; 8],
+    sum: [f64; 4],
+    count: u64,
+    is_leaf: bool,
+}
+
+impl OctreeNode {
+    fn new(is_leaf: bool) -> Self {
+        Self {
+            children: [NO_CHILD; 8],
+            sum: [0.0; 4],
+            count: 0,
+            is_leaf,
+        }
+    }
+
+    fn average(&self) -> Color {
+        let n = self.count as f64;
+        Color::new_xyza(
+            self.sum[0] / n,
+            self.sum[1] / n,
+            self.sum[2] / n,
+            self.sum[3] / n,
+        )
+    }
+}
+
+impl OctreeQuantizer {
+    /// `max_colors` is at least 8, because each level of the tree can contain up to 8 colors.
+    pub fn new(max_colors: usize, color_space: &ColorSpace) -> Self {
+        Self {
+            color_space: color_space.clone(),
+            max_colors: max_colors.max(8),
+            nodes: alloc::vec![OctreeNode::new(false)],
+            free_nodes: Vec::new(),
+            reducible: Default::default(),
+            leaf_count: 0,
+        }
+    }
+
+    pub fn add(&mut self, color: &Color) {
+        let path = self.path(color);
+        let mut node = 0;
+        for (level, child_index) in path.iter().enumerate() {
+            if self.nodes[node].is_leaf {
+                break;
+            }
+            let mut child = self.nodes[node].children[*child_index];
+            if child == NO_CHILD {
+                if self.nodes[node].children == [NO_CHILD; 8] {
+                    self.reducible[level].push(node);
+                }
+                child = self.allocate(OctreeNode::new(level + 1 == OCTREE_DEPTH));
+                self.nodes[node].children[*child_index] = child;
+                if self.nodes[child].is_leaf {
+                    self.leaf_count += 1;
+                }
+            }
+            node = child;
+        }
+
+        let node = &mut self.nodes[node];
+        node.sum[0] += color.X;
+        node.sum[1] += color.Y;
+        node.sum[2] += color.Z;
+        node.sum[3] += color.a;
+        node.count += 1;
+
+        while self.leaf_count > self.max_colors {
+            self.reduce();
+        }
+    }
+
+    /// Finishes adding colors and builds the palette.
+    pub fn finish(self) -> OctreePalette {
+        let mut palette = Vec::new();
+        let mut leaf_indices = alloc::vec![NO_CHILD; self.nodes.len()];
+        let mut stack = alloc::vec![0];
+        while let Some(node) = stack.pop() {
+            if self.nodes[node].is_leaf {
+                leaf_indices[node] = palette.len();
+                palette.push(self.nodes[node].average());
+            } else {
+                stack.extend(self.nodes[node].children.iter().filter(|c| **c != NO_CHILD));
+            }
+        }
+        OctreePalette {
+            quantizer: self,
+            palette,
+            leaf_indices,
+        }
+    }
+
+    /// The child index at each level of the tree for the color, from the bits of its encoded RGB values.
+    fn path(&self, color: &Color) -> [usize; OCTREE_DEPTH] {
+        let (r, g, b, _) = self.color_space.color_to_rgba(color);
+        let to_byte = |v: f64| float::round(v * 255.0) as usize;
+        let (r, g, b) = (to_byte(r), to_byte(g), to_byte(b));
+        let mut path = [0; OCTREE_DEPTH];
+        for (level, index) in path.iter_mut().enumerate() {
+            let shift = 7 - level;
+            *index = ((r >> shift) & 1) << 2 | ((g >> shift) & 1) << 1 | ((b >> shift) & 1);
+        }
+        path
+    }
+
+    fn allocate(&mut self, node: OctreeNode) -> usize {
+        if let Some(index) = self.free_nodes.pop() {
+            self.nodes[index] = node;
+            index
+        } else {
+            self.nodes.push(node);
+            self.nodes.len() - 1
+        }
+    }
+
+    /// Merges the children of the deepest reducible node into it.
+    fn reduce(&mut self) {
+        let node = match self
+            .reducible
+            .iter_mut()
+            .rev()
+            .find_map(|level| level.pop())
+        {
+            Some(node) => node,
+            None => return,
+        };
+        let children = core::mem::replace(&mut self.nodes[node].children, [NO_CHILD; 8]);
+        for child in children.iter().filter(|c| **c != NO_CHILD) {
+            let (sum, count) = (self.nodes[*child].sum, self.nodes[*child].count);
+            let node = &mut self.nodes[node];
+            for (total, value) in node.sum.iter_mut().zip(sum) {
+                *total += value;
+            }
+            node.count += count;
+            self.leaf_count -= 1;
+            self.free_nodes.push(*child);
+        }
+        self.nodes[node].is_leaf = true;
+        self.leaf_count += 1;
+    }
+}
+
+/// The palette produced by an `OctreeQuantizer`.
+#[derive(Debug, Clone)]
+pub struct OctreePalette {
+    quantizer: OctreeQuantizer,
+    palette: Vec<Color>,
+    /// The palette index of each leaf node.
+    leaf_indices: Vec<usize>,
+}
+
+impl OctreePalette {
+    pub fn palette(&self) -> &[Color] {
+        &self.palette
+    }
+
+    /// The index in the palette of the color that represents `color`.
+    /// Colors that weren't added to the quantizer are matched to a nearby palette color.
+    pub fn index_of(&self, color: &Color) -> usize {
+        let nodes = &self.quantizer.nodes;
+        let path = self.quantizer.path(color);
+        let mut node = 0;
+        for child_index in path.iter() {
+            if nodes[node].is_leaf {
+                break;
+            }
+            let child = nodes[node].children[*child_index];
+            node = if child != NO_CHILD {
+                child
+            } else {
+                // Pick the most similar of the existing children.
+                let target = color.to_oklab();
+                *nodes[node]
+                    .children
+                    .iter()
+                    .filter(|c| **c != NO_CHILD)
+                    .min_by(|a, b| {
+                        let distance = |node: usize| {
+                            let oklab = nodes[node].average().to_oklab();
+                            let (dl, da, db) =
+                                (oklab.L - target.L, oklab.a - target.a, oklab.b - target.b);
+                            dl * dl + da * da + db * db
+                        };
+                        distance(**a).total_cmp(&distance(**b))
+                    })
+                    .unwrap()
+            };
+        }
+        self.leaf_indices[node]
+    }
+}
+
+/// A color picked by `extract_dominant_colors`.
+#[derive(Debug, Copy, Clone)]
+pub struct DominantColor {
+    pub color: Color,
+    /// The fraction of the sampled colors closest to this color, from 0.0 to 1.0.
+    pub coverage: f64,
+    /// How well the color represents the image, used to rank the colors.
+    pub score: f64,
+}
+
+/// Picks up to `count` colors that stand out in an image, for example to derive accent colors
+/// from album art or wallpapers. The best color is first.
+///
+/// The image is sampled down to a few thousand colors, which are clustered in Oklab with `k_means`.
+/// Clusters are scored by how much of the image they cover and by how colorful they are,
+/// so a small vivid area can outrank a large dull background.
+pub fn extract_dominant_colors(colors: &[Color], count: usize) -> Vec<DominantColor> {
+    const MAX_SAMPLES: usize = 4096;
+    // The score of gray colors relative to the most colorful colors.
+    const GRAY_WEIGHT: f64 = 0.1;
+
+    if colors.is_empty() || count == 0 {
+        return Vec::new();
+    }
+    let step = colors.len().div_ceil(MAX_SAMPLES);
+    let samples: Vec<Color> = colors.iter().step_by(step).copied().collect();
+
+    // Cluster into more colors than requested so small but vivid areas get their own cluster.
+    let palette = k_means(&samples, count * 2, &DeltaEOk);
+    let index = PaletteIndex::new(&palette, DeltaEOk);
+    let mut counts = alloc::vec![0usize; palette.len()];
+    for sample in &samples {
+        if let Some(i) = index.nearest(sample) {
+            counts[i] += 1;
+        }
+    }
+
+    let mut dominant: Vec<DominantColor> = palette
+        .iter()
+        .zip(counts)
+        .filter(|(_, n)| *n > 0)
+        .map(|(color, n)| {
+            let coverage = n as f64 / samples.len() as f64;
+            // Oklch chroma of the most saturated colors is around 0.3.
+            let chroma = (color.to_oklch().C / 0.3).min(1.0);
+            // Coverage has diminishing returns so large areas don't always win.
+            DominantColor {
+                color: *color,
+                coverage,
+                score: float::sqrt(coverage) * (GRAY_WEIGHT + chroma),
+            }
+        })
+        .collect();
+    dominant.sort_by(|a, b| b.score.total_cmp(&a.score));
+    dominant.truncate(count);
+    dominant
+}
@@ -0,0 +1,164 @@
+//! Random color sampling with the `rand` crate.
+use crate::*;
+use rand::distr::Distribution;
+use rand::Rng;
+
+/// Samples colors uniformly in a color space's encoded RGB values.
+/// All sampled colors are within the color space's gamut and fully opaque.
+#[derive(Debug, Clone)]
+pub struct UniformRgb {
+    color_space: ColorSpace,
+}
+
+impl UniformRgb {
+    pub fn new(color_space: &ColorSpace) -> Self {
+        Self {
+            color_space: color_space.clone(),
+        }
+    }
+}
+
+impl Distribution<Color> for UniformRgb {
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> Color {
+        self.color_space
+            .new_color(rng.random(), rng.random(), rng.random(), 1.0)
+    }
+}
+
+/// Samples colors uniformly in Oklab, so perceptually similar colors are
+/// equally likely regardless of how the color space encodes them.
+/// All sampled colors are within the color space's gamut and fully opaque.
+#[derive(Debug, Clone)]
+pub struct UniformOklab {
+    color_space: ColorSpace,
+}
+
+impl UniformOklab {
+    pub fn new(color_space: &ColorSpace) -> Self {
+        Self {
+            color_space: color_space.clone(),
+        }
+    }
+}
+
+impl Distribution<Color> for UniformOklab {
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> Color {
+        // Rejection sample from a box that contains the gamut of any
+        // reasonable color space. Each attempt is independent and uniform
+        // so the accepted samples are uniform within the gamut.
+        loop {
+            let oklab = Oklab::new(
+                rng.random_range(0.0..=1.0),
+                rng.random_range(-0.5..=0.5),
+                rng.random_range(-0.5..=0.5),
+            );
+            let color = Color::from_oklab(oklab, 1.0);
+            let (r, g, b, _) = self.color_space.color_to_rgba_unclipped(&color);
+            if (0.0..=1.0).contains(&r) && (0.0..=1.0).contains(&g) && (0.0..=1.0).contains(&b) {
+                return color;
+            }
+        }
+    }
+}
+
+/// Samples random colors that satisfy a set of constraints, like colors for
+/// generative art or for automatically tagging items.
+///
+/// Colors are sampled uniformly in Oklch within the constraints and are
+/// rejected if they're outside the color space's gamut or don't contrast
+/// enough with the background. All sampled colors are fully opaque.
+#[derive(Debug, Clone)]
+pub struct ConstrainedRandom {
+    color_space: ColorSpace,
+    hue_range: (f64, f64),
+    min_chroma: f64,
+    lightness_range: (f64, f64),
+    min_contrast: Option<(Color, f64)>,
+}
+
+impl ConstrainedRandom {
+    /// How many candidates are tried before giving up.
+    pub const MAX_ATTEMPTS: usize = 1000;
+
+    pub fn new(color_space: &ColorSpace) -> Self {
+        Self {
+            color_space: color_space.clone(),
+            hue_range: (0.0, 360.0),
+            min_chroma: 0.0,
+            lightness_range: (0.0, 1.0),
+            min_contrast: None,
+        }
+    }
+
+    /// Restricts the Oklch hue, in degrees. The range goes counterclockwise
+    /// from `start` to `end` and wraps around, so `(330.0, 30.0)` is reds.
+    pub fn hue_range(mut self, start: f64, end: f64) -> Self {
+        self.hue_range = (start, end);
+        self
+    }
+
+    /// Restricts the Oklch chroma to at least `min_chroma`.
+    pub fn min_chroma(mut self, min_chroma: f64) -> Self {
+        self.min_chroma = min_chroma;
+        self
+    }
+
+    /// Restricts the Oklab lightness to between `min` and `max`.
+    pub fn lightness_range(mut self, min: f64, max: f64) -> Self {
+        self.lightness_range = (min, max);
+        self
+    }
+
+    /// Requires a WCAG contrast ratio of at least `ratio` against `background`.
+    pub fn min_contrast(mut self, background: &Color, ratio: f64) -> Self {
+        self.min_contrast = Some((*background, ratio));
+        self
+    }
+
+    /// Samples a color, or returns `None` if no color satisfying the
+    /// constraints was found within [`Self::MAX_ATTEMPTS`] attempts.
+    pub fn try_sample<R: Rng + ?Sized>(&self, rng: &mut R) -> Option<Color> {
+        let (hue_start, hue_end) = self.hue_range;
+        let hue_span = if hue_end - hue_start >= 360.0 {
+            360.0
+        } else {
+            float::rem_euclid(hue_end - hue_start, 360.0)
+        };
+        let (min_lightness, max_lightness) = self.lightness_range;
+        if min_lightness > max_lightness || self.min_chroma > MAX_CHROMA {
+            return None;
+        }
+
+        for _ in 0..Self::MAX_ATTEMPTS {
+            let oklch = Oklch::new(
+                rng.random_range(min_lightness..=max_lightness),
+                rng.random_range(self.min_chroma..=MAX_CHROMA),
+                float::rem_euclid(hue_start + rng.random_range(0.0..=hue_span), 360.0),
+            );
+            let color = Color::from_oklab(oklch.into(), 1.0);
+            let (r, g, b, _) = self.color_space.color_to_rgba_unclipped(&color);
+            if !((0.0..=1.0).contains(&r) && (0.0..=1.0).contains(&g) && (0.0..=1.0).contains(&b)) {
+                continue;
+            }
+            if let Some((background, ratio)) = &self.min_contrast {
+                if color.contrast_ratio(background) < *ratio {
+                    continue;
+                }
+            }
+            return Some(color);
+        }
+        None
+    }
+}
+
+// Larger than the chroma of any color in common color spaces.
+const MAX_CHROMA: f64 = 0.4;
+
+impl Distribution<Color> for ConstrainedRandom {
+    /// Panics if the constraints can't be satisfied.
+    /// Use [`ConstrainedRandom::try_sample`] to handle that case.
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> Color {
+        self.try_sample(rng)
+            .expect("No color satisfies the constraints")
+    }
+}
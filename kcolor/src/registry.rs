@@ -0,0 +1,79 @@
+use crate::*;
+use alloc::collections::BTreeMap;
+use alloc::string::String;
+use alloc::vec::Vec;
+
+/// A collection of named color spaces that caches the converters between them.
+///
+/// Apps that handle many documents, each with its own color profile, can register every
+/// profile once and then request converters by name instead of creating them repeatedly.
+#[derive(Debug, Clone, Default)]
+pub struct ColorSpaceRegistry {
+    color_spaces: Vec<(String, ColorSpace)>,
+    converters: BTreeMap<(usize, usize), ColorSpaceConverter>,
+}
+
+impl ColorSpaceRegistry {
+    /// An empty registry.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// A registry with each of the `StandardColorSpace`s registered by its name, such as "srgb".
+    pub fn with_standard_color_spaces() -> Self {
+        let mut registry = Self::new();
+        for standard in StandardColorSpace::ALL.iter() {
+            registry.register(standard.name(), ColorSpace::from_standard(*standard));
+        }
+        registry
+    }
+
+    /// Registers a color space, replacing any color space already registered with the same name.
+    pub fn register(&mut self, name: &str, color_space: ColorSpace) {
+        match self.index(name) {
+            Some(index) => {
+                self.color_spaces[index].1 = color_space;
+                // Converters to or from the old color space are now wrong.
+                self.converters
+                    .retain(|(from, to), _| *from != index && *to != index);
+            }
+            None => self.color_spaces.push((String::from(name), color_space)),
+        }
+    }
+
+    /// Registers the color space described by an ICC profile.
+    pub fn register_icc_profile(
+        &mut self,
+        name: &str,
+        bytes: &[u8],
+    ) -> Result<(), ICCProfileError> {
+        let color_space = ColorSpace::from_icc_profile(bytes)?;
+        self.register(name, color_space);
+        Ok(())
+    }
+
+    pub fn get(&self, name: &str) -> Option<&ColorSpace> {
+        self.index(name).map(|index| &self.color_spaces[index].1)
+    }
+
+    /// The names of the registered color spaces, in the order they were registered.
+    pub fn names(&self) -> impl Iterator<Item = &str> {
+        self.color_spaces.iter().map(|(name, _)| name.as_str())
+    }
+
+    /// A converter between two registered color spaces, which is created the first time
+    /// it's requested and reused afterwards.
+    /// Returns `None` if either color space isn't registered.
+    pub fn converter(&mut self, from: &str, to: &str) -> Option<&ColorSpaceConverter> {
+        let from = self.index(from)?;
+        let to = self.index(to)?;
+        let color_spaces = &self.color_spaces;
+        Some(self.converters.entry((from, to)).or_insert_with(|| {
+            ColorSpaceConverter::new(&color_spaces[from].1, &color_spaces[to].1)
+        }))
+    }
+
+    fn index(&self, name: &str) -> Option<usize> {
+        self.color_spaces.iter().position(|(n, _)| n == name)
+    }
+}
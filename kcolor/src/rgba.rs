@@ -0,0 +1,160 @@
+use crate::*;
+use alloc::{format, string::String};
+
+/// A plain RGBA color with no color space attached.
+/// This is the value a `ColorSpace` produces when a `Color` is expressed in it.
+///
+/// `Rgba` is `#[repr(C)]` so slices of it can be passed directly to GPUs or written to files.
+/// With the `bytemuck` feature `Rgba<T>` is `Pod` whenever `T` is.
+#[repr(C)]
+#[derive(Debug, Copy, Clone, PartialEq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Rgba<T> {
+    pub r: T,
+    pub g: T,
+    pub b: T,
+    pub a: T,
+}
+
+/// 8-bit RGBA values, as used by most image formats and graphics APIs.
+pub type Rgba8 = Rgba<u8>;
+
+impl<T> Rgba<T> {
+    pub fn new(r: T, g: T, b: T, a: T) -> Self {
+        Self { r, g, b, a }
+    }
+}
+
+impl<T> From<(T, T, T, T)> for Rgba<T> {
+    fn from((r, g, b, a): (T, T, T, T)) -> Self {
+        Self { r, g, b, a }
+    }
+}
+
+impl<T> From<[T; 4]> for Rgba<T> {
+    fn from([r, g, b, a]: [T; 4]) -> Self {
+        Self { r, g, b, a }
+    }
+}
+
+impl<T> From<Rgba<T>> for (T, T, T, T) {
+    fn from(rgba: Rgba<T>) -> Self {
+        (rgba.r, rgba.g, rgba.b, rgba.a)
+    }
+}
+
+impl From<(f64, f64, f64, f64)> for Rgba<f32> {
+    fn from((r, g, b, a): (f64, f64, f64, f64)) -> Self {
+        Self {
+            r: r as f32,
+            g: g as f32,
+            b: b as f32,
+            a: a as f32,
+        }
+    }
+}
+
+// Safety: `Rgba` is `#[repr(C)]` and made of four fields of the same type,
+// so it has no padding and every bit pattern is valid when it is valid for `T`.
+#[cfg(feature = "bytemuck")]
+unsafe impl<T: bytemuck::Zeroable> bytemuck::Zeroable for Rgba<T> {}
+#[cfg(feature = "bytemuck")]
+unsafe impl<T: bytemuck::Pod> bytemuck::Pod for Rgba<T> {}
+
+impl ColorSpace {
+    /// Gets the `f32` RGBA values for the color as expressed in this color space
+    /// RGB values outside of 0.0 to 1.0 will be clipped.
+    pub fn color_to_rgba_f32(&self, color: &Color) -> Rgba<f32> {
+        self.color_to_rgba(color).into()
+    }
+
+    /// Creates a color from `f32` RGBA values expressed in this color space
+    pub fn new_color_from_rgba_f32(&self, rgba: Rgba<f32>) -> Color {
+        self.new_color(rgba.r as f64, rgba.g as f64, rgba.b as f64, rgba.a as f64)
+    }
+
+    /// Gets the 8-bit RGBA values for the color as expressed in this color space.
+    /// Values are clipped to 0.0 to 1.0 and then rounded to the nearest byte.
+    pub fn color_to_rgba8(&self, color: &Color) -> [u8; 4] {
+        let (r, g, b, a) = self.color_to_rgba(color);
+        let to_u8 = |v: f64| quantize(v, u8::MAX as f64) as u8;
+        [to_u8(r), to_u8(g), to_u8(b), to_u8(a)]
+    }
+
+    /// Creates a color from 8-bit RGBA values, in that order, expressed in this color space.
+    /// This is the inverse of `color_to_rgba8`.
+    pub fn new_color_from_rgba8_array(&self, rgba: [u8; 4]) -> Color {
+        self.new_color_from_rgba8(rgba.into())
+    }
+
+    /// Creates a color from 8-bit RGBA values expressed in this color space.
+    pub fn new_color_from_rgba8(&self, rgba: Rgba8) -> Color {
+        self.new_color(
+            rgba.r as f64 / 255.0,
+            rgba.g as f64 / 255.0,
+            rgba.b as f64 / 255.0,
+            rgba.a as f64 / 255.0,
+        )
+    }
+
+    /// Gets 8-bit RGBA values for the color in this color space's primaries
+    /// without applying the transfer function.
+    /// Only use this for linear textures: 8 bits aren't enough precision for dark linear values,
+    /// and displaying linear values as if they were encoded looks washed out.
+    pub fn color_to_linear_rgba8(&self, color: &Color) -> [u8; 4] {
+        let (r, g, b, a) = self.color_to_linear_rgba(color);
+        let to_u8 = |v: f64| quantize(v, u8::MAX as f64) as u8;
+        [to_u8(r), to_u8(g), to_u8(b), to_u8(a)]
+    }
+
+    /// Creates a color from 8-bit linear RGBA values in this color space's primaries.
+    /// This is the inverse of `color_to_linear_rgba8`.
+    pub fn new_color_from_linear_rgba8_array(&self, rgba: [u8; 4]) -> Color {
+        let rgb = Vector3::new(
+            rgba[0] as f64 / 255.0,
+            rgba[1] as f64 / 255.0,
+            rgba[2] as f64 / 255.0,
+        );
+        let xyz = self.to_XYZ * rgb;
+        Color::new_xyza(xyz.x, xyz.y, xyz.z, rgba[3] as f64 / 255.0)
+    }
+
+    /// Formats the color as a `#rrggbb` hex string in this color space, or as `#rrggbbaa`
+    /// if `include_alpha` is true. This is the counterpart of `new_color_from_hex`.
+    ///
+    /// Colors outside of the color space's gamut are gamut mapped (see `ColorSpace::gamut_map`).
+    pub fn color_to_hex_string(&self, color: &Color, include_alpha: bool) -> String {
+        let [r, g, b, a] = self.color_to_rgba8(&self.gamut_map(color));
+        if include_alpha {
+            format!("#{:02x}{:02x}{:02x}{:02x}", r, g, b, a)
+        } else {
+            format!("#{:02x}{:02x}{:02x}", r, g, b)
+        }
+    }
+
+    /// Gets the 16-bit RGBA values for the color as expressed in this color space,
+    /// as used by 16-bit PNG and TIFF images.
+    /// Values are clipped to 0.0 to 1.0 and then rounded to the nearest integer.
+    pub fn color_to_rgba16(&self, color: &Color) -> [u16; 4] {
+        let (r, g, b, a) = self.color_to_rgba(color);
+        let to_u16 = |v: f64| quantize(v, u16::MAX as f64) as u16;
+        [to_u16(r), to_u16(g), to_u16(b), to_u16(a)]
+    }
+
+    /// Creates a color from 16-bit RGBA values, in that order, expressed in this color space.
+    /// This is the inverse of `color_to_rgba16`.
+    pub fn new_color_from_rgba16_array(&self, rgba: [u16; 4]) -> Color {
+        let max = u16::MAX as f64;
+        self.new_color(
+            rgba[0] as f64 / max,
+            rgba[1] as f64 / max,
+            rgba[2] as f64 / max,
+            rgba[3] as f64 / max,
+        )
+    }
+}
+
+/// Clips `v` to 0.0 to 1.0 and scales it to the nearest integer from 0 to `max`.
+fn quantize(v: f64, max: f64) -> f64 {
+    float::round(v.clamp(0.0, 1.0) * max)
+}
@@ -0,0 +1,56 @@
+// `Serialize` and `Deserialize` support for types that can't simply derive it.
+use crate::*;
+use serde::{Deserialize, Serialize};
+
+/// `ColorSpace` is serialized as the values it was constructed from instead of
+/// its conversion matrices. This keeps files readable and allows the matrix math
+/// to change without breaking saved color spaces.
+#[derive(Serialize, Deserialize, Clone)]
+#[serde(rename = "ColorSpace")]
+struct ColorSpaceDescription {
+    red_primary: Chromaticity,
+    green_primary: Chromaticity,
+    blue_primary: Chromaticity,
+    white_point: Chromaticity,
+    transfer_function: TransferFunction,
+    #[serde(default)]
+    adaptation_method: ChromaticAdaptationMethod,
+}
+
+impl From<ColorSpace> for ColorSpaceDescription {
+    fn from(color_space: ColorSpace) -> Self {
+        Self {
+            red_primary: color_space.red_primary(),
+            green_primary: color_space.green_primary(),
+            blue_primary: color_space.blue_primary(),
+            white_point: color_space.white_point,
+            transfer_function: color_space.transfer_function,
+            adaptation_method: color_space.adaptation_method,
+        }
+    }
+}
+
+impl From<ColorSpaceDescription> for ColorSpace {
+    fn from(description: ColorSpaceDescription) -> Self {
+        ColorSpace::new_with_adaptation_method(
+            description.red_primary,
+            description.green_primary,
+            description.blue_primary,
+            description.white_point,
+            description.transfer_function,
+            description.adaptation_method,
+        )
+    }
+}
+
+impl Serialize for ColorSpace {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        ColorSpaceDescription::from(self.clone()).serialize(serializer)
+    }
+}
+
+impl<'de> Deserialize<'de> for ColorSpace {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        ColorSpaceDescription::deserialize(deserializer).map(ColorSpace::from)
+    }
+}
@@ -0,0 +1,378 @@
+//! Generates shader source that performs the same conversion as
+//! `ColorSpaceConverter::convert_encoded_color`.
+//!
+//! The generated code declares three functions: `<name>_to_linear`, `<name>_from_linear`,
+//! and `<name>` itself which takes and returns an RGB `vec3`.
+//!
+//! `TransferFunction::Sampled` is emitted as a table that's interpolated like
+//! `TransferFunctionLut`, which needs GLSL 3.30 or GLSL ES 3.00 for array constructors.
+use crate::*;
+use alloc::format;
+use alloc::string::String;
+use alloc::vec::Vec;
+
+// The same constants as `TransferFunction::Pq` and `TransferFunction::Hlg`.
+const PQ_M1: f64 = 2610.0 / 16384.0;
+const PQ_M2: f64 = 2523.0 / 4096.0 * 128.0;
+const PQ_C1: f64 = 3424.0 / 4096.0;
+const PQ_C2: f64 = 2413.0 / 4096.0 * 32.0;
+const PQ_C3: f64 = 2392.0 / 4096.0 * 32.0;
+const HLG_A: f64 = 0.17883277;
+const HLG_B: f64 = 0.28466892;
+const HLG_C: f64 = 0.55991073;
+
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub enum ShaderLanguage {
+    Wgsl,
+    Glsl,
+}
+
+impl ShaderLanguage {
+    fn function(
+        &self,
+        name: &str,
+        parameter: &str,
+        parameter_type: &str,
+        return_type: &str,
+    ) -> String {
+        match self {
+            ShaderLanguage::Wgsl => format!(
+                "fn {}({}: {}) -> {}",
+                name,
+                parameter,
+                self.type_name(parameter_type),
+                self.type_name(return_type)
+            ),
+            ShaderLanguage::Glsl => format!(
+                "{} {}({} {})",
+                self.type_name(return_type),
+                name,
+                self.type_name(parameter_type),
+                parameter
+            ),
+        }
+    }
+
+    fn type_name(&self, name: &str) -> &'static str {
+        match (self, name) {
+            (ShaderLanguage::Wgsl, "float") => "f32",
+            (ShaderLanguage::Wgsl, "vec3") => "vec3<f32>",
+            (ShaderLanguage::Wgsl, "mat3") => "mat3x3<f32>",
+            (ShaderLanguage::Glsl, "float") => "float",
+            (ShaderLanguage::Glsl, "vec3") => "vec3",
+            (ShaderLanguage::Glsl, "mat3") => "mat3",
+            (ShaderLanguage::Wgsl, "int") => "i32",
+            (ShaderLanguage::Glsl, "int") => "int",
+            _ => unreachable!(),
+        }
+    }
+
+    /// Declares a float array that can be indexed with a value only known at runtime.
+    fn float_array(&self, name: &str, values: &[f64]) -> String {
+        let values: Vec<String> = values.iter().map(|v| format!("{:?}", *v as f32)).collect();
+        match self {
+            ShaderLanguage::Wgsl => format!(
+                "    var {} = array<f32, {}>({});\n",
+                name,
+                values.len(),
+                values.join(", ")
+            ),
+            ShaderLanguage::Glsl => format!(
+                "    float {}[{}] = float[{}]({});\n",
+                name,
+                values.len(),
+                values.len(),
+                values.join(", ")
+            ),
+        }
+    }
+
+    fn variable(&self, name: &str, type_name: &str, value: &str) -> String {
+        match self {
+            ShaderLanguage::Wgsl => format!("    let {} = {};\n", name, value),
+            ShaderLanguage::Glsl => {
+                format!("    {} {} = {};\n", self.type_name(type_name), name, value)
+            }
+        }
+    }
+}
+
+/// Generates a WGSL function named `name` that performs the conversion.
+pub fn wgsl(converter: &ColorSpaceConverter, name: &str) -> String {
+    shader(converter, name, ShaderLanguage::Wgsl)
+}
+
+/// Generates a GLSL function named `name` that performs the conversion.
+pub fn glsl(converter: &ColorSpaceConverter, name: &str) -> String {
+    shader(converter, name, ShaderLanguage::Glsl)
+}
+
+/// Generates a function named `name` in the given language that performs the conversion.
+pub fn shader(converter: &ColorSpaceConverter, name: &str, language: ShaderLanguage) -> String {
+    let to_linear_name = format!("{}_to_linear", name);
+    let from_linear_name = format!("{}_from_linear", name);
+
+    let mut source = String::new();
+    source += &transfer_function(
+        &converter.from_transfer_function,
+        &to_linear_name,
+        true,
+        language,
+    );
+    source += "\n";
+    source += &transfer_function(
+        &converter.to_transfer_function,
+        &from_linear_name,
+        false,
+        language,
+    );
+    source += "\n";
+
+    // Both WGSL and GLSL matrices are constructed from columns.
+    let m = &converter.conversion_matrix;
+    let column = |c: &Vector3| {
+        format!(
+            "{}({:?}, {:?}, {:?})",
+            language.type_name("vec3"),
+            c.x as f32,
+            c.y as f32,
+            c.z as f32
+        )
+    };
+    let matrix = format!(
+        "{}(\n        {},\n        {},\n        {}\n    )",
+        language.type_name("mat3"),
+        column(&m.c0),
+        column(&m.c1),
+        column(&m.c2)
+    );
+
+    source += &language.function(name, "color", "vec3", "vec3");
+    source += " {\n";
+    source += &language.variable(
+        "linear",
+        "vec3",
+        &format!(
+            "{}({to}(color.r), {to}(color.g), {to}(color.b))",
+            language.type_name("vec3"),
+            to = to_linear_name
+        ),
+    );
+    source += &language.variable("converted", "vec3", &format!("{} * linear", matrix));
+    source += &format!(
+        "    return {}({from}(converted.r), {from}(converted.g), {from}(converted.b));\n}}\n",
+        language.type_name("vec3"),
+        from = from_linear_name
+    );
+    source
+}
+
+fn transfer_function(
+    transfer_function: &TransferFunction,
+    name: &str,
+    to_linear: bool,
+    language: ShaderLanguage,
+) -> String {
+    let mut source = language.function(name, "x", "float", "float");
+    source += " {\n";
+
+    // These mirror `TransferFunction::to_linear` and `TransferFunction::from_linear`.
+    let body = match (transfer_function, to_linear) {
+        (TransferFunction::None, _) => String::from("    return x;\n"),
+        (TransferFunction::ParametricCurve(ParametricCurve::Function0 { gamma }), _) => {
+            let exponent = if to_linear { *gamma } else { 1.0 / *gamma };
+            format!(
+                "    return sign(x) * pow(abs(x), {exponent:?});\n",
+                exponent = exponent as f32
+            )
+        }
+        (TransferFunction::ParametricCurve(ParametricCurve::Function1 { gamma, a, b }), true) => {
+            format!(
+                "    if (x >= {start:?}) {{\n        return pow({a:?} * x + {b:?}, {gamma:?});\n    }}\n    return 0.0;\n",
+                start = (-*b / *a) as f32,
+                gamma = *gamma as f32,
+                a = *a as f32,
+                b = *b as f32,
+            )
+        }
+        (TransferFunction::ParametricCurve(ParametricCurve::Function1 { gamma, a, b }), false) => {
+            format!(
+                "    if (x > 0.0) {{\n        return (pow(x, {inverse_gamma:?}) - {b:?}) / {a:?};\n    }}\n    return {start:?};\n",
+                start = (-*b / *a) as f32,
+                inverse_gamma = (1.0 / *gamma) as f32,
+                a = *a as f32,
+                b = *b as f32,
+            )
+        }
+        (TransferFunction::ParametricCurve(ParametricCurve::Function2 { gamma, a, b, c }), true) => {
+            format!(
+                "    if (x >= {start:?}) {{\n        return pow({a:?} * x + {b:?}, {gamma:?}) + {c:?};\n    }}\n    return {c:?};\n",
+                start = (-*b / *a) as f32,
+                gamma = *gamma as f32,
+                a = *a as f32,
+                b = *b as f32,
+                c = *c as f32,
+            )
+        }
+        (TransferFunction::ParametricCurve(ParametricCurve::Function2 { gamma, a, b, c }), false) => {
+            format!(
+                "    if (x > {c:?}) {{\n        return (pow(x - {c:?}, {inverse_gamma:?}) - {b:?}) / {a:?};\n    }}\n    return {start:?};\n",
+                start = (-*b / *a) as f32,
+                inverse_gamma = (1.0 / *gamma) as f32,
+                a = *a as f32,
+                b = *b as f32,
+                c = *c as f32,
+            )
+        }
+        (TransferFunction::ParametricCurve(ParametricCurve::Function3 { gamma, a, b, c, d }), true) => {
+            language.variable("s", "float", "sign(x)")
+                + &language.variable("v", "float", "abs(x)")
+                + &format!(
+                    "    if (v >= {d:?}) {{\n        return s * pow({a:?} * v + {b:?}, {gamma:?});\n    }}\n    return s * (v * {c:?});\n",
+                    gamma = *gamma as f32,
+                    a = *a as f32,
+                    b = *b as f32,
+                    c = *c as f32,
+                    d = *d as f32,
+                )
+        }
+        (TransferFunction::ParametricCurve(ParametricCurve::Function3 { gamma, a, b, c, d }), false) => {
+            language.variable("s", "float", "sign(x)")
+                + &language.variable("v", "float", "abs(x)")
+                + &format!(
+                    "    if (v >= {dc:?}) {{\n        return s * ((pow(v, {inverse_gamma:?}) - {b:?}) / {a:?});\n    }}\n    return s * (v / {c:?});\n",
+                    inverse_gamma = (1.0 / *gamma) as f32,
+                    a = *a as f32,
+                    b = *b as f32,
+                    c = *c as f32,
+                    dc = (*d * *c) as f32,
+                )
+        }
+        (
+            TransferFunction::ParametricCurve(ParametricCurve::Function4 {
+                gamma,
+                a,
+                b,
+                c,
+                d,
+                e,
+                f,
+            }),
+            true,
+        ) => format!(
+            "    if (x >= {d:?}) {{\n        return pow({a:?} * x + {b:?}, {gamma:?}) + {e:?};\n    }}\n    return {c:?} * x + {f:?};\n",
+            gamma = *gamma as f32,
+            a = *a as f32,
+            b = *b as f32,
+            c = *c as f32,
+            d = *d as f32,
+            e = *e as f32,
+            f = *f as f32,
+        ),
+        (
+            TransferFunction::ParametricCurve(ParametricCurve::Function4 {
+                gamma,
+                a,
+                b,
+                c,
+                d,
+                e,
+                f,
+            }),
+            false,
+        ) => format!(
+            "    if (x >= {threshold:?}) {{\n        return (pow(x - {e:?}, {inverse_gamma:?}) - {b:?}) / {a:?};\n    }}\n    return (x - {f:?}) / {c:?};\n",
+            threshold = (*d * *c + *f) as f32,
+            inverse_gamma = (1.0 / *gamma) as f32,
+            a = *a as f32,
+            b = *b as f32,
+            c = *c as f32,
+            e = *e as f32,
+            f = *f as f32,
+        ),
+        (TransferFunction::Pq { reference_white }, true) => {
+            language.variable("e", "float", &format!("pow(abs(x), {:?})", (1.0 / PQ_M2) as f32))
+                + &language.variable(
+                    "y",
+                    "float",
+                    &format!(
+                        "pow(max(e - {c1:?}, 0.0) / ({c2:?} - {c3:?} * e), {inverse_m1:?})",
+                        c1 = PQ_C1 as f32,
+                        c2 = PQ_C2 as f32,
+                        c3 = PQ_C3 as f32,
+                        inverse_m1 = (1.0 / PQ_M1) as f32,
+                    ),
+                )
+                + &format!(
+                    "    return sign(x) * y * {scale:?};\n",
+                    scale = (10000.0 / *reference_white) as f32
+                )
+        }
+        (TransferFunction::Pq { reference_white }, false) => {
+            language.variable(
+                "y",
+                "float",
+                &format!(
+                    "pow(abs(x) * {scale:?}, {m1:?})",
+                    scale = (*reference_white / 10000.0) as f32,
+                    m1 = PQ_M1 as f32,
+                ),
+            ) + &format!(
+                "    return sign(x) * pow(({c1:?} + {c2:?} * y) / (1.0 + {c3:?} * y), {m2:?});\n",
+                c1 = PQ_C1 as f32,
+                c2 = PQ_C2 as f32,
+                c3 = PQ_C3 as f32,
+                m2 = PQ_M2 as f32,
+            )
+        }
+        (TransferFunction::Hlg, true) => {
+            language.variable("s", "float", "sign(x)")
+                + &language.variable("v", "float", "abs(x)")
+                + &format!(
+                    "    if (v <= 0.5) {{\n        return s * (v * v / 3.0);\n    }}\n    return s * ((exp((v - {c:?}) / {a:?}) + {b:?}) / 12.0);\n",
+                    a = HLG_A as f32,
+                    b = HLG_B as f32,
+                    c = HLG_C as f32,
+                )
+        }
+        (TransferFunction::Hlg, false) => {
+            language.variable("s", "float", "sign(x)")
+                + &language.variable("v", "float", "abs(x)")
+                + &format!(
+                    "    if (v <= {threshold:?}) {{\n        return s * sqrt(3.0 * v);\n    }}\n    return s * ({a:?} * log(12.0 * v - {b:?}) + {c:?});\n",
+                    threshold = (1.0 / 12.0) as f32,
+                    a = HLG_A as f32,
+                    b = HLG_B as f32,
+                    c = HLG_C as f32,
+                )
+        }
+        // This mirrors `TransferFunctionLut::to_linear` and `TransferFunctionLut::from_linear`.
+        (TransferFunction::Sampled(lut), _) => {
+            let table = if to_linear { lut.decode() } else { lut.encode() };
+            language.float_array("table", table)
+                + &language.variable(
+                    "position",
+                    "float",
+                    &format!("clamp(x, 0.0, 1.0) * {:?}", (table.len() - 1) as f32),
+                )
+                + &language.variable(
+                    "index",
+                    "int",
+                    &format!(
+                        "min({int}(floor(position)), {last})",
+                        int = language.type_name("int"),
+                        last = table.len() - 2
+                    ),
+                )
+                + &language.variable(
+                    "fraction",
+                    "float",
+                    &format!("position - {}(index)", language.type_name("float")),
+                )
+                + "    return table[index] * (1.0 - fraction) + table[index + 1] * fraction;\n"
+        }
+    };
+    source += &body;
+    source += "}\n";
+    source
+}
@@ -0,0 +1,74 @@
+//! Keys for sorting colors in a perceptually sensible order.
+use crate::*;
+use core::cmp::Ordering;
+
+/// Colors with an Oklch chroma below this are considered gray and have no meaningful hue.
+const GRAY_CHROMA: f64 = 0.0001;
+
+/// A key for sorting colors, returned by `Color::sort_key_hue`, `Color::sort_key_lightness`,
+/// and `Color::sort_key_chroma`.
+///
+/// Keys are compared by their primary value and then by a secondary value to break ties.
+/// Values are compared with `f64::total_cmp`, so the ordering is total and consistent
+/// even for colors that produce NaN values.
+#[derive(Debug, Copy, Clone)]
+pub struct SortKey {
+    primary: f64,
+    secondary: f64,
+}
+
+impl PartialEq for SortKey {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl Eq for SortKey {}
+
+impl PartialOrd for SortKey {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for SortKey {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.primary
+            .total_cmp(&other.primary)
+            .then(self.secondary.total_cmp(&other.secondary))
+    }
+}
+
+impl Color {
+    /// Sorts colors around the color wheel by Oklch hue, starting at pink-red (hue 0.0).
+    /// Grays have no hue, so they sort before all other colors, from dark to light.
+    /// Colors with the same hue are sorted from dark to light.
+    pub fn sort_key_hue(&self) -> SortKey {
+        let oklch = self.to_oklch();
+        let primary = if oklch.C < GRAY_CHROMA { -1.0 } else { oklch.h };
+        SortKey {
+            primary,
+            secondary: oklch.L,
+        }
+    }
+
+    /// Sorts colors from dark to light by Oklch lightness.
+    /// Colors with the same lightness are sorted by hue, with grays first.
+    pub fn sort_key_lightness(&self) -> SortKey {
+        let oklch = self.to_oklch();
+        SortKey {
+            primary: oklch.L,
+            secondary: if oklch.C < GRAY_CHROMA { -1.0 } else { oklch.h },
+        }
+    }
+
+    /// Sorts colors from least to most colorful by Oklch chroma.
+    /// Colors with the same chroma are sorted from dark to light.
+    pub fn sort_key_chroma(&self) -> SortKey {
+        let oklch = self.to_oklch();
+        SortKey {
+            primary: oklch.C,
+            secondary: oklch.L,
+        }
+    }
+}
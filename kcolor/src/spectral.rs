@@ -0,0 +1,755 @@
+//! Converting light described by its spectrum to XYZ.
+//!
+//! The CIE color matching functions describe how strongly an average observer's eyes
+//! respond to each wavelength of light. Integrating a spectrum against them produces XYZ values.
+//!
+//! The color matching functions used are the tabulated CIE data from `kcolor_types::color_matching`.
+pub use crate::color_samples::{COLOR_EVALUATION_SAMPLES, SAMPLE_WAVELENGTHS, TEST_COLOR_SAMPLES};
+use crate::white_points::Observer;
+use crate::*;
+use alloc::vec::Vec;
+
+/// The response of the standard observer to light of `wavelength` nanometers.
+/// The peak of the Y response is close to 1.0 at 555 nanometers.
+pub fn color_matching_function(wavelength: f64, observer: Observer) -> XYZ {
+    match observer {
+        Observer::TwoDegrees => color_matching::cie_1931(wavelength),
+        Observer::TenDegrees => color_matching::cie_1964(wavelength),
+    }
+}
+
+/// The chromaticities of pure wavelengths of light (the spectral locus) from 380 to 700 nanometers
+/// in 1 nanometer steps. Every color is inside the horseshoe shaped polygon these form,
+/// which is closed by the straight "line of purples" from the last point to the first.
+pub fn spectral_locus(observer: Observer) -> Vec<Chromaticity> {
+    (380..=700)
+        .map(|wavelength| color_matching_function(wavelength as f64, observer).to_chromaticity())
+        .collect()
+}
+
+/// Integrates a spectrum against the color matching functions of `observer`.
+///
+/// `wavelengths` are in nanometers and increasing, and `values` are the spectrum at each wavelength.
+/// They don't need to be evenly spaced.
+///
+/// If `illuminant` is `Some` then `values` are reflectances (or transmittances) from 0.0 to 1.0 of
+/// an object lit by the illuminant, whose spectrum is sampled at the same wavelengths.
+/// The result is scaled so a perfect white reflector has a Y of 1.0, so it's relative to the
+/// illuminant's white point.
+///
+/// If `illuminant` is `None` then `values` are the spectrum of a light source and the result isn't
+/// scaled. For spectral radiance in W/(sr·m²·nm) multiply Y by 683.0 to get luminance in cd/m².
+///
+/// # Panics
+/// If `wavelengths`, `values`, and `illuminant` don't have the same length.
+pub fn xyz_from_spd(
+    wavelengths: &[f64],
+    values: &[f64],
+    illuminant: Option<&[f64]>,
+    observer: Observer,
+) -> XYZ {
+    assert_eq!(wavelengths.len(), values.len());
+    if let Some(illuminant) = illuminant {
+        assert_eq!(wavelengths.len(), illuminant.len());
+    }
+    let light = |i: usize| illuminant.map_or(1.0, |illuminant| illuminant[i]);
+
+    // Integrate with the trapezoidal rule.
+    let mut sum = XYZ::new(0.0, 0.0, 0.0);
+    let mut white_y = 0.0;
+    for i in 1..wavelengths.len() {
+        let width = (wavelengths[i] - wavelengths[i - 1]) / 2.0;
+        for j in [i - 1, i] {
+            let cmf = color_matching_function(wavelengths[j], observer);
+            let weight = values[j] * light(j) * width;
+            sum.X += cmf.X * weight;
+            sum.Y += cmf.Y * weight;
+            sum.Z += cmf.Z * weight;
+            white_y += cmf.Y * light(j) * width;
+        }
+    }
+
+    if illuminant.is_some() && white_y > 0.0 {
+        sum * (1.0 / white_y)
+    } else {
+        sum
+    }
+}
+
+/// The spectral radiance of a black body at `kelvin` for light of `wavelength` nanometers,
+/// in W/(sr·m²·nm), from Planck's law.
+pub fn planck(wavelength: f64, kelvin: f64) -> f64 {
+    // Physical constants from CODATA 2018.
+    const SPEED_OF_LIGHT: f64 = 299_792_458.0;
+    const PLANCK: f64 = 6.626_070_15e-34;
+    const BOLTZMANN: f64 = 1.380_649e-23;
+    const C1: f64 = 2.0 * PLANCK * SPEED_OF_LIGHT * SPEED_OF_LIGHT;
+    const C2: f64 = PLANCK * SPEED_OF_LIGHT / BOLTZMANN;
+
+    let meters = wavelength * 1e-9;
+    let meters5 = meters * meters * meters * meters * meters;
+    // Convert from per meter to per nanometer of wavelength.
+    C1 / (meters5 * (float::exp(C2 / (meters * kelvin)) - 1.0)) * 1e-9
+}
+
+/// The XYZ value of a black body at `kelvin`, scaled to a luminance (Y) of 1.0.
+pub(crate) fn blackbody_xyz(kelvin: f64, observer: Observer) -> XYZ {
+    let mut wavelengths = [0.0; 95];
+    let mut values = [0.0; 95];
+    for (i, (wavelength, value)) in wavelengths.iter_mut().zip(values.iter_mut()).enumerate() {
+        *wavelength = 360.0 + i as f64 * 5.0;
+        *value = planck(*wavelength, kelvin);
+    }
+    let xyz = xyz_from_spd(&wavelengths, &values, None, observer);
+    xyz * (1.0 / xyz.Y)
+}
+
+/// Coordinates in the CIE 1960 UCS, where distances from the Planckian locus are measured.
+fn uv(xyz: XYZ) -> (f64, f64) {
+    let d = xyz.X + 15.0 * xyz.Y + 3.0 * xyz.Z;
+    (4.0 * xyz.X / d, 6.0 * xyz.Y / d)
+}
+
+impl Color {
+    /// The correlated color temperature (CCT) in Kelvin of the color, and its distance (Duv)
+    /// from the Planckian locus in the CIE 1960 UCS. This is the inverse of `from_blackbody`.
+    ///
+    /// Duv is positive for colors above the locus (greenish) and negative for colors below it (pinkish).
+    /// CCT is only meaningful for colors close to the locus, with a Duv within about ±0.05.
+    /// Temperatures are found between 1000K and 100000K.
+    ///
+    /// The closest point on the locus is found by searching black body colors calculated
+    /// with the spectral module, similar to Ohno's method.
+    pub fn cct_duv(&self) -> (f64, f64) {
+        let (u, v) = uv(self.xyz());
+        let distance = |kelvin: f64| {
+            let (lu, lv) = uv(blackbody_xyz(kelvin, Observer::TwoDegrees));
+            let (du, dv) = (u - lu, v - lv);
+            du * du + dv * dv
+        };
+
+        // Find the closest of a set of temperatures spaced evenly in reciprocal temperature,
+        // which is roughly even along the locus.
+        const STEPS: usize = 64;
+        let (min_mired, max_mired) = (10.0, 1000.0);
+        let kelvin_at =
+            |i: usize| 1e6 / (max_mired - (max_mired - min_mired) * i as f64 / (STEPS - 1) as f64);
+        let closest = (0..STEPS)
+            .map(|i| (i, distance(kelvin_at(i))))
+            .min_by(|a, b| a.1.total_cmp(&b.1))
+            .map(|(i, _)| i)
+            .unwrap();
+
+        // Then refine with a golden section search between its neighbors.
+        let mut low = kelvin_at(closest.saturating_sub(1));
+        let mut high = kelvin_at((closest + 1).min(STEPS - 1));
+        const INVERSE_GOLDEN_RATIO: f64 = 0.618_033_988_749_895;
+        for _ in 0..40 {
+            let a = high - (high - low) * INVERSE_GOLDEN_RATIO;
+            let b = low + (high - low) * INVERSE_GOLDEN_RATIO;
+            if distance(a) < distance(b) {
+                high = b;
+            } else {
+                low = a;
+            }
+        }
+        let cct = (low + high) / 2.0;
+
+        let (_, locus_v) = uv(blackbody_xyz(cct, Observer::TwoDegrees));
+        let duv = float::sqrt(distance(cct));
+        (cct, if v < locus_v { -duv } else { duv })
+    }
+
+    /// The color of a black body (Planckian radiator) at `kelvin`, with a luminance (Y) of 1.0.
+    /// Incandescent lights and stars are close to black bodies.
+    ///
+    /// The color isn't adapted to any white point, so it's how the light appears next
+    /// to white (D50) light. For example 5000K appears white and 2700K appears orange.
+    /// Use `ChromaticAdaptation` to find how objects appear when the light is the only light.
+    pub fn from_blackbody(kelvin: f64) -> Color {
+        let xyz = blackbody_xyz(kelvin, Observer::TwoDegrees);
+        Color::new_xyza(xyz.X, xyz.Y, xyz.Z, 1.0)
+    }
+}
+
+/// The wavelengths, in nanometers, of the values returned by `d_series_spd`.
+/// These are 300 to 830 nanometers in 10 nanometer steps.
+pub const D_SERIES_WAVELENGTHS: [f64; 54] = {
+    let mut wavelengths = [0.0; 54];
+    let mut i = 0;
+    while i < 54 {
+        wavelengths[i] = 300.0 + 10.0 * i as f64;
+        i += 1;
+    }
+    wavelengths
+};
+
+/// The spectral power distribution of the CIE D-series (daylight) illuminant with a
+/// correlated color temperature of `kelvin`, sampled at `D_SERIES_WAVELENGTHS`.
+/// Values are relative and scaled to 100.0 at 560 nanometers.
+///
+/// The temperature is clamped to 4000K to 25000K, the range the D-series is defined for.
+/// The standard illuminants use slightly different temperatures than their names suggest
+/// because of a change to a physical constant: D50 is 5003K and D65 is 6504K.
+///
+/// The spectrum is a combination of the S0, S1, and S2 basis functions:
+/// https://en.wikipedia.org/wiki/Standard_illuminant#Computation
+pub fn d_series_spd(kelvin: f64) -> [f64; 54] {
+    let chromaticity = XYZ::from_cct(kelvin.clamp(4000.0, 25000.0)).to_chromaticity();
+    let (x, y) = (chromaticity.x, chromaticity.y);
+    let m = 0.0241 + 0.2562 * x - 0.7341 * y;
+    let m1 = (-1.3515 - 1.7703 * x + 5.9114 * y) / m;
+    let m2 = (0.0300 - 31.4424 * x + 30.0717 * y) / m;
+    D_SERIES_BASIS.map(|[s0, s1, s2]| s0 + m1 * s1 + m2 * s2)
+}
+
+/// The white point of the CIE D-series illuminant with a correlated color temperature of `kelvin`,
+/// calculated from its spectrum. Unlike `XYZ::from_cct` this works for the 10 degree observer.
+pub fn d_series_white_point(kelvin: f64, observer: Observer) -> Chromaticity {
+    // The CIE recommends linearly interpolating the spectrum to 5 nanometer steps
+    // to match the color matching functions.
+    let spd = d_series_spd(kelvin);
+    let mut wavelengths = [0.0; 107];
+    let mut values = [0.0; 107];
+    for i in 0..107 {
+        wavelengths[i] = 300.0 + 5.0 * i as f64;
+        values[i] = if i % 2 == 0 {
+            spd[i / 2]
+        } else {
+            (spd[i / 2] + spd[i / 2 + 1]) / 2.0
+        };
+    }
+    xyz_from_spd(&wavelengths, &values, None, observer).to_chromaticity()
+}
+
+/// The S0, S1, and S2 basis functions of the D-series illuminants from 300 to 830 nanometers
+/// in 10 nanometer steps.
+#[rustfmt::skip]
+const D_SERIES_BASIS: [[f64; 3]; 54] = [
+    [0.04, 0.02, 0.00],
+    [6.0, 4.5, 2.0],
+    [29.6, 22.4, 4.0],
+    [55.3, 42.0, 8.5],
+    [57.3, 40.6, 7.8],
+    [61.8, 41.6, 6.7],
+    [61.5, 38.0, 5.3],
+    [68.8, 42.4, 6.1],
+    [63.4, 38.5, 3.0],
+    [65.8, 35.0, 1.2],
+    [94.8, 43.4, -1.1],
+    [104.8, 46.3, -0.5],
+    [105.9, 43.9, -0.7],
+    [96.8, 37.1, -1.2],
+    [113.9, 36.7, -2.6],
+    [125.6, 35.9, -2.9],
+    [125.5, 32.6, -2.8],
+    [121.3, 27.9, -2.6],
+    [121.3, 24.3, -2.6],
+    [113.5, 20.1, -1.8],
+    [113.1, 16.2, -1.5],
+    [110.8, 13.2, -1.3],
+    [106.5, 8.6, -1.2],
+    [108.8, 6.1, -1.0],
+    [105.3, 4.2, -0.5],
+    [104.4, 1.9, -0.3],
+    [100.0, 0.0, 0.0],
+    [96.0, -1.6, 0.2],
+    [95.1, -3.5, 0.5],
+    [89.1, -3.5, 2.1],
+    [90.5, -5.8, 3.2],
+    [90.3, -7.2, 4.1],
+    [88.4, -8.6, 4.7],
+    [84.0, -9.5, 5.1],
+    [85.1, -10.9, 6.7],
+    [81.9, -10.7, 7.3],
+    [82.6, -12.0, 8.6],
+    [84.9, -14.0, 9.8],
+    [81.3, -13.6, 10.2],
+    [71.9, -12.0, 8.3],
+    [74.3, -13.3, 9.6],
+    [76.4, -12.9, 8.5],
+    [63.3, -10.6, 7.0],
+    [71.7, -11.6, 7.6],
+    [77.0, -12.2, 8.0],
+    [65.2, -10.2, 6.7],
+    [47.7, -7.8, 5.2],
+    [68.6, -11.2, 7.4],
+    [65.0, -10.4, 6.8],
+    [66.0, -10.6, 7.0],
+    [61.0, -9.7, 6.4],
+    [53.3, -8.3, 5.5],
+    [58.9, -9.3, 6.1],
+    [61.9, -9.8, 6.5],
+];
+
+/// The results of `color_rendering_index`.
+#[derive(Debug, Clone)]
+pub struct ColorRendering {
+    /// The general color rendering index (Ra): the average of the first eight special indices.
+    /// If fewer than eight samples were provided, the average of all of them.
+    pub general: f64,
+    /// The special color rendering index (Ri) of each test sample, in the order provided.
+    /// With the standard CIE test color samples the ninth (R9, strong red) is often quoted with Ra.
+    pub special: Vec<f64>,
+}
+
+/// Calculates the CIE 1995 color rendering index (CRI) of a light source, which measures
+/// how faithfully it shows the colors of objects compared to a reference light
+/// (a black body or D-series illuminant) of the same correlated color temperature.
+/// A light that matches its reference scores 100.0.
+///
+/// `wavelengths` and `spd` describe the light source. `samples` are the reflectances of the
+/// test colors sampled at the same wavelengths. For the standard Ra and R9 pass `TEST_COLOR_SAMPLES`
+/// with a spectrum sampled at `SAMPLE_WAVELENGTHS`.
+///
+/// The calculation follows CIE 13.3: the colors are compared in the CIE 1964 U*V*W* space after
+/// a von Kries adaptation in the CIE 1960 UCS.
+///
+/// Returns `Error::NotEnoughSamples` if `samples` is empty.
+pub fn color_rendering_index(
+    wavelengths: &[f64],
+    spd: &[f64],
+    samples: &[&[f64]],
+) -> Result<ColorRendering, Error> {
+    if samples.is_empty() {
+        return Err(Error::NotEnoughSamples);
+    }
+    let cct = source_cct(wavelengths, spd);
+    let reference: Vec<f64> = if cct < 5000.0 {
+        wavelengths.iter().map(|w| planck(*w, cct)).collect()
+    } else {
+        d_series_at(wavelengths, cct)
+    };
+
+    // The chromaticity, in the CIE 1960 UCS, and the luminance (scaled so white is 100.0)
+    // of each sample under a light.
+    let measure = |light: &[f64]| {
+        let white = xyz_from_spd(wavelengths, light, None, Observer::TwoDegrees);
+        let (u, v) = uv(white);
+        let samples: Vec<(f64, f64, f64)> = samples
+            .iter()
+            .map(|reflectance| {
+                let xyz = xyz_from_spd(wavelengths, reflectance, Some(light), Observer::TwoDegrees);
+                let (u, v) = uv(xyz);
+                (u, v, xyz.Y * 100.0)
+            })
+            .collect();
+        ((u, v), samples)
+    };
+    let ((uk, vk), test) = measure(spd);
+    let ((ur, vr), reference) = measure(&reference);
+
+    let c = |u: f64, v: f64| (4.0 - u - 10.0 * v) / v;
+    let d = |u: f64, v: f64| (1.708 * v + 0.404 - 1.481 * u) / v;
+    let (ck, dk) = (c(uk, vk), d(uk, vk));
+    let (cr, dr) = (c(ur, vr), d(ur, vr));
+
+    // The CIE 1964 U*V*W* coordinates relative to the reference white.
+    let uvw = |u: f64, v: f64, y: f64| {
+        let w = 25.0 * cbrt(y) - 17.0;
+        (13.0 * w * (u - ur), 13.0 * w * (v - vr), w)
+    };
+
+    let special: Vec<f64> = test
+        .iter()
+        .zip(reference.iter())
+        .map(|(&(u, v, y), &(ru, rv, ry))| {
+            // Adapt the sample under the test light to the reference white.
+            let (ci, di) = (c(u, v), d(u, v));
+            let denominator = 16.518 + 1.481 * (cr / ck) * ci - (dr / dk) * di;
+            let u = (10.872 + 0.404 * (cr / ck) * ci - 4.0 * (dr / dk) * di) / denominator;
+            let v = 5.520 / denominator;
+
+            let (u1, v1, w1) = uvw(u, v, y);
+            let (u2, v2, w2) = uvw(ru, rv, ry);
+            let delta_e =
+                float::sqrt((u1 - u2) * (u1 - u2) + (v1 - v2) * (v1 - v2) + (w1 - w2) * (w1 - w2));
+            100.0 - 4.6 * delta_e
+        })
+        .collect();
+
+    let general_count = special.len().min(8);
+    let general = special[..general_count].iter().sum::<f64>() / general_count as f64;
+    Ok(ColorRendering { general, special })
+}
+
+/// The results of `tm30`.
+#[derive(Debug, Clone)]
+pub struct Tm30 {
+    /// The fidelity index (Rf) from 0.0 to 100.0: how closely the colors of all samples
+    /// match their colors under the reference illuminant.
+    pub fidelity: f64,
+    /// The gamut index (Rg): the area enclosed by the average colors of the 16 hue bins
+    /// relative to the reference illuminant's. Above 100.0 the light is more saturated
+    /// than the reference on average and below 100.0 it's less saturated.
+    pub gamut: f64,
+    /// The fidelity (Rf,CESi) of each sample, in the order provided.
+    pub sample_fidelity: Vec<f64>,
+}
+
+/// Calculates the ANSI/IES TM-30-20 fidelity (Rf) and gamut (Rg) indices of a light source,
+/// which compare the colors of samples under it to their colors under a reference illuminant
+/// of the same correlated color temperature.
+///
+/// `wavelengths` and `spd` describe the light source. `samples` are the reflectances of the
+/// samples at the same wavelengths. For the standard Rf and Rg pass `COLOR_EVALUATION_SAMPLES`
+/// with a spectrum sampled at `SAMPLE_WAVELENGTHS`.
+///
+/// The reference is a black body below 4000K, a D-series illuminant above 5000K,
+/// and a blend of the two in between. Colors are calculated with the CIE 1964 10 degree observer
+/// and compared in CAM02-UCS.
+///
+/// Returns `Error::NotEnoughSamples` unless each of the 16 hue bins, by the samples' hue
+/// under the reference illuminant, contains at least one sample.
+pub fn tm30(wavelengths: &[f64], spd: &[f64], samples: &[&[f64]]) -> Result<Tm30, Error> {
+    const BINS: usize = 16;
+    let cct = source_cct(wavelengths, spd);
+    let normalized = |spd: Vec<f64>| {
+        let y = xyz_from_spd(wavelengths, &spd, None, Observer::TenDegrees).Y;
+        spd.into_iter().map(|v| v / y).collect::<Vec<f64>>()
+    };
+    let planckian = || normalized(wavelengths.iter().map(|w| planck(*w, cct)).collect());
+    let reference = if cct < 4000.0 {
+        planckian()
+    } else if cct > 5000.0 {
+        d_series_at(wavelengths, cct)
+    } else {
+        let t = (cct - 4000.0) / 1000.0;
+        planckian()
+            .iter()
+            .zip(normalized(d_series_at(wavelengths, cct)).iter())
+            .map(|(p, d)| p * (1.0 - t) + d * t)
+            .collect()
+    };
+
+    // The CAM02-UCS coordinates of each sample under a light.
+    let measure = |light: &[f64]| {
+        let white = xyz_from_spd(wavelengths, light, None, Observer::TenDegrees);
+        let white = white * (100.0 / white.Y);
+        let cam = Cam02::new(white);
+        samples
+            .iter()
+            .map(|reflectance| {
+                let xyz = xyz_from_spd(wavelengths, reflectance, Some(light), Observer::TenDegrees);
+                cam.ucs(xyz * 100.0)
+            })
+            .collect::<Vec<[f64; 3]>>()
+    };
+    let test = measure(spd);
+    let reference = measure(&reference);
+
+    // The scaling factor for the CIE 1964 10 degree observer from TM-30-18 onwards.
+    let fidelity =
+        |delta_e: f64| 10.0 * float::ln(float::exp((100.0 - 6.73 * delta_e) / 10.0) + 1.0);
+    let delta_e: Vec<f64> = test
+        .iter()
+        .zip(reference.iter())
+        .map(|(t, r)| {
+            float::sqrt(
+                (t[0] - r[0]) * (t[0] - r[0])
+                    + (t[1] - r[1]) * (t[1] - r[1])
+                    + (t[2] - r[2]) * (t[2] - r[2]),
+            )
+        })
+        .collect();
+
+    // Average the a' and b' coordinates of the samples in each hue bin.
+    let mut test_bins = [[0.0; 2]; BINS];
+    let mut reference_bins = [[0.0; 2]; BINS];
+    let mut counts = [0; BINS];
+    for (t, r) in test.iter().zip(reference.iter()) {
+        let hue = float::atan2(r[2], r[1]).to_degrees();
+        let hue = if hue < 0.0 { hue + 360.0 } else { hue };
+        let bin = ((hue / (360.0 / BINS as f64)) as usize).min(BINS - 1);
+        test_bins[bin] = [test_bins[bin][0] + t[1], test_bins[bin][1] + t[2]];
+        reference_bins[bin] = [reference_bins[bin][0] + r[1], reference_bins[bin][1] + r[2]];
+        counts[bin] += 1;
+    }
+    if counts.contains(&0) {
+        return Err(Error::NotEnoughSamples);
+    }
+    for ((t, r), count) in test_bins
+        .iter_mut()
+        .zip(reference_bins.iter_mut())
+        .zip(counts.iter())
+    {
+        *t = t.map(|v| v / *count as f64);
+        *r = r.map(|v| v / *count as f64);
+    }
+
+    let mean = delta_e.iter().sum::<f64>() / delta_e.len() as f64;
+    Ok(Tm30 {
+        fidelity: fidelity(mean),
+        gamut: 100.0 * polygon_area(&test_bins) / polygon_area(&reference_bins),
+        sample_fidelity: delta_e.into_iter().map(fidelity).collect(),
+    })
+}
+
+/// The area of a polygon, from the shoelace formula.
+fn polygon_area(points: &[[f64; 2]]) -> f64 {
+    (0..points.len())
+        .map(|i| {
+            let (a, b) = (points[i], points[(i + 1) % points.len()]);
+            a[0] * b[1] - b[0] * a[1]
+        })
+        .sum::<f64>()
+        / 2.0
+}
+
+/// The correlated color temperature of a light source, from the CIE 1931 2 degree observer.
+fn source_cct(wavelengths: &[f64], spd: &[f64]) -> f64 {
+    let white = xyz_from_spd(wavelengths, spd, None, Observer::TwoDegrees);
+    Color::new_xyza(white.X, white.Y, white.Z, 1.0).cct_duv().0
+}
+
+/// The D-series spectrum of `kelvin`, interpolated to `wavelengths`.
+fn d_series_at(wavelengths: &[f64], kelvin: f64) -> Vec<f64> {
+    let d_series = d_series_spd(kelvin);
+    wavelengths
+        .iter()
+        .map(|w| {
+            let position = ((w - 300.0) / 10.0).clamp(0.0, 53.0);
+            let i = (position as usize).min(52);
+            let t = position - i as f64;
+            d_series[i] * (1.0 - t) + d_series[i + 1] * t
+        })
+        .collect()
+}
+
+/// The CIECAM02 color appearance model with the viewing conditions used by TM-30:
+/// an adapting luminance of 100 cd/m², a background luminance factor of 20,
+/// an average surround, and complete adaptation to the white point.
+/// https://en.wikipedia.org/wiki/CIECAM02
+struct Cam02 {
+    white: Vector3,
+    luminance_adaptation: f64,
+    n: f64,
+    z: f64,
+    nbb: f64,
+    achromatic_white: f64,
+}
+
+impl Cam02 {
+    const ADAPTING_LUMINANCE: f64 = 100.0;
+    const BACKGROUND: f64 = 20.0;
+    const SURROUND_C: f64 = 0.69;
+    const SURROUND_NC: f64 = 1.0;
+
+    /// `white` is scaled so Y is 100.0.
+    fn new(white: XYZ) -> Self {
+        let k = 1.0 / (5.0 * Self::ADAPTING_LUMINANCE + 1.0);
+        let k4 = k * k * k * k;
+        let luminance_adaptation = 0.2 * k4 * (5.0 * Self::ADAPTING_LUMINANCE)
+            + 0.1 * (1.0 - k4) * (1.0 - k4) * cbrt(5.0 * Self::ADAPTING_LUMINANCE);
+        let n = Self::BACKGROUND / white.Y;
+        let mut cam = Self {
+            white: ChromaticAdaptationMethod::Cat02.matrices().0
+                * Vector3::new(white.X, white.Y, white.Z),
+            luminance_adaptation,
+            n,
+            z: 1.48 + float::sqrt(n),
+            nbb: 0.725 * float::powf(1.0 / n, 0.2),
+            achromatic_white: 0.0,
+        };
+        cam.achromatic_white = cam.achromatic(cam.compressed_cone_response(white).0);
+        cam
+    }
+
+    /// The post-adaptation cone responses and their compression, then the red-green (a)
+    /// and yellow-blue (b) opponent values.
+    fn compressed_cone_response(&self, xyz: XYZ) -> ([f64; 3], f64, f64) {
+        let (cat02, cat02_inverse) = ChromaticAdaptationMethod::Cat02.matrices();
+        let rgb = cat02 * Vector3::new(xyz.X, xyz.Y, xyz.Z);
+        let adapted = Vector3::new(
+            rgb.x * 100.0 / self.white.x,
+            rgb.y * 100.0 / self.white.y,
+            rgb.z * 100.0 / self.white.z,
+        );
+        let hunt_pointer_estevez = Matrix3x3 {
+            c0: Vector3::new(0.38971, -0.22981, 0.0),
+            c1: Vector3::new(0.68898, 1.18340, 0.0),
+            c2: Vector3::new(-0.07868, 0.04641, 1.0),
+        };
+        let cones = hunt_pointer_estevez * (cat02_inverse * adapted);
+        let compress = |c: f64| {
+            let p = float::powf(self.luminance_adaptation * c.abs() / 100.0, 0.42);
+            c.signum() * 400.0 * p / (p + 27.13) + 0.1
+        };
+        let [r, g, b] = [compress(cones.x), compress(cones.y), compress(cones.z)];
+        (
+            [r, g, b],
+            r - 12.0 * g / 11.0 + b / 11.0,
+            (r + g - 2.0 * b) / 9.0,
+        )
+    }
+
+    fn achromatic(&self, [r, g, b]: [f64; 3]) -> f64 {
+        (2.0 * r + g + b / 20.0 - 0.305) * self.nbb
+    }
+
+    /// The CAM02-UCS coordinates (J', a', b') of `xyz`, scaled so the white point's Y is 100.0.
+    fn ucs(&self, xyz: XYZ) -> [f64; 3] {
+        let (rgb, a, b) = self.compressed_cone_response(xyz);
+        let hue = float::atan2(b, a);
+        let lightness = 100.0
+            * float::powf(
+                (self.achromatic(rgb) / self.achromatic_white).max(0.0),
+                Self::SURROUND_C * self.z,
+            );
+        let eccentricity = 0.25 * (float::cos(hue + 2.0) + 3.8);
+        let t = (50000.0 / 13.0
+            * Self::SURROUND_NC
+            * self.nbb
+            * eccentricity
+            * float::sqrt(a * a + b * b))
+            / (rgb[0] + rgb[1] + 21.0 / 20.0 * rgb[2]);
+        let chroma = float::powf(t, 0.9)
+            * float::sqrt(lightness / 100.0)
+            * float::powf(1.64 - float::powf(0.29, self.n), 0.73);
+        let colorfulness = chroma * float::powf(self.luminance_adaptation, 0.25);
+
+        let j = 1.7 * lightness / (1.0 + 0.007 * lightness);
+        let m = float::ln(1.0 + 0.0228 * colorfulness) / 0.0228;
+        [j, m * float::cos(hue), m * float::sin(hue)]
+    }
+}
+
+/// A smooth reflectance spectrum described by three coefficients, as in
+/// "A Low-Dimensional Function Space for Efficient Spectral Upsampling" by Jakob and Hanika.
+/// Reflectances are always between 0.0 and 1.0, so they're physically plausible.
+///
+/// Created with `SigmoidSpectrum::from_color`.
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct SigmoidSpectrum {
+    pub coefficients: [f64; 3],
+}
+
+impl SigmoidSpectrum {
+    /// Finds a smooth reflectance spectrum with the color `color` when lit by D50 light,
+    /// which is the white point of `Color`. This lets spectral renderers use colors as inputs.
+    ///
+    /// The coefficients are found with the Gauss-Newton method, minimizing the CIELAB difference.
+    /// Colors that no reflectance can produce, like colors brighter than white or some
+    /// very saturated colors, get the closest spectrum that could be found.
+    pub fn from_color(color: &Color) -> Self {
+        let target = color.to_lab();
+        let target = [target.L, target.a, target.b];
+        let residual = |coefficients: [f64; 3]| {
+            let lab = SigmoidSpectrum { coefficients }.to_color().to_lab();
+            [lab.L - target[0], lab.a - target[1], lab.b - target[2]]
+        };
+
+        let mut coefficients = [0.0; 3];
+        for _ in 0..64 {
+            let r = residual(coefficients);
+            if r.iter().map(|v| v * v).sum::<f64>() < 1e-12 {
+                break;
+            }
+
+            // The Jacobian, from finite differences.
+            const H: f64 = 1e-5;
+            let mut jacobian = [[0.0; 3]; 3];
+            for j in 0..3 {
+                let mut shifted = coefficients;
+                shifted[j] += H;
+                let shifted = residual(shifted);
+                for i in 0..3 {
+                    jacobian[i][j] = (shifted[i] - r[i]) / H;
+                }
+            }
+
+            // Solve the damped normal equations (JᵀJ + λI) Δ = -Jᵀr.
+            let mut normal = [[0.0; 3]; 3];
+            let mut gradient = [0.0; 3];
+            for i in 0..3 {
+                for j in 0..3 {
+                    normal[i][j] = (0..3).map(|k| jacobian[k][i] * jacobian[k][j]).sum();
+                }
+                normal[i][i] += 1e-6;
+                gradient[i] = -(0..3).map(|k| jacobian[k][i] * r[k]).sum::<f64>();
+            }
+            let normal = Matrix3x3::from_columns(
+                Vector3::new(normal[0][0], normal[1][0], normal[2][0]),
+                Vector3::new(normal[0][1], normal[1][1], normal[2][1]),
+                Vector3::new(normal[0][2], normal[1][2], normal[2][2]),
+            );
+            let step = normal.inverse() * Vector3::new(gradient[0], gradient[1], gradient[2]);
+            if !(step.x.is_finite() && step.y.is_finite() && step.z.is_finite()) {
+                break;
+            }
+
+            // Take a step, halving it until it improves the fit.
+            let error = |r: [f64; 3]| r.iter().map(|v| v * v).sum::<f64>();
+            let current = error(r);
+            let mut scale = 1.0;
+            loop {
+                let candidate = [
+                    coefficients[0] + step.x * scale,
+                    coefficients[1] + step.y * scale,
+                    coefficients[2] + step.z * scale,
+                ];
+                if error(residual(candidate)) < current {
+                    coefficients = candidate;
+                    break;
+                }
+                scale /= 2.0;
+                if scale < 1e-4 {
+                    return SigmoidSpectrum { coefficients };
+                }
+            }
+        }
+        SigmoidSpectrum { coefficients }
+    }
+
+    /// The reflectance, from 0.0 to 1.0, at `wavelength` nanometers.
+    pub fn reflectance(&self, wavelength: f64) -> f64 {
+        // Wavelengths are scaled to 0.0 to 1.0 over the visible range to keep the
+        // coefficients a reasonable size.
+        let t = (wavelength - 380.0) / 400.0;
+        let [c0, c1, c2] = self.coefficients;
+        let x = (c0 * t + c1) * t + c2;
+        0.5 + x / (2.0 * float::sqrt(1.0 + x * x))
+    }
+
+    /// The color of the spectrum when lit by D50 light.
+    pub fn to_color(&self) -> Color {
+        let (wavelengths, d50) = d50_spd_5nm();
+        let mut reflectances = [0.0; 81];
+        for (reflectance, wavelength) in reflectances.iter_mut().zip(wavelengths.iter()) {
+            *reflectance = self.reflectance(*wavelength);
+        }
+        let xyz = xyz_from_spd(
+            &wavelengths,
+            &reflectances,
+            Some(&d50),
+            Observer::TwoDegrees,
+        );
+        // Correct for the small difference between the calculated and standard D50 white points.
+        let white = xyz_from_spd(&wavelengths, &[1.0; 81], Some(&d50), Observer::TwoDegrees);
+        let d50_white = crate::white_points::D50_WHITE_POINT_2DEGREES.to_XYZ();
+        Color::new_xyza(
+            xyz.X * d50_white.X / white.X,
+            xyz.Y * d50_white.Y / white.Y,
+            xyz.Z * d50_white.Z / white.Z,
+            1.0,
+        )
+    }
+}
+
+/// The D50 spectrum interpolated to 5 nanometer steps from 380 to 780 nanometers.
+fn d50_spd_5nm() -> ([f64; 81], [f64; 81]) {
+    let spd = d_series_spd(5003.0);
+    let mut wavelengths = [0.0; 81];
+    let mut values = [0.0; 81];
+    for i in 0..81 {
+        wavelengths[i] = 380.0 + 5.0 * i as f64;
+        // 380 nanometers is index 8 of the D-series spectrum.
+        let j = 8 + i / 2;
+        values[i] = if i % 2 == 0 {
+            spd[j]
+        } else {
+            (spd[j] + spd[j + 1]) / 2.0
+        };
+    }
+    (wavelengths, values)
+}
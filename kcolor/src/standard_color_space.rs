@@ -0,0 +1,145 @@
+use crate::hdr::REC2020_PRIMARIES;
+use crate::white_points::*;
+use crate::*;
+
+// A pure gamma of 563 / 256.
+pub(crate) const ADOBE_RGB_TRANSFER_FUNCTION: TransferFunction =
+    TransferFunction::ParametricCurve(ParametricCurve::Function3 {
+        gamma: 563.0 / 256.0,
+        a: 1.0,
+        b: 0.0,
+        c: 1.0,
+        d: 0.0,
+    });
+
+// The BT.2020 (and BT.709) transfer function.
+pub(crate) const REC2020_TRANSFER_FUNCTION: TransferFunction =
+    TransferFunction::ParametricCurve(ParametricCurve::Function3 {
+        gamma: 1.0 / 0.45,
+        a: 1.0 / 1.099,
+        b: 0.099 / 1.099,
+        c: 1.0 / 4.5,
+        d: 0.081,
+    });
+
+/// The color spaces built into kcolor, so they can be selected by name.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum StandardColorSpace {
+    Srgb,
+    SrgbLinear,
+    /// Apple's Display P3: DCI-P3 primaries with a D65 white point and the sRGB transfer function.
+    DisplayP3,
+    /// Adobe RGB (1998).
+    AdobeRgb,
+    /// ITU-R BT.2020, used for UHD video, with the BT.2020 (and BT.709) transfer function.
+    Rec2020,
+    /// ITU-R BT.2100 with the PQ transfer function and `HDR_REFERENCE_WHITE` as 1.0.
+    Rec2100Pq,
+    /// ITU-R BT.2100 with the HLG transfer function.
+    Rec2100Hlg,
+    /// The linear ACEScg working space used by visual effects and animation.
+    AcesCg,
+}
+
+impl StandardColorSpace {
+    pub const ALL: [StandardColorSpace; 8] = [
+        StandardColorSpace::Srgb,
+        StandardColorSpace::SrgbLinear,
+        StandardColorSpace::DisplayP3,
+        StandardColorSpace::AdobeRgb,
+        StandardColorSpace::Rec2020,
+        StandardColorSpace::Rec2100Pq,
+        StandardColorSpace::Rec2100Hlg,
+        StandardColorSpace::AcesCg,
+    ];
+
+    /// The name of the color space. Where CSS defines one (in CSS Color 4) the CSS name is used.
+    pub fn name(&self) -> &'static str {
+        match self {
+            StandardColorSpace::Srgb => "srgb",
+            StandardColorSpace::SrgbLinear => "srgb-linear",
+            StandardColorSpace::DisplayP3 => "display-p3",
+            StandardColorSpace::AdobeRgb => "a98-rgb",
+            StandardColorSpace::Rec2020 => "rec2020",
+            StandardColorSpace::Rec2100Pq => "rec2100-pq",
+            StandardColorSpace::Rec2100Hlg => "rec2100-hlg",
+            StandardColorSpace::AcesCg => "acescg",
+        }
+    }
+
+    /// Finds a color space by its name or a common alias, ignoring case
+    /// and treating spaces, underscores, and hyphens the same.
+    pub fn from_name(name: &str) -> Option<Self> {
+        let mut normalized = [0u8; 32];
+        let name = name.trim();
+        if name.len() > normalized.len() {
+            return None;
+        }
+        for (n, c) in normalized.iter_mut().zip(name.bytes()) {
+            *n = match c {
+                b' ' | b'_' => b'-',
+                c => c.to_ascii_lowercase(),
+            };
+        }
+        let name = &normalized[..name.len()];
+
+        let standard = match name {
+            b"srgb" => StandardColorSpace::Srgb,
+            b"srgb-linear" | b"linear-srgb" => StandardColorSpace::SrgbLinear,
+            b"display-p3" | b"p3" => StandardColorSpace::DisplayP3,
+            b"a98-rgb" | b"adobe-rgb" | b"adobergb" => StandardColorSpace::AdobeRgb,
+            b"rec2020" | b"rec-2020" | b"bt2020" => StandardColorSpace::Rec2020,
+            b"rec2100-pq" | b"bt2100-pq" | b"hdr10" => StandardColorSpace::Rec2100Pq,
+            b"rec2100-hlg" | b"bt2100-hlg" | b"hlg" => StandardColorSpace::Rec2100Hlg,
+            b"acescg" | b"aces-cg" => StandardColorSpace::AcesCg,
+            _ => return None,
+        };
+        Some(standard)
+    }
+}
+
+impl ColorSpace {
+    pub fn from_standard(standard: StandardColorSpace) -> ColorSpace {
+        match standard {
+            StandardColorSpace::Srgb => ColorSpace::SRGB,
+            StandardColorSpace::SrgbLinear => ColorSpace::SRGB_LINEAR,
+            StandardColorSpace::DisplayP3 => ColorSpace::new(
+                Chromaticity::new(0.680, 0.320),
+                Chromaticity::new(0.265, 0.690),
+                Chromaticity::new(0.150, 0.060),
+                D65_WHITE_POINT_2DEGREES,
+                SRGB_TRANSFER_FUNCTION,
+            ),
+            StandardColorSpace::AdobeRgb => ColorSpace::new(
+                Chromaticity::new(0.64, 0.33),
+                Chromaticity::new(0.21, 0.71),
+                Chromaticity::new(0.15, 0.06),
+                D65_WHITE_POINT_2DEGREES,
+                ADOBE_RGB_TRANSFER_FUNCTION,
+            ),
+            StandardColorSpace::Rec2020 => ColorSpace::new(
+                REC2020_PRIMARIES[0],
+                REC2020_PRIMARIES[1],
+                REC2020_PRIMARIES[2],
+                D65_WHITE_POINT_2DEGREES,
+                REC2020_TRANSFER_FUNCTION,
+            ),
+            StandardColorSpace::Rec2100Pq => ColorSpace::rec2100_pq(HDR_REFERENCE_WHITE),
+            StandardColorSpace::Rec2100Hlg => ColorSpace::rec2100_hlg(),
+            StandardColorSpace::AcesCg => ColorSpace::new(
+                Chromaticity::new(0.713, 0.293),
+                Chromaticity::new(0.165, 0.830),
+                Chromaticity::new(0.128, 0.044),
+                D60_WHITE_POINT_2DEGREES,
+                TransferFunction::None,
+            ),
+        }
+    }
+
+    /// Finds a built-in color space by name, such as "display-p3".
+    /// See `StandardColorSpace::from_name` for the accepted names.
+    pub fn from_name(name: &str) -> Option<ColorSpace> {
+        StandardColorSpace::from_name(name).map(ColorSpace::from_standard)
+    }
+}
@@ -0,0 +1,249 @@
+//! Reference data for checking conversions, and `validate` to check a color space against it.
+//!
+//! This is useful for demonstrating that kcolor produces correct results when it's
+//! integrated into a pipeline that must be verified.
+use crate::*;
+
+/// An RGB to XYZ (D50) matrix for a standard color space, in rows.
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct MatrixReference {
+    pub color_space: StandardColorSpace,
+    pub to_xyz_d50: [[f64; 3]; 3],
+}
+
+/// Bradford adapted RGB to XYZ (D50) matrices published by Bruce Lindbloom.
+/// http://www.brucelindbloom.com/index.html?Eqn_RGB_XYZ_Matrix.html
+pub const LINDBLOOM_MATRICES: [MatrixReference; 2] = [
+    MatrixReference {
+        color_space: StandardColorSpace::Srgb,
+        to_xyz_d50: [
+            [0.4360747, 0.3850649, 0.1430804],
+            [0.2225045, 0.7168786, 0.0606169],
+            [0.0139322, 0.0971045, 0.7141733],
+        ],
+    },
+    MatrixReference {
+        color_space: StandardColorSpace::AdobeRgb,
+        to_xyz_d50: [
+            [0.6097559, 0.2052401, 0.1492240],
+            [0.3111242, 0.6256560, 0.0632197],
+            [0.0194811, 0.0608902, 0.7448387],
+        ],
+    },
+];
+
+/// An encoded sRGB color and its CIELAB (D50) value, as used by an ICC profile connection space.
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct LabReference {
+    pub srgb: [f64; 3],
+    pub lab: [f64; 3],
+}
+
+/// sRGB primaries and secondaries in CIELAB (D50), calculated with Bruce Lindbloom's
+/// color calculator using Bradford adaptation.
+pub const SRGB_LAB_REFERENCE: [LabReference; 7] = [
+    LabReference {
+        srgb: [1.0, 1.0, 1.0],
+        lab: [100.0, 0.0, 0.0],
+    },
+    LabReference {
+        srgb: [1.0, 0.0, 0.0],
+        lab: [54.2905, 80.8049, 69.8910],
+    },
+    LabReference {
+        srgb: [0.0, 1.0, 0.0],
+        lab: [87.8185, -79.2711, 80.9946],
+    },
+    LabReference {
+        srgb: [0.0, 0.0, 1.0],
+        lab: [29.5683, 68.2874, -112.0294],
+    },
+    LabReference {
+        srgb: [0.0, 1.0, 1.0],
+        lab: [90.6655, -50.6654, -14.9620],
+    },
+    LabReference {
+        srgb: [1.0, 0.0, 1.0],
+        lab: [60.1697, 93.5500, -60.4986],
+    },
+    LabReference {
+        srgb: [1.0, 1.0, 0.0],
+        lab: [97.6071, -15.7529, 93.3885],
+    },
+];
+
+/// A pair of CIELAB colors and the CIEDE2000 difference between them.
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct DeltaEReference {
+    pub a: [f64; 3],
+    pub b: [f64; 3],
+    pub delta_e: f64,
+}
+
+const fn pair(a: [f64; 3], b: [f64; 3], delta_e: f64) -> DeltaEReference {
+    DeltaEReference { a, b, delta_e }
+}
+
+/// The 34 test pairs from "The CIEDE2000 Color-Difference Formula: Implementation Notes,
+/// Supplementary Test Data, and Mathematical Observations" by Sharma, Wu, and Dalal.
+/// These cover the edge cases of the formula, like hue angles on either side of 0.
+pub const CIEDE2000_REFERENCE: [DeltaEReference; 34] = [
+    pair([50.0, 2.6772, -79.7751], [50.0, 0.0, -82.7485], 2.0425),
+    pair([50.0, 3.1571, -77.2803], [50.0, 0.0, -82.7485], 2.8615),
+    pair([50.0, 2.8361, -74.0200], [50.0, 0.0, -82.7485], 3.4412),
+    pair([50.0, -1.3802, -84.2814], [50.0, 0.0, -82.7485], 1.0000),
+    pair([50.0, -1.1848, -84.8006], [50.0, 0.0, -82.7485], 1.0000),
+    pair([50.0, -0.9009, -85.5211], [50.0, 0.0, -82.7485], 1.0000),
+    pair([50.0, 0.0, 0.0], [50.0, -1.0, 2.0], 2.3669),
+    pair([50.0, -1.0, 2.0], [50.0, 0.0, 0.0], 2.3669),
+    pair([50.0, 2.4900, -0.0010], [50.0, -2.4900, 0.0009], 7.1792),
+    pair([50.0, 2.4900, -0.0010], [50.0, -2.4900, 0.0010], 7.1792),
+    pair([50.0, 2.4900, -0.0010], [50.0, -2.4900, 0.0011], 7.2195),
+    pair([50.0, 2.4900, -0.0010], [50.0, -2.4900, 0.0012], 7.2195),
+    pair([50.0, -0.0010, 2.4900], [50.0, 0.0009, -2.4900], 4.8045),
+    pair([50.0, -0.0010, 2.4900], [50.0, 0.0010, -2.4900], 4.8045),
+    pair([50.0, -0.0010, 2.4900], [50.0, 0.0011, -2.4900], 4.7461),
+    pair([50.0, 2.5, 0.0], [50.0, 0.0, -2.5], 4.3065),
+    pair([50.0, 2.5, 0.0], [73.0, 25.0, -18.0], 27.1492),
+    pair([50.0, 2.5, 0.0], [61.0, -5.0, 29.0], 22.8977),
+    pair([50.0, 2.5, 0.0], [56.0, -27.0, -3.0], 31.9030),
+    pair([50.0, 2.5, 0.0], [58.0, 24.0, 15.0], 19.4535),
+    pair([50.0, 2.5, 0.0], [50.0, 3.1736, 0.5854], 1.0000),
+    pair([50.0, 2.5, 0.0], [50.0, 3.2972, 0.0], 1.0000),
+    pair([50.0, 2.5, 0.0], [50.0, 1.8634, 0.5757], 1.0000),
+    pair([50.0, 2.5, 0.0], [50.0, 3.2592, 0.3350], 1.0000),
+    pair(
+        [60.2574, -34.0099, 36.2677],
+        [60.4626, -34.1751, 39.4387],
+        1.2644,
+    ),
+    pair(
+        [63.0109, -31.0961, -5.8663],
+        [62.8187, -29.7946, -4.0864],
+        1.2630,
+    ),
+    pair(
+        [61.2901, 3.7196, -5.3901],
+        [61.4292, 2.2480, -4.9620],
+        1.8731,
+    ),
+    pair(
+        [35.0831, -44.1164, 3.7933],
+        [35.0232, -40.0716, 1.5901],
+        1.8645,
+    ),
+    pair(
+        [22.7233, 20.0904, -46.6940],
+        [23.0331, 14.9730, -42.5619],
+        2.0373,
+    ),
+    pair(
+        [36.4612, 47.8580, 18.3852],
+        [36.2715, 50.5065, 21.2231],
+        1.4146,
+    ),
+    pair(
+        [90.8027, -2.0831, 1.4410],
+        [91.1528, -1.6435, 0.0447],
+        1.4441,
+    ),
+    pair(
+        [90.9257, -0.5406, -0.9208],
+        [88.6381, -0.8985, -0.7239],
+        1.5381,
+    ),
+    pair(
+        [6.7747, -0.2908, -2.4247],
+        [5.8714, -0.0985, -2.2286],
+        0.6377,
+    ),
+    pair(
+        [2.0776, 0.0795, -1.1350],
+        [0.9033, -0.0636, -0.5514],
+        0.9082,
+    ),
+];
+
+/// The largest errors found by [`validate`].
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct ValidationReport {
+    /// The largest difference between an element of the color space's RGB to XYZ
+    /// matrix and the reference matrix, if there's a reference for the color space.
+    pub max_matrix_error: Option<f64>,
+    /// The largest difference between a CIELAB component and the reference,
+    /// if the color space is sRGB.
+    pub max_lab_error: Option<f64>,
+    /// The largest difference from the reference CIEDE2000 values.
+    pub max_delta_e_2000_error: f64,
+    /// The largest difference in encoded RGB values after converting
+    /// a grid of colors to XYZ and back.
+    pub max_round_trip_error: f64,
+}
+
+/// Checks conversions for `color_space` against the reference data in this module.
+/// Color spaces are matched to references with `ColorSpace::approx_eq`.
+pub fn validate(color_space: &ColorSpace) -> ValidationReport {
+    let matches = |standard: StandardColorSpace| {
+        color_space.approx_eq(&ColorSpace::from_standard(standard), 0.0001)
+    };
+
+    let max_matrix_error = LINDBLOOM_MATRICES
+        .iter()
+        .find(|reference| matches(reference.color_space))
+        .map(|reference| {
+            let m = &color_space.to_XYZ;
+            let columns = [m.c0, m.c1, m.c2];
+            let mut max_error: f64 = 0.0;
+            for (row, reference_row) in reference.to_xyz_d50.iter().enumerate() {
+                for (column, reference) in reference_row.iter().enumerate() {
+                    let value = [columns[column].x, columns[column].y, columns[column].z][row];
+                    max_error = max_error.max((value - reference).abs());
+                }
+            }
+            max_error
+        });
+
+    let max_lab_error = if matches(StandardColorSpace::Srgb) {
+        let mut max_error: f64 = 0.0;
+        for reference in SRGB_LAB_REFERENCE.iter() {
+            let [r, g, b] = reference.srgb;
+            let lab = color_space.new_color(r, g, b, 1.0).to_lab();
+            for (value, reference) in [lab.L, lab.a, lab.b].iter().zip(reference.lab.iter()) {
+                max_error = max_error.max((value - reference).abs());
+            }
+        }
+        Some(max_error)
+    } else {
+        None
+    };
+
+    let lab = |[l, a, b]: [f64; 3]| Lab::new(l, a, b);
+    let max_delta_e_2000_error = CIEDE2000_REFERENCE
+        .iter()
+        .map(|reference| {
+            (lab(reference.a).delta_e_2000(&lab(reference.b)) - reference.delta_e).abs()
+        })
+        .fold(0.0, f64::max);
+
+    let mut max_round_trip_error: f64 = 0.0;
+    const STEPS: usize = 8;
+    for r in 0..=STEPS {
+        for g in 0..=STEPS {
+            for b in 0..=STEPS {
+                let rgb = [r, g, b].map(|v| v as f64 / STEPS as f64);
+                let color = color_space.new_color(rgb[0], rgb[1], rgb[2], 1.0);
+                let (r, g, b, _) = color_space.color_to_rgba_unclipped(&color);
+                for (value, reference) in [r, g, b].iter().zip(rgb.iter()) {
+                    max_round_trip_error = max_round_trip_error.max((value - reference).abs());
+                }
+            }
+        }
+    }
+
+    ValidationReport {
+        max_matrix_error,
+        max_lab_error,
+        max_delta_e_2000_error,
+        max_round_trip_error,
+    }
+}
@@ -171,3 +171,3004 @@ fn color_space_from_icc_profile() {
     println!("color space: {:?}", srgb);
     println!("color: {:?}", color);
 }
+
+// Tests loading a V2 profile with sampled `curv` curves and no chromatic adaptation tag.
+#[test]
+fn icc_profile_sampled_curves() {
+    let bytes =
+        std::fs::read("../icc_parser/examples/sRGB Profile.icc").expect("Could not find file");
+    let srgb = ColorSpace::from_icc_profile(&bytes).unwrap();
+    for rgb in [(0.5, 0.0, 0.0), (0.2, 0.6, 0.9), (0.02, 0.01, 0.03)] {
+        let color = srgb.new_color(rgb.0, rgb.1, rgb.2, 1.0);
+        println!("{:?} {:?}", rgb, color.to_srgb());
+        assert!(color.approx_eq(&Color::new_srgb(rgb.0, rgb.1, rgb.2, 1.0), 0.002));
+        let (r, g, b, _) = srgb.color_to_rgba(&color);
+        assert!(
+            (r - rgb.0).abs() < 0.002 && (g - rgb.1).abs() < 0.002 && (b - rgb.2).abs() < 0.002
+        );
+    }
+}
+
+// Tests that truncated or corrupted ICC profiles return errors instead of panicking.
+#[test]
+fn malformed_icc_profile() {
+    let bytes = std::fs::read("../icc_parser/examples/sRGB-v4.icc").expect("Could not find file");
+    for length in 0..bytes.len() {
+        assert!(ColorSpace::from_icc_profile(&bytes[..length]).is_err());
+    }
+
+    // Point every tag past the end of the profile and make its size huge.
+    let tag_count = u32::from_be_bytes([bytes[128], bytes[129], bytes[130], bytes[131]]) as usize;
+    let mut corrupted = bytes.clone();
+    for i in 0..tag_count {
+        let entry = 132 + i * 12;
+        corrupted[entry + 4..entry + 12].copy_from_slice(&[0xFF; 8]);
+    }
+    assert!(ColorSpace::from_icc_profile(&corrupted).is_err());
+
+    // Claim an enormous tag count. Tag entries past the end of the profile are ignored.
+    let mut corrupted = bytes;
+    corrupted[128..132].copy_from_slice(&[0xFF; 4]);
+    assert!(ColorSpace::from_icc_profile(&corrupted).is_ok());
+}
+
+// Tests applying the video card gamma (vcgt) curves of a display profile.
+#[test]
+fn icc_video_card_gamma() {
+    let mut bytes =
+        std::fs::read("../icc_parser/examples/sRGB-v4.icc").expect("Could not find file");
+    // Replace the copyright tag with a single channel vcgt table that halves values.
+    let mut vcgt = b"vcgt".to_vec();
+    vcgt.extend_from_slice(&[0, 0, 0, 0, 0, 0, 0, 0]);
+    for value in [1u16, 2, 2, 0, 32768] {
+        vcgt.extend_from_slice(&value.to_be_bytes());
+    }
+    let entry = 132 + 12;
+    assert!(&bytes[entry..entry + 4] == b"cprt");
+    bytes[entry..entry + 4].copy_from_slice(b"vcgt");
+    let offset = bytes.len() as u32;
+    bytes[entry + 4..entry + 8].copy_from_slice(&offset.to_be_bytes());
+    bytes[entry + 8..entry + 12].copy_from_slice(&(vcgt.len() as u32).to_be_bytes());
+    bytes.extend_from_slice(&vcgt);
+
+    let video_card_gamma = ColorSpace::video_card_gamma_from_icc_profile(&bytes)
+        .unwrap()
+        .unwrap();
+    println!("{:?}", video_card_gamma);
+    assert!(approx_equal_f64(video_card_gamma.apply(2, 0.5), 0.25));
+
+    let profile = ColorSpace::from_icc_profile(&bytes).unwrap();
+    let calibrated = ColorSpace::from_icc_profile_with_calibration(&bytes).unwrap();
+    let white = calibrated.new_color(1.0, 1.0, 1.0, 1.0);
+    assert!(white.approx_eq(&profile.new_color(0.5, 0.5, 0.5, 1.0), 0.0001));
+    let (r, _, _, _) = calibrated.color_to_rgba(&profile.new_color(0.25, 0.25, 0.25, 1.0));
+    assert!((r - 0.5).abs() < 0.001);
+
+    // A table shorter than its entry count, or with a single entry, is an error.
+    let tag_end = bytes.len();
+    assert!(ColorSpace::video_card_gamma_from_icc_profile(&bytes[..tag_end - 1]).is_err());
+    let count = tag_end - 8;
+    bytes[count..count + 2].copy_from_slice(&1u16.to_be_bytes());
+    assert!(ColorSpace::video_card_gamma_from_icc_profile(&bytes).is_err());
+}
+
+// Tests that the `fast-math` approximations stay within their documented error.
+#[test]
+fn fast_math() {
+    let mut max_cbrt_error: f64 = 0.0;
+    for i in -300..=300 {
+        let x = 1.7f64.powi(i);
+        let expected = x.cbrt();
+        let error = ((fast_math::cbrt(x) - expected) / expected).abs();
+        max_cbrt_error = max_cbrt_error.max(error);
+        assert!(fast_math::cbrt(-x) == -fast_math::cbrt(x));
+    }
+    println!("max_cbrt_error: {:?}", max_cbrt_error);
+    assert!(max_cbrt_error < 1e-14);
+}
+
+// Tests that a color space survives a round trip through serde
+// and is stored as primaries and a white point rather than matrices.
+#[cfg(feature = "serde")]
+#[test]
+fn serde_color_space() {
+    let json = serde_json::to_string(&ColorSpace::SRGB).unwrap();
+    println!("json: {}", json);
+    assert!(json.contains("\"white_point\":{\"x\":0.31271"));
+
+    let value: serde_json::Value = serde_json::from_str(&json).unwrap();
+    let chromaticity = |name: &str| {
+        Chromaticity::new(
+            value[name]["x"].as_f64().unwrap(),
+            value[name]["y"].as_f64().unwrap(),
+        )
+    };
+    let red = chromaticity("red_primary");
+    let green = chromaticity("green_primary");
+    let blue = chromaticity("blue_primary");
+    assert!(approx_equal_f64(red.x, 0.64) && approx_equal_f64(red.y, 0.33));
+    assert!(approx_equal_f64(green.x, 0.3) && approx_equal_f64(green.y, 0.6));
+    assert!(approx_equal_f64(blue.x, 0.15) && approx_equal_f64(blue.y, 0.06));
+
+    let color_space: ColorSpace = serde_json::from_str(&json).unwrap();
+
+    let color = color_space.new_color(0.2, 0.5, 0.8, 1.0);
+    assert!(approx_equal(color.to_srgb(), (0.2, 0.5, 0.8, 1.0)));
+
+    let color: Color = serde_json::from_str(&serde_json::to_string(&color).unwrap()).unwrap();
+    assert!(approx_equal(color.to_srgb(), (0.2, 0.5, 0.8, 1.0)));
+
+    // Tables too short to interpolate are rejected.
+    let lut = TransferFunction::Sampled(SRGB_TRANSFER_FUNCTION.to_lut(4));
+    let json = serde_json::to_string(&lut).unwrap();
+    assert!(serde_json::from_str::<TransferFunction>(&json).unwrap() == lut);
+    let short = r#"{"Sampled":{"decode":[0.0],"encode":[0.0,1.0]}}"#;
+    assert!(serde_json::from_str::<TransferFunction>(short).is_err());
+}
+
+// Tests that a buffer of colors can be reinterpreted as plain floats.
+#[cfg(feature = "bytemuck")]
+#[test]
+fn rgba_pod() {
+    let colors = [
+        ColorSpace::SRGB.color_to_rgba_f32(&Color::new_srgb(1.0, 0.5, 0.0, 1.0)),
+        ColorSpace::SRGB.color_to_rgba_f32(&Color::new_srgb(0.0, 0.25, 1.0, 0.5)),
+    ];
+    let floats: &[f32] = bytemuck::cast_slice(&colors);
+    assert!(floats.len() == 8);
+    assert!((floats[1] - 0.5).abs() < 0.00001);
+    assert!((floats[6] - 1.0).abs() < 0.00001);
+    assert!(floats[7] == 0.5);
+    assert!(std::mem::size_of::<Rgba<f32>>() == 16);
+}
+
+// Tests that the GPU array helpers match the sRGB and linear sRGB color spaces.
+#[test]
+fn f32_arrays() {
+    let color = Color::new_srgb(0.5, 0.25, 1.0, 0.75);
+    let encoded = color.to_rgba_f32_array(&ColorSpace::SRGB);
+    let linear = color.to_linear_f32_array(&ColorSpace::SRGB);
+    let expected_linear = color.to_linear_srgb();
+
+    assert!((encoded[0] - 0.5).abs() < 0.00001);
+    assert!((encoded[1] - 0.25).abs() < 0.00001);
+    assert!((encoded[2] - 1.0).abs() < 0.00001);
+    assert!(encoded[3] == 0.75);
+    assert!((linear[0] as f64 - expected_linear.0).abs() < 0.00001);
+    assert!((linear[1] as f64 - expected_linear.1).abs() < 0.00001);
+    assert!((linear[2] as f64 - expected_linear.2).abs() < 0.00001);
+}
+
+// Tests Oklab against the reference values for sRGB white and red:
+// https://bottosson.github.io/posts/oklab/
+#[test]
+fn oklab() {
+    let white = Color::new_srgb(1.0, 1.0, 1.0, 1.0).to_oklab();
+    println!("white: {:?}", white);
+    assert!((white.L - 1.0).abs() < 0.001);
+    assert!(white.a.abs() < 0.001 && white.b.abs() < 0.001);
+
+    let red = Color::new_srgb(1.0, 0.0, 0.0, 1.0).to_oklab();
+    println!("red: {:?}", red);
+    assert!((red.L - 0.62796).abs() < 0.001);
+    assert!((red.a - 0.22486).abs() < 0.001);
+    assert!((red.b - 0.12585).abs() < 0.001);
+
+    let color = Color::new_srgb(0.2, 0.6, 0.9, 1.0);
+    let round_trip = Color::from_oklab(color.to_oklab(), 1.0);
+    assert!(approx_equal(round_trip.to_srgb(), (0.2, 0.6, 0.9, 1.0)));
+}
+
+// Tests that the random samplers only produce in-gamut colors.
+#[cfg(feature = "rand")]
+#[test]
+fn random_colors() {
+    use rand::distr::Distribution;
+    use rand::SeedableRng;
+
+    let mut rng = rand::rngs::StdRng::seed_from_u64(0);
+    let display_p3 = ColorSpace::new(
+        Chromaticity { x: 0.68, y: 0.32 },
+        Chromaticity { x: 0.265, y: 0.69 },
+        Chromaticity { x: 0.15, y: 0.06 },
+        D65_WHITE_POINT_2DEGREES,
+        SRGB_TRANSFER_FUNCTION,
+    );
+    let in_gamut = |(r, g, b, _): (f64, f64, f64, f64)| {
+        [r, g, b].iter().all(|v| *v > -0.00001 && *v < 1.00001)
+    };
+
+    let uniform_rgb = random::UniformRgb::new(&display_p3);
+    let uniform_oklab = random::UniformOklab::new(&ColorSpace::SRGB);
+    for _ in 0..100 {
+        assert!(in_gamut(
+            display_p3.color_to_rgba_unclipped(&uniform_rgb.sample(&mut rng))
+        ));
+        assert!(in_gamut(uniform_oklab.sample(&mut rng).to_srgb_unclipped()));
+    }
+}
+
+// Tests that constrained random colors satisfy their constraints.
+#[cfg(feature = "rand")]
+#[test]
+fn constrained_random_colors() {
+    use rand::distr::Distribution;
+    use rand::SeedableRng;
+
+    let mut rng = rand::rngs::StdRng::seed_from_u64(0);
+    let sampler = random::ConstrainedRandom::new(&ColorSpace::SRGB)
+        .hue_range(330.0, 30.0)
+        .min_chroma(0.1)
+        .lightness_range(0.3, 0.6)
+        .min_contrast(&Color::WHITE, 4.5);
+    for _ in 0..100 {
+        let color = sampler.sample(&mut rng);
+        let oklch = color.to_oklch();
+        println!("{:?}", oklch);
+        assert!(oklch.h >= 329.9999 || oklch.h <= 30.0001);
+        assert!(oklch.C >= 0.0999);
+        assert!(oklch.L >= 0.2999 && oklch.L <= 0.6001);
+        assert!(color.contrast_ratio(&Color::WHITE) >= 4.5);
+    }
+
+    // Impossible constraints return `None` instead of looping forever.
+    let impossible = random::ConstrainedRandom::new(&ColorSpace::SRGB)
+        .lightness_range(0.9, 1.0)
+        .min_contrast(&Color::WHITE, 4.5);
+    assert!(impossible.try_sample(&mut rng).is_none());
+}
+
+// Tests the per-pixel distance map and its statistics.
+#[test]
+fn delta_e_map_stats() {
+    let a: Vec<[f64; 3]> = (0..100).map(|i| [i as f64 / 100.0, 0.5, 0.5]).collect();
+    let mut b = a.clone();
+    b[10] = [1.0, 0.0, 0.0];
+    let map = delta_e_map(&a, &b, &ColorSpace::SRGB, &DeltaE2000);
+    assert!(map.len() == 100);
+    assert!(map.iter().enumerate().all(|(i, d)| (i == 10) == (*d > 1.0)));
+
+    let stats = DeltaEStats::from_distances(&map).unwrap();
+    println!("{:?}", stats);
+    assert!(approx_equal_f64(stats.max, map[10]));
+    assert!(approx_equal_f64(stats.mean, map[10] / 100.0));
+    assert!(stats.percentile_95 < 0.00001);
+    assert!(DeltaEStats::from_distances(&[]).is_none());
+}
+
+// Tests that the white point estimators find the tint of a scene and undo it.
+#[test]
+fn white_point_estimation() {
+    use white_balance::WhitePointEstimator;
+
+    let illuminant = XYZ::from_cct(3000.0).to_chromaticity();
+    let tint = ChromaticAdaptation::new(white_points::D50_WHITE_POINT_2DEGREES, illuminant);
+    let scene = [
+        (0.9, 0.9, 0.9),
+        (0.5, 0.5, 0.5),
+        // Pairs of complementary colors average to gray.
+        (0.6, 0.2, 0.2),
+        (0.2, 0.6, 0.6),
+        (0.3, 0.6, 0.3),
+        (0.6, 0.3, 0.6),
+    ];
+    let pixels: Vec<[f64; 3]> = scene
+        .iter()
+        .map(|(r, g, b)| {
+            let color = ColorSpace::SRGB
+                .new_color(*r, *g, *b, 1.0)
+                .map_xyz(|xyz| tint.convert(xyz));
+            let (r, g, b, _) = ColorSpace::SRGB.color_to_rgba_unclipped(&color);
+            [r, g, b]
+        })
+        .collect();
+
+    for estimator in [
+        WhitePointEstimator::GrayWorld,
+        WhitePointEstimator::WhitePatch,
+    ] {
+        let estimate = estimator
+            .estimate(&pixels, &ColorSpace::SRGB)
+            .unwrap()
+            .to_chromaticity();
+        println!("{:?}: {:?}", estimator, estimate);
+        assert!((estimate.x - illuminant.x).abs() < 0.001);
+        assert!((estimate.y - illuminant.y).abs() < 0.001);
+
+        let balance = estimator.white_balance(&pixels, &ColorSpace::SRGB).unwrap();
+        let [r, g, b] = pixels[1];
+        let gray = ColorSpace::SRGB
+            .new_color(r, g, b, 1.0)
+            .map_xyz(|xyz| balance.convert(xyz));
+        assert!(approx_equal(gray.to_srgb(), (0.5, 0.5, 0.5, 1.0)));
+    }
+    assert!(WhitePointEstimator::GrayWorld
+        .estimate(&[], &ColorSpace::SRGB)
+        .is_none());
+}
+
+// Tests that the built in color spaces match the reference data.
+#[test]
+fn validate_reference_data() {
+    let report = testing::validate(&ColorSpace::SRGB);
+    println!("{:?}", report);
+    assert!(report.max_matrix_error.unwrap() < 0.0001);
+    assert!(report.max_lab_error.unwrap() < 0.02);
+    assert!(report.max_delta_e_2000_error < 0.0001);
+    assert!(report.max_round_trip_error < 0.00001);
+
+    let report = testing::validate(&ColorSpace::from_standard(StandardColorSpace::AdobeRgb));
+    println!("{:?}", report);
+    assert!(report.max_matrix_error.unwrap() < 0.0001);
+    assert!(report.max_lab_error.is_none());
+
+    let report = testing::validate(&ColorSpace::from_standard(StandardColorSpace::AcesCg));
+    assert!(report.max_matrix_error.is_none());
+}
+
+// Tests that the chroma key mask removes the key and keeps other colors.
+#[test]
+fn chroma_key() {
+    let pixels = [
+        [0.0, 1.0, 0.0],
+        [0.05, 0.95, 0.05],
+        [0.3, 0.8, 0.3],
+        [1.0, 0.2, 0.6],
+    ];
+    let mask = chroma_key_mask(
+        &pixels,
+        &ColorSpace::SRGB,
+        &Color::GREEN,
+        0.05,
+        0.2,
+        &DeltaEOk,
+    );
+    println!("{:?}", mask);
+    assert!(mask[0] == 0.0 && mask[1] == 0.0);
+    assert!(mask[2] > 0.0 && mask[2] < 1.0);
+    assert!(mask[3] == 1.0);
+}
+
+// Tests sampling gradients and false coloring values with them.
+#[test]
+fn gradient_map_values() {
+    use gradient::*;
+
+    let gradient = Gradient::evenly_spaced(&[Color::BLACK, Color::WHITE]);
+    assert!(approx_equal_f64(gradient.sample(0.5).to_oklab().L, 0.5));
+    let hues = Gradient::evenly_spaced(&[Color::RED, Color::BLUE])
+        .interpolation(GradientInterpolation::Oklch)
+        .sample(0.5)
+        .to_oklch();
+    println!("{:?}", hues);
+    assert!(hues.h > Color::BLUE.to_oklch().h || hues.h < Color::RED.to_oklch().h);
+
+    let colors = gradient.map_values(&[0.0, 5.0, 10.0, 20.0], 0.0, 10.0);
+    assert!(approx_equal_f64(colors[1].to_oklab().L, 0.5));
+    assert!(colors[3].approx_eq(&Color::WHITE, 1e-9));
+
+    let log = gradient
+        .value_scale(ValueScale::Log)
+        .under_color(Color::RED)
+        .over_color(Color::BLUE);
+    let colors = log.map_values(&[10.0, 0.0, 1000.0, f64::NAN], 1.0, 100.0);
+    assert!(approx_equal_f64(colors[0].to_oklab().L, 0.5));
+    assert!(colors[1].approx_eq(&Color::RED, 1e-9));
+    assert!(colors[2].approx_eq(&Color::BLUE, 1e-9));
+    assert!(colors[3].approx_eq(&Color::TRANSPARENT, 1e-9));
+
+    assert!(log.sample(f64::NAN).approx_eq(&Color::TRANSPARENT, 1e-9));
+    let nan_stop = Gradient::new([(0.0, Color::BLACK), (f64::NAN, Color::WHITE)]);
+    assert!(nan_stop.sample(0.5).approx_eq(&Color::WHITE, 1e-9));
+}
+
+// Tests exporting gradients to CSS and SVG.
+#[test]
+fn gradient_export() {
+    use gradient::*;
+
+    let gradient = Gradient::evenly_spaced(&[Color::RED, Color::BLUE.with_alpha(0.5)]);
+    let css = gradient.to_css("to right");
+    println!("{}", css);
+    assert!(css.starts_with("linear-gradient(to right in oklab, oklch(0.628 0.2576 29.23) 0%"));
+    assert!(css.ends_with(" / 0.5) 100%)"));
+
+    let stops = gradient.resampled_stops(3);
+    assert!(stops.len() == 5);
+    assert!(approx_equal_f64(stops[2].0, 0.5));
+
+    let css = gradient.to_css_srgb("45deg", 1);
+    println!("{}", css);
+    assert!(css.starts_with("linear-gradient(45deg, #ff0000 0%, #"));
+    assert!(css.ends_with("#0000ff80 100%)"));
+
+    let svg = gradient.to_svg("fade", 1);
+    println!("{}", svg);
+    assert!(svg.starts_with(
+        "<linearGradient id=\"fade\">\n  <stop offset=\"0%\" stop-color=\"#ff0000\"/>"
+    ));
+    assert!(svg.contains("<stop offset=\"100%\" stop-color=\"#0000ff\" stop-opacity=\"0.5\"/>"));
+    assert!(svg.matches("<stop").count() == 3);
+}
+
+// Tests that the lenient parser understands common inputs and reports its assumptions.
+#[test]
+fn parse_lossy() {
+    use ParseAssumption::*;
+
+    let orange = ColorSpace::SRGB.new_color_from_rgba8_array([255, 128, 0, 255]);
+    let cases: [(&str, &[ParseAssumption]); 9] = [
+        ("#ff8000", &[]),
+        ("ff8000", &[MissingHash]),
+        ("0xFF8000", &[HexPrefix0x]),
+        ("rgb(255, 128, 0)", &[]),
+        ("rgb 255 128 0", &[ImplicitRgb]),
+        ("255,128,0", &[ImplicitRgb]),
+        ("RGBA(100%, 50.196%, 0%, 1)", &[]),
+        ("1.0 0.50196 0", &[ImplicitRgb, UnitRange]),
+        ("1; 0,50196; 0", &[ImplicitRgb, DecimalComma, UnitRange]),
+    ];
+    for (text, expected) in cases {
+        let (color, assumptions) = Color::parse_lossy(text).unwrap();
+        println!("{}: {:?}", text, assumptions);
+        assert!(color.approx_eq(&orange, 0.0001));
+        assert!(assumptions == expected);
+    }
+    let (color, _) = Color::parse_lossy("rgb(0,5 0,5 0,5)").unwrap();
+    assert!(approx_equal(color.to_srgb(), (0.5, 0.5, 0.5, 1.0)));
+
+    assert!(Color::parse_lossy("purple-ish").is_err());
+    assert!(Color::parse_lossy("rgb(1, 2)").is_err());
+}
+
+// Tests deriving a display's color spaces from its EDID.
+#[test]
+fn edid_color_space() {
+    use edid::*;
+
+    let with_checksum = |mut block: [u8; 128]| {
+        block[127] = 0u8.wrapping_sub(block[..127].iter().fold(0u8, |s, b| s.wrapping_add(*b)));
+        block
+    };
+    let mut base = [0u8; 128];
+    base[..8].copy_from_slice(&[0x00, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0x00]);
+    base[23] = 120;
+    base[24] = 0b100;
+    let coordinates = [0.64, 0.33, 0.3, 0.6, 0.15, 0.06, 0.3127, 0.329];
+    for (i, c) in coordinates.iter().enumerate() {
+        let value = (c * 1024.0f64).round() as u16;
+        base[27 + i] = (value >> 2) as u8;
+        base[25 + i / 4] |= ((value & 0b11) as u8) << (6 - (i % 4) * 2);
+    }
+    base[126] = 1;
+
+    let mut cta = [0u8; 128];
+    cta[0] = 0x02;
+    cta[1] = 3;
+    cta[2] = 11;
+    cta[4..11].copy_from_slice(&[0xe0 | 6, 6, 0b101, 1, 138, 110, 20]);
+
+    let mut bytes = with_checksum(base).to_vec();
+    bytes.extend_from_slice(&with_checksum(cta));
+    let edid = Edid::parse(&bytes).unwrap();
+    println!("{:?}", edid);
+    assert!(edid.gamma == Some(2.2));
+    assert!((edid.white_point.x - 0.3127).abs() < 0.001);
+    assert!(edid.color_space().approx_eq(&ColorSpace::SRGB, 0.001));
+
+    let hdr = edid.hdr.unwrap();
+    assert!(hdr.sdr && hdr.pq && !hdr.hlg);
+    assert!((hdr.max_luminance.unwrap() - 993.5).abs() < 0.1);
+    assert!(edid.pq_color_space(HDR_REFERENCE_WHITE).is_some());
+
+    bytes[20] ^= 1;
+    assert!(matches!(
+        Edid::parse(&bytes),
+        Err(EdidError::InvalidChecksum)
+    ));
+}
+
+// Tests Y'CbCr quantization against known video levels.
+#[test]
+fn ycbcr_levels() {
+    use ycbcr::*;
+
+    let srgb = ColorSpace::SRGB;
+    let bt709 = YCbCrFormat::BT709_8BIT;
+    assert!(srgb.color_to_ycbcr(&Color::WHITE, &bt709) == [235, 128, 128]);
+    assert!(srgb.color_to_ycbcr(&Color::BLACK, &bt709) == [16, 128, 128]);
+    assert!(srgb.color_to_ycbcr(&Color::RED, &bt709) == [63, 102, 240]);
+
+    let bt709_10bit = YCbCrFormat::new(YCbCrMatrix::Bt709, YCbCrRange::Limited, 10);
+    assert!(srgb.color_to_ycbcr(&Color::WHITE, &bt709_10bit) == [940, 512, 512]);
+    let full = YCbCrFormat::new(YCbCrMatrix::Bt709, YCbCrRange::Full, 8);
+    assert!(srgb.color_to_ycbcr(&Color::WHITE, &full) == [255, 128, 128]);
+    assert!(srgb.color_to_ycbcr(&Color::BLACK, &full) == [0, 128, 128]);
+
+    // Bit depths that would overflow the quantization are rejected.
+    for bit_depth in [0, 7, 17] {
+        assert!(YCbCrFormat::try_new(YCbCrMatrix::Bt709, YCbCrRange::Full, bit_depth).is_err());
+    }
+    let sixteen = YCbCrFormat::try_new(YCbCrMatrix::Bt709, YCbCrRange::Full, 16).unwrap();
+    assert!(srgb.color_to_ycbcr(&Color::WHITE, &sixteen) == [65535, 32768, 32768]);
+    #[cfg(feature = "serde")]
+    {
+        let json = serde_json::to_string(&YCbCrFormat::JPEG).unwrap();
+        assert!(serde_json::from_str::<YCbCrFormat>(&json).unwrap() == YCbCrFormat::JPEG);
+        let invalid = json.replace("\"bit_depth\":8", "\"bit_depth\":0");
+        assert!(serde_json::from_str::<YCbCrFormat>(&invalid).is_err());
+    }
+
+    // Decoding limited range as full range is what makes video look washed out.
+    let washed_out = srgb.new_color_from_ycbcr([16, 128, 128], &full);
+    assert!(washed_out.to_srgb().0 > 0.06);
+
+    for format in [
+        bt709,
+        bt709_10bit,
+        full,
+        YCbCrFormat::BT2020_10BIT,
+        YCbCrFormat::JPEG,
+    ] {
+        let color = srgb.new_color(0.2, 0.6, 0.9, 1.0);
+        let values = srgb.color_to_ycbcr(&color, &format);
+        let round_trip = srgb.new_color_from_ycbcr(values, &format);
+        println!("{:?} {:?}", format, values);
+        assert!(color.approx_eq(&round_trip, 0.01));
+    }
+}
+
+// Tests converting images to and from subsampled Y′CbCr planes.
+#[test]
+fn ycbcr_chroma_subsampling() {
+    use ycbcr::*;
+
+    let srgb = ColorSpace::SRGB;
+    let format = YCbCrFormat::BT709_8BIT;
+    assert!(ChromaSubsampling::Yuv420.chroma_size(5, 3) == (3, 2));
+    assert!(ChromaSubsampling::Yuv422.chroma_size(5, 3) == (3, 3));
+
+    // Flat colors survive every subsampling, siting, and filter.
+    let (width, height) = (5, 3);
+    let pixels = vec![[0.2, 0.6, 0.9]; width * height];
+    for subsampling in [
+        ChromaSubsampling::Yuv444,
+        ChromaSubsampling::Yuv422,
+        ChromaSubsampling::Yuv420,
+    ] {
+        for siting in [
+            ChromaSiting::Left,
+            ChromaSiting::Center,
+            ChromaSiting::TopLeft,
+        ] {
+            let planes =
+                YCbCrPlanes::from_rgb(&pixels, width, &srgb, &srgb, &format, subsampling, siting);
+            let (chroma_width, chroma_height) = subsampling.chroma_size(width, height);
+            assert!(planes.cb.len() == chroma_width * chroma_height);
+            for filter in [ChromaFilter::Nearest, ChromaFilter::Bilinear] {
+                let decoded = planes.to_rgb(&format, filter, &srgb, &srgb);
+                for p in decoded {
+                    assert!((0..3).all(|i| (p[i] - pixels[0][i]).abs() < 0.01));
+                }
+            }
+        }
+    }
+
+    // Decoding straight to another color space.
+    let planes = YCbCrPlanes::from_rgb(
+        &pixels,
+        width,
+        &srgb,
+        &srgb,
+        &format,
+        ChromaSubsampling::Yuv420,
+        ChromaSiting::Left,
+    );
+    let linear = planes.to_rgb(
+        &format,
+        ChromaFilter::Bilinear,
+        &srgb,
+        &ColorSpace::SRGB_LINEAR,
+    );
+    let expected = ColorSpaceConverter::new(&srgb, &ColorSpace::SRGB_LINEAR)
+        .convert_encoded_color(&(0.2, 0.6, 0.9));
+    println!("{:?} {:?}", linear[0], expected);
+    assert!((linear[0][2] - expected.2).abs() < 0.01);
+
+    // 4:4:4 chroma is untouched, so sharp colored edges are kept.
+    let edge: Vec<[f64; 3]> = (0..8)
+        .map(|x| {
+            if x < 4 {
+                [1.0, 0.0, 0.0]
+            } else {
+                [0.0, 0.0, 1.0]
+            }
+        })
+        .collect();
+    let planes = YCbCrPlanes::from_rgb(
+        &edge,
+        8,
+        &srgb,
+        &srgb,
+        &format,
+        ChromaSubsampling::Yuv444,
+        ChromaSiting::Left,
+    );
+    let decoded = planes.to_rgb(&format, ChromaFilter::Bilinear, &srgb, &srgb);
+    assert!((decoded[3][0] - 1.0).abs() < 0.01 && (decoded[4][2] - 1.0).abs() < 0.01);
+
+    // Subsampled chroma blends across the edge, but only near it.
+    let planes = YCbCrPlanes::from_rgb(
+        &edge,
+        8,
+        &srgb,
+        &srgb,
+        &format,
+        ChromaSubsampling::Yuv422,
+        ChromaSiting::Center,
+    );
+    let decoded = planes.to_rgb(&format, ChromaFilter::Bilinear, &srgb, &srgb);
+    println!("{:?}", decoded);
+    assert!((decoded[0][0] - 1.0).abs() < 0.02 && (decoded[7][2] - 1.0).abs() < 0.02);
+    assert!(decoded[3][2] > 0.05);
+}
+
+// Tests converting between NV12, I420, and P010 frames and RGBA.
+#[test]
+fn yuv_layouts() {
+    use ycbcr::*;
+
+    let srgb = ColorSpace::SRGB;
+    let format = YCbCrFormat::BT709_8BIT;
+
+    // A black I420 frame, written by hand.
+    let (width, height) = (4, 2);
+    let mut black = vec![16; 8];
+    black.extend([128; 4]);
+    assert!(black.len() == YuvLayout::I420.frame_size(width, height));
+    let rgba = yuv_to_rgba8(
+        &black,
+        width,
+        height,
+        YuvLayout::I420,
+        &format,
+        &srgb,
+        &srgb,
+    );
+    assert!(rgba == [0, 0, 0, 255].repeat(8));
+
+    // White NV12 has full luma and neutral interleaved chroma.
+    let white = rgba8_to_yuv(&[255; 32], width, &srgb, &srgb, &format, YuvLayout::Nv12);
+    assert!(white[..8] == [235; 8] && white[8..] == [128; 4]);
+
+    // Odd sized frames round trip through every layout.
+    let (width, height) = (3, 3);
+    let rgba = [51, 153, 230, 255].repeat(width * height);
+    for (layout, format) in [
+        (YuvLayout::I420, YCbCrFormat::BT709_8BIT),
+        (YuvLayout::Nv12, YCbCrFormat::JPEG),
+        (YuvLayout::P010, YCbCrFormat::BT2020_10BIT),
+    ] {
+        let bytes = rgba8_to_yuv(&rgba, width, &srgb, &srgb, &format, layout);
+        assert!(bytes.len() == layout.frame_size(width, height));
+        let round_trip = yuv_to_rgba8(&bytes, width, height, layout, &format, &srgb, &srgb);
+        println!("{:?} {:?}", layout, &round_trip[..4]);
+        assert!(rgba
+            .iter()
+            .zip(&round_trip)
+            .all(|(a, b)| (*a as i32 - *b as i32).abs() <= 2));
+    }
+
+    // P010 keeps samples in the high bits.
+    let planes = YCbCrPlanes::from_yuv_bytes(
+        &rgba8_to_yuv(
+            &rgba,
+            width,
+            &srgb,
+            &srgb,
+            &YCbCrFormat::BT2020_10BIT,
+            YuvLayout::P010,
+        ),
+        width,
+        height,
+        YuvLayout::P010,
+        ChromaSiting::Left,
+    );
+    assert!(planes.y.iter().all(|v| (64..=940).contains(v)));
+    assert!(planes.cb.len() == 4);
+}
+
+// Tests that matrices keep their layout when converted to and from mint.
+#[cfg(feature = "mint")]
+#[test]
+fn mint_conversions() {
+    let matrix = ColorSpace::SRGB.to_XYZ;
+    let rows: mint::RowMatrix3<f64> = matrix.into();
+    assert!(rows.x.y == matrix.c1.x);
+    assert!(rows.y.x == matrix.c0.y);
+    assert!(Matrix3x3::from(rows) == matrix);
+
+    let columns: mint::ColumnMatrix3<f64> = matrix.into();
+    assert!(columns.x.y == matrix.c0.y);
+    assert!(Matrix3x3::from(columns) == matrix);
+
+    let vector: mint::Vector3<f64> = Vector3::new(1.0, 2.0, 3.0).into();
+    assert!(Vector3::from(vector) == Vector3::new(1.0, 2.0, 3.0));
+}
+
+// Tests that generated shaders contain the conversion matrix and transfer functions.
+#[test]
+fn shader_generation() {
+    let converter = ColorSpaceConverter::new(&ColorSpace::SRGB, &ColorSpace::SRGB_LINEAR);
+    let wgsl = shader::wgsl(&converter, "srgb_to_linear_srgb");
+    println!("{}", wgsl);
+    assert!(wgsl.contains("fn srgb_to_linear_srgb(color: vec3<f32>) -> vec3<f32> {"));
+    assert!(wgsl.contains("fn srgb_to_linear_srgb_to_linear(x: f32) -> f32 {"));
+    assert!(wgsl.contains("pow(0.9478673 * v + 0.0521327, 2.4)"));
+    assert!(wgsl.contains("mat3x3<f32>("));
+
+    let glsl = shader::glsl(&converter, "convert");
+    println!("{}", glsl);
+    assert!(glsl.contains("vec3 convert(vec3 color) {"));
+    assert!(glsl.contains("float convert_from_linear(float x) {\n    return x;\n}"));
+    assert!(glsl.contains("    vec3 converted = mat3("));
+
+    // HDR and sampled transfer functions.
+    let converter = ColorSpaceConverter::new(
+        &ColorSpace::rec2100_pq(HDR_REFERENCE_WHITE),
+        &ColorSpace::rec2100_hlg(),
+    );
+    let wgsl = shader::wgsl(&converter, "pq_to_hlg");
+    println!("{}", wgsl);
+    assert!(wgsl.contains("    let y = pow(max(e - 0.8359375, 0.0) / (18.851563 - 18.6875 * e),"));
+    assert!(wgsl.contains("log(12.0 * v - 0.28466892)"));
+
+    let sampled = ColorSpace::new(
+        Chromaticity::new(0.64, 0.33),
+        Chromaticity::new(0.30, 0.60),
+        Chromaticity::new(0.15, 0.06),
+        D65_WHITE_POINT_2DEGREES,
+        TransferFunction::Sampled(
+            TransferFunctionLut::new(vec![0.0, 0.25, 1.0], vec![0.0, 1.0]).unwrap(),
+        ),
+    );
+    let converter = ColorSpaceConverter::new(&sampled, &sampled);
+    let wgsl = shader::wgsl(&converter, "sampled");
+    println!("{}", wgsl);
+    assert!(wgsl.contains("    var table = array<f32, 3>(0.0, 0.25, 1.0);"));
+    assert!(wgsl.contains("    let index = min(i32(floor(position)), 1);"));
+    let glsl = shader::glsl(&converter, "sampled");
+    println!("{}", glsl);
+    assert!(glsl.contains("    float table[2] = float[2](0.0, 1.0);"));
+    assert!(glsl.contains("    float fraction = position - float(index);"));
+}
+
+// Tests the ICC parametric curves of types 0, 1, and 2 and their inverses.
+#[test]
+fn parametric_curves() {
+    let curve = |c: ParametricCurve| TransferFunction::ParametricCurve(c);
+    let gamma = curve(ParametricCurve::Function0 { gamma: 2.2 });
+    assert!(approx_equal_f64(gamma.to_linear(0.5), 0.21764));
+    assert!(approx_equal_f64(gamma.to_linear(-0.5), -0.21764));
+
+    // The part of the sRGB curve above its linear segment.
+    let offset = curve(ParametricCurve::Function1 {
+        gamma: 2.4,
+        a: 1.0 / 1.055,
+        b: 0.055 / 1.055,
+    });
+    assert!(approx_equal_f64(
+        offset.to_linear(0.5),
+        SRGB_TRANSFER_FUNCTION.to_linear(0.5)
+    ));
+    let raised = curve(ParametricCurve::Function2 {
+        gamma: 2.0,
+        a: 2.0,
+        b: -0.5,
+        c: 0.1,
+    });
+    assert!(approx_equal_f64(raised.to_linear(0.1), 0.1));
+    assert!(approx_equal_f64(raised.to_linear(0.75), 1.1));
+    assert!(approx_equal_f64(raised.from_linear(0.0), 0.25));
+
+    for function in [gamma, offset, raised] {
+        for i in 0..=10 {
+            let x = 0.25 + i as f64 * 0.075;
+            assert!(approx_equal_f64(
+                function.from_linear(function.to_linear(x)),
+                x
+            ));
+        }
+        let converter = ColorSpaceConverter::new(
+            &ColorSpace::new(
+                Chromaticity::new(0.64, 0.33),
+                Chromaticity::new(0.3, 0.6),
+                Chromaticity::new(0.15, 0.06),
+                Chromaticity::new(0.31271, 0.32902),
+                function,
+            ),
+            &ColorSpace::SRGB,
+        );
+        assert!(shader::wgsl(&converter, "convert").contains("pow("));
+    }
+}
+
+// Tests that the converter applies transfer functions to encoded colors like `ColorSpace` does.
+#[test]
+fn color_space_converter() {
+    let converter = ColorSpaceConverter::new(&ColorSpace::SRGB, &ColorSpace::SRGB_LINEAR);
+    let (r, g, b) = converter.convert_encoded_color(&(0.5, 0.25, 1.0));
+    let expected = Color::new_srgb(0.5, 0.25, 1.0, 1.0).to_linear_srgb();
+    assert!(approx_equal((r, g, b, 1.0), expected));
+
+    // `convert_color` only applies the matrix, so it's unchanged between sRGB and linear sRGB.
+    let (r, g, b) = converter.convert_color(&(0.5, 0.25, 1.0));
+    assert!(approx_equal((r, g, b, 1.0), (0.5, 0.25, 1.0, 1.0)));
+}
+
+// Tests the C interface by calling it from Rust.
+#[cfg(feature = "ffi")]
+#[test]
+fn ffi_conversion() {
+    use crate::ffi::*;
+    unsafe {
+        let srgb = kcolor_color_space_srgb();
+        let chromaticities = [0.64, 0.33, 0.3, 0.6, 0.15, 0.06, 0.31271, 0.32902];
+        let linear = kcolor_color_space_new(
+            chromaticities.as_ptr(),
+            KCOLOR_TRANSFER_FUNCTION_NONE,
+            std::ptr::null(),
+        );
+        assert!(*linear == ColorSpace::SRGB_LINEAR);
+
+        let mut out = [0.0; 4];
+        kcolor_convert_color(
+            srgb,
+            linear,
+            [0.5, 0.25, 1.0, 1.0].as_ptr(),
+            out.as_mut_ptr(),
+        );
+        let expected = Color::new_srgb(0.5, 0.25, 1.0, 1.0).to_linear_srgb();
+        assert!(approx_equal((out[0], out[1], out[2], out[3]), expected));
+
+        let converter = kcolor_converter_new(srgb, linear);
+        let mut pixels = [0.5f32, 0.25, 1.0, 0.5, 0.5, 0.25, 1.0, 0.25];
+        kcolor_converter_convert_f32_buffer(converter, pixels.as_mut_ptr(), 2, 4);
+        assert!((pixels[4] as f64 - expected.0).abs() < 0.00001);
+        assert!(pixels[7] == 0.25);
+
+        kcolor_converter_free(converter);
+        kcolor_color_space_free(srgb);
+        kcolor_color_space_free(linear);
+    }
+}
+
+// Tests Von Kries adaptation against Bruce Lindbloom's D65 to D50 matrix:
+// http://www.brucelindbloom.com/index.html?Eqn_ChromAdapt.html
+#[test]
+fn chromatic_adaptation_von_kries() {
+    let adaptation = ChromaticAdaptation::new_with_method(
+        D65_WHITE_POINT_2DEGREES,
+        D50_WHITE_POINT_2DEGREES,
+        ChromaticAdaptationMethod::VonKries,
+    );
+    let m = adaptation.inner_matrix;
+    println!("von kries: {:?}", m);
+    assert!((m.c0.x - 1.0160803).abs() < 0.001);
+    assert!((m.c1.x - 0.0552297).abs() < 0.001);
+    assert!((m.c2.x - -0.0521326).abs() < 0.001);
+    assert!((m.c0.y - 0.0060666).abs() < 0.001);
+    assert!((m.c2.z - 0.7578869).abs() < 0.001);
+
+    // The white point should map exactly to the destination white point.
+    let white = adaptation.convert(D65_WHITE_POINT_2DEGREES.to_XYZ());
+    let expected = D50_WHITE_POINT_2DEGREES.to_XYZ();
+    assert!(approx_equal_f64(white.X, expected.X) && approx_equal_f64(white.Z, expected.Z));
+}
+
+// Tests that every adaptation method's matrices are inverses of each other
+// and that each maps the source white point to the destination white point.
+#[test]
+fn chromatic_adaptation_methods() {
+    let methods = [
+        ChromaticAdaptationMethod::Bradford,
+        ChromaticAdaptationMethod::VonKries,
+        ChromaticAdaptationMethod::Cat02,
+        ChromaticAdaptationMethod::Cat16,
+        ChromaticAdaptationMethod::XyzScaling,
+    ];
+    for method in methods.iter() {
+        let adaptation = ChromaticAdaptation::new_with_method(
+            D65_WHITE_POINT_2DEGREES,
+            D50_WHITE_POINT_2DEGREES,
+            *method,
+        );
+        let white = adaptation.convert(D65_WHITE_POINT_2DEGREES.to_XYZ());
+        let expected = D50_WHITE_POINT_2DEGREES.to_XYZ();
+        println!("{:?}: {:?}", method, white);
+        assert!(approx_equal_f64(white.X, expected.X));
+        assert!(approx_equal_f64(white.Y, expected.Y));
+        assert!(approx_equal_f64(white.Z, expected.Z));
+    }
+}
+
+// Tests that the adaptation method used by a color space changes its matrices
+// while keeping white mapped to white.
+#[test]
+fn color_space_adaptation_method() {
+    let srgb_cat02 = ColorSpace::new_with_adaptation_method(
+        Chromaticity::new(0.64, 0.33),
+        Chromaticity::new(0.3, 0.6),
+        Chromaticity::new(0.15, 0.06),
+        D65_WHITE_POINT_2DEGREES,
+        SRGB_TRANSFER_FUNCTION,
+        ChromaticAdaptationMethod::Cat02,
+    );
+    assert!(srgb_cat02 != ColorSpace::SRGB);
+
+    let red = srgb_cat02.new_color(1.0, 0.0, 0.0, 1.0);
+    assert!(!approx_equal(red.to_srgb_unclipped(), (1.0, 0.0, 0.0, 1.0)));
+
+    let white = srgb_cat02.new_color(1.0, 1.0, 1.0, 1.0);
+    assert!(approx_equal(white.to_srgb(), (1.0, 1.0, 1.0, 1.0)));
+}
+
+// Tests that partial adaptation lands between no adaptation and full adaptation.
+#[test]
+fn partial_chromatic_adaptation() {
+    let white = D65_WHITE_POINT_2DEGREES.to_XYZ();
+    let adapt = |d| {
+        ChromaticAdaptation::new_with_degree_of_adaptation(
+            D65_WHITE_POINT_2DEGREES,
+            D50_WHITE_POINT_2DEGREES,
+            ChromaticAdaptationMethod::Cat02,
+            d,
+        )
+        .convert(white)
+    };
+    let none = adapt(0.0);
+    let half = adapt(0.5);
+    let full = adapt(1.0);
+    assert!(approx_equal_f64(none.Z, white.Z));
+    assert!(approx_equal_f64(
+        full.Z,
+        D50_WHITE_POINT_2DEGREES.to_XYZ().Z
+    ));
+    assert!(approx_equal_f64(half.Z, (none.Z + full.Z) / 2.0));
+
+    // Bright, average surround viewing conditions are nearly fully adapted.
+    let d = ChromaticAdaptation::degree_of_adaptation(1.0, 1000.0);
+    assert!(d > 0.99 && d <= 1.0);
+    let d = ChromaticAdaptation::degree_of_adaptation(0.8, 0.0);
+    assert!((d - 0.6592).abs() < 0.001);
+}
+
+// Tests that composed adaptations match a direct adaptation and that inverses undo them.
+#[test]
+fn compose_chromatic_adaptation() {
+    let d55 = XYZ::new(0.95682, 1.0, 0.92149).to_chromaticity();
+    let d65_to_d55 = ChromaticAdaptation::new(D65_WHITE_POINT_2DEGREES, d55);
+    let d55_to_d50 = ChromaticAdaptation::new(d55, D50_WHITE_POINT_2DEGREES);
+    let combined = d65_to_d55.then(&d55_to_d50);
+
+    let xyz = XYZ::new(0.3, 0.4, 0.5);
+    let stepped = d55_to_d50.convert(d65_to_d55.convert(xyz));
+    let direct = combined.convert(xyz);
+    assert!(approx_equal_f64(stepped.X, direct.X));
+    assert!(approx_equal_f64(stepped.Y, direct.Y));
+    assert!(approx_equal_f64(stepped.Z, direct.Z));
+
+    let round_trip = combined.inverse().convert(direct);
+    assert!(approx_equal_f64(round_trip.X, xyz.X));
+    assert!(approx_equal_f64(round_trip.Y, xyz.Y));
+    assert!(approx_equal_f64(round_trip.Z, xyz.Z));
+    assert!(combined.matrix() == combined.inner_matrix);
+}
+
+// Tests the standard illuminants against their published XYZ values:
+// https://en.wikipedia.org/wiki/Standard_illuminant#White_points_of_standard_illuminants
+#[test]
+fn standard_illuminants() {
+    let a = A_WHITE_POINT_2DEGREES.to_XYZ();
+    assert!((a.X - 1.09850).abs() < 0.0001 && (a.Z - 0.35585).abs() < 0.0001);
+    let d60 = D60_WHITE_POINT_2DEGREES.to_XYZ();
+    assert!((d60.X - 0.95265).abs() < 0.0001 && (d60.Z - 1.00883).abs() < 0.0001);
+    let e = E_WHITE_POINT_2DEGREES.to_XYZ();
+    assert!(approx_equal_f64(e.X, 1.0) && approx_equal_f64(e.Z, 1.0));
+}
+
+// Tests that white points generated from color temperatures match the standard illuminants.
+#[test]
+fn white_point_from_cct() {
+    // D65 is defined as approximately 6504K on the daylight locus.
+    let d65 = XYZ::from_cct(6504.0).to_chromaticity();
+    assert!((d65.x - D65_WHITE_POINT_2DEGREES.x).abs() < 0.0001);
+    assert!((d65.y - D65_WHITE_POINT_2DEGREES.y).abs() < 0.0001);
+
+    let d50 = XYZ::from_cct(5003.0).to_chromaticity();
+    assert!((d50.x - D50_WHITE_POINT_2DEGREES.x).abs() < 0.0001);
+    assert!((d50.y - D50_WHITE_POINT_2DEGREES.y).abs() < 0.0001);
+
+    // Illuminant A is a black body at 2856K.
+    let a = XYZ::from_cct(2856.0).to_chromaticity();
+    assert!((a.x - A_WHITE_POINT_2DEGREES.x).abs() < 0.001);
+    assert!((a.y - A_WHITE_POINT_2DEGREES.y).abs() < 0.001);
+    assert!(XYZ::from_cct(2856.0).Y == 1.0);
+}
+
+// Tests converting between chromaticities and XYZ values.
+#[test]
+fn chromaticity_xyz() {
+    let xyz = D65_WHITE_POINT_2DEGREES.to_XYZ_with_luminance(0.25);
+    assert!(approx_equal_f64(xyz.Y, 0.25));
+    let chromaticity = xyz.to_chromaticity();
+    assert!(approx_equal_f64(chromaticity.x, D65_WHITE_POINT_2DEGREES.x));
+    assert!(approx_equal_f64(chromaticity.y, D65_WHITE_POINT_2DEGREES.y));
+}
+
+// Tests Lab and Luv for sRGB red.
+// The Lab values are from Bruce Lindbloom's calculator:
+// http://www.brucelindbloom.com/index.html?ColorCalculator.html
+#[test]
+fn lab_and_luv() {
+    let color = Color::new_srgb(1.0, 0.0, 0.0, 1.0);
+    let lab = color.to_lab();
+    println!("lab: {:?}", lab);
+    assert!((lab.L - 54.2905).abs() < 0.01);
+    assert!((lab.a - 80.8049).abs() < 0.01);
+    assert!((lab.b - 69.8910).abs() < 0.01);
+    assert!(approx_equal(
+        Color::from_lab(lab, 1.0).to_srgb(),
+        (1.0, 0.0, 0.0, 1.0)
+    ));
+
+    let luv = color.to_luv();
+    println!("luv: {:?}", luv);
+    assert!((luv.L - 54.2905).abs() < 0.01);
+    assert!((luv.u - 175.04).abs() < 0.01);
+    assert!((luv.v - 25.96).abs() < 0.01);
+    assert!(approx_equal(
+        Color::from_luv(luv, 1.0).to_srgb(),
+        (1.0, 0.0, 0.0, 1.0)
+    ));
+
+    // White is neutral for either observer as long as the reference white matches.
+    let white = D50_WHITE_POINT_10DEGREES.to_XYZ();
+    let lab = Lab::from_XYZ_for_observer(white, Observer::TenDegrees);
+    assert!(approx_equal_f64(lab.L, 100.0) && approx_equal_f64(lab.a, 0.0));
+    assert!(
+        Lab::from_XYZ_for_observer(white, Observer::TwoDegrees)
+            .a
+            .abs()
+            > 0.1
+    );
+    let xyz = lab.to_XYZ_for_observer(Observer::TenDegrees);
+    assert!(approx_equal_f64(xyz.X, white.X) && approx_equal_f64(xyz.Z, white.Z));
+    let luv = Luv::from_XYZ_for_observer(white, Observer::TenDegrees);
+    assert!(approx_equal_f64(luv.u, 0.0) && approx_equal_f64(luv.v, 0.0));
+    let xyz = luv.to_XYZ_for_observer(Observer::TenDegrees);
+    assert!(approx_equal_f64(xyz.X, white.X) && approx_equal_f64(xyz.Z, white.Z));
+
+    let black = Color::new_xyza(0.0, 0.0, 0.0, 1.0);
+    assert!(black.to_luv().L == 0.0);
+    assert!(Color::from_luv(black.to_luv(), 1.0).Y == 0.0);
+}
+
+// Tests Lab relative to D65, as reported by many instruments.
+#[test]
+fn lab_reference_white() {
+    // sRGB white is exactly the D65 white point, so it's neutral relative to D65.
+    let white = Color::new_srgb(1.0, 1.0, 1.0, 1.0);
+    let lab = ColorSpace::SRGB.color_to_lab(&white);
+    assert!(approx_equal_f64(lab.L, 100.0));
+    assert!(lab.a.abs() < 0.001 && lab.b.abs() < 0.001);
+
+    // Values from Bruce Lindbloom's calculator with a D65 reference white.
+    let red = Color::new_srgb(1.0, 0.0, 0.0, 1.0);
+    let lab = red.to_lab_with_reference_white(D65_WHITE_POINT_2DEGREES);
+    println!("lab: {:?}", lab);
+    assert!((lab.L - 53.24).abs() < 0.01);
+    assert!((lab.a - 80.09).abs() < 0.01);
+    assert!((lab.b - 67.20).abs() < 0.01);
+
+    let color = ColorSpace::SRGB.new_color_from_lab(lab, 1.0);
+    assert!(approx_equal(color.to_srgb(), (1.0, 0.0, 0.0, 1.0)));
+    let color = ColorSpace::SRGB.new_color_from_luv(ColorSpace::SRGB.color_to_luv(&red), 1.0);
+    assert!(approx_equal(color.to_srgb(), (1.0, 0.0, 0.0, 1.0)));
+}
+
+// Tests that a color space's primaries and white point can be recovered.
+#[test]
+fn color_space_accessors() {
+    let srgb = ColorSpace::SRGB;
+    assert!(srgb.white_point() == D65_WHITE_POINT_2DEGREES);
+    let red = srgb.red_primary();
+    let green = srgb.green_primary();
+    let blue = srgb.blue_primary();
+    assert!(approx_equal_f64(red.x, 0.64) && approx_equal_f64(red.y, 0.33));
+    assert!(approx_equal_f64(green.x, 0.3) && approx_equal_f64(green.y, 0.6));
+    assert!(approx_equal_f64(blue.x, 0.15) && approx_equal_f64(blue.y, 0.06));
+    assert!(*srgb.transfer_function() == SRGB_TRANSFER_FUNCTION);
+    assert!(srgb.adaptation_method() == ChromaticAdaptationMethod::Bradford);
+
+    let rebuilt = ColorSpace::new(
+        red,
+        green,
+        blue,
+        srgb.white_point(),
+        srgb.transfer_function().clone(),
+    );
+    let color = rebuilt.new_color(0.1, 0.5, 0.9, 1.0);
+    assert!(approx_equal(color.to_srgb(), (0.1, 0.5, 0.9, 1.0)));
+}
+
+// Tests that the exposed matrices convert between linear sRGB and XYZ.
+#[test]
+fn color_space_matrices() {
+    let to_xyz = ColorSpace::SRGB.to_XYZ_matrix();
+    let from_xyz = ColorSpace::SRGB.from_XYZ_matrix();
+    let rows = to_xyz.to_rows_array();
+    // sRGB white has a luminance of 1.0, so the second row sums to 1.0.
+    assert!(approx_equal_f64(rows[1][0] + rows[1][1] + rows[1][2], 1.0));
+    assert!(to_xyz.to_columns_array()[0][1] == rows[1][0]);
+
+    let identity = from_xyz * to_xyz;
+    assert!(approx_equal_f64(identity.c0.x, 1.0) && approx_equal_f64(identity.c1.x, 0.0));
+
+    let converter = ColorSpaceConverter::new(&ColorSpace::SRGB, &ColorSpace::SRGB_LINEAR);
+    assert!(approx_equal_f64(converter.conversion_matrix().c2.z, 1.0));
+}
+
+// Tests that lightening and darkening change Oklch lightness and stay within the gamut.
+#[test]
+fn lighten_and_darken() {
+    let blue = Color::new_srgb(0.2, 0.3, 0.9, 1.0);
+    let lighter = blue.lighten(0.1, &ColorSpace::SRGB);
+    let darker = blue.darken(0.1, &ColorSpace::SRGB);
+    assert!(ColorSpace::SRGB.contains(&lighter));
+    assert!(ColorSpace::SRGB.contains(&darker));
+    assert!((lighter.to_oklch().L - (blue.to_oklch().L + 0.1)).abs() < 0.001);
+    assert!((darker.to_oklch().L - (blue.to_oklch().L - 0.1)).abs() < 0.001);
+    // The hue is preserved.
+    assert!((lighter.to_oklch().h - blue.to_oklch().h).abs() < 0.5);
+
+    // Lightening a saturated color pushes it out of gamut, so chroma is reduced.
+    let red = Color::new_srgb(1.0, 0.0, 0.0, 1.0);
+    let lighter_red = red.lighten(0.2, &ColorSpace::SRGB);
+    assert!(ColorSpace::SRGB.contains(&lighter_red));
+    assert!(lighter_red.to_oklch().C < red.to_oklch().C);
+
+    let white = Color::new_srgb(1.0, 1.0, 1.0, 1.0).lighten(0.5, &ColorSpace::SRGB);
+    assert!(approx_equal(white.to_srgb(), (1.0, 1.0, 1.0, 1.0)));
+}
+
+// Tests that saturation scales chroma and is limited by the gamut.
+#[test]
+fn saturate_and_desaturate() {
+    let color = Color::new_srgb(0.4, 0.5, 0.6, 1.0);
+    let chroma = color.to_oklch().C;
+    let more = color.saturate(0.5, &ColorSpace::SRGB);
+    assert!((more.to_oklch().C - chroma * 1.5).abs() < 0.001);
+
+    let gray = color.desaturate(1.0, &ColorSpace::SRGB);
+    assert!(gray.to_oklch().C < 0.0001);
+    assert!((gray.to_oklch().L - color.to_oklch().L).abs() < 0.0001);
+
+    let oklch = color.to_oklch();
+    let max_chroma = ColorSpace::SRGB.max_chroma(oklch.L, oklch.h);
+    let saturated = color.saturate(100.0, &ColorSpace::SRGB);
+    assert!(ColorSpace::SRGB.contains(&saturated));
+    assert!((saturated.to_oklch().C - max_chroma).abs() < 0.001);
+}
+
+// Tests inverting colors in encoded and linear values.
+#[test]
+fn invert() {
+    let color = Color::new_srgb(0.2, 0.5, 1.0, 0.5);
+    let inverted = color.invert_in(&ColorSpace::SRGB);
+    assert!(approx_equal(inverted.to_srgb(), (0.8, 0.5, 0.0, 0.5)));
+
+    let linear = color.to_linear_srgb();
+    let inverted = color.invert_linear_in(&ColorSpace::SRGB);
+    let expected = (1.0 - linear.0, 1.0 - linear.1, 1.0 - linear.2, 0.5);
+    assert!(approx_equal(inverted.to_linear_srgb(), expected));
+
+    // Inverting twice returns the original color.
+    let twice = color
+        .invert_in(&ColorSpace::SRGB)
+        .invert_in(&ColorSpace::SRGB);
+    assert!(approx_equal(twice.to_srgb(), color.to_srgb()));
+}
+
+// Tests that grayscale conversion preserves luminance.
+#[test]
+fn grayscale() {
+    let color = Color::new_srgb(0.9, 0.2, 0.4, 1.0);
+    let gray = color.to_grayscale();
+    assert!(approx_equal_f64(gray.Y, color.Y));
+    let (r, g, b, _) = gray.to_srgb();
+    assert!(approx_equal_f64(r, g) && approx_equal_f64(g, b));
+
+    let mut pixels = [[0.9, 0.2, 0.4], [1.0, 1.0, 1.0]];
+    ColorSpace::SRGB.grayscale_slice(&mut pixels);
+    assert!(approx_equal_f64(pixels[0][0], r) && approx_equal_f64(pixels[0][2], r));
+    assert!(approx_equal_f64(pixels[1][1], 1.0));
+}
+
+// Tests that harmonies are spaced evenly in Oklch hue.
+#[test]
+fn harmony() {
+    let color = Color::new_srgb(0.5, 0.4, 0.3, 1.0);
+    let [base, complement] = harmony::complementary(&color, &ColorSpace::SRGB);
+    assert!(approx_equal_f64(base.to_oklch().h, color.to_oklch().h));
+    let difference = (complement.to_oklch().h - base.to_oklch().h).rem_euclid(360.0);
+    assert!((difference - 180.0).abs() < 1e-3);
+
+    let triad = harmony::triadic(&color, &ColorSpace::SRGB);
+    for color in &triad {
+        assert!(ColorSpace::SRGB.contains(color));
+    }
+    assert_eq!(harmony::tetradic(&color, &ColorSpace::SRGB).len(), 4);
+}
+
+// Tests that white balance adjustments warm, cool, and tint colors.
+#[test]
+fn white_balance() {
+    let gray = Color::new_srgb(0.5, 0.5, 0.5, 1.0);
+    let unchanged = gray.adjust_white_balance(0.0, 0.0).to_srgb();
+    assert!(approx_equal(unchanged, gray.to_srgb()));
+
+    let (r, _, b, _) = gray.adjust_white_balance(1000.0, 0.0).to_srgb();
+    assert!(r > b);
+    let (r, _, b, _) = gray.adjust_white_balance(-1000.0, 0.0).to_srgb();
+    assert!(r < b);
+    let (r, g, _, _) = gray.adjust_white_balance(0.0, 0.01).to_srgb();
+    assert!(r > g);
+
+    let mut pixels = [[0.5, 0.5, 0.5]];
+    ColorSpace::SRGB.adjust_white_balance_slice(&mut pixels, 1000.0, 0.0);
+    let (r, g, b, _) = gray.adjust_white_balance(1000.0, 0.0).to_srgb();
+    assert!(approx_equal(
+        (pixels[0][0], pixels[0][1], pixels[0][2], 1.0),
+        (r, g, b, 1.0)
+    ));
+}
+
+// Tests that vibrance boosts muted colors more than colorful ones.
+#[test]
+fn vibrance() {
+    let muted = Color::new_srgb(0.5, 0.45, 0.6, 1.0);
+    let colorful = Color::new_srgb(0.1, 0.1, 0.9, 1.0);
+    let muted_ratio = muted.vibrance(0.5, &ColorSpace::SRGB).to_oklch().C / muted.to_oklch().C;
+    let colorful_ratio =
+        colorful.vibrance(0.5, &ColorSpace::SRGB).to_oklch().C / colorful.to_oklch().C;
+    assert!(muted_ratio > colorful_ratio);
+    assert!(colorful_ratio >= 1.0 - 1e-3);
+
+    // Skin tones are boosted less than other muted colors.
+    let skin = Color::new_srgb(0.8, 0.6, 0.5, 1.0);
+    let skin_ratio = skin.vibrance(0.5, &ColorSpace::SRGB).to_oklch().C / skin.to_oklch().C;
+    assert!(skin_ratio > 1.0 && skin_ratio < muted_ratio);
+}
+
+// Tests exposure and gamma adjustments.
+#[test]
+fn exposure_and_gamma() {
+    let color = ColorSpace::SRGB_LINEAR.new_color(0.2, 0.1, 0.05, 1.0);
+    let brighter = ColorSpace::SRGB_LINEAR.color_to_rgba(&color.exposure(1.0));
+    assert!(approx_equal(brighter, (0.4, 0.2, 0.1, 1.0)));
+
+    let gray = Color::new_srgb(0.25, 0.25, 0.25, 1.0);
+    let (r, _, _, _) = gray.gamma(2.0, &ColorSpace::SRGB).to_srgb();
+    assert!(approx_equal_f64(r, 0.5));
+    let white = Color::new_srgb(1.0, 1.0, 1.0, 1.0).gamma(2.0, &ColorSpace::SRGB);
+    assert!(approx_equal(white.to_srgb(), (1.0, 1.0, 1.0, 1.0)));
+
+    let mut pixels = [[0.25, 0.5, 1.0]];
+    ColorSpace::SRGB.gamma_slice(&mut pixels, 2.0);
+    assert!(approx_equal_f64(pixels[0][0], 0.5));
+    let mut pixels = [[0.2, 0.1, 0.05]];
+    ColorSpace::SRGB_LINEAR.exposure_slice(&mut pixels, -1.0);
+    assert!(approx_equal_f64(pixels[0][0], 0.1));
+}
+
+// Tests premultiplying and unpremultiplying alpha.
+#[test]
+fn premultiply() {
+    let color = Color::new_srgb(1.0, 0.5, 0.2, 0.5);
+    let premultiplied = color.premultiply();
+    assert!(approx_equal_f64(premultiplied.Y, color.Y * 0.5));
+    assert!(approx_equal(
+        premultiplied.unpremultiply().to_srgb(),
+        color.to_srgb()
+    ));
+
+    let (r, g, b, a) = ColorSpace::SRGB.color_to_rgba_premultiplied(&color);
+    assert!(approx_equal((r, g, b, a), (0.5, 0.25, 0.1, 0.5)));
+    let round_trip = ColorSpace::SRGB.new_color_premultiplied(r, g, b, a);
+    assert!(approx_equal(round_trip.to_srgb(), color.to_srgb()));
+
+    let transparent = Color::new_srgb(1.0, 1.0, 1.0, 0.0);
+    assert!(approx_equal(
+        transparent.premultiply().unpremultiply().to_srgb(),
+        (0.0, 0.0, 0.0, 0.0)
+    ));
+}
+
+// Tests Porter-Duff compositing in linear light.
+#[test]
+fn porter_duff() {
+    let red = Color::new_linear_srgb(1.0, 0.0, 0.0, 0.5);
+    let blue = Color::new_linear_srgb(0.0, 0.0, 1.0, 1.0);
+    let result = red.over(&blue).to_linear_srgb();
+    assert!(approx_equal(result, (0.5, 0.0, 0.5, 1.0)));
+
+    let opaque = Color::new_linear_srgb(0.2, 0.4, 0.6, 1.0);
+    assert!(approx_equal(
+        opaque.over(&blue).to_linear_srgb(),
+        opaque.to_linear_srgb()
+    ));
+    assert!(approx_equal(
+        red.destination_over(&blue).to_linear_srgb(),
+        blue.to_linear_srgb()
+    ));
+    assert!(approx_equal_f64(red.source_in(&blue).a, 0.5));
+    assert!(approx_equal_f64(red.source_out(&blue).a, 0.0));
+    assert!(approx_equal_f64(red.xor(&blue).a, 0.5));
+    assert!(approx_equal_f64(red.plus(&blue).a, 1.0));
+}
+
+// Tests a few blend modes against values computed from the specification.
+#[test]
+fn blend_modes() {
+    let source = Color::new_srgb(0.5, 0.2, 0.8, 1.0);
+    let backdrop = Color::new_srgb(0.4, 0.6, 0.1, 1.0);
+    let blend = |mode| source.blend(&backdrop, mode, &ColorSpace::SRGB).to_srgb();
+
+    assert!(approx_equal(blend(BlendMode::Normal), source.to_srgb()));
+    assert!(approx_equal(
+        blend(BlendMode::Multiply),
+        (0.2, 0.12, 0.08, 1.0)
+    ));
+    assert!(approx_equal(
+        blend(BlendMode::Screen),
+        (0.7, 0.68, 0.82, 1.0)
+    ));
+    assert!(approx_equal(
+        blend(BlendMode::Difference),
+        (0.1, 0.4, 0.7, 1.0)
+    ));
+    assert!(approx_equal(blend(BlendMode::Darken), (0.4, 0.2, 0.1, 1.0)));
+
+    // Luminosity keeps the luminosity of the source.
+    let (r, g, b, _) = blend(BlendMode::Luminosity);
+    assert!(approx_equal_f64(
+        0.3 * r + 0.59 * g + 0.11 * b,
+        0.3 * 0.5 + 0.59 * 0.2 + 0.11 * 0.8
+    ));
+
+    // A transparent backdrop leaves the source unchanged.
+    let transparent = Color::new_srgb(0.4, 0.6, 0.1, 0.0);
+    let result = source.blend(&transparent, BlendMode::Multiply, &ColorSpace::SRGB);
+    assert!(approx_equal(result.to_srgb(), source.to_srgb()));
+}
+
+// Tests that averaging happens in linear light.
+#[test]
+fn average() {
+    let black = Color::new_srgb(0.0, 0.0, 0.0, 1.0);
+    let white = Color::new_srgb(1.0, 1.0, 1.0, 1.0);
+    let average = Color::average(&[black, white]).unwrap();
+    assert!(approx_equal(average.to_linear_srgb(), (0.5, 0.5, 0.5, 1.0)));
+
+    let weighted = Color::weighted_average([(black, 3.0), (white, 1.0)]).unwrap();
+    assert!(approx_equal_f64(weighted.Y, 0.25));
+
+    let oklab = Color::average_oklab(&[black, white]).unwrap();
+    assert!(approx_equal_f64(oklab.to_oklab().L, 0.5));
+
+    assert!(Color::average(&[]).is_none());
+    assert!(Color::weighted_average([(white, 0.0)]).is_none());
+}
+
+// Tests that colors sort by hue, lightness, and chroma.
+#[test]
+fn sort_keys() {
+    let red = Color::new_srgb(1.0, 0.0, 0.0, 1.0);
+    let green = Color::new_srgb(0.0, 1.0, 0.0, 1.0);
+    let blue = Color::new_srgb(0.0, 0.0, 1.0, 1.0);
+    let gray = Color::new_srgb(0.5, 0.5, 0.5, 1.0);
+
+    let mut colors = [blue, gray, green, red];
+    colors.sort_by_key(|c| c.sort_key_hue());
+    assert!(approx_equal(colors[0].to_srgb(), gray.to_srgb()));
+    assert!(approx_equal(colors[1].to_srgb(), red.to_srgb()));
+    assert!(approx_equal(colors[3].to_srgb(), blue.to_srgb()));
+
+    colors.sort_by_key(|c| c.sort_key_lightness());
+    assert!(approx_equal(colors[0].to_srgb(), blue.to_srgb()));
+    assert!(approx_equal(colors[3].to_srgb(), green.to_srgb()));
+
+    colors.sort_by_key(|c| c.sort_key_chroma());
+    assert!(approx_equal(colors[0].to_srgb(), gray.to_srgb()));
+}
+
+// Tests that median cut finds the distinct colors in an image.
+#[test]
+fn median_cut() {
+    let red = Color::new_srgb(1.0, 0.0, 0.0, 1.0);
+    let blue = Color::new_srgb(0.0, 0.0, 1.0, 1.0);
+    let mut pixels = Vec::new();
+    for i in 0..10 {
+        let offset = i as f64 * 0.001;
+        pixels.push(Color::new_srgb(1.0 - offset, offset, 0.0, 1.0));
+        pixels.push(Color::new_srgb(offset, 0.0, 1.0 - offset, 1.0));
+    }
+
+    for space in [
+        quantize::QuantizeSpace::Linear,
+        quantize::QuantizeSpace::Oklab,
+    ] {
+        let palette = quantize::median_cut(&pixels, 2, space);
+        assert_eq!(palette.len(), 2);
+        let has_color = |target: &Color| {
+            palette.iter().any(|c| {
+                c.to_oklab().L > 0.0 && (c.to_oklab().a - target.to_oklab().a).abs() < 0.02
+            })
+        };
+        assert!(has_color(&red) && has_color(&blue));
+    }
+
+    // Duplicate colors can't be split further.
+    assert_eq!(
+        quantize::median_cut(&[red, red, red], 4, Default::default()).len(),
+        1
+    );
+    assert!(quantize::median_cut(&[], 4, Default::default()).is_empty());
+}
+
+// Tests delta E metrics against reference values from Sharma et al.
+#[test]
+fn delta_e() {
+    let a = Lab::new(50.0, 2.6772, -79.7751);
+    let b = Lab::new(50.0, 0.0, -82.7485);
+    assert!((a.delta_e_2000(&b) - 2.0425).abs() < 1e-4);
+    let a = Lab::new(50.0, 2.5, 0.0);
+    let b = Lab::new(73.0, 25.0, -18.0);
+    assert!((a.delta_e_2000(&b) - 27.1492).abs() < 1e-4);
+    assert!(approx_equal_f64(
+        Lab::new(50.0, 0.0, 0.0).delta_e_76(&Lab::new(53.0, 4.0, 0.0)),
+        5.0
+    ));
+
+    let color = Color::new_srgb(0.2, 0.5, 0.7, 1.0);
+    assert!(DeltaE2000.distance(&color, &color) < 1e-9);
+    assert!(DeltaEOk.distance(&color, &Color::black()) > 0.0);
+}
+
+// Tests that k-means clustering finds distinct groups of colors.
+#[test]
+fn k_means() {
+    let mut pixels = Vec::new();
+    for i in 0..20 {
+        let offset = i as f64 * 0.005;
+        pixels.push(Color::new_srgb(0.9 - offset, 0.1, 0.1, 1.0));
+        pixels.push(Color::new_srgb(0.1, 0.8 - offset, 0.2, 1.0));
+        pixels.push(Color::new_srgb(0.1, 0.1 + offset, 0.9, 1.0));
+    }
+    let palette = quantize::k_means(&pixels, 3, &DeltaE2000);
+    assert_eq!(palette.len(), 3);
+    for pixel in &pixels {
+        let closest = palette
+            .iter()
+            .map(|p| DeltaEOk.distance(p, pixel))
+            .fold(f64::INFINITY, f64::min);
+        assert!(closest < 0.1);
+    }
+
+    // The same input always produces the same palette.
+    let again = quantize::k_means(&pixels, 3, &DeltaE2000);
+    for (a, b) in palette.iter().zip(again.iter()) {
+        assert!(approx_equal(a.to_srgb(), b.to_srgb()));
+    }
+}
+
+// Tests that the octree quantizer limits the palette size and maps colors to it.
+#[test]
+fn octree_quantizer() {
+    let mut quantizer = quantize::OctreeQuantizer::new(16, &ColorSpace::SRGB);
+    for r in 0..16 {
+        for g in 0..16 {
+            for b in 0..4 {
+                let color = Color::new_srgb(r as f64 / 15.0, g as f64 / 15.0, b as f64 / 3.0, 1.0);
+                quantizer.add(&color);
+            }
+        }
+    }
+    let palette = quantizer.finish();
+    assert!(palette.palette().len() <= 16 && palette.palette().len() >= 8);
+
+    let red = Color::new_srgb(1.0, 0.0, 0.0, 1.0);
+    let index = palette.index_of(&red);
+    let (r, g, _, _) = palette.palette()[index].to_srgb();
+    assert!(r > 0.5 && g < 0.5);
+}
+
+// Tests that the palette index finds the same color as a brute force search.
+#[test]
+fn palette_index() {
+    let mut palette = Vec::new();
+    for i in 0..27 {
+        let channel = |v: usize| (v % 3) as f64 / 2.0;
+        palette.push(Color::new_srgb(
+            channel(i),
+            channel(i / 3),
+            channel(i / 9),
+            1.0,
+        ));
+    }
+    let ok_index = PaletteIndex::new(&palette, DeltaEOk);
+    let de2000_index = PaletteIndex::new(&palette, DeltaE2000);
+
+    for i in 0..100 {
+        let t = i as f64 / 100.0;
+        let color = Color::new_srgb(t, (t * 7.0) % 1.0, (t * 13.0) % 1.0, 1.0);
+        let brute_force = |metric: &dyn ColorDistance| {
+            (0..palette.len())
+                .min_by(|a, b| {
+                    metric
+                        .distance(&palette[*a], &color)
+                        .total_cmp(&metric.distance(&palette[*b], &color))
+                })
+                .unwrap()
+        };
+        assert_eq!(ok_index.nearest(&color), Some(brute_force(&DeltaEOk)));
+        assert_eq!(de2000_index.nearest(&color), Some(brute_force(&DeltaE2000)));
+    }
+    assert!(PaletteIndex::new(&[], DeltaEOk)
+        .nearest(&Color::black())
+        .is_none());
+}
+
+// Tests that error diffusion preserves the average brightness of a gradient.
+#[test]
+fn error_diffusion() {
+    let black = Color::new_srgb(0.0, 0.0, 0.0, 1.0);
+    let white = Color::new_srgb(1.0, 1.0, 1.0, 1.0);
+    let palette = PaletteIndex::new(&[black, white], DeltaEOk);
+    let width = 128;
+    let mut pixels = Vec::new();
+    for _ in 0..32 {
+        for x in 0..width {
+            let value = x as f64 / (width - 1) as f64;
+            pixels.push([value, value, value]);
+        }
+    }
+    let input_average = pixels
+        .iter()
+        .map(|p| ColorSpace::SRGB.transfer_function().to_linear(p[0]))
+        .sum::<f64>()
+        / pixels.len() as f64;
+
+    for pattern in [
+        dither::ErrorDiffusion::FloydSteinberg,
+        dither::ErrorDiffusion::JarvisJudiceNinke,
+    ] {
+        let indices = dither::error_diffusion(&pixels, width, &ColorSpace::SRGB, &palette, pattern);
+        assert_eq!(indices.len(), pixels.len());
+        let output_average = indices.iter().sum::<usize>() as f64 / indices.len() as f64;
+        assert!((output_average - input_average).abs() < 0.02);
+    }
+}
+
+// Tests that ordered dithering only produces the allowed levels and preserves averages.
+#[test]
+fn ordered_dither() {
+    let width = 16;
+    let mut pixels = alloc::vec![[0.3, 0.5, 0.61]; width * width];
+    dither::ordered(&mut pixels, width, [2, 3, 32], 4);
+    let mut average = [0.0; 3];
+    for pixel in &pixels {
+        assert!(pixel[0] == 0.0 || pixel[0] == 1.0);
+        assert!(pixel[1] == 0.0 || pixel[1] == 0.5 || pixel[1] == 1.0);
+        for c in 0..3 {
+            average[c] += pixel[c] / pixels.len() as f64;
+        }
+    }
+    assert!((average[0] - 0.3).abs() < 0.05);
+    assert!((average[1] - 0.5).abs() < 0.05);
+    assert!((average[2] - 0.61).abs() < 0.01);
+}
+
+// Tests mapping colors to ANSI terminal colors.
+#[test]
+fn ansi() {
+    assert_eq!(Color::new_srgb(1.0, 0.0, 0.0, 1.0).to_ansi_16(), 9);
+    assert_eq!(Color::new_srgb(0.0, 0.0, 0.0, 1.0).to_ansi_16(), 0);
+    assert_eq!(Color::new_srgb(1.0, 0.0, 0.0, 1.0).to_ansi_256(), 196);
+    assert_eq!(Color::from_hex_srgb(0x808080, 1.0).to_ansi_256(), 244);
+    assert_eq!(
+        Color::from_hex_srgb(0x0a141e, 1.0).ansi_truecolor_foreground(),
+        "\x1b[38;2;10;20;30m"
+    );
+    assert_eq!(
+        Color::new_srgb(1.0, 0.0, 0.0, 1.0).ansi_256_background(),
+        "\x1b[48;5;196m"
+    );
+}
+
+// Tests that dominant color extraction favors a vivid accent over a dull background.
+#[test]
+fn dominant_colors() {
+    let mut pixels = alloc::vec![Color::new_srgb(0.5, 0.5, 0.5, 1.0); 900];
+    pixels.extend(core::iter::repeat_n(
+        Color::new_srgb(1.0, 0.4, 0.0, 1.0),
+        100,
+    ));
+    let dominant = quantize::extract_dominant_colors(&pixels, 2);
+    assert_eq!(dominant.len(), 2);
+    let (r, g, b, _) = dominant[0].color.to_srgb();
+    assert!(r > 0.9 && g < 0.5 && b < 0.1);
+    assert!((dominant[0].coverage - 0.1).abs() < 0.01);
+    assert!((dominant[1].coverage - 0.9).abs() < 0.01);
+}
+
+// Tests that palettes survive being written and read in each file format.
+#[test]
+fn palette_files() {
+    use palette_file::*;
+    let palette = Palette {
+        name: Some(String::from("Test")),
+        entries: alloc::vec![
+            PaletteEntry {
+                name: Some(String::from("Orange")),
+                color: Color::from_hex_srgb(0xff8000, 1.0),
+            },
+            PaletteEntry {
+                name: Some(String::from("Sky blue")),
+                color: Color::from_hex_srgb(0x64b4fa, 1.0),
+            },
+        ],
+    };
+    let check = |read: Palette| {
+        assert_eq!(read.entries.len(), 2);
+        for (a, b) in read.entries.iter().zip(palette.entries.iter()) {
+            assert_eq!(a.name, b.name);
+            let (r0, g0, b0, _) = a.color.to_srgb();
+            let (r1, g1, b1, _) = b.color.to_srgb();
+            assert!((r0 - r1).abs() < 1e-3 && (g0 - g1).abs() < 1e-3 && (b0 - b1).abs() < 1e-3);
+        }
+    };
+    let gpl = Palette::from_gpl(&palette.to_gpl()).unwrap();
+    assert_eq!(gpl.name.as_deref(), Some("Test"));
+    check(gpl);
+    check(Palette::from_aco(&palette.to_aco()).unwrap());
+    check(Palette::from_ase(&palette.to_ase()).unwrap());
+    assert!(Palette::from_gpl("Not a palette").is_err());
+}
+
+// Tests that truncated or corrupted palette files return errors instead of panicking.
+#[test]
+fn malformed_palette_files() {
+    use palette_file::*;
+    let palette = Palette {
+        name: Some(String::from("Test")),
+        entries: alloc::vec![PaletteEntry {
+            name: Some(String::from("Orange")),
+            color: Color::from_hex_srgb(0xff8000, 1.0),
+        }],
+    };
+    let aco = palette.to_aco();
+    let ase = palette.to_ase();
+    // The version 1 section alone is a valid file.
+    let version_1_length = 4 + 10 * palette.entries.len();
+    for length in 0..aco.len() {
+        let read = Palette::from_aco(&aco[..length]);
+        assert!(read.is_err() == (length != version_1_length));
+    }
+    for length in 0..ase.len() {
+        assert!(Palette::from_ase(&ase[..length]).is_err());
+    }
+
+    // A version 2 color with a huge name length.
+    let mut corrupted = alloc::vec![0, 2, 0, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0];
+    corrupted.extend_from_slice(&0xFFFF_FFF0u32.to_be_bytes());
+    corrupted.extend_from_slice(&[0, 0, 0, 0]);
+    assert!(matches!(
+        Palette::from_aco(&corrupted),
+        Err(PaletteFileError::UnexpectedEnd)
+    ));
+
+    // A block with a huge length.
+    let mut corrupted = ase.clone();
+    corrupted[14..18].copy_from_slice(&[0xFF; 4]);
+    assert!(Palette::from_ase(&corrupted).is_err());
+
+    assert!(Palette::from_gpl("").err() == Some(PaletteFileError::InvalidHeader));
+    assert!(PaletteFileError::UnexpectedEnd.to_string() == "the file ends unexpectedly");
+}
+
+// Tests that spectra integrate to the expected chromaticities.
+#[test]
+fn spectral() {
+    let wavelengths: Vec<f64> = (0..=80).map(|i| 380.0 + i as f64 * 5.0).collect();
+    let equal_energy = alloc::vec![1.0; wavelengths.len()];
+    for observer in [Observer::TwoDegrees, Observer::TenDegrees] {
+        let xyz = spectral::xyz_from_spd(&wavelengths, &equal_energy, None, observer);
+        let chromaticity = xyz.to_chromaticity();
+        println!("{:?}", chromaticity);
+        assert!((chromaticity.x - 1.0 / 3.0).abs() < 0.005);
+        assert!((chromaticity.y - 1.0 / 3.0).abs() < 0.005);
+    }
+
+    // A perfect reflector under an illuminant has a Y of 1.0.
+    let xyz = spectral::xyz_from_spd(
+        &wavelengths,
+        &equal_energy,
+        Some(&equal_energy),
+        Observer::TwoDegrees,
+    );
+    assert!(approx_equal_f64(xyz.Y, 1.0));
+    let peak = spectral::color_matching_function(555.0, Observer::TwoDegrees);
+    assert!((peak.Y - 1.0).abs() < 0.02);
+}
+
+// Tests black body colors against the known chromaticity of illuminant A (2856K).
+#[test]
+fn blackbody() {
+    let a = Color::from_blackbody(2856.0).xyz().to_chromaticity();
+    assert!((a.x - A_WHITE_POINT_2DEGREES.x).abs() < 0.003);
+    assert!((a.y - A_WHITE_POINT_2DEGREES.y).abs() < 0.003);
+    assert!(approx_equal_f64(Color::from_blackbody(6500.0).Y, 1.0));
+
+    let (r, _, b, _) = Color::from_blackbody(2000.0).to_srgb();
+    assert!(r > b);
+    let (r, _, b, _) = Color::from_blackbody(15000.0).to_srgb();
+    assert!(r < b);
+}
+
+// Tests that CCT and Duv are the inverse of black body colors.
+#[test]
+fn cct_duv() {
+    for kelvin in [1500.0, 2856.0, 5000.0, 9000.0, 20000.0] {
+        let (cct, duv) = Color::from_blackbody(kelvin).cct_duv();
+        assert!((cct - kelvin).abs() / kelvin < 1e-4);
+        assert!(duv.abs() < 1e-6);
+    }
+
+    // D65 is slightly above the Planckian locus.
+    let d65 = D65_WHITE_POINT_2DEGREES.to_XYZ();
+    let (cct, duv) = Color::new_xyza(d65.X, d65.Y, d65.Z, 1.0).cct_duv();
+    println!("D65 CCT: {} Duv: {}", cct, duv);
+    assert!((cct - 6504.0).abs() < 30.0);
+    assert!((duv - 0.0032).abs() < 0.001);
+}
+
+// Tests the chromaticity of pure wavelengths against the tabulated CIE 1931 values.
+#[test]
+fn spectral_locus() {
+    for (wavelength, x, y) in [
+        (450.0, 0.1566, 0.0177),
+        (520.0, 0.0743, 0.8338),
+        (580.0, 0.5125, 0.4866),
+        (700.0, 0.7347, 0.2653),
+    ] {
+        let chromaticity = Chromaticity::from_wavelength(wavelength);
+        assert!((chromaticity.x - x).abs() < 0.001 && (chromaticity.y - y).abs() < 0.001);
+    }
+
+    // Wavelengths past the ends of the locus get the endpoints instead of NaN.
+    assert!(Chromaticity::from_wavelength(300.0) == Chromaticity::from_wavelength(380.0));
+    assert!(Chromaticity::from_wavelength(1000.0) == Chromaticity::from_wavelength(780.0));
+    assert!(!Chromaticity::from_wavelength(1000.0).x.is_nan());
+    let locus = spectral::spectral_locus(Observer::TwoDegrees);
+    assert_eq!(locus.len(), 321);
+}
+
+// Tests that D-series spectra produce the standard white points.
+#[test]
+fn d_series() {
+    let d65 = spectral::d_series_white_point(6504.0, Observer::TwoDegrees);
+    assert!((d65.x - D65_WHITE_POINT_2DEGREES.x).abs() < 1e-4);
+    assert!((d65.y - D65_WHITE_POINT_2DEGREES.y).abs() < 1e-4);
+    let d50 = spectral::d_series_white_point(5003.0, Observer::TwoDegrees);
+    assert!((d50.x - D50_WHITE_POINT_2DEGREES.x).abs() < 1e-4);
+    assert!((d50.y - D50_WHITE_POINT_2DEGREES.y).abs() < 1e-4);
+
+    // The 10 degree white points are published with the tabulated CIE 1964 functions.
+    for (kelvin, white_point) in [
+        (6504.0, D65_WHITE_POINT_10DEGREES),
+        (5003.0, D50_WHITE_POINT_10DEGREES),
+    ] {
+        let calculated = spectral::d_series_white_point(kelvin, Observer::TenDegrees);
+        println!("{:?} {:?}", calculated, white_point);
+        assert!((calculated.x - white_point.x).abs() < 1e-4);
+        assert!((calculated.y - white_point.y).abs() < 1e-4);
+    }
+
+    let spd = spectral::d_series_spd(6504.0);
+    assert!(approx_equal_f64(spd[26], 100.0));
+}
+
+// Tests that a light scores a perfect color rendering index compared to itself,
+// and a light with a gap in its spectrum scores lower.
+#[test]
+fn color_rendering_index() {
+    let wavelengths: Vec<f64> = (0..=80).map(|i| 380.0 + i as f64 * 5.0).collect();
+    // Smooth made up reflectances, from red to blue.
+    let samples: Vec<Vec<f64>> = (0..8)
+        .map(|s| {
+            let peak = 420.0 + s as f64 * 40.0;
+            wavelengths
+                .iter()
+                .map(|w| 0.2 + 0.6 * float::exp(-((w - peak) / 60.0) * ((w - peak) / 60.0)))
+                .collect()
+        })
+        .collect();
+    let samples: Vec<&[f64]> = samples.iter().map(|s| s.as_slice()).collect();
+
+    let incandescent: Vec<f64> = wavelengths
+        .iter()
+        .map(|w| spectral::planck(*w, 2700.0))
+        .collect();
+    let result = spectral::color_rendering_index(&wavelengths, &incandescent, &samples).unwrap();
+    assert!((result.general - 100.0).abs() < 0.1);
+
+    // Remove the light's red wavelengths.
+    let filtered: Vec<f64> = wavelengths
+        .iter()
+        .zip(incandescent.iter())
+        .map(|(w, v)| if *w > 600.0 && *w < 650.0 { 0.0 } else { *v })
+        .collect();
+    let result = spectral::color_rendering_index(&wavelengths, &filtered, &samples).unwrap();
+    assert!(result.general < 99.0);
+    assert_eq!(result.special.len(), 8);
+
+    assert!(matches!(
+        spectral::color_rendering_index(&wavelengths, &filtered, &[]),
+        Err(Error::NotEnoughSamples)
+    ));
+}
+
+// Tests that a light scores a TM-30 fidelity and gamut of 100 against itself,
+// and that a light with a gap in its spectrum loses fidelity and red saturation.
+#[test]
+fn tm30() {
+    let wavelengths: Vec<f64> = (0..=80).map(|i| 380.0 + i as f64 * 5.0).collect();
+    // Smooth made up reflectances with peaks and dips across the spectrum, to cover every hue.
+    let samples: Vec<Vec<f64>> = (0..99)
+        .map(|s| {
+            let peak = 380.0 + (s / 3) as f64 * 12.5;
+            wavelengths
+                .iter()
+                .map(|w| {
+                    let bump = |peak: f64| float::exp(-((w - peak) / 40.0) * ((w - peak) / 40.0));
+                    match s % 3 {
+                        0 => 0.1 + 0.8 * bump(peak),
+                        1 => 0.9 - 0.8 * bump(peak),
+                        // Reds and pinks, with some blue to pull them towards magenta.
+                        _ => 0.05 + 0.8 * bump(640.0) + 0.01 * (s / 3) as f64 * bump(440.0),
+                    }
+                })
+                .collect()
+        })
+        .collect();
+    let samples: Vec<&[f64]> = samples.iter().map(|s| s.as_slice()).collect();
+
+    for kelvin in [2700.0, 4500.0, 6500.0] {
+        let light: Vec<f64> = if kelvin < 5000.0 {
+            wavelengths
+                .iter()
+                .map(|w| spectral::planck(*w, kelvin))
+                .collect()
+        } else {
+            let d_series = spectral::d_series_spd(kelvin);
+            wavelengths
+                .iter()
+                .map(|w| {
+                    let position = (w - 300.0) / 10.0;
+                    let i = position as usize;
+                    let t = position - i as f64;
+                    d_series[i] * (1.0 - t) + d_series[i + 1] * t
+                })
+                .collect()
+        };
+        let result = spectral::tm30(&wavelengths, &light, &samples).unwrap();
+        println!("{}K Rf: {} Rg: {}", kelvin, result.fidelity, result.gamut);
+        assert_eq!(result.sample_fidelity.len(), samples.len());
+        if kelvin == 4500.0 {
+            // Between 4000K and 5000K the reference is a blend of a black body and a D-series
+            // illuminant, so a black body is close to it but doesn't match exactly.
+            assert!(result.fidelity > 95.0 && (result.gamut - 100.0).abs() < 5.0);
+        } else {
+            assert!((result.fidelity - 100.0).abs() < 0.1);
+            assert!((result.gamut - 100.0).abs() < 0.1);
+        }
+    }
+
+    // Remove the light's red wavelengths.
+    let filtered: Vec<f64> = wavelengths
+        .iter()
+        .map(|w| {
+            if *w > 600.0 && *w < 650.0 {
+                0.0
+            } else {
+                spectral::planck(*w, 2700.0)
+            }
+        })
+        .collect();
+    let result = spectral::tm30(&wavelengths, &filtered, &samples).unwrap();
+    println!("Filtered Rf: {} Rg: {}", result.fidelity, result.gamut);
+    assert!(result.fidelity < 95.0);
+    assert!(result.gamut < 100.0);
+
+    // Every hue bin needs a sample.
+    assert!(matches!(
+        spectral::tm30(&wavelengths, &filtered, &samples[..4]),
+        Err(Error::NotEnoughSamples)
+    ));
+}
+
+// The spectrum of the CIE F2 (cool white fluorescent) illuminant from 380 to 780 nanometers
+// in 5 nanometer steps.
+#[rustfmt::skip]
+const F2_SPD: [f64; 81] = [
+    1.18, 1.48, 1.84, 2.15, 3.44, 15.69, 3.85, 3.74, 4.19, 4.62,
+    5.06, 34.98, 11.81, 6.27, 6.63, 6.93, 7.19, 7.40, 7.54, 7.62,
+    7.65, 7.62, 7.62, 7.45, 7.28, 7.15, 7.05, 7.04, 7.16, 7.47,
+    8.04, 8.88, 10.01, 24.88, 16.64, 14.59, 16.16, 17.56, 18.62, 21.47,
+    22.79, 19.29, 18.66, 17.73, 16.54, 15.21, 13.80, 12.36, 10.95, 9.65,
+    8.40, 7.32, 6.31, 5.43, 4.68, 4.02, 3.45, 2.96, 2.55, 2.19,
+    1.89, 1.64, 1.53, 1.27, 1.10, 0.99, 0.88, 0.76, 0.68, 0.61,
+    0.56, 0.54, 0.51, 0.47, 0.47, 0.43, 0.46, 0.47, 0.40, 0.33,
+    0.27,
+];
+
+// Tests CRI and TM-30 with the standard samples against published values for CIE F2.
+#[test]
+fn standard_color_samples() {
+    let samples: Vec<&[f64]> = spectral::TEST_COLOR_SAMPLES
+        .iter()
+        .map(|s| s.as_slice())
+        .collect();
+    let cri =
+        spectral::color_rendering_index(&spectral::SAMPLE_WAVELENGTHS, &F2_SPD, &samples).unwrap();
+    println!("F2 Ra: {} R: {:?}", cri.general, cri.special);
+    // CIE 15 lists each index rounded to a whole number.
+    let published = [56, 77, 90, 57, 59, 67, 74, 33, -84, 45, 46, 54, 60, 94];
+    assert!(float::round(cri.general) == 64.0);
+    for (calculated, published) in cri.special.iter().zip(published) {
+        assert!(float::round(*calculated) == published as f64);
+    }
+
+    let samples: Vec<&[f64]> = spectral::COLOR_EVALUATION_SAMPLES
+        .iter()
+        .map(|s| s.as_slice())
+        .collect();
+    let tm30 = spectral::tm30(&spectral::SAMPLE_WAVELENGTHS, &F2_SPD, &samples).unwrap();
+    println!("F2 Rf: {} Rg: {}", tm30.fidelity, tm30.gamut);
+    // The IES TM-30-20 calculator gives Rf 70 and Rg 86.
+    assert!((tm30.fidelity - 70.1).abs() < 0.5);
+    assert!((tm30.gamut - 86.4).abs() < 0.5);
+}
+
+// Tests that colors can be turned into reflectance spectra with the same color.
+#[test]
+fn sigmoid_spectrum() {
+    for (r, g, b) in [
+        (0.8, 0.2, 0.1),
+        (0.1, 0.6, 0.3),
+        (0.2, 0.3, 0.9),
+        (0.5, 0.5, 0.5),
+    ] {
+        let color = Color::new_srgb(r, g, b, 1.0);
+        let spectrum = spectral::SigmoidSpectrum::from_color(&color);
+        assert!(DeltaE2000.distance(&color, &spectrum.to_color()) < 0.01);
+        for wavelength in [380.0, 500.0, 780.0] {
+            let reflectance = spectrum.reflectance(wavelength);
+            assert!((0.0..=1.0).contains(&reflectance));
+        }
+    }
+}
+
+// Tests PQ encoding of relative and absolute colors against known code values.
+#[test]
+fn pq() {
+    let pq = TransferFunction::Pq {
+        reference_white: 1.0,
+    };
+    // 100 cd/m² is about 0.508 and 10000 cd/m² is 1.0.
+    assert!((pq.from_linear(100.0) - 0.5081).abs() < 1e-4);
+    assert!(approx_equal_f64(pq.from_linear(10000.0), 1.0));
+    assert!(approx_equal_f64(pq.to_linear(pq.from_linear(42.0)), 42.0));
+
+    // Diffuse white is 203 cd/m², which is about 0.58.
+    let relative = ColorSpace::rec2100_pq(HDR_REFERENCE_WHITE);
+    let absolute = ColorSpace::rec2100_pq(1.0);
+    let white = Color::new_srgb(1.0, 1.0, 1.0, 1.0);
+    let (r, _, _, _) = relative.color_to_rgba(&white);
+    assert!((r - 0.5807).abs() < 1e-3);
+    let (r2, _, _, _) = absolute.color_to_rgba(&white.relative_to_absolute(HDR_REFERENCE_WHITE));
+    assert!(approx_equal_f64(r, r2));
+    assert!(approx_equal_f64(
+        white
+            .relative_to_absolute(HDR_REFERENCE_WHITE)
+            .absolute_to_relative(HDR_REFERENCE_WHITE)
+            .Y,
+        white.Y
+    ));
+}
+
+// Tests that Reinhard tone mapping compresses luminance and preserves chromaticity.
+#[test]
+fn reinhard() {
+    use tonemap::ToneMapping;
+    let bright = Color::new_linear_srgb(3.0, 1.5, 0.5, 1.0);
+    let mapped = ToneMapping::Reinhard.map(&bright);
+    assert!(approx_equal_f64(mapped.Y, bright.Y / (1.0 + bright.Y)));
+    let (a, b) = (
+        bright.xyz().to_chromaticity(),
+        mapped.xyz().to_chromaticity(),
+    );
+    assert!(approx_equal_f64(a.x, b.x) && approx_equal_f64(a.y, b.y));
+
+    let extended = ToneMapping::ReinhardExtended { white: 4.0 };
+    let white = Color::new_linear_srgb(4.0, 4.0, 4.0, 1.0);
+    assert!(approx_equal_f64(extended.map(&white).Y, 1.0));
+
+    let mut pixels = [[3.0, 1.5, 0.5]];
+    ToneMapping::Reinhard.map_slice(&mut pixels, &ColorSpace::SRGB_LINEAR);
+    let expected = mapped.to_linear_srgb_unclipped();
+    assert!(approx_equal(
+        (pixels[0][0], pixels[0][1], pixels[0][2], 1.0),
+        expected
+    ));
+}
+
+// Tests that the ACES tone mapping curves compress HDR values into the output's range.
+#[test]
+fn aces() {
+    use tonemap::{AcesOutput, ToneMapping};
+    for output in [AcesOutput::Srgb, AcesOutput::DisplayP3].iter() {
+        for mapping in [
+            ToneMapping::AcesNarkowicz { output: *output },
+            ToneMapping::AcesHillFit { output: *output },
+            ToneMapping::AcesRrtOdt { output: *output },
+        ]
+        .iter()
+        {
+            let black = mapping.map(&Color::black());
+            assert!(black.Y.abs() < 0.01);
+
+            // Mid gray stays darker than diffuse white and very bright values approach white.
+            let gray = mapping.map(&Color::new_linear_srgb(0.18, 0.18, 0.18, 1.0));
+            let bright = mapping.map(&Color::new_linear_srgb(100.0, 100.0, 100.0, 1.0));
+            println!("{:?} gray: {:?} bright: {:?}", mapping, gray.Y, bright.Y);
+            assert!(gray.Y > 0.05 && gray.Y < 0.5);
+            assert!(bright.Y > 0.95 && bright.Y < 1.0 + 1e-6);
+
+            let mut pixels = [[20.0, 0.5, 0.1]];
+            mapping.map_slice(&mut pixels, &ColorSpace::SRGB);
+            assert!(pixels[0].iter().all(|c| *c >= -1e-6 && *c <= 1.0 + 1e-6));
+        }
+    }
+
+    // The reference ACES sRGB output maps mid gray to about 10 cd/m², or 0.356 encoded,
+    // and darkens saturated reds.
+    let mapping = ToneMapping::AcesRrtOdt {
+        output: AcesOutput::Srgb,
+    };
+    let gray = mapping.map(&Color::new_linear_srgb(0.18, 0.18, 0.18, 1.0));
+    let (r, g, b, _) = ColorSpace::SRGB.color_to_rgba(&gray);
+    println!("ACES RRT + ODT gray: {:?}", (r, g, b));
+    assert!((r - 0.356).abs() < 1e-3 && (g - 0.356).abs() < 1e-3 && (b - 0.356).abs() < 1e-3);
+
+    let red = mapping.map(&Color::new_linear_srgb(1.0, 0.0, 0.0, 1.0));
+    let (r, g, b, _) = ColorSpace::SRGB.color_to_rgba(&red);
+    println!("ACES RRT + ODT red: {:?}", (r, g, b));
+    assert!(r > 0.5 && r < 1.0 && g < r && b < r);
+}
+
+// Tests the HLG transfer function and the conversion from scene light to display light.
+#[test]
+fn hlg() {
+    let hlg = TransferFunction::Hlg;
+    assert!(approx_equal_f64(hlg.from_linear(1.0 / 12.0), 0.5));
+    assert!(approx_equal_f64(hlg.from_linear(1.0), 1.0));
+    assert!(approx_equal_f64(hlg.to_linear(hlg.from_linear(0.3)), 0.3));
+
+    assert!(approx_equal_f64(hlg_system_gamma(1000.0), 1.2));
+    assert!(hlg_system_gamma(100.0) < 1.0);
+    assert!(hlg_system_gamma(4000.0) > 1.2);
+
+    // Peak white is shown at the display's peak luminance.
+    let color_space = ColorSpace::rec2100_hlg();
+    let white = color_space.new_color(1.0, 1.0, 1.0, 1.0);
+    let display = white.hlg_ootf(1000.0);
+    println!("HLG white: {:?}", display);
+    assert!((display.Y - 1000.0).abs() < 1e-2);
+
+    let color = color_space.new_color(0.7, 0.4, 0.2, 1.0);
+    let display = color.hlg_ootf(400.0);
+    let scene = display.hlg_inverse_ootf(400.0);
+    assert!(approx_equal(
+        color_space.color_to_rgba(&scene),
+        (0.7, 0.4, 0.2, 1.0)
+    ));
+}
+
+// Tests that a display pipeline tone maps, adapts, and gamut maps colors in order.
+#[test]
+fn display_pipeline() {
+    use tonemap::ToneMapping;
+    let hdr = Color::new_linear_srgb(6.0, 3.0, 0.2, 1.0);
+
+    let pipeline = DisplayPipeline::new(ColorSpace::SRGB).tone_mapping(ToneMapping::Reinhard);
+    let expected = ColorSpace::SRGB
+        .color_to_rgba(&ColorSpace::SRGB.gamut_map(&ToneMapping::Reinhard.map(&hdr)));
+    assert!(approx_equal(pipeline.apply(&hdr), expected));
+
+    // Without tone mapping the bright color is mapped to white.
+    let untoned = DisplayPipeline::new(ColorSpace::SRGB).apply(&hdr);
+    assert!(approx_equal(untoned, (1.0, 1.0, 1.0, 1.0)));
+
+    let clipped = DisplayPipeline::new(ColorSpace::SRGB)
+        .gamut_mapping(GamutMapping::Clip)
+        .apply(&hdr);
+    assert!(approx_equal(clipped, hdr.to_srgb()));
+
+    // Adapting from D50 to D65 makes white bluer.
+    let adapted = DisplayPipeline::new(ColorSpace::SRGB)
+        .chromatic_adaptation(ChromaticAdaptation::new(
+            D50_WHITE_POINT_2DEGREES,
+            D65_WHITE_POINT_2DEGREES,
+        ))
+        .apply(&Color::new_srgb(0.5, 0.5, 0.5, 1.0));
+    assert!(adapted.2 > adapted.0);
+
+    // The precomputed steps match applying each step separately.
+    let aces = ToneMapping::AcesRrtOdt {
+        output: tonemap::AcesOutput::Srgb,
+    };
+    let adaptation = ChromaticAdaptation::new(D65_WHITE_POINT_2DEGREES, D50_WHITE_POINT_2DEGREES);
+    let combined = DisplayPipeline::new(ColorSpace::SRGB)
+        .tone_mapping(aces)
+        .chromatic_adaptation(adaptation.clone())
+        .gamut_mapping(GamutMapping::Clip)
+        .apply(&hdr);
+    let xyz = adaptation.convert(aces.map(&hdr).xyz());
+    let expected_combined =
+        ColorSpace::SRGB.color_to_rgba(&Color::new_xyza(xyz.X, xyz.Y, xyz.Z, 1.0));
+    assert!(approx_equal(combined, expected_combined));
+
+    let mut pixels = [[6.0, 3.0, 0.2]];
+    pipeline.apply_slice(&mut pixels, &ColorSpace::SRGB_LINEAR);
+    assert!(approx_equal(
+        (pixels[0][0], pixels[0][1], pixels[0][2], 1.0),
+        expected
+    ));
+}
+
+// Tests that 8-bit RGBA values round trip and are rounded and clamped.
+#[test]
+fn rgba8() {
+    for space in [ColorSpace::SRGB, ColorSpace::SRGB_LINEAR].iter() {
+        for v in 0..=255u8 {
+            let rgba = [v, 255 - v, v / 2, 255];
+            let color = space.new_color_from_rgba8_array(rgba);
+            assert_eq!(space.color_to_rgba8(&color), rgba);
+        }
+    }
+    let color = ColorSpace::SRGB.new_color(1.5, -0.2, 0.5, 1.0);
+    assert_eq!(ColorSpace::SRGB.color_to_rgba8(&color), [255, 0, 128, 255]);
+    // 0.499 / 255 rounds down and 0.501 / 255 rounds up.
+    let color = ColorSpace::SRGB_LINEAR.new_color(0.499 / 255.0, 0.501 / 255.0, 0.0, 0.0);
+    assert_eq!(ColorSpace::SRGB_LINEAR.color_to_rgba8(&color), [0, 1, 0, 0]);
+}
+
+// Tests that colors created from bytes keep their channel order.
+#[test]
+fn rgba8_struct() {
+    let rgba = Rgba8 {
+        r: 255,
+        g: 128,
+        b: 0,
+        a: 255,
+    };
+    let color = ColorSpace::SRGB.new_color_from_rgba8(rgba);
+    assert_eq!(ColorSpace::SRGB.color_to_rgba8(&color), [255, 128, 0, 255]);
+
+    let color = Color::from_bytes_srgb(255, 128, 0, 255);
+    assert!(approx_equal(
+        color.to_srgb(),
+        (1.0, 128.0 / 255.0, 0.0, 1.0)
+    ));
+}
+
+// Tests that 16-bit RGBA values round trip without losing precision.
+#[test]
+fn rgba16() {
+    for space in [ColorSpace::SRGB, ColorSpace::SRGB_LINEAR].iter() {
+        for v in (0..=u16::MAX).step_by(7) {
+            let rgba = [v, u16::MAX - v, v / 3, u16::MAX];
+            let color = space.new_color_from_rgba16_array(rgba);
+            assert_eq!(space.color_to_rgba16(&color), rgba);
+        }
+    }
+    // 8-bit values scale to 16-bit values by multiplying by 257.
+    let color = ColorSpace::SRGB.new_color_from_rgba8_array([1, 128, 255, 0]);
+    assert_eq!(
+        ColorSpace::SRGB.color_to_rgba16(&color),
+        [257, 128 * 257, 65535, 0]
+    );
+}
+
+// Tests packing colors into RGB565 and RGBA4444.
+#[test]
+fn packed() {
+    let srgb = ColorSpace::SRGB;
+    assert_eq!(
+        srgb.color_to_rgb565(&Color::from_hex_srgb(0xFF0000, 1.0)),
+        0xF800
+    );
+    assert_eq!(
+        srgb.color_to_rgb565(&Color::from_hex_srgb(0x00FF00, 1.0)),
+        0x07E0
+    );
+    assert_eq!(
+        srgb.color_to_rgb565(&Color::from_hex_srgb(0xFFFFFF, 1.0)),
+        0xFFFF
+    );
+    assert_eq!(
+        srgb.color_to_rgba4444(&Color::from_hex_srgb(0x0000FF, 0.0)),
+        0x00F0
+    );
+    for packed in [0x1234u16, 0xABCD, 0x0841, 0xFFFF].iter() {
+        assert_eq!(
+            srgb.color_to_rgb565(&srgb.new_color_from_rgb565(*packed)),
+            *packed
+        );
+        assert_eq!(
+            srgb.color_to_rgba4444(&srgb.new_color_from_rgba4444(*packed)),
+            *packed
+        );
+    }
+
+    // Dithering offsets average out to the original value.
+    let value = 0.3;
+    let offsets = [-0.375, -0.125, 0.125, 0.375];
+    let average = offsets
+        .iter()
+        .map(|o| packed::unpack_rgb565(packed::pack_rgb565_dithered([value; 3], *o))[0])
+        .sum::<f64>()
+        / 4.0;
+    println!("Dithered average: {:?}", average);
+    assert!(
+        (average - value).abs()
+            < (value - packed::unpack_rgb565(packed::pack_rgb565([value; 3]))[0]).abs()
+    );
+}
+
+// Tests packing colors into 10-bit formats.
+#[test]
+fn packed_10_bit() {
+    let srgb = ColorSpace::SRGB;
+    assert_eq!(
+        srgb.color_to_rgb10a2(&Color::from_hex_srgb(0xFF0000, 1.0)),
+        0xC00003FF
+    );
+    assert_eq!(
+        srgb.color_to_rgb10a2(&Color::from_hex_srgb(0x0000FF, 0.0)),
+        0x3FF00000
+    );
+    for packed in [0x12345678u32, 0xC00FF3FF, 0].iter() {
+        assert_eq!(
+            srgb.color_to_rgb10a2(&srgb.new_color_from_rgb10a2(*packed)),
+            *packed
+        );
+    }
+
+    // A PQ encoded HDR10 pixel of diffuse white.
+    let hdr10 = ColorSpace::rec2100_pq(HDR_REFERENCE_WHITE);
+    let white = hdr10.color_to_rgb10a2(&Color::new_srgb(1.0, 1.0, 1.0, 1.0));
+    println!("HDR10 white: {:x}", white);
+    assert!(((white & 0x3FF) as i32 - 594).abs() <= 1);
+
+    assert_eq!(packed::pack_p010(1.0), 0xFFC0);
+    assert_eq!(packed::pack_p010(0.0), 0);
+    assert!(approx_equal_f64(
+        packed::unpack_p010(packed::pack_p010(512.0 / 1023.0)),
+        512.0 / 1023.0
+    ));
+}
+
+// Tests the RGB9E5 shared exponent format.
+#[test]
+fn rgb9e5() {
+    use packed::{pack_rgb9e5, unpack_rgb9e5};
+    assert_eq!(unpack_rgb9e5(pack_rgb9e5([0.0, 0.0, 0.0])), [0.0, 0.0, 0.0]);
+    assert_eq!(
+        unpack_rgb9e5(pack_rgb9e5([1.0, 0.5, 0.25])),
+        [1.0, 0.5, 0.25]
+    );
+    assert_eq!(
+        unpack_rgb9e5(pack_rgb9e5([1e9, -1.0, 0.0])),
+        [65408.0, 0.0, 0.0]
+    );
+
+    // The largest channel keeps at least 8 bits of precision.
+    for value in [0.001, 0.18, 0.999, 1.0, 3.7, 250.0, 4095.9].iter() {
+        let rgb = unpack_rgb9e5(pack_rgb9e5([*value, value / 4.0, 0.0]));
+        println!("{:?} -> {:?}", value, rgb);
+        assert!((rgb[0] - value).abs() / value < 1.0 / 256.0);
+    }
+
+    let color = Color::new_linear_srgb(12.0, 1.0, 0.5, 1.0);
+    let round_trip = ColorSpace::SRGB_LINEAR
+        .new_color_from_rgb9e5(ColorSpace::SRGB_LINEAR.color_to_rgb9e5(&color));
+    assert!(approx_equal(
+        round_trip.to_linear_srgb_unclipped(),
+        (12.0, 1.0, 0.5, 1.0)
+    ));
+}
+
+// Tests formatting colors as hex strings.
+#[test]
+fn hex_string() {
+    let srgb = ColorSpace::SRGB;
+    let color = Color::from_hex_srgb(0x1a2b3c, 0.5);
+    assert_eq!(srgb.color_to_hex_string(&color, false), "#1a2b3c");
+    assert_eq!(srgb.color_to_hex_string(&color, true), "#1a2b3c80");
+
+    // Out of gamut colors stay valid hex colors with a similar hue.
+    let vivid = Color::from_oklch(Oklch::new(0.7, 0.4, 150.0), 1.0);
+    let hex = srgb.color_to_hex_string(&vivid, false);
+    println!("Vivid green: {}", hex);
+    assert_eq!(hex.len(), 7);
+    assert!(hex.starts_with('#'));
+}
+
+// Tests that encoded and linear 8-bit values differ by the transfer function.
+#[test]
+fn encoded_and_linear_rgba8() {
+    let gray = ColorSpace::SRGB.new_color_from_rgba8_array([128, 128, 128, 255]);
+    assert_eq!(
+        gray.to_encoded_rgba8(&ColorSpace::SRGB),
+        [128, 128, 128, 255]
+    );
+    // Linear mid gray is about 21% of white.
+    assert_eq!(gray.to_linear_rgba8(&ColorSpace::SRGB), [55, 55, 55, 255]);
+    assert_eq!(
+        gray.to_linear_rgba8(&ColorSpace::SRGB),
+        gray.to_encoded_rgba8(&ColorSpace::SRGB_LINEAR)
+    );
+
+    let color = ColorSpace::SRGB.new_color_from_linear_rgba8_array([55, 200, 10, 255]);
+    assert_eq!(color.to_linear_rgba8(&ColorSpace::SRGB), [55, 200, 10, 255]);
+}
+
+// Tests that a 3D LUT reproduces the conversion it was generated from.
+#[test]
+fn lut3d() {
+    let rec2100 = ColorSpace::rec2100_pq(HDR_REFERENCE_WHITE);
+    let lut = lut::generate_lut3d(&ColorSpace::SRGB, &rec2100, 33);
+    assert_eq!(lut.entries().len(), 33 * 33 * 33);
+
+    let converter = ColorSpaceConverter::new(&ColorSpace::SRGB, &rec2100);
+    for rgb in [
+        [0.0, 0.0, 0.0],
+        [1.0, 1.0, 1.0],
+        [0.25, 0.5, 0.75],
+        [0.9, 0.1, 0.3],
+    ]
+    .iter()
+    {
+        let expected = converter.convert_encoded_color(&(rgb[0], rgb[1], rgb[2]));
+        let result = lut.apply(*rgb);
+        println!("{:?} -> {:?} expected {:?}", rgb, result, expected);
+        assert!((result[0] - expected.0).abs() < 2e-3);
+        assert!((result[1] - expected.1).abs() < 2e-3);
+        assert!((result[2] - expected.2).abs() < 2e-3);
+    }
+
+    // Grid points are exact.
+    let identity = lut::generate_lut3d(&ColorSpace::SRGB, &ColorSpace::SRGB, 5);
+    let result = identity.apply([0.25, 0.75, 1.0]);
+    assert!(approx_equal(
+        (result[0], result[1], result[2], 1.0),
+        (0.25, 0.75, 1.0, 1.0)
+    ));
+
+    let pipeline = DisplayPipeline::new(ColorSpace::SRGB).gamut_mapping(GamutMapping::Clip);
+    let lut = lut::generate_lut3d_with_pipeline(&rec2100, &pipeline, 17);
+    assert!(lut
+        .entries()
+        .iter()
+        .all(|e| e.iter().all(|v| (0.0..=1.0).contains(v))));
+}
+
+// Tests reading and writing .cube LUT files.
+#[test]
+fn cube_file() {
+    use lut_file::CubeFile;
+    let text = "# A comment\nTITLE \"Invert\"\nLUT_1D_SIZE 2\nDOMAIN_MIN 0 0 0\nDOMAIN_MAX 2 2 2\n1 1 1\n0 0 0\n";
+    let file = CubeFile::from_cube(text).unwrap();
+    assert_eq!(file.title.as_deref(), Some("Invert"));
+    assert!(file.lut_3d.is_none());
+    // The domain is 0.0 to 2.0, so 1.0 is in the middle.
+    let result = file.apply([1.0, 0.0, 2.0]);
+    assert!(approx_equal(
+        (result[0], result[1], result[2], 1.0),
+        (0.5, 1.0, 0.0, 1.0)
+    ));
+
+    let round_trip = CubeFile::from_cube(&file.to_cube()).unwrap();
+    assert_eq!(round_trip, file);
+
+    // A shaper and a 3D LUT together.
+    let mut lut_3d = lut::generate_lut3d(&ColorSpace::SRGB, &ColorSpace::SRGB_LINEAR, 3);
+    lut_3d.set_domain([0.0; 3], [0.5; 3]);
+    let file = CubeFile {
+        title: None,
+        lut_1d: lut::Lut1d::new(vec![[0.0; 3], [0.25; 3], [1.0; 3]]),
+        lut_3d: Some(lut_3d),
+    };
+    let text = file.to_cube();
+    println!("{}", text);
+    assert!(text.contains("LUT_3D_INPUT_RANGE 0.000000 0.500000"));
+    let round_trip = CubeFile::from_cube(&text).unwrap();
+    assert_eq!(round_trip.lut_3d.unwrap().domain(), ([0.0; 3], [0.5; 3]));
+    assert_eq!(round_trip.lut_1d.unwrap().size(), 3);
+
+    assert!(CubeFile::from_cube("LUT_3D_SIZE 2\n0 0 0\n").is_err());
+    assert!(CubeFile::from_cube("0 0 0\n").is_err());
+
+    // 4194304³ overflows, and would wrap to 0 entries.
+    assert!(matches!(
+        CubeFile::from_cube("LUT_3D_SIZE 4194304\n"),
+        Err(lut_file::LutFileError::InvalidSize)
+    ));
+    assert!(lut::Lut3d::new(4194304, vec![]).is_none());
+    assert!(
+        CubeFile::from_cube("LUT_3D_SIZE 2\n0 0 0\n").err()
+            == Some(lut_file::LutFileError::WrongEntryCount)
+    );
+    assert!(lut_file::LutFileError::InvalidSize.to_string() == "invalid LUT size");
+    #[cfg(feature = "serde")]
+    {
+        let lut_3d = lut::Lut3d::from_fn(2, |rgb| rgb);
+        let json = serde_json::to_string(&lut_3d).unwrap();
+        assert!(serde_json::from_str::<lut::Lut3d>(&json).unwrap() == lut_3d);
+        let domain = r#""domain_min":[0.0,0.0,0.0],"domain_max":[1.0,1.0,1.0]"#;
+        for invalid in [
+            alloc::format!(r#"{{"size":0,"entries":[],{}}}"#, domain),
+            alloc::format!(r#"{{"size":2,"entries":[[0.0,0.0,0.0]],{}}}"#, domain),
+            alloc::format!(r#"{{"size":4194304,"entries":[],{}}}"#, domain),
+        ] {
+            assert!(serde_json::from_str::<lut::Lut3d>(&invalid).is_err());
+        }
+        let invalid = alloc::format!(r#"{{"entries":[[0.0,0.0,0.0]],{}}}"#, domain);
+        assert!(serde_json::from_str::<lut::Lut1d>(&invalid).is_err());
+    }
+}
+
+// Tests sampling transfer functions into tables and shaper LUTs.
+#[test]
+fn transfer_function_lut() {
+    let lut = SRGB_TRANSFER_FUNCTION.to_lut(1024);
+    assert!(TransferFunctionLut::from_decode(vec![0.0], 16).is_err());
+    assert!(TransferFunctionLut::new(vec![0.0, 1.0], vec![]).is_err());
+    for i in 0..=100 {
+        let x = i as f64 / 100.0;
+        assert!((lut.to_linear(x) - SRGB_TRANSFER_FUNCTION.to_linear(x)).abs() < 1e-5);
+        // The encoding is steep near 0.0 so it's less accurate.
+        assert!((lut.from_linear(x) - SRGB_TRANSFER_FUNCTION.from_linear(x)).abs() < 2e-3);
+    }
+
+    let (shaper, cube) = lut::generate_lut3d_with_shaper(
+        &ColorSpace::SRGB_LINEAR,
+        &ColorSpace::SRGB,
+        &SRGB_TRANSFER_FUNCTION,
+        4096,
+        17,
+    );
+    let file = lut_file::CubeFile {
+        title: None,
+        lut_1d: Some(shaper),
+        lut_3d: Some(cube),
+    };
+    for rgb in [[0.01, 0.02, 0.005], [0.5, 0.2, 0.9]].iter() {
+        let result = file.apply(*rgb);
+        let expected = Color::new_linear_srgb(rgb[0], rgb[1], rgb[2], 1.0).to_srgb();
+        println!("{:?} expected {:?}", result, expected);
+        assert!((result[0] - expected.0).abs() < 1e-3);
+        assert!((result[1] - expected.1).abs() < 1e-3);
+        assert!((result[2] - expected.2).abs() < 1e-3);
+    }
+}
+
+// Tests that tetrahedral interpolation keeps grays on the LUT's neutral axis.
+#[test]
+fn lut3d_tetrahedral() {
+    // A LUT that desaturates colors, so off-diagonal entries differ from grays.
+    let lut = lut::Lut3d::from_fn(2, |[r, g, b]| {
+        let gray = (r + g + b) / 3.0;
+        [gray * gray, gray * gray, gray * gray]
+    });
+    // Along the diagonal only the black and white corners are used.
+    let result = lut.apply([0.5, 0.5, 0.5]);
+    assert!(approx_equal(
+        (result[0], result[1], result[2], 1.0),
+        (0.5, 0.5, 0.5, 1.0)
+    ));
+    let trilinear = lut.apply_trilinear([0.5, 0.5, 0.5]);
+    println!("Tetrahedral: {:?} trilinear: {:?}", result, trilinear);
+    assert!(trilinear[0] < 0.45);
+
+    // Both methods are exact for linear conversions.
+    let lut = lut::generate_lut3d(&ColorSpace::SRGB_LINEAR, &ColorSpace::SRGB_LINEAR, 4);
+    for rgb in [[0.1, 0.7, 0.3], [0.9, 0.2, 0.95], [0.4, 0.4, 0.8]].iter() {
+        for result in [lut.apply(*rgb), lut.apply_trilinear(*rgb)].iter() {
+            assert!(approx_equal(
+                (result[0], result[1], result[2], 1.0),
+                (rgb[0], rgb[1], rgb[2], 1.0)
+            ));
+        }
+    }
+    let mut pixels = [[0.1, 0.7, 0.3]];
+    lut.apply_slice(&mut pixels);
+    assert!(approx_equal_f64(pixels[0][1], 0.7));
+}
+
+// Tests reading and writing HALD CLUT images.
+#[test]
+fn hald() {
+    let (pixels, width) = lut::hald_identity(4);
+    assert_eq!(width, 64);
+    assert_eq!(pixels.len(), 64 * 64);
+    // The first row has the first 4 green rows of 16 red values.
+    assert_eq!(pixels[15], [1.0, 0.0, 0.0]);
+    assert_eq!(pixels[16], [0.0, 1.0 / 15.0, 0.0]);
+
+    let identity = lut::Lut3d::from_hald(pixels, width).unwrap();
+    assert_eq!(identity.size(), 16);
+    let result = identity.apply([0.3, 0.6, 0.9]);
+    assert!(approx_equal(
+        (result[0], result[1], result[2], 1.0),
+        (0.3, 0.6, 0.9, 1.0)
+    ));
+
+    let lut = lut::generate_lut3d(&ColorSpace::SRGB, &ColorSpace::SRGB_LINEAR, 33);
+    let (pixels, width) = lut.to_hald(8);
+    assert_eq!(width, 512);
+    let round_trip = lut::Lut3d::from_hald(pixels, width).unwrap();
+    let (a, b) = (
+        lut.apply([0.2, 0.5, 0.7]),
+        round_trip.apply([0.2, 0.5, 0.7]),
+    );
+    assert!((a[0] - b[0]).abs() < 1e-3 && (a[1] - b[1]).abs() < 1e-3 && (a[2] - b[2]).abs() < 1e-3);
+
+    assert!(lut::Lut3d::from_hald(vec![[0.0; 3]; 100], 10).is_none());
+}
+
+// Tests that the color space builder matches `ColorSpace::new` and rejects invalid settings.
+#[test]
+fn color_space_builder() {
+    let red = Chromaticity::new(0.64, 0.33);
+    let green = Chromaticity::new(0.30, 0.60);
+    let blue = Chromaticity::new(0.15, 0.06);
+    let built = ColorSpace::builder()
+        .primaries(red, green, blue)
+        .white_point_xyz(D65_WHITE_POINT_2DEGREES.to_XYZ())
+        .transfer_function(SRGB_TRANSFER_FUNCTION)
+        .build()
+        .unwrap();
+    let color = Color::new_srgb(0.2, 0.4, 0.8, 1.0);
+    assert!(approx_equal(
+        built.color_to_rgba(&color),
+        (0.2, 0.4, 0.8, 1.0)
+    ));
+
+    assert!(matches!(
+        ColorSpace::builder().red_primary(red).build(),
+        Err(ColorSpaceError::MissingPrimary)
+    ));
+    assert!(matches!(
+        ColorSpace::builder().primaries(red, green, blue).build(),
+        Err(ColorSpaceError::MissingWhitePoint)
+    ));
+    assert!(matches!(
+        ColorSpace::builder()
+            .primaries(red, green, Chromaticity::new(0.15, 0.0))
+            .white_point(D65_WHITE_POINT_2DEGREES)
+            .build(),
+        Err(ColorSpaceError::InvalidChromaticity)
+    ));
+    assert!(matches!(
+        ColorSpace::builder()
+            .primaries(
+                red,
+                Chromaticity::new(0.5, 0.4),
+                Chromaticity::new(0.36, 0.47)
+            )
+            .white_point(D65_WHITE_POINT_2DEGREES)
+            .build(),
+        Err(ColorSpaceError::CollinearPrimaries)
+    ));
+
+    let error = ColorSpace::builder().build().err();
+    assert!(error == Some(ColorSpaceError::MissingPrimary));
+    assert!(ColorSpaceError::MissingWhitePoint.to_string() == "the white point is missing");
+    assert!(Error::from(ColorSpaceError::MissingWhitePoint) == Error::MissingSetting);
+}
+
+// Tests the fallible constructors.
+#[test]
+fn fallible_constructors() {
+    let srgb = ColorSpace::SRGB;
+    let color = srgb.new_color_from_hex_str("#1a2b3c").unwrap();
+    assert_eq!(srgb.color_to_rgba8(&color), [0x1a, 0x2b, 0x3c, 255]);
+    let color = srgb.new_color_from_hex_str("f0a8").unwrap();
+    assert_eq!(srgb.color_to_rgba8(&color), [0xff, 0x00, 0xaa, 0x88]);
+    assert_eq!(
+        srgb.new_color_from_hex_str("#12345").unwrap_err(),
+        Error::InvalidHex
+    );
+    assert_eq!(
+        srgb.new_color_from_hex_str("#ggg").unwrap_err(),
+        Error::InvalidHex
+    );
+    assert_eq!(
+        srgb.new_color_from_hex_str("#é12").unwrap_err(),
+        Error::InvalidHex
+    );
+
+    assert!(ColorSpace::try_new(
+        Chromaticity::new(0.64, 0.33),
+        Chromaticity::new(0.30, 0.60),
+        Chromaticity::new(0.15, 0.06),
+        D65_WHITE_POINT_2DEGREES,
+        TransferFunction::None,
+    )
+    .is_ok());
+    assert_eq!(
+        ColorSpace::try_new(
+            Chromaticity::new(0.64, 0.33),
+            Chromaticity::new(0.30, 0.60),
+            Chromaticity::new(0.15, 0.06),
+            Chromaticity::new(0.3, 0.0),
+            TransferFunction::None,
+        )
+        .unwrap_err(),
+        Error::InvalidChromaticity
+    );
+
+    let xyz = XYZ::new(0.5, 0.5, 0.5);
+    assert_eq!(
+        ColorSpace::try_new_xyz_d50(xyz, xyz, XYZ::new(0.1, 0.2, 0.3), TransferFunction::None)
+            .unwrap_err(),
+        Error::SingularMatrix
+    );
+}
+
+// Tests looking up built-in color spaces by name.
+#[test]
+fn standard_color_spaces() {
+    for standard in StandardColorSpace::ALL.iter() {
+        assert_eq!(
+            StandardColorSpace::from_name(standard.name()),
+            Some(*standard)
+        );
+        // Every built-in color space round trips colors within its gamut.
+        let color_space = ColorSpace::from_standard(*standard);
+        let color = Color::new_srgb(0.3, 0.6, 0.4, 1.0);
+        let (r, g, b, a) = color_space.color_to_rgba(&color);
+        let round_trip = color_space.new_color(r, g, b, a);
+        println!("{:?}: {:?}", standard, (r, g, b));
+        assert!(approx_equal(round_trip.to_srgb(), (0.3, 0.6, 0.4, 1.0)));
+    }
+    assert_eq!(
+        StandardColorSpace::from_name(" Display_P3 "),
+        Some(StandardColorSpace::DisplayP3)
+    );
+    assert_eq!(StandardColorSpace::from_name("not a color space"), None);
+    assert_eq!(ColorSpace::from_name("srgb"), Some(ColorSpace::SRGB));
+}
+
+// Tests approximate equality of colors and color spaces.
+#[test]
+fn approx_eq() {
+    let a = Color::new_srgb(0.2, 0.4, 0.6, 1.0);
+    let b = ColorSpace::SRGB_LINEAR.new_color_from_rgba_f32(a.to_linear_srgb().into());
+    assert!(a.approx_eq(&b, 1e-6));
+    assert!(!a.approx_eq(&Color::new_srgb(0.2, 0.4, 0.61, 1.0), 1e-6));
+
+    // The same color space built from its chromaticities and with an equivalent curve.
+    let built = ColorSpace::new(
+        Chromaticity::new(0.64, 0.33),
+        Chromaticity::new(0.30, 0.60),
+        Chromaticity::new(0.15, 0.06),
+        D65_WHITE_POINT_2DEGREES,
+        SRGB_TRANSFER_FUNCTION,
+    );
+    assert!(built.approx_eq(&ColorSpace::SRGB, 1e-6));
+    assert!(!ColorSpace::SRGB.approx_eq(&ColorSpace::SRGB_LINEAR, 1e-6));
+    assert!(!ColorSpace::SRGB.approx_eq(
+        &ColorSpace::from_standard(StandardColorSpace::DisplayP3),
+        1e-6
+    ));
+}
+
+// Tests that the color constants match their sRGB values.
+#[test]
+fn color_constants() {
+    let constants = [
+        (Color::BLACK, (0.0, 0.0, 0.0, 1.0)),
+        (Color::WHITE, (1.0, 1.0, 1.0, 1.0)),
+        (Color::TRANSPARENT, (0.0, 0.0, 0.0, 0.0)),
+        (Color::RED, (1.0, 0.0, 0.0, 1.0)),
+        (Color::GREEN, (0.0, 1.0, 0.0, 1.0)),
+        (Color::BLUE, (0.0, 0.0, 1.0, 1.0)),
+        (Color::YELLOW, (1.0, 1.0, 0.0, 1.0)),
+        (Color::CYAN, (0.0, 1.0, 1.0, 1.0)),
+        (Color::MAGENTA, (1.0, 0.0, 1.0, 1.0)),
+    ];
+    for (color, srgb) in constants.iter() {
+        assert!(approx_equal(color.to_srgb_unclipped(), *srgb));
+        assert!(color.approx_eq(&Color::new_srgb(srgb.0, srgb.1, srgb.2, srgb.3), 1e-9));
+    }
+}
+
+// Tests the color combinator methods.
+#[test]
+fn color_combinators() {
+    let color = Color::RED.with_alpha(0.5);
+    assert_eq!(color.alpha(), 0.5);
+    assert_eq!(color.luminance(), Color::RED.luminance());
+
+    let brighter = color.map_xyz(|xyz| xyz * 2.0);
+    assert!(approx_equal_f64(
+        brighter.luminance(),
+        color.luminance() * 2.0
+    ));
+    assert_eq!(brighter.alpha(), 0.5);
+    assert!(approx_equal_f64(
+        brighter.chromaticity().x,
+        color.chromaticity().x
+    ));
+
+    // Colors adapted to D50 have D50's chromaticity.
+    let white = Color::WHITE.chromaticity();
+    assert!((white.x - D50_WHITE_POINT_2DEGREES.x).abs() < 1e-4);
+    assert!((white.y - D50_WHITE_POINT_2DEGREES.y).abs() < 1e-4);
+
+    assert!(color.is_finite());
+    assert!(!Color::new_xyza(f64::NAN, 0.0, 0.0, 1.0).is_finite());
+}
+
+// Tests arithmetic on colors.
+#[test]
+fn color_arithmetic() {
+    let mut sum = Color::TRANSPARENT;
+    let samples = [Color::RED, Color::GREEN, Color::BLUE.with_alpha(0.0)];
+    for sample in samples.iter() {
+        sum += *sample;
+    }
+    // Adding the primaries is white, and averaging them is a third as bright.
+    let average = sum / 3.0;
+    assert!(approx_equal(
+        (sum - Color::TRANSPARENT).to_srgb_unclipped(),
+        (1.0, 1.0, 1.0, 2.0)
+    ));
+    assert!(average.approx_eq(&(Color::WHITE * (1.0 / 3.0)).with_alpha(2.0 / 3.0), 1e-9));
+    assert!((Color::WHITE - Color::RED).approx_eq(&Color::CYAN.with_alpha(0.0), 1e-9));
+}
+
+// Tests XYZ arithmetic and conversions.
+#[test]
+fn xyz_math() {
+    let red = XYZ::from(Color::RED);
+    let green = XYZ::from(Color::GREEN);
+    let sum = red + green;
+    assert!(Color::from_xyz(sum, 1.0).approx_eq(&Color::YELLOW, 1e-12));
+    assert!(Color::from_xyz(sum - green, 1.0).approx_eq(&Color::RED, 1e-12));
+    assert!(approx_equal_f64((sum / 2.0).Y, sum.Y / 2.0));
+
+    let normalized = sum.normalize();
+    assert!(approx_equal_f64(normalized.Y, 1.0));
+    assert!(approx_equal_f64(
+        normalized.to_chromaticity().x,
+        sum.to_chromaticity().x
+    ));
+    assert!(approx_equal_f64(sum.with_luminance(0.25).Y, 0.25));
+}
+
+// Tests formatting colors with their nearest sRGB color.
+#[test]
+fn color_display() {
+    let red = format!("{}", Color::RED);
+    assert_eq!(red, "XYZ(0.4360, 0.2225, 0.0139) alpha 1 ≈ #ff0000");
+
+    let vivid = Color::from_oklch(Oklch::new(0.7, 0.4, 150.0), 0.5);
+    let text = format!("{:.2}", vivid);
+    println!("{}", text);
+    assert!(text.ends_with("(out of sRGB gamut)"));
+
+    let debug = format!("{:#?}", Color::BLUE);
+    assert!(debug.contains("srgb: #0000ff"));
+    assert!(!format!("{:?}", Color::BLUE).contains("srgb"));
+}
+
+// Tests converting typed colors between encodings and color spaces.
+#[test]
+fn typed_colors() {
+    use typed::*;
+    let encoded = EncodedColor::<SrgbSpace>::new(0.5, 0.25, 1.0, 1.0);
+    let linear = encoded.to_linear();
+    let expected = Color::new_srgb(0.5, 0.25, 1.0, 1.0).to_linear_srgb();
+    assert!(approx_equal(
+        (linear.r, linear.g, linear.b, linear.a),
+        expected
+    ));
+    let round_trip = linear.to_encoded().to_linear();
+    assert!(approx_equal(
+        (round_trip.r, round_trip.g, round_trip.b, round_trip.a),
+        (linear.r, linear.g, linear.b, linear.a)
+    ));
+
+    let p3: EncodedColor<DisplayP3Space> = encoded.convert();
+    assert!(p3.to_color().approx_eq(&encoded.to_color(), 1e-9));
+    let back: EncodedColor<SrgbSpace> = p3.convert();
+    assert!(approx_equal(
+        (back.r, back.g, back.b, back.a),
+        (0.5, 0.25, 1.0, 1.0)
+    ));
+
+    let aces = LinearColor::<AcesCgSpace>::from_color(&Color::WHITE);
+    assert!(approx_equal(
+        (aces.r, aces.g, aces.b, aces.a),
+        (1.0, 1.0, 1.0, 1.0)
+    ));
+}
+
+// Tests that the constants of each typed color space match the calculated color space.
+#[test]
+fn typed_color_space_constants() {
+    use typed::*;
+    fn check<S: Space>() {
+        let color_space = S::color_space();
+        let rows = |m: Matrix3x3| m.to_rows_array().concat();
+        for (a, b) in rows(S::TO_XYZ)
+            .iter()
+            .zip(rows(color_space.to_XYZ_matrix()).iter())
+            .chain(
+                rows(S::FROM_XYZ)
+                    .iter()
+                    .zip(rows(color_space.from_XYZ_matrix()).iter()),
+            )
+        {
+            assert!(approx_equal_f64(*a, *b));
+        }
+        assert!(&S::TRANSFER_FUNCTION == color_space.transfer_function());
+    }
+    check::<SrgbSpace>();
+    check::<DisplayP3Space>();
+    check::<AdobeRgbSpace>();
+    check::<Rec2020Space>();
+    check::<Rec2100PqSpace>();
+    check::<Rec2100HlgSpace>();
+    check::<AcesCgSpace>();
+}
+
+// Tests converting between color representations with `ConvertColor`.
+#[test]
+fn convert_color() {
+    let color = Color::new_srgb(0.8, 0.3, 0.1, 1.0);
+    let lab: Lab = color.convert_color();
+    let oklch: Oklch = lab.convert_color();
+    let xyz: XYZ = oklch.convert_color();
+    let luv: Luv = xyz.convert_color();
+    let encoded: typed::EncodedColor<typed::SrgbSpace> = luv.convert_color();
+    assert!(approx_equal(
+        (encoded.r, encoded.g, encoded.b, encoded.a),
+        (0.8, 0.3, 0.1, 1.0)
+    ));
+    let back: Color = encoded.convert_color();
+    assert!(back.approx_eq(&color, 1e-9));
+
+    fn generic_distance<A: ConvertColor<Oklab>, B: ConvertColor<Oklab>>(a: &A, b: &B) -> f64 {
+        let (a, b): (Oklab, Oklab) = (a.convert_color(), b.convert_color());
+        ((a.L - b.L).powi(2) + (a.a - b.a).powi(2) + (a.b - b.b).powi(2)).sqrt()
+    }
+    assert!(generic_distance(&color, &lab) < 1e-9);
+}
+
+// Tests registering color spaces and reusing converters between them.
+#[test]
+fn color_space_registry() {
+    let mut registry = ColorSpaceRegistry::with_standard_color_spaces();
+    assert_eq!(
+        registry.get("display-p3"),
+        Some(&ColorSpace::from_standard(StandardColorSpace::DisplayP3))
+    );
+    assert!(registry.names().any(|n| n == "acescg"));
+
+    let converter = registry.converter("srgb", "srgb-linear").unwrap();
+    let (r, g, b) = converter.convert_encoded_color(&(0.5, 0.5, 0.5));
+    assert!(approx_equal(
+        (r, g, b, 1.0),
+        Color::new_srgb(0.5, 0.5, 0.5, 1.0).to_linear_srgb()
+    ));
+    assert!(registry.converter("srgb", "unknown").is_none());
+
+    // Replacing a color space replaces the converters that use it.
+    registry.register("document", ColorSpace::SRGB);
+    let identity = registry
+        .converter("document", "srgb")
+        .unwrap()
+        .convert_encoded_color(&(0.2, 0.4, 0.6));
+    assert!(approx_equal(
+        (identity.0, identity.1, identity.2, 1.0),
+        (0.2, 0.4, 0.6, 1.0)
+    ));
+    registry.register("document", ColorSpace::SRGB_LINEAR);
+    let converted = registry
+        .converter("document", "srgb")
+        .unwrap()
+        .convert_encoded_color(&(0.2, 0.4, 0.6));
+    assert!(!approx_equal(
+        (converted.0, converted.1, converted.2, 1.0),
+        (0.2, 0.4, 0.6, 1.0)
+    ));
+}
+
+// Tests that convenience methods use the default working color space.
+#[test]
+fn default_working_space() {
+    use working_space::*;
+    let color = Color::from_hex_str("#ff0000").unwrap();
+    assert!(color.approx_eq(&Color::RED, 1e-9));
+
+    let p3 = ColorSpace::from_standard(StandardColorSpace::DisplayP3);
+    let p3_red = with_default_color_space(p3.clone(), || {
+        // Nested scopes restore the outer scope's color space.
+        with_default_color_space(ColorSpace::SRGB, || {
+            assert_eq!(Color::RED.to_hex_string(false), "#ff0000");
+        });
+        Color::from_hex_str("#ff0000").unwrap()
+    });
+    assert!(p3_red.approx_eq(&p3.new_color(1.0, 0.0, 0.0, 1.0), 1e-9));
+    assert_eq!(default_color_space(), ColorSpace::SRGB);
+
+    // Other threads don't see scoped color spaces.
+    with_default_color_space(p3, || {
+        let other = std::thread::spawn(default_color_space).join().unwrap();
+        assert_eq!(other, ColorSpace::SRGB);
+    });
+}
+
+// Tests that the distinct color sequence is deterministic and spreads hues apart.
+#[test]
+fn distinct_colors() {
+    use harmony::DistinctColors;
+    let colors: Vec<Color> = DistinctColors::new(&ColorSpace::SRGB).take(12).collect();
+    let again: Vec<Color> = DistinctColors::new(&ColorSpace::SRGB).take(12).collect();
+    assert!(colors.iter().zip(&again).all(|(a, b)| a.approx_eq(b, 0.0)));
+
+    // Every pair of colors is clearly different.
+    for (i, a) in colors.iter().enumerate() {
+        assert!(ColorSpace::SRGB.contains(a));
+        let l = a.to_oklch().L;
+        assert!((0.55 - 1e-3..=0.8 + 1e-3).contains(&l));
+        for b in &colors[i + 1..] {
+            let distance = DeltaEOk.distance(a, b);
+            assert!(distance > 0.05, "{} {} {}", a, b, distance);
+        }
+    }
+
+    let seeded: Vec<Color> = DistinctColors::new(&ColorSpace::SRGB)
+        .with_seed(7)
+        .take(2)
+        .collect();
+    assert!(!seeded[0].approx_eq(&colors[0], 1e-3));
+}
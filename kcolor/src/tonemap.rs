@@ -0,0 +1,465 @@
+//! Tone mapping: compressing the luminance of high dynamic range (HDR) colors into the range
+//! a display can show.
+//!
+//! Colors are expected to be relative, where a luminance (Y) of 1.0 is diffuse white.
+use crate::white_points::*;
+use crate::*;
+use alloc::boxed::Box;
+
+/// A tone mapping operator.
+#[derive(Debug, Copy, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum ToneMapping {
+    /// Reinhard's simple operator: `L / (1 + L)`.
+    /// Every luminance is compressed below 1.0, so pure white is never reached.
+    Reinhard,
+    /// Reinhard's extended operator, where `white` is the smallest luminance that maps to 1.0.
+    ReinhardExtended { white: f64 },
+    /// Krzysztof Narkowicz's fit of the ACES filmic curve, applied to each channel
+    /// in the output's primaries. Cheap, but over-saturates bright colors.
+    /// https://knarkowicz.wordpress.com/2016/01/06/aces-filmic-tone-mapping-curve/
+    AcesNarkowicz { output: AcesOutput },
+    /// Stephen Hill's fit of the ACES reference rendering transform (RRT) and output
+    /// device transform (ODT): a rational curve applied in the ACES working space, with
+    /// matrices that approximate the RRT and ODT's saturation adjustments.
+    /// It desaturates bright colors towards white like `AcesRrtOdt`, for much less work,
+    /// but it isn't the ACES pipeline and doesn't match it exactly.
+    /// https://github.com/TheRealMJP/BakingLab/blob/master/BakingLab/ACES.hlsl
+    AcesHillFit { output: AcesOutput },
+    /// The ACES 1.x reference rendering transform (RRT) followed by the output device transform
+    /// for a 100 cd/m² monitor in a dim surround (`ODT.Academy.RGBmonitor_100nits_dim`),
+    /// with the output's primaries.
+    /// https://github.com/ampas/aces-dev
+    AcesRrtOdt { output: AcesOutput },
+}
+
+/// The display an ACES tone mapping operator targets.
+/// The result is clipped to the display's gamut.
+#[derive(Debug, Copy, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum AcesOutput {
+    Srgb,
+    DisplayP3,
+}
+
+impl AcesOutput {
+    /// A linear color space with the display's primaries.
+    fn linear_color_space(&self) -> ColorSpace {
+        match self {
+            AcesOutput::Srgb => ColorSpace::SRGB_LINEAR,
+            AcesOutput::DisplayP3 => ColorSpace::new(
+                Chromaticity::new(0.680, 0.320),
+                Chromaticity::new(0.265, 0.690),
+                Chromaticity::new(0.150, 0.060),
+                D65_WHITE_POINT_2DEGREES,
+                TransferFunction::None,
+            ),
+        }
+    }
+}
+
+impl ToneMapping {
+    /// Tone maps the color.
+    /// The Reinhard operators preserve the color's chromaticity.
+    pub fn map(&self, color: &Color) -> Color {
+        ToneMapper::new(*self).map(color)
+    }
+
+    /// Tone maps RGB values in `color_space` in place.
+    pub fn map_slice(&self, pixels: &mut [[f64; 3]], color_space: &ColorSpace) {
+        let tone_mapper = ToneMapper::new(*self);
+        for pixel in pixels.iter_mut() {
+            let color = color_space.new_color(pixel[0], pixel[1], pixel[2], 1.0);
+            let (r, g, b, _) = color_space.color_to_rgba_unclipped(&tone_mapper.map(&color));
+            *pixel = [r, g, b];
+        }
+    }
+
+    /// The tone mapped luminance divided by the original luminance for the Reinhard operators.
+    fn luminance_scale(&self, luminance: f64) -> f64 {
+        if luminance <= 0.0 {
+            return 1.0;
+        }
+        let mapped = match self {
+            ToneMapping::ReinhardExtended { white } => {
+                luminance * (1.0 + luminance / (white * white)) / (1.0 + luminance)
+            }
+            _ => luminance / (1.0 + luminance),
+        };
+        mapped / luminance
+    }
+}
+
+/// A tone mapping operator with the color spaces, matrices, and curves it needs
+/// calculated ahead of time, for mapping many colors.
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) enum ToneMapper {
+    /// `Reinhard` or `ReinhardExtended`.
+    Reinhard(ToneMapping),
+    AcesNarkowicz {
+        output: ColorSpace,
+    },
+    AcesHillFit {
+        output: ColorSpace,
+    },
+    AcesRrtOdt(Box<AcesRrtOdt>),
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) struct AcesRrtOdt {
+    output: ColorSpace,
+    ap0: ColorSpace,
+    ap1: ColorSpace,
+    ap0_to_ap1: Matrix3x3,
+    odt_spline: SegmentedSpline,
+}
+
+impl ToneMapper {
+    pub(crate) fn new(tone_mapping: ToneMapping) -> Self {
+        match tone_mapping {
+            ToneMapping::Reinhard | ToneMapping::ReinhardExtended { .. } => {
+                ToneMapper::Reinhard(tone_mapping)
+            }
+            ToneMapping::AcesNarkowicz { output } => ToneMapper::AcesNarkowicz {
+                output: output.linear_color_space(),
+            },
+            ToneMapping::AcesHillFit { output } => ToneMapper::AcesHillFit {
+                output: output.linear_color_space(),
+            },
+            ToneMapping::AcesRrtOdt { output } => {
+                let ap0 = ColorSpace::new(
+                    Chromaticity::new(0.7347, 0.2653),
+                    Chromaticity::new(0.0, 1.0),
+                    Chromaticity::new(0.0001, -0.0770),
+                    D60_WHITE_POINT_2DEGREES,
+                    TransferFunction::None,
+                );
+                let ap1 = ColorSpace::from_standard(StandardColorSpace::AcesCg);
+                let ap0_to_ap1 = ColorSpaceConverter::new(&ap0, &ap1).conversion_matrix();
+                ToneMapper::AcesRrtOdt(Box::new(AcesRrtOdt {
+                    output: output.linear_color_space(),
+                    ap0,
+                    ap1,
+                    ap0_to_ap1,
+                    odt_spline: odt_spline(),
+                }))
+            }
+        }
+    }
+
+    pub(crate) fn map(&self, color: &Color) -> Color {
+        match self {
+            ToneMapper::Reinhard(tone_mapping) => {
+                let scale = tone_mapping.luminance_scale(color.Y);
+                Color::new_xyza(color.X * scale, color.Y * scale, color.Z * scale, color.a)
+            }
+            ToneMapper::AcesNarkowicz { output } => {
+                let (r, g, b, a) = output.color_to_rgba_unclipped(color);
+                // The curve was fit to input that was exposed by 0.6.
+                let curve = |x: f64| {
+                    let x = x.max(0.0) * 0.6;
+                    (x * (2.51 * x + 0.03)) / (x * (2.43 * x + 0.59) + 0.14)
+                };
+                output.new_color(
+                    curve(r).clamp(0.0, 1.0),
+                    curve(g).clamp(0.0, 1.0),
+                    curve(b).clamp(0.0, 1.0),
+                    a,
+                )
+            }
+            ToneMapper::AcesHillFit { output } => {
+                // Hill's matrices convert from and to linear sRGB.
+                // The input matrix includes the RRT's saturation adjustment and the output
+                // matrix includes the ODT's.
+                let input = Matrix3x3::from_columns(
+                    Vector3::new(0.59719, 0.07600, 0.02840),
+                    Vector3::new(0.35458, 0.90834, 0.13383),
+                    Vector3::new(0.04823, 0.01566, 0.83777),
+                );
+                let output_matrix = Matrix3x3::from_columns(
+                    Vector3::new(1.60475, -0.10208, -0.00327),
+                    Vector3::new(-0.53108, 1.10813, -0.07276),
+                    Vector3::new(-0.07367, -0.00605, 1.07602),
+                );
+                let curve = |x: f64| {
+                    let a = x * (x + 0.0245786) - 0.000090537;
+                    let b = x * (0.983729 * x + 0.4329510) + 0.238081;
+                    a / b
+                };
+
+                let (r, g, b, a) = ColorSpace::SRGB_LINEAR.color_to_rgba_unclipped(color);
+                let aces = input * Vector3::new(r.max(0.0), g.max(0.0), b.max(0.0));
+                let rgb = output_matrix * Vector3::new(curve(aces.x), curve(aces.y), curve(aces.z));
+                let mapped = ColorSpace::SRGB_LINEAR.new_color(rgb.x, rgb.y, rgb.z, a);
+
+                let (r, g, b, a) = output.color_to_rgba(&mapped);
+                output.new_color(r, g, b, a)
+            }
+            ToneMapper::AcesRrtOdt(aces) => {
+                let (r, g, b, a) = aces.ap0.color_to_rgba_unclipped(color);
+                // The RRT ends by converting back to ACES2065-1 (AP0) and the ODT starts
+                // by converting to ACEScg (AP1), so that round trip is skipped.
+                let linear = aces_odt(aces_rrt([r, g, b], &aces.ap0_to_ap1), &aces.odt_spline);
+
+                let mapped = aces.ap1.new_color(linear.x, linear.y, linear.z, a);
+                let (r, g, b, a) = aces.output.color_to_rgba(&mapped);
+                aces.output.new_color(r, g, b, a)
+            }
+        }
+    }
+}
+
+// The constants and functions below are from the ACES 1.x CTL reference implementation:
+// https://github.com/ampas/aces-dev/tree/v1.3/transforms/ctl
+
+const HALF_MIN: f64 = 5.96046448e-08;
+const HALF_MAX: f64 = 65504.0;
+
+/// The luminance of ACEScg (AP1) RGB values.
+const AP1_RGB_TO_Y: [f64; 3] = [0.2722287168, 0.6740817658, 0.0536895174];
+
+const RRT_GLOW_GAIN: f64 = 0.05;
+const RRT_GLOW_MID: f64 = 0.08;
+const RRT_RED_SCALE: f64 = 0.82;
+const RRT_RED_PIVOT: f64 = 0.03;
+const RRT_RED_HUE: f64 = 0.0;
+const RRT_RED_WIDTH: f64 = 135.0;
+const RRT_SAT_FACTOR: f64 = 0.96;
+
+const ODT_SAT_FACTOR: f64 = 0.93;
+const CINEMA_WHITE: f64 = 48.0;
+const CINEMA_BLACK: f64 = 0.02;
+const DIM_SURROUND_GAMMA: f64 = 0.9811;
+
+/// The RRT's tone scale, `segmented_spline_c5_fwd`.
+const RRT_SPLINE: SegmentedSpline = SegmentedSpline {
+    coefs_low: &[
+        -4.0000000000,
+        -4.0000000000,
+        -3.1573765773,
+        -0.4852499958,
+        1.8477324706,
+        1.8477324706,
+    ],
+    coefs_high: &[
+        -0.7185482425,
+        2.0810307172,
+        3.6681241237,
+        4.0000000000,
+        4.0000000000,
+        4.0000000000,
+    ],
+    min: (0.18 / 32768.0, 0.0001),
+    mid: (0.18, 4.8),
+    max: (0.18 * 262144.0, 10000.0),
+    slope_low: 0.0,
+    slope_high: 0.0,
+};
+
+/// The 48 cd/m² ODT tone scale, `segmented_spline_c9_fwd`. Its end points are the
+/// RRT's tone scale applied to 6.5 stops above and below mid gray.
+fn odt_spline() -> SegmentedSpline {
+    let stops = float::powf(2.0, 6.5);
+    SegmentedSpline {
+        coefs_low: &[
+            -1.6989700043,
+            -1.6989700043,
+            -1.4779000000,
+            -1.2291000000,
+            -0.8648000000,
+            -0.4480000000,
+            0.0051800000,
+            0.4511080334,
+            0.9113744414,
+            0.9113744414,
+        ],
+        coefs_high: &[
+            0.5154386965,
+            0.8470437783,
+            1.1358000000,
+            1.3802000000,
+            1.5197000000,
+            1.5985000000,
+            1.6467000000,
+            1.6746091357,
+            1.6878733390,
+            1.6878733390,
+        ],
+        min: (RRT_SPLINE.evaluate(0.18 / stops), 0.02),
+        mid: (RRT_SPLINE.evaluate(0.18), 4.8),
+        max: (RRT_SPLINE.evaluate(0.18 * stops), 48.0),
+        slope_low: 0.0,
+        slope_high: 0.04,
+    }
+}
+
+/// A curve in log-log space made of quadratic B-spline segments below and above mid gray.
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) struct SegmentedSpline {
+    coefs_low: &'static [f64],
+    coefs_high: &'static [f64],
+    min: (f64, f64),
+    mid: (f64, f64),
+    max: (f64, f64),
+    slope_low: f64,
+    slope_high: f64,
+}
+
+impl SegmentedSpline {
+    fn evaluate(&self, x: f64) -> f64 {
+        let log_x = log10(x.max(HALF_MIN));
+        let (min_x, mid_x, max_x) = (log10(self.min.0), log10(self.mid.0), log10(self.max.0));
+        let log_y = if log_x <= min_x {
+            log_x * self.slope_low + (log10(self.min.1) - self.slope_low * min_x)
+        } else if log_x < mid_x {
+            quadratic_b_spline(self.coefs_low, (log_x - min_x) / (mid_x - min_x))
+        } else if log_x < max_x {
+            quadratic_b_spline(self.coefs_high, (log_x - mid_x) / (max_x - mid_x))
+        } else {
+            log_x * self.slope_high + (log10(self.max.1) - self.slope_high * max_x)
+        };
+        float::powf(10.0, log_y)
+    }
+}
+
+/// Evaluates the segment of a uniform quadratic B-spline at `t` from 0.0 to 1.0
+/// along the whole curve.
+fn quadratic_b_spline(coefs: &[f64], t: f64) -> f64 {
+    let knot = (coefs.len() - 3) as f64 * t;
+    let j = (float::floor(knot) as usize).min(coefs.len() - 3);
+    let t = knot - j as f64;
+    let (c0, c1, c2) = (coefs[j], coefs[j + 1], coefs[j + 2]);
+    t * t * (0.5 * c0 - c1 + 0.5 * c2) + t * (c1 - c0) + 0.5 * (c0 + c1)
+}
+
+fn log10(x: f64) -> f64 {
+    float::ln(x) / core::f64::consts::LN_10
+}
+
+/// The reference rendering transform, from ACES2065-1 (AP0) to tone mapped ACEScg (AP1).
+fn aces_rrt(aces: [f64; 3], ap0_to_ap1: &Matrix3x3) -> Vector3 {
+    // The glow module brightens dark, saturated colors.
+    let saturation = rgb_saturation(aces);
+    let shaped_saturation = sigmoid_shaper((saturation - 0.4) / 0.2);
+    let glow = 1.0
+        + glow(
+            rgb_yc(aces),
+            RRT_GLOW_GAIN * shaped_saturation,
+            RRT_GLOW_MID,
+        );
+    let [mut r, g, b] = aces.map(|c| c * glow);
+
+    // The red modifier darkens saturated reds.
+    if let Some(hue) = rgb_hue([r, g, b]) {
+        let weight = cubic_basis_shaper(center_hue(hue, RRT_RED_HUE), RRT_RED_WIDTH);
+        r += weight * saturation * (RRT_RED_PIVOT - r) * (1.0 - RRT_RED_SCALE);
+    }
+
+    let clamp = |v: Vector3| {
+        Vector3::new(
+            v.x.clamp(0.0, HALF_MAX),
+            v.y.clamp(0.0, HALF_MAX),
+            v.z.clamp(0.0, HALF_MAX),
+        )
+    };
+    let rgb = clamp(*ap0_to_ap1 * clamp(Vector3::new(r, g, b)));
+    let rgb = desaturate(rgb, RRT_SAT_FACTOR);
+    Vector3::new(
+        RRT_SPLINE.evaluate(rgb.x),
+        RRT_SPLINE.evaluate(rgb.y),
+        RRT_SPLINE.evaluate(rgb.z),
+    )
+}
+
+/// The output device transform for a 100 cd/m² monitor in a dim surround,
+/// from the RRT's output in ACEScg (AP1) to linear ACEScg values from 0.0 to 1.0.
+fn aces_odt(rgb: Vector3, spline: &SegmentedSpline) -> Vector3 {
+    let linear = |v: f64| (spline.evaluate(v) - CINEMA_BLACK) / (CINEMA_WHITE - CINEMA_BLACK);
+    let rgb = Vector3::new(linear(rgb.x), linear(rgb.y), linear(rgb.z));
+
+    // Compensate for the dim surround by applying a gamma to luminance.
+    let luminance = luminance(rgb).clamp(0.0, HALF_MAX);
+    let rgb = if luminance > 0.0 {
+        rgb * (float::powf(luminance, DIM_SURROUND_GAMMA) / luminance)
+    } else {
+        Vector3::new(0.0, 0.0, 0.0)
+    };
+    desaturate(rgb, ODT_SAT_FACTOR)
+}
+
+fn luminance(rgb: Vector3) -> f64 {
+    rgb.x * AP1_RGB_TO_Y[0] + rgb.y * AP1_RGB_TO_Y[1] + rgb.z * AP1_RGB_TO_Y[2]
+}
+
+/// Moves RGB values towards their luminance, keeping `factor` of their saturation.
+fn desaturate(rgb: Vector3, factor: f64) -> Vector3 {
+    let y = luminance(rgb) * (1.0 - factor);
+    Vector3::new(y + rgb.x * factor, y + rgb.y * factor, y + rgb.z * factor)
+}
+
+fn rgb_saturation(rgb: [f64; 3]) -> f64 {
+    let max = rgb[0].max(rgb[1]).max(rgb[2]);
+    let min = rgb[0].min(rgb[1]).min(rgb[2]);
+    (max.max(1e-10) - min.max(1e-10)) / max.max(1e-2)
+}
+
+/// A luminance-like value that increases with chroma.
+fn rgb_yc(rgb: [f64; 3]) -> f64 {
+    let [r, g, b] = rgb;
+    let chroma = float::sqrt((b * (b - g) + g * (g - r) + r * (r - b)).max(0.0));
+    (b + g + r + 1.75 * chroma) / 3.0
+}
+
+/// The hue in degrees from 0.0 to 360.0, or `None` for neutral colors.
+fn rgb_hue(rgb: [f64; 3]) -> Option<f64> {
+    let [r, g, b] = rgb;
+    if r == g && g == b {
+        return None;
+    }
+    let hue = float::atan2(float::sqrt(3.0) * (g - b), 2.0 * r - g - b).to_degrees();
+    Some(if hue < 0.0 { hue + 360.0 } else { hue })
+}
+
+fn center_hue(hue: f64, center: f64) -> f64 {
+    let centered = hue - center;
+    if centered < -180.0 {
+        centered + 360.0
+    } else if centered > 180.0 {
+        centered - 360.0
+    } else {
+        centered
+    }
+}
+
+fn sigmoid_shaper(x: f64) -> f64 {
+    let t = (1.0 - (x / 2.0).abs()).max(0.0);
+    (1.0 + x.signum() * (1.0 - t * t)) / 2.0
+}
+
+fn glow(yc: f64, gain: f64, mid: f64) -> f64 {
+    if yc <= 2.0 / 3.0 * mid {
+        gain
+    } else if yc >= 2.0 * mid {
+        0.0
+    } else {
+        gain * (mid / yc - 0.5)
+    }
+}
+
+/// A smooth bump from 0.0 at `-width / 2` to 1.0 at 0.0 and back to 0.0 at `width / 2`.
+fn cubic_basis_shaper(x: f64, width: f64) -> f64 {
+    if x <= -width / 2.0 || x >= width / 2.0 {
+        return 0.0;
+    }
+    let knot = (x + width / 2.0) * 4.0 / width;
+    let j = (float::floor(knot) as usize).min(3);
+    let t = knot - j as f64;
+    // The segments of a uniform cubic B-spline, from the rightmost to the leftmost.
+    let y = match j {
+        3 => (-t * t * t + 3.0 * t * t - 3.0 * t + 1.0) / 6.0,
+        2 => (3.0 * t * t * t - 6.0 * t * t + 4.0) / 6.0,
+        1 => (-3.0 * t * t * t + 3.0 * t * t + 3.0 * t + 1.0) / 6.0,
+        _ => t * t * t / 6.0,
+    };
+    y * 1.5
+}
@@ -0,0 +1,376 @@
+//! Color types that know which color space they're in and whether their values are encoded
+//! with the color space's transfer function or linear.
+//!
+//! Mixing up encoded and linear values is an easy mistake to make with plain `f64`s.
+//! With these types it's a compile error, and converting is always an explicit method call.
+//!
+//! ```
+//! # use kcolor::typed::*;
+//! let encoded = EncodedColor::<SrgbSpace>::new(1.0, 0.5, 0.0, 1.0);
+//! let linear: LinearColor<SrgbSpace> = encoded.to_linear();
+//! let p3: EncodedColor<DisplayP3Space> = encoded.convert();
+//! ```
+use crate::standard_color_space::{ADOBE_RGB_TRANSFER_FUNCTION, REC2020_TRANSFER_FUNCTION};
+use crate::*;
+use core::marker::PhantomData;
+
+/// A marker type for one of the built-in color spaces.
+///
+/// The matrices and transfer function are constants so converting typed colors
+/// doesn't construct a `ColorSpace`.
+pub trait Space {
+    const STANDARD: StandardColorSpace;
+    /// Converts linear RGB to XYZ relative to D50.
+    const TO_XYZ: Matrix3x3;
+    /// Converts XYZ relative to D50 to linear RGB.
+    const FROM_XYZ: Matrix3x3;
+    const TRANSFER_FUNCTION: TransferFunction;
+
+    fn color_space() -> ColorSpace {
+        ColorSpace::from_standard(Self::STANDARD)
+    }
+}
+
+// These matrices were calculated with this library from `ColorSpace::from_standard`.
+
+const fn rows(rows: [[f64; 3]; 3]) -> Matrix3x3 {
+    Matrix3x3 {
+        c0: Vector3 {
+            x: rows[0][0],
+            y: rows[1][0],
+            z: rows[2][0],
+        },
+        c1: Vector3 {
+            x: rows[0][1],
+            y: rows[1][1],
+            z: rows[2][1],
+        },
+        c2: Vector3 {
+            x: rows[0][2],
+            y: rows[1][2],
+            z: rows[2][2],
+        },
+    }
+}
+
+const SRGB_TO_XYZ: Matrix3x3 = rows([
+    [0.4360219083775758, 0.3851088006156898, 0.14308127508123153],
+    [0.2224751872467074, 0.7169066518920372, 0.06061819697439862],
+    [
+        0.013928117106761706,
+        0.09710152837405213,
+        0.7141585850968147,
+    ],
+]);
+const SRGB_FROM_XYZ: Matrix3x3 = rows([
+    [3.1343112068079124, -1.617232140026793, -0.4906858992761275],
+    [-0.9787436666690797, 1.9161138492090442, 0.03344988853522833],
+    [
+        0.07194823874527759,
+        -0.22898637371922786,
+        1.4052709248478632,
+    ],
+]);
+
+const DISPLAY_P3_TO_XYZ: Matrix3x3 = rows([
+    [0.5150972992846677, 0.291969736877977, 0.15714494791185238],
+    [0.24117951258455375, 0.692244073181441, 0.0665764503471483],
+    [
+        -0.0010491051472238115,
+        0.041883033653342075,
+        0.7843543020715107,
+    ],
+]);
+const DISPLAY_P3_FROM_XYZ: Matrix3x3 = rows([
+    [2.4040693614652286, -0.9899132311417859, -0.3976295465953886],
+    [-0.8422181009922756, 1.798830692346005, 0.01605213014794206],
+    [
+        0.048188389492971224,
+        -0.09737819922523298,
+        1.2735450424006374,
+    ],
+]);
+
+const ADOBE_RGB_TO_XYZ: Matrix3x3 = rows([
+    [0.6097189980252682, 0.20526822229403985, 0.1492247637551887],
+    [0.311102138785379, 0.6256769334550658, 0.06322096387269817],
+    [
+        0.019476630516830462,
+        0.06088914803617807,
+        0.7448224520246199,
+    ],
+]);
+const ADOBE_RGB_FROM_XYZ: Matrix3x3 = rows([
+    [1.9625818308198548, -0.6106505556636014, -0.3413698024874557],
+    [
+        -0.9787436666690805,
+        1.9161138492090448,
+        0.033449888535228435,
+    ],
+    [
+        0.028691920837338004,
+        -0.14067396100411853,
+        1.3487939247360783,
+    ],
+]);
+
+const REC2020_TO_XYZ: Matrix3x3 = rows([
+    [0.6734510958450083, 0.16566319229455276, 0.12509769593493592],
+    [
+        0.27903012254378556,
+        0.6753430309785812,
+        0.045626882590776234,
+    ],
+    [
+        -0.0019307349540145008,
+        0.02997842219545136,
+        0.7971405433361918,
+    ],
+]);
+const REC2020_FROM_XYZ: Matrix3x3 = rows([
+    [1.647296249864962, -0.3936101574679462, -0.23598568974016398],
+    [-0.6826140252147732, 1.647606747918402, 0.012818670696927862],
+    [
+        0.02966124868608568,
+        -0.06291564017124551,
+        1.2534302752640716,
+    ],
+]);
+
+const ACES_CG_TO_XYZ: Matrix3x3 = rows([
+    [0.6898510126117597, 0.14975008045112592, 0.12461089101161152],
+    [0.28450037966406816, 0.6716882201749262, 0.04381143627414922],
+    [
+        -0.006044717551864247,
+        0.009996341734876877,
+        0.8212366063946164,
+    ],
+]);
+const ACES_CG_FROM_XYZ: Matrix3x3 = rows([
+    [1.5927082310885008, -0.351770286218034, -0.22290437237490046],
+    [
+        -0.6759090651200078,
+        1.6392522219111185,
+        0.015108479691845342,
+    ],
+    [
+        0.019950510326164475,
+        -0.022542684141274767,
+        1.2158512768755332,
+    ],
+]);
+
+/// sRGB. The linear version is `LinearColor<SrgbSpace>`.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct SrgbSpace;
+impl Space for SrgbSpace {
+    const STANDARD: StandardColorSpace = StandardColorSpace::Srgb;
+    const TO_XYZ: Matrix3x3 = SRGB_TO_XYZ;
+    const FROM_XYZ: Matrix3x3 = SRGB_FROM_XYZ;
+    const TRANSFER_FUNCTION: TransferFunction = SRGB_TRANSFER_FUNCTION;
+}
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct DisplayP3Space;
+impl Space for DisplayP3Space {
+    const STANDARD: StandardColorSpace = StandardColorSpace::DisplayP3;
+    const TO_XYZ: Matrix3x3 = DISPLAY_P3_TO_XYZ;
+    const FROM_XYZ: Matrix3x3 = DISPLAY_P3_FROM_XYZ;
+    const TRANSFER_FUNCTION: TransferFunction = SRGB_TRANSFER_FUNCTION;
+}
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct AdobeRgbSpace;
+impl Space for AdobeRgbSpace {
+    const STANDARD: StandardColorSpace = StandardColorSpace::AdobeRgb;
+    const TO_XYZ: Matrix3x3 = ADOBE_RGB_TO_XYZ;
+    const FROM_XYZ: Matrix3x3 = ADOBE_RGB_FROM_XYZ;
+    const TRANSFER_FUNCTION: TransferFunction = ADOBE_RGB_TRANSFER_FUNCTION;
+}
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct Rec2020Space;
+impl Space for Rec2020Space {
+    const STANDARD: StandardColorSpace = StandardColorSpace::Rec2020;
+    const TO_XYZ: Matrix3x3 = REC2020_TO_XYZ;
+    const FROM_XYZ: Matrix3x3 = REC2020_FROM_XYZ;
+    const TRANSFER_FUNCTION: TransferFunction = REC2020_TRANSFER_FUNCTION;
+}
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct Rec2100PqSpace;
+impl Space for Rec2100PqSpace {
+    const STANDARD: StandardColorSpace = StandardColorSpace::Rec2100Pq;
+    const TO_XYZ: Matrix3x3 = REC2020_TO_XYZ;
+    const FROM_XYZ: Matrix3x3 = REC2020_FROM_XYZ;
+    const TRANSFER_FUNCTION: TransferFunction = TransferFunction::Pq {
+        reference_white: HDR_REFERENCE_WHITE,
+    };
+}
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct Rec2100HlgSpace;
+impl Space for Rec2100HlgSpace {
+    const STANDARD: StandardColorSpace = StandardColorSpace::Rec2100Hlg;
+    const TO_XYZ: Matrix3x3 = REC2020_TO_XYZ;
+    const FROM_XYZ: Matrix3x3 = REC2020_FROM_XYZ;
+    const TRANSFER_FUNCTION: TransferFunction = TransferFunction::Hlg;
+}
+
+/// ACEScg has no transfer function, so its encoded and linear values are the same.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct AcesCgSpace;
+impl Space for AcesCgSpace {
+    const STANDARD: StandardColorSpace = StandardColorSpace::AcesCg;
+    const TO_XYZ: Matrix3x3 = ACES_CG_TO_XYZ;
+    const FROM_XYZ: Matrix3x3 = ACES_CG_FROM_XYZ;
+    const TRANSFER_FUNCTION: TransferFunction = TransferFunction::None;
+}
+
+/// RGBA values in the color space `S` with its transfer function applied,
+/// as stored in most images and shown on displays.
+#[repr(C)]
+pub struct EncodedColor<S: Space> {
+    pub r: f64,
+    pub g: f64,
+    pub b: f64,
+    pub a: f64,
+    space: PhantomData<S>,
+}
+
+/// RGBA values in the color space `S` without its transfer function applied,
+/// which is proportional to light and used for blending and lighting.
+#[repr(C)]
+pub struct LinearColor<S: Space> {
+    pub r: f64,
+    pub g: f64,
+    pub b: f64,
+    pub a: f64,
+    space: PhantomData<S>,
+}
+
+impl<S: Space> EncodedColor<S> {
+    pub fn new(r: f64, g: f64, b: f64, a: f64) -> Self {
+        Self {
+            r,
+            g,
+            b,
+            a,
+            space: PhantomData,
+        }
+    }
+
+    /// The color in `S`. RGB values outside of 0.0 to 1.0 are not clipped.
+    pub fn from_color(color: &Color) -> Self {
+        LinearColor::from_color(color).to_encoded()
+    }
+
+    pub fn to_color(&self) -> Color {
+        self.to_linear().to_color()
+    }
+
+    /// Removes the transfer function.
+    pub fn to_linear(&self) -> LinearColor<S> {
+        let tf = S::TRANSFER_FUNCTION;
+        LinearColor::new(
+            tf.to_linear(self.r),
+            tf.to_linear(self.g),
+            tf.to_linear(self.b),
+            self.a,
+        )
+    }
+
+    /// Converts the color to another color space. Values are not clipped.
+    pub fn convert<T: Space>(&self) -> EncodedColor<T> {
+        self.to_linear().convert().to_encoded()
+    }
+}
+
+impl<S: Space> LinearColor<S> {
+    pub fn new(r: f64, g: f64, b: f64, a: f64) -> Self {
+        Self {
+            r,
+            g,
+            b,
+            a,
+            space: PhantomData,
+        }
+    }
+
+    /// The linear color in `S`'s primaries. RGB values outside of 0.0 to 1.0 are not clipped.
+    pub fn from_color(color: &Color) -> Self {
+        let rgb = S::FROM_XYZ * color.xyz().to_vector3();
+        Self::new(rgb.x, rgb.y, rgb.z, color.a)
+    }
+
+    pub fn to_color(&self) -> Color {
+        let xyz = S::TO_XYZ * Vector3::new(self.r, self.g, self.b);
+        Color::new_xyza(xyz.x, xyz.y, xyz.z, self.a)
+    }
+
+    /// Applies the transfer function.
+    pub fn to_encoded(&self) -> EncodedColor<S> {
+        let tf = S::TRANSFER_FUNCTION;
+        EncodedColor::new(
+            tf.from_linear(self.r),
+            tf.from_linear(self.g),
+            tf.from_linear(self.b),
+            self.a,
+        )
+    }
+
+    /// Converts the color to another color space's primaries. Values are not clipped.
+    pub fn convert<T: Space>(&self) -> LinearColor<T> {
+        let rgb = T::FROM_XYZ * (S::TO_XYZ * Vector3::new(self.r, self.g, self.b));
+        LinearColor::new(rgb.x, rgb.y, rgb.z, self.a)
+    }
+}
+
+// These are implemented by hand because derived implementations would require
+// the marker type to implement them too.
+
+impl<S: Space> Clone for EncodedColor<S> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+impl<S: Space> Copy for EncodedColor<S> {}
+impl<S: Space> PartialEq for EncodedColor<S> {
+    fn eq(&self, other: &Self) -> bool {
+        (self.r, self.g, self.b, self.a) == (other.r, other.g, other.b, other.a)
+    }
+}
+impl<S: Space> core::fmt::Debug for EncodedColor<S> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("EncodedColor")
+            .field("space", &S::STANDARD)
+            .field("r", &self.r)
+            .field("g", &self.g)
+            .field("b", &self.b)
+            .field("a", &self.a)
+            .finish()
+    }
+}
+
+impl<S: Space> Clone for LinearColor<S> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+impl<S: Space> Copy for LinearColor<S> {}
+impl<S: Space> PartialEq for LinearColor<S> {
+    fn eq(&self, other: &Self) -> bool {
+        (self.r, self.g, self.b, self.a) == (other.r, other.g, other.b, other.a)
+    }
+}
+impl<S: Space> core::fmt::Debug for LinearColor<S> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("LinearColor")
+            .field("space", &S::STANDARD)
+            .field("r", &self.r)
+            .field("g", &self.g)
+            .field("b", &self.b)
+            .field("a", &self.a)
+            .finish()
+    }
+}
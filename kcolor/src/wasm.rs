@@ -0,0 +1,217 @@
+//! JavaScript bindings for WebAssembly, enabled with the `wasm` feature.
+//!
+//! Build a package for the web with:
+//! `wasm-pack build kcolor --features wasm`
+//!
+//! The bindings expose `ColorSpace`, `Color`, and `Gradient` classes that use the same
+//! conversion code as native builds. RGBA values are passed as arrays of 4 numbers.
+use crate::gradient::{Gradient, GradientInterpolation};
+use crate::*;
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
+use wasm_bindgen::prelude::*;
+
+#[wasm_bindgen(js_name = ColorSpace)]
+pub struct WasmColorSpace(ColorSpace);
+
+#[wasm_bindgen(js_class = ColorSpace)]
+impl WasmColorSpace {
+    /// A built in color space by name, like `"srgb"` or `"display-p3"`.
+    #[wasm_bindgen(js_name = fromName)]
+    pub fn from_name(name: &str) -> Result<WasmColorSpace, JsError> {
+        ColorSpace::from_name(name)
+            .map(WasmColorSpace)
+            .ok_or_else(|| JsError::new("unknown color space name"))
+    }
+
+    /// A color space from the bytes of an ICC profile.
+    #[wasm_bindgen(js_name = fromIccProfile)]
+    pub fn from_icc_profile(bytes: &[u8]) -> Result<WasmColorSpace, JsError> {
+        ColorSpace::from_icc_profile(bytes)
+            .map(WasmColorSpace)
+            .map_err(|e| JsError::new(&alloc::format!("{:?}", e)))
+    }
+
+    /// A color space from 8 chromaticity values (the x and y of the red, green, and blue
+    /// primaries followed by the white point) and a pure gamma transfer function.
+    #[wasm_bindgen(js_name = fromChromaticities)]
+    pub fn from_chromaticities(
+        chromaticities: &[f64],
+        gamma: f64,
+    ) -> Result<WasmColorSpace, JsError> {
+        let c = chromaticities;
+        if c.len() != 8 {
+            return Err(JsError::new("expected 8 chromaticity values"));
+        }
+        let transfer_function = if gamma == 1.0 {
+            TransferFunction::None
+        } else {
+            TransferFunction::ParametricCurve(ParametricCurve::Function0 { gamma })
+        };
+        ColorSpace::try_new(
+            Chromaticity::new(c[0], c[1]),
+            Chromaticity::new(c[2], c[3]),
+            Chromaticity::new(c[4], c[5]),
+            Chromaticity::new(c[6], c[7]),
+            transfer_function,
+        )
+        .map(WasmColorSpace)
+        .map_err(|e| JsError::new(&e.to_string()))
+    }
+
+    #[wasm_bindgen(js_name = newColor)]
+    pub fn new_color(&self, r: f64, g: f64, b: f64, a: f64) -> WasmColor {
+        WasmColor(self.0.new_color(r, g, b, a))
+    }
+
+    /// The color's RGBA values in this color space, clipped to 0.0 to 1.0.
+    #[wasm_bindgen(js_name = colorToRgba)]
+    pub fn color_to_rgba(&self, color: &WasmColor) -> Vec<f64> {
+        let (r, g, b, a) = self.0.color_to_rgba(&color.0);
+        alloc::vec![r, g, b, a]
+    }
+
+    /// The color's RGBA values in this color space, which may be outside 0.0 to 1.0.
+    #[wasm_bindgen(js_name = colorToRgbaUnclipped)]
+    pub fn color_to_rgba_unclipped(&self, color: &WasmColor) -> Vec<f64> {
+        let (r, g, b, a) = self.0.color_to_rgba_unclipped(&color.0);
+        alloc::vec![r, g, b, a]
+    }
+
+    /// Converts RGBA values in this color space to `to`. The result isn't clipped.
+    pub fn convert(&self, to: &WasmColorSpace, rgba: &[f64]) -> Result<Vec<f64>, JsError> {
+        if rgba.len() != 4 {
+            return Err(JsError::new("expected 4 RGBA values"));
+        }
+        let color = self.0.new_color(rgba[0], rgba[1], rgba[2], rgba[3]);
+        Ok(to.color_to_rgba_unclipped(&WasmColor(color)))
+    }
+
+    /// Parses a hex color like `#ff8000` in this color space.
+    #[wasm_bindgen(js_name = parseHex)]
+    pub fn parse_hex(&self, hex: &str) -> Result<WasmColor, JsError> {
+        self.0
+            .new_color_from_hex_str(hex)
+            .map(WasmColor)
+            .map_err(|e| JsError::new(&e.to_string()))
+    }
+
+    /// Formats the color as a hex string, gamut mapping it to this color space.
+    #[wasm_bindgen(js_name = toHexString)]
+    pub fn to_hex_string(&self, color: &WasmColor, include_alpha: bool) -> String {
+        self.0.color_to_hex_string(&color.0, include_alpha)
+    }
+
+    /// Whether the color is within this color space's gamut.
+    pub fn contains(&self, color: &WasmColor) -> bool {
+        self.0.contains(&color.0)
+    }
+
+    /// The nearest color within this color space's gamut that keeps lightness and hue.
+    #[wasm_bindgen(js_name = gamutMap)]
+    pub fn gamut_map(&self, color: &WasmColor) -> WasmColor {
+        WasmColor(self.0.gamut_map(&color.0))
+    }
+}
+
+#[wasm_bindgen(js_name = Color)]
+#[derive(Clone)]
+pub struct WasmColor(Color);
+
+#[wasm_bindgen(js_class = Color)]
+impl WasmColor {
+    #[wasm_bindgen(js_name = fromOklch)]
+    pub fn from_oklch(l: f64, c: f64, h: f64, alpha: f64) -> WasmColor {
+        WasmColor(Color::from_oklch(Oklch::new(l, c, h), alpha))
+    }
+
+    #[wasm_bindgen(js_name = fromLab)]
+    pub fn from_lab(l: f64, a: f64, b: f64, alpha: f64) -> WasmColor {
+        WasmColor(Color::from_lab(Lab::new(l, a, b), alpha))
+    }
+
+    /// The color's Oklch values as `[L, C, h]`.
+    #[wasm_bindgen(js_name = toOklch)]
+    pub fn to_oklch(&self) -> Vec<f64> {
+        let oklch = self.0.to_oklch();
+        alloc::vec![oklch.L, oklch.C, oklch.h]
+    }
+
+    /// The color's CIELAB (D50) values as `[L, a, b]`.
+    #[wasm_bindgen(js_name = toLab)]
+    pub fn to_lab(&self) -> Vec<f64> {
+        let lab = self.0.to_lab();
+        alloc::vec![lab.L, lab.a, lab.b]
+    }
+
+    pub fn alpha(&self) -> f64 {
+        self.0.alpha()
+    }
+
+    #[wasm_bindgen(js_name = deltaE2000)]
+    pub fn delta_e_2000(&self, other: &WasmColor) -> f64 {
+        DeltaE2000.distance(&self.0, &other.0)
+    }
+
+    #[wasm_bindgen(js_name = deltaEOk)]
+    pub fn delta_e_ok(&self, other: &WasmColor) -> f64 {
+        DeltaEOk.distance(&self.0, &other.0)
+    }
+
+    #[wasm_bindgen(js_name = toString)]
+    pub fn to_js_string(&self) -> String {
+        self.0.to_string()
+    }
+}
+
+#[wasm_bindgen(js_name = Gradient)]
+pub struct WasmGradient(Gradient);
+
+#[wasm_bindgen(js_class = Gradient)]
+impl WasmGradient {
+    /// A gradient with `colors` at `positions`, which must be the same length.
+    #[wasm_bindgen(constructor)]
+    pub fn new(positions: &[f64], colors: Vec<WasmColor>) -> Result<WasmGradient, JsError> {
+        if positions.len() != colors.len() {
+            return Err(JsError::new("expected a position for each color"));
+        }
+        Ok(WasmGradient(Gradient::new(
+            positions
+                .iter()
+                .copied()
+                .zip(colors.into_iter().map(|c| c.0)),
+        )))
+    }
+
+    /// Sets the space colors are mixed in: `"xyz"`, `"oklab"`, or `"oklch"`.
+    #[wasm_bindgen(js_name = setInterpolation)]
+    pub fn set_interpolation(&mut self, interpolation: &str) -> Result<(), JsError> {
+        let interpolation = match interpolation {
+            "xyz" => GradientInterpolation::Xyz,
+            "oklab" => GradientInterpolation::Oklab,
+            "oklch" => GradientInterpolation::Oklch,
+            _ => return Err(JsError::new("unknown interpolation")),
+        };
+        self.0 = self.0.clone().interpolation(interpolation);
+        Ok(())
+    }
+
+    pub fn sample(&self, position: f64) -> WasmColor {
+        WasmColor(self.0.sample(position))
+    }
+
+    #[wasm_bindgen(js_name = toCss)]
+    pub fn to_css(&self, direction: &str) -> String {
+        self.0.to_css(direction)
+    }
+
+    #[wasm_bindgen(js_name = toCssSrgb)]
+    pub fn to_css_srgb(&self, direction: &str, samples_per_segment: usize) -> String {
+        self.0.to_css_srgb(direction, samples_per_segment)
+    }
+
+    #[wasm_bindgen(js_name = toSvg)]
+    pub fn to_svg(&self, id: &str, samples_per_segment: usize) -> String {
+        self.0.to_svg(id, samples_per_segment)
+    }
+}
@@ -0,0 +1,74 @@
+//! Automatic white balance: estimating the color of the light an image was captured
+//! under from its pixels.
+//!
+//! Estimates are relative to D50 like all colors, so they can be passed directly to
+//! `ChromaticAdaptation` to make the estimated illuminant appear white.
+use crate::white_points::*;
+use crate::*;
+
+/// A method of estimating the illuminant of an image.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum WhitePointEstimator {
+    /// Assumes the average color of the image is gray.
+    /// Works well for varied scenes, but is thrown off by large areas of one color.
+    #[default]
+    GrayWorld,
+    /// Assumes the largest value of each linear RGB channel is from a white surface
+    /// or a reflection of the light. Works well for scenes with highlights,
+    /// but is sensitive to clipped or noisy pixels.
+    WhitePatch,
+}
+
+impl WhitePointEstimator {
+    /// Estimates the illuminant of `pixels`, which are encoded RGB values in `color_space`.
+    /// The result is scaled to a luminance (Y) of 1.0.
+    /// Returns `None` if there are no pixels or they're all black.
+    pub fn estimate(&self, pixels: &[[f64; 3]], color_space: &ColorSpace) -> Option<XYZ> {
+        let transfer_function = &color_space.transfer_function;
+        let linear = pixels.iter().map(|pixel| {
+            Vector3::new(
+                transfer_function.to_linear(pixel[0]),
+                transfer_function.to_linear(pixel[1]),
+                transfer_function.to_linear(pixel[2]),
+            )
+        });
+        let rgb = match self {
+            WhitePointEstimator::GrayWorld => {
+                if pixels.is_empty() {
+                    return None;
+                }
+                let sum = linear.fold(Vector3::new(0.0, 0.0, 0.0), |sum, v| {
+                    Vector3::new(sum.x + v.x, sum.y + v.y, sum.z + v.z)
+                });
+                let count = pixels.len() as f64;
+                Vector3::new(sum.x / count, sum.y / count, sum.z / count)
+            }
+            WhitePointEstimator::WhitePatch => linear
+                .fold(Vector3::new(0.0, 0.0, 0.0), |max, v| {
+                    Vector3::new(max.x.max(v.x), max.y.max(v.y), max.z.max(v.z))
+                }),
+        };
+        let xyz = color_space.to_XYZ * rgb;
+        let xyz = XYZ::new(xyz.x, xyz.y, xyz.z);
+        if xyz.Y <= 0.0 || xyz.Y.is_nan() {
+            return None;
+        }
+        Some(xyz.normalize())
+    }
+
+    /// Estimates the illuminant of `pixels` and returns an adaptation that makes it white.
+    /// Apply it to colors from the image to white balance them.
+    /// Returns `None` if there are no pixels or they're all black.
+    pub fn white_balance(
+        &self,
+        pixels: &[[f64; 3]],
+        color_space: &ColorSpace,
+    ) -> Option<ChromaticAdaptation> {
+        let illuminant = self.estimate(pixels, color_space)?;
+        Some(ChromaticAdaptation::new(
+            illuminant.to_chromaticity(),
+            D50_WHITE_POINT_2DEGREES,
+        ))
+    }
+}
@@ -1,5 +1,21 @@
 use crate::Chromaticity;
 
+/// Incandescent / tungsten light.
+// Chromaticity values from here:
+// https://en.wikipedia.org/wiki/Standard_illuminant
+pub const A_WHITE_POINT_2DEGREES: Chromaticity = Chromaticity {
+    x: 0.44757,
+    y: 0.40745,
+};
+
+/// An obsolete approximation of average daylight. Used by NTSC.
+// Chromaticity values from here:
+// https://en.wikipedia.org/wiki/Standard_illuminant
+pub const C_WHITE_POINT_2DEGREES: Chromaticity = Chromaticity {
+    x: 0.31006,
+    y: 0.31616,
+};
+
 /// "Horizon light". A commonly used white point.
 /// https://en.wikipedia.org/wiki/Standard_illuminant
 // Chromaticity values from here:
@@ -9,6 +25,23 @@ pub const D50_WHITE_POINT_2DEGREES: Chromaticity = Chromaticity {
     y: 0.35850,
 };
 
+/// Mid-morning / mid-afternoon daylight.
+// Chromaticity values from here:
+// https://en.wikipedia.org/wiki/Standard_illuminant
+pub const D55_WHITE_POINT_2DEGREES: Chromaticity = Chromaticity {
+    x: 0.33242,
+    y: 0.34743,
+};
+
+/// The white point used by the ACES color spaces.
+/// It is close to, but not exactly, the CIE daylight locus at 6000K.
+// Chromaticity values from here:
+// https://en.wikipedia.org/wiki/Academy_Color_Encoding_System
+pub const D60_WHITE_POINT_2DEGREES: Chromaticity = Chromaticity {
+    x: 0.32168,
+    y: 0.33767,
+};
+
 /// A white point that corresponds to average midday light in Western / Northern Europe:
 /// https://en.wikipedia.org/wiki/Illuminant_D65
 // Chromaticity values from here:
@@ -17,3 +50,122 @@ pub const D65_WHITE_POINT_2DEGREES: Chromaticity = Chromaticity {
     x: 0.31271,
     y: 0.32902,
 };
+
+/// North sky daylight.
+// Chromaticity values from here:
+// https://en.wikipedia.org/wiki/Standard_illuminant
+pub const D75_WHITE_POINT_2DEGREES: Chromaticity = Chromaticity {
+    x: 0.29902,
+    y: 0.31485,
+};
+
+/// The equal energy illuminant. Not a real light source, but useful as a reference.
+pub const E_WHITE_POINT_2DEGREES: Chromaticity = Chromaticity {
+    x: 1.0 / 3.0,
+    y: 1.0 / 3.0,
+};
+
+/// Cool white fluorescent light.
+// Chromaticity values from here:
+// https://en.wikipedia.org/wiki/Standard_illuminant
+pub const F2_WHITE_POINT_2DEGREES: Chromaticity = Chromaticity {
+    x: 0.37208,
+    y: 0.37529,
+};
+
+/// Broad-band daylight fluorescent light.
+// Chromaticity values from here:
+// https://en.wikipedia.org/wiki/Standard_illuminant
+pub const F7_WHITE_POINT_2DEGREES: Chromaticity = Chromaticity {
+    x: 0.31292,
+    y: 0.32933,
+};
+
+/// Narrow tri-band fluorescent light.
+// Chromaticity values from here:
+// https://en.wikipedia.org/wiki/Standard_illuminant
+pub const F11_WHITE_POINT_2DEGREES: Chromaticity = Chromaticity {
+    x: 0.38052,
+    y: 0.37713,
+};
+
+// The following white points are for the CIE 1964 10 degree standard observer.
+// They should only be used with XYZ values measured for the 10 degree observer,
+// which some spectrophotometers report.
+// Chromaticity values from here:
+// https://en.wikipedia.org/wiki/Standard_illuminant#White_points_of_standard_illuminants
+
+pub const A_WHITE_POINT_10DEGREES: Chromaticity = Chromaticity {
+    x: 0.45117,
+    y: 0.40594,
+};
+
+pub const C_WHITE_POINT_10DEGREES: Chromaticity = Chromaticity {
+    x: 0.31039,
+    y: 0.31905,
+};
+
+pub const D50_WHITE_POINT_10DEGREES: Chromaticity = Chromaticity {
+    x: 0.34773,
+    y: 0.35952,
+};
+
+pub const D55_WHITE_POINT_10DEGREES: Chromaticity = Chromaticity {
+    x: 0.33411,
+    y: 0.34877,
+};
+
+pub const D65_WHITE_POINT_10DEGREES: Chromaticity = Chromaticity {
+    x: 0.31382,
+    y: 0.33100,
+};
+
+pub const D75_WHITE_POINT_10DEGREES: Chromaticity = Chromaticity {
+    x: 0.29968,
+    y: 0.31740,
+};
+
+pub const F2_WHITE_POINT_10DEGREES: Chromaticity = Chromaticity {
+    x: 0.37928,
+    y: 0.36723,
+};
+
+pub const F7_WHITE_POINT_10DEGREES: Chromaticity = Chromaticity {
+    x: 0.31565,
+    y: 0.32951,
+};
+
+pub const F11_WHITE_POINT_10DEGREES: Chromaticity = Chromaticity {
+    x: 0.38543,
+    y: 0.37110,
+};
+
+/// A CIE standard observer. The observer describes the field of view used when
+/// measuring the color matching functions that XYZ values are based on.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Observer {
+    /// The CIE 1931 2 degree standard observer. Used by nearly all color spaces.
+    #[default]
+    TwoDegrees,
+    /// The CIE 1964 10 degree standard observer. Often used by spectrophotometers.
+    TenDegrees,
+}
+
+impl Observer {
+    /// The D50 white point for this observer.
+    pub fn d50_white_point(&self) -> Chromaticity {
+        match self {
+            Observer::TwoDegrees => D50_WHITE_POINT_2DEGREES,
+            Observer::TenDegrees => D50_WHITE_POINT_10DEGREES,
+        }
+    }
+
+    /// The D65 white point for this observer.
+    pub fn d65_white_point(&self) -> Chromaticity {
+        match self {
+            Observer::TwoDegrees => D65_WHITE_POINT_2DEGREES,
+            Observer::TenDegrees => D65_WHITE_POINT_10DEGREES,
+        }
+    }
+}
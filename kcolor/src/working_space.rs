@@ -0,0 +1,73 @@
+//! The default working color space used by convenience methods like `Color::from_hex_str`,
+//! so application code doesn't need to pass a `ColorSpace` to every call.
+//!
+//! The default is sRGB. With the `std` feature it can be changed for the whole process
+//! with `set_default_color_space`, or for a single thread while a closure runs
+//! with `with_default_color_space`.
+use crate::*;
+use alloc::string::String;
+
+#[cfg(feature = "std")]
+static DEFAULT_COLOR_SPACE: std::sync::RwLock<Option<ColorSpace>> = std::sync::RwLock::new(None);
+
+#[cfg(feature = "std")]
+std::thread_local! {
+    static SCOPED_COLOR_SPACE: core::cell::RefCell<Option<ColorSpace>> = const { core::cell::RefCell::new(None) };
+}
+
+/// The current default color space: the scoped color space of this thread if there is one,
+/// then the process-wide default, then sRGB.
+pub fn default_color_space() -> ColorSpace {
+    #[cfg(feature = "std")]
+    {
+        if let Some(color_space) = SCOPED_COLOR_SPACE.with(|scoped| scoped.borrow().clone()) {
+            return color_space;
+        }
+        let default = DEFAULT_COLOR_SPACE
+            .read()
+            .unwrap_or_else(|poisoned| poisoned.into_inner());
+        if let Some(color_space) = default.as_ref() {
+            return color_space.clone();
+        }
+    }
+    ColorSpace::SRGB
+}
+
+/// Sets the default color space for every thread. This is usually done once at startup.
+#[cfg(feature = "std")]
+pub fn set_default_color_space(color_space: ColorSpace) {
+    let mut default = DEFAULT_COLOR_SPACE
+        .write()
+        .unwrap_or_else(|poisoned| poisoned.into_inner());
+    *default = Some(color_space);
+}
+
+/// Calls `f` with `color_space` as the default color space of the current thread.
+/// Calls can be nested, and the previous default is restored afterwards even if `f` panics.
+#[cfg(feature = "std")]
+pub fn with_default_color_space<R>(color_space: ColorSpace, f: impl FnOnce() -> R) -> R {
+    struct Restore(Option<ColorSpace>);
+    impl Drop for Restore {
+        fn drop(&mut self) {
+            let previous = self.0.take();
+            SCOPED_COLOR_SPACE.with(|scoped| *scoped.borrow_mut() = previous);
+        }
+    }
+
+    let previous = SCOPED_COLOR_SPACE.with(|scoped| scoped.replace(Some(color_space)));
+    let _restore = Restore(previous);
+    f()
+}
+
+impl Color {
+    /// Parses a hex color string in the default color space (see `default_color_space`).
+    /// See `ColorSpace::new_color_from_hex_str` for the accepted formats.
+    pub fn from_hex_str(hex: &str) -> Result<Color, Error> {
+        default_color_space().new_color_from_hex_str(hex)
+    }
+
+    /// Formats the color as a hex string in the default color space (see `default_color_space`).
+    pub fn to_hex_string(&self, include_alpha: bool) -> String {
+        default_color_space().color_to_hex_string(self, include_alpha)
+    }
+}
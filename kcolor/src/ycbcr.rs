@@ -0,0 +1,627 @@
+//! Y′CbCr, the encoding used by digital video and JPEG, where a luma value (Y′) is stored
+//! separately from two color difference values (Cb and Cr).
+//!
+//! Y′CbCr is calculated from a color space's encoded (non-linear) R′G′B′ values.
+//! Mismatched matrices or ranges are the usual cause of slightly wrong colors (matrix)
+//! or washed out and crushed blacks (range).
+use crate::*;
+use alloc::vec::Vec;
+
+/// The coefficients used to calculate luma from R′G′B′.
+/// Use the one matching the video's color space: BT.601 for standard definition,
+/// BT.709 for HD, and BT.2020 for UHD.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum YCbCrMatrix {
+    Bt601,
+    #[default]
+    Bt709,
+    /// The non-constant luminance BT.2020 matrix, which is what nearly all UHD video uses.
+    Bt2020,
+}
+
+/// How Y′CbCr values are quantized to integers.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum YCbCrRange {
+    /// Also called "TV" or "video" range: luma is from 16 to 235 and chroma from 16 to 240
+    /// (for 8 bits), which leaves room for overshoot. Almost all video uses this.
+    #[default]
+    Limited,
+    /// Also called "PC" or "JPEG" range: every value uses the whole range of integers.
+    Full,
+}
+
+/// A Y′CbCr value where luma (`y`) is from 0.0 to 1.0 and chroma (`cb` and `cr`)
+/// are from -0.5 to 0.5.
+#[derive(Debug, Copy, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct YCbCr {
+    pub y: f64,
+    pub cb: f64,
+    pub cr: f64,
+}
+
+impl YCbCrMatrix {
+    /// The red and blue luma coefficients, Kr and Kb.
+    pub fn coefficients(&self) -> (f64, f64) {
+        match self {
+            YCbCrMatrix::Bt601 => (0.299, 0.114),
+            YCbCrMatrix::Bt709 => (0.2126, 0.0722),
+            YCbCrMatrix::Bt2020 => (0.2627, 0.0593),
+        }
+    }
+
+    pub fn from_rgb(&self, rgb: [f64; 3]) -> YCbCr {
+        let (kr, kb) = self.coefficients();
+        let [r, g, b] = rgb;
+        let y = kr * r + (1.0 - kr - kb) * g + kb * b;
+        YCbCr {
+            y,
+            cb: (b - y) / (2.0 * (1.0 - kb)),
+            cr: (r - y) / (2.0 * (1.0 - kr)),
+        }
+    }
+
+    pub fn to_rgb(&self, ycbcr: YCbCr) -> [f64; 3] {
+        let (kr, kb) = self.coefficients();
+        let YCbCr { y, cb, cr } = ycbcr;
+        let r = y + 2.0 * (1.0 - kr) * cr;
+        let b = y + 2.0 * (1.0 - kb) * cb;
+        let g = (y - kr * r - kb * b) / (1.0 - kr - kb);
+        [r, g, b]
+    }
+}
+
+/// How Y′CbCr is stored: the matrix, the quantization range, and the bit depth (8 to 16).
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(try_from = "YCbCrFormatFields"))]
+pub struct YCbCrFormat {
+    matrix: YCbCrMatrix,
+    range: YCbCrRange,
+    bit_depth: u32,
+}
+
+/// The unvalidated form of `YCbCrFormat` that's deserialized.
+#[cfg(feature = "serde")]
+#[derive(serde::Deserialize)]
+struct YCbCrFormatFields {
+    matrix: YCbCrMatrix,
+    range: YCbCrRange,
+    bit_depth: u32,
+}
+
+#[cfg(feature = "serde")]
+impl core::convert::TryFrom<YCbCrFormatFields> for YCbCrFormat {
+    type Error = Error;
+    fn try_from(fields: YCbCrFormatFields) -> Result<Self, Self::Error> {
+        Self::try_new(fields.matrix, fields.range, fields.bit_depth)
+    }
+}
+
+impl YCbCrFormat {
+    /// 8-bit limited range BT.709, used by most HD video.
+    pub const BT709_8BIT: YCbCrFormat =
+        YCbCrFormat::new(YCbCrMatrix::Bt709, YCbCrRange::Limited, 8);
+    /// 10-bit limited range BT.2020, used by HDR10 and HLG video.
+    pub const BT2020_10BIT: YCbCrFormat =
+        YCbCrFormat::new(YCbCrMatrix::Bt2020, YCbCrRange::Limited, 10);
+    /// 8-bit full range BT.601, used by JPEG.
+    pub const JPEG: YCbCrFormat = YCbCrFormat::new(YCbCrMatrix::Bt601, YCbCrRange::Full, 8);
+
+    /// # Panics
+    /// If `bit_depth` isn't from 8 to 16. Use `try_new` for bit depths that aren't known
+    /// to be valid, like ones read from a file.
+    pub const fn new(matrix: YCbCrMatrix, range: YCbCrRange, bit_depth: u32) -> Self {
+        assert!(
+            bit_depth >= 8 && bit_depth <= 16,
+            "bit depth must be from 8 to 16"
+        );
+        Self {
+            matrix,
+            range,
+            bit_depth,
+        }
+    }
+
+    /// The same as `YCbCrFormat::new`, but returns an error if `bit_depth` isn't from 8 to 16.
+    pub fn try_new(matrix: YCbCrMatrix, range: YCbCrRange, bit_depth: u32) -> Result<Self, Error> {
+        if !(8..=16).contains(&bit_depth) {
+            return Err(Error::InvalidBitDepth);
+        }
+        Ok(Self::new(matrix, range, bit_depth))
+    }
+
+    pub fn matrix(&self) -> YCbCrMatrix {
+        self.matrix
+    }
+
+    pub fn range(&self) -> YCbCrRange {
+        self.range
+    }
+
+    pub fn bit_depth(&self) -> u32 {
+        self.bit_depth
+    }
+
+    /// The largest integer value at this bit depth.
+    pub fn max_value(&self) -> u16 {
+        ((1u32 << self.bit_depth) - 1) as u16
+    }
+
+    /// Quantizes Y′CbCr to integers, as described by ITU-R BT.2100.
+    /// Values are rounded and clamped to the integers available at the bit depth.
+    pub fn quantize(&self, ycbcr: YCbCr) -> [u16; 3] {
+        [
+            self.quantize_luma(ycbcr.y),
+            self.quantize_chroma(ycbcr.cb),
+            self.quantize_chroma(ycbcr.cr),
+        ]
+    }
+
+    /// The inverse of `quantize`. Limited range values outside the nominal range
+    /// produce values outside 0.0 to 1.0 (or -0.5 to 0.5), which aren't clamped.
+    pub fn dequantize(&self, values: [u16; 3]) -> YCbCr {
+        YCbCr {
+            y: self.dequantize_luma(values[0]),
+            cb: self.dequantize_chroma(values[1]),
+            cr: self.dequantize_chroma(values[2]),
+        }
+    }
+
+    /// The scale and offset that map luma or chroma to integers.
+    fn quantization(&self, chroma: bool) -> (f64, f64) {
+        let bit_scale = (1u32 << self.bit_depth.saturating_sub(8)) as f64;
+        let half = (1u32 << (self.bit_depth - 1)) as f64;
+        match (self.range, chroma) {
+            (YCbCrRange::Limited, false) => (219.0 * bit_scale, 16.0 * bit_scale),
+            (YCbCrRange::Limited, true) => (224.0 * bit_scale, 128.0 * bit_scale),
+            (YCbCrRange::Full, false) => (self.max_value() as f64, 0.0),
+            (YCbCrRange::Full, true) => (self.max_value() as f64, half),
+        }
+    }
+
+    fn round_to_integer(&self, v: f64) -> u16 {
+        float::round(v).clamp(0.0, self.max_value() as f64) as u16
+    }
+
+    pub(crate) fn quantize_luma(&self, y: f64) -> u16 {
+        let (scale, offset) = self.quantization(false);
+        self.round_to_integer(y * scale + offset)
+    }
+
+    pub(crate) fn quantize_chroma(&self, c: f64) -> u16 {
+        let (scale, offset) = self.quantization(true);
+        self.round_to_integer(c * scale + offset)
+    }
+
+    pub(crate) fn dequantize_luma(&self, value: u16) -> f64 {
+        let (scale, offset) = self.quantization(false);
+        (value as f64 - offset) / scale
+    }
+
+    pub(crate) fn dequantize_chroma(&self, value: u16) -> f64 {
+        let (scale, offset) = self.quantization(true);
+        (value as f64 - offset) / scale
+    }
+
+    /// Converts encoded R′G′B′ values from 0.0 to 1.0 to quantized Y′CbCr.
+    pub fn from_rgb(&self, rgb: [f64; 3]) -> [u16; 3] {
+        self.quantize(self.matrix.from_rgb(rgb))
+    }
+
+    /// Converts quantized Y′CbCr to encoded R′G′B′ values. These aren't clamped,
+    /// so overshoot in limited range video produces values outside of 0.0 to 1.0.
+    pub fn to_rgb(&self, values: [u16; 3]) -> [f64; 3] {
+        self.matrix.to_rgb(self.dequantize(values))
+    }
+}
+
+impl ColorSpace {
+    /// Gets quantized Y′CbCr values for the color as expressed in this color space.
+    /// RGB values outside of 0.0 to 1.0 are clipped first. Alpha is ignored.
+    pub fn color_to_ycbcr(&self, color: &Color, format: &YCbCrFormat) -> [u16; 3] {
+        let (r, g, b, _) = self.color_to_rgba(color);
+        format.from_rgb([r, g, b])
+    }
+
+    /// Creates an opaque color from quantized Y′CbCr values in this color space.
+    pub fn new_color_from_ycbcr(&self, values: [u16; 3], format: &YCbCrFormat) -> Color {
+        let [r, g, b] = format.to_rgb(values);
+        self.new_color(r, g, b, 1.0)
+    }
+}
+
+/// How much the chroma planes of an image are reduced in resolution relative to luma.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum ChromaSubsampling {
+    /// Chroma at full resolution.
+    Yuv444,
+    /// Chroma at half horizontal resolution.
+    Yuv422,
+    /// Chroma at half horizontal and half vertical resolution. Most video uses this.
+    #[default]
+    Yuv420,
+}
+
+impl ChromaSubsampling {
+    /// The horizontal and vertical number of luma samples per chroma sample.
+    pub fn factors(&self) -> (usize, usize) {
+        match self {
+            Self::Yuv444 => (1, 1),
+            Self::Yuv422 => (2, 1),
+            Self::Yuv420 => (2, 2),
+        }
+    }
+
+    /// The width and height of each chroma plane for an image of the given size.
+    /// Odd sizes round up.
+    pub fn chroma_size(&self, width: usize, height: usize) -> (usize, usize) {
+        let (sx, sy) = self.factors();
+        (width.div_ceil(sx), height.div_ceil(sy))
+    }
+}
+
+/// Where subsampled chroma samples are located relative to luma samples.
+/// Decoding with the wrong siting shifts colors by a fraction of a pixel,
+/// which is most visible as fringes on sharp colored edges.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum ChromaSiting {
+    /// Horizontally co-sited with the left luma sample and vertically between luma rows.
+    /// Used by MPEG-2, H.264, and HEVC unless the stream says otherwise.
+    #[default]
+    Left,
+    /// Centered between the luma samples. Used by JPEG and MPEG-1.
+    Center,
+    /// Co-sited with the top left luma sample. Used by BT.2020 and BT.2100.
+    TopLeft,
+}
+
+impl ChromaSiting {
+    /// The position of the first chroma sample in luma sample units.
+    fn offset(&self, subsampling: ChromaSubsampling) -> (f64, f64) {
+        let (sx, sy) = subsampling.factors();
+        let (x, y) = match self {
+            Self::Left => (0.0, 0.5),
+            Self::Center => (0.5, 0.5),
+            Self::TopLeft => (0.0, 0.0),
+        };
+        (if sx > 1 { x } else { 0.0 }, if sy > 1 { y } else { 0.0 })
+    }
+}
+
+/// How subsampled chroma is upsampled to full resolution.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum ChromaFilter {
+    /// Uses the closest chroma sample. Fast, but blocky on colored edges.
+    Nearest,
+    #[default]
+    Bilinear,
+}
+
+/// A quantized Y′CbCr image stored as separate luma and chroma planes,
+/// the way video decoders output frames. Planes are tightly packed rows.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct YCbCrPlanes {
+    pub width: usize,
+    pub height: usize,
+    pub subsampling: ChromaSubsampling,
+    pub siting: ChromaSiting,
+    /// `width * height` luma samples.
+    pub y: Vec<u16>,
+    /// Chroma samples sized by `ChromaSubsampling::chroma_size`.
+    pub cb: Vec<u16>,
+    pub cr: Vec<u16>,
+}
+
+impl YCbCrPlanes {
+    /// Encodes RGB pixels in the `source` color space as Y′CbCr planes for a video in
+    /// the `video` color space. Pixels are clipped to the video color space before encoding.
+    /// Chroma is downsampled with a filter centered on each chroma sample's siting.
+    pub fn from_rgb(
+        pixels: &[[f64; 3]],
+        width: usize,
+        source: &ColorSpace,
+        video: &ColorSpace,
+        format: &YCbCrFormat,
+        subsampling: ChromaSubsampling,
+        siting: ChromaSiting,
+    ) -> Self {
+        assert!(width > 0 && pixels.len().is_multiple_of(width));
+        let height = pixels.len() / width;
+        let converter = ColorSpaceConverter::new(source, video);
+        let full: Vec<YCbCr> = pixels
+            .iter()
+            .map(|p| {
+                let (r, g, b) = converter.convert_encoded_color(&(p[0], p[1], p[2]));
+                format
+                    .matrix
+                    .from_rgb([r.clamp(0.0, 1.0), g.clamp(0.0, 1.0), b.clamp(0.0, 1.0)])
+            })
+            .collect();
+
+        let (sx, sy) = subsampling.factors();
+        let (ox, oy) = siting.offset(subsampling);
+        let (chroma_width, chroma_height) = subsampling.chroma_size(width, height);
+        let mut cb = Vec::with_capacity(chroma_width * chroma_height);
+        let mut cr = Vec::with_capacity(chroma_width * chroma_height);
+        for j in 0..chroma_height {
+            let cy = (j * sy) as f64 + oy;
+            for i in 0..chroma_width {
+                let cx = (i * sx) as f64 + ox;
+                // A tent filter as wide as the subsampling factor around the chroma sample.
+                let (mut sum_cb, mut sum_cr, mut total) = (0.0, 0.0, 0.0);
+                for py in (j * sy).saturating_sub(sy)..((j + 2) * sy).min(height) {
+                    let wy = 1.0 - (py as f64 - cy).abs() / sy as f64;
+                    if wy <= 0.0 {
+                        continue;
+                    }
+                    for px in (i * sx).saturating_sub(sx)..((i + 2) * sx).min(width) {
+                        let wx = 1.0 - (px as f64 - cx).abs() / sx as f64;
+                        if wx <= 0.0 {
+                            continue;
+                        }
+                        let sample = full[py * width + px];
+                        sum_cb += sample.cb * wx * wy;
+                        sum_cr += sample.cr * wx * wy;
+                        total += wx * wy;
+                    }
+                }
+                cb.push(format.quantize_chroma(sum_cb / total));
+                cr.push(format.quantize_chroma(sum_cr / total));
+            }
+        }
+
+        Self {
+            width,
+            height,
+            subsampling,
+            siting,
+            y: full.iter().map(|v| format.quantize_luma(v.y)).collect(),
+            cb,
+            cr,
+        }
+    }
+
+    /// Decodes the planes of a video in the `video` color space to RGB pixels
+    /// in the `target` color space. Values are not clipped.
+    pub fn to_rgb(
+        &self,
+        format: &YCbCrFormat,
+        filter: ChromaFilter,
+        video: &ColorSpace,
+        target: &ColorSpace,
+    ) -> Vec<[f64; 3]> {
+        let (chroma_width, chroma_height) = self.subsampling.chroma_size(self.width, self.height);
+        assert!(self.y.len() == self.width * self.height);
+        assert!(self.cb.len() == chroma_width * chroma_height);
+        assert!(self.cr.len() == chroma_width * chroma_height);
+
+        let (sx, sy) = self.subsampling.factors();
+        let (ox, oy) = self.siting.offset(self.subsampling);
+        let cb: Vec<f64> = self
+            .cb
+            .iter()
+            .map(|v| format.dequantize_chroma(*v))
+            .collect();
+        let cr: Vec<f64> = self
+            .cr
+            .iter()
+            .map(|v| format.dequantize_chroma(*v))
+            .collect();
+
+        // The chroma samples surrounding a luma position and the weight of the second one.
+        let neighbors = |p: usize, offset: f64, factor: usize, size: usize| {
+            let u = ((p as f64 - offset) / factor as f64).clamp(0.0, (size - 1) as f64);
+            match filter {
+                ChromaFilter::Nearest => {
+                    let i = float::round(u) as usize;
+                    (i, i, 0.0)
+                }
+                ChromaFilter::Bilinear => {
+                    let i = float::floor(u) as usize;
+                    (i, (i + 1).min(size - 1), u - i as f64)
+                }
+            }
+        };
+        let columns: Vec<_> = (0..self.width)
+            .map(|x| neighbors(x, ox, sx, chroma_width))
+            .collect();
+
+        let converter = ColorSpaceConverter::new(video, target);
+        let mut pixels = Vec::with_capacity(self.width * self.height);
+        for y in 0..self.height {
+            let (j0, j1, ty) = neighbors(y, oy, sy, chroma_height);
+            for (x, (i0, i1, tx)) in columns.iter().enumerate() {
+                let sample = |plane: &[f64]| {
+                    let top = plane[j0 * chroma_width + i0] * (1.0 - tx)
+                        + plane[j0 * chroma_width + i1] * tx;
+                    let bottom = plane[j1 * chroma_width + i0] * (1.0 - tx)
+                        + plane[j1 * chroma_width + i1] * tx;
+                    top * (1.0 - ty) + bottom * ty
+                };
+                let [r, g, b] = format.matrix.to_rgb(YCbCr {
+                    y: format.dequantize_luma(self.y[y * self.width + x]),
+                    cb: sample(&cb),
+                    cr: sample(&cr),
+                });
+                let (r, g, b) = converter.convert_encoded_color(&(r, g, b));
+                pixels.push([r, g, b]);
+            }
+        }
+        pixels
+    }
+}
+
+/// Byte layouts of 4:2:0 frames as output by hardware and software video decoders.
+/// Rows are tightly packed: copy rows out first if the decoder's stride is wider than the frame.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum YuvLayout {
+    /// 8-bit planar: a luma plane followed by a Cb plane and a Cr plane.
+    /// Also called YUV420P or IYUV.
+    I420,
+    /// 8-bit semi-planar: a luma plane followed by a single plane of interleaved Cb and Cr.
+    /// The most common output of hardware decoders.
+    Nv12,
+    /// 10-bit semi-planar with NV12's layout, where each sample is a little endian `u16`
+    /// with the value in the high 10 bits. Used for HDR video.
+    P010,
+}
+
+impl YuvLayout {
+    /// The bit depth of samples in this layout.
+    pub fn bit_depth(&self) -> u32 {
+        match self {
+            Self::I420 | Self::Nv12 => 8,
+            Self::P010 => 10,
+        }
+    }
+
+    /// The number of bytes in a frame of the given size.
+    pub fn frame_size(&self, width: usize, height: usize) -> usize {
+        let (chroma_width, chroma_height) = ChromaSubsampling::Yuv420.chroma_size(width, height);
+        let samples = width * height + 2 * chroma_width * chroma_height;
+        match self {
+            Self::I420 | Self::Nv12 => samples,
+            Self::P010 => samples * 2,
+        }
+    }
+}
+
+impl YCbCrPlanes {
+    /// Reads a 4:2:0 frame from a decoder's byte layout.
+    pub fn from_yuv_bytes(
+        bytes: &[u8],
+        width: usize,
+        height: usize,
+        layout: YuvLayout,
+        siting: ChromaSiting,
+    ) -> Self {
+        assert!(bytes.len() >= layout.frame_size(width, height));
+        let (chroma_width, chroma_height) = ChromaSubsampling::Yuv420.chroma_size(width, height);
+        let luma_size = width * height;
+        let chroma_size = chroma_width * chroma_height;
+
+        let samples: Vec<u16> = match layout {
+            YuvLayout::I420 | YuvLayout::Nv12 => bytes[..luma_size + 2 * chroma_size]
+                .iter()
+                .map(|v| *v as u16)
+                .collect(),
+            YuvLayout::P010 => bytes[..(luma_size + 2 * chroma_size) * 2]
+                .chunks_exact(2)
+                .map(|v| u16::from_le_bytes([v[0], v[1]]) >> 6)
+                .collect(),
+        };
+        let (y, chroma) = samples.split_at(luma_size);
+        let (cb, cr) = match layout {
+            YuvLayout::I420 => (
+                chroma[..chroma_size].to_vec(),
+                chroma[chroma_size..].to_vec(),
+            ),
+            YuvLayout::Nv12 | YuvLayout::P010 => (
+                chroma.iter().step_by(2).copied().collect(),
+                chroma.iter().skip(1).step_by(2).copied().collect(),
+            ),
+        };
+
+        Self {
+            width,
+            height,
+            subsampling: ChromaSubsampling::Yuv420,
+            siting,
+            y: y.to_vec(),
+            cb,
+            cr,
+        }
+    }
+
+    /// Writes 4:2:0 planes in a decoder's byte layout.
+    pub fn to_yuv_bytes(&self, layout: YuvLayout) -> Vec<u8> {
+        assert!(self.subsampling == ChromaSubsampling::Yuv420);
+        let mut samples = self.y.clone();
+        match layout {
+            YuvLayout::I420 => {
+                samples.extend_from_slice(&self.cb);
+                samples.extend_from_slice(&self.cr);
+            }
+            YuvLayout::Nv12 | YuvLayout::P010 => {
+                for (cb, cr) in self.cb.iter().zip(&self.cr) {
+                    samples.push(*cb);
+                    samples.push(*cr);
+                }
+            }
+        }
+        match layout {
+            YuvLayout::I420 | YuvLayout::Nv12 => samples.iter().map(|v| *v as u8).collect(),
+            YuvLayout::P010 => samples
+                .iter()
+                .flat_map(|v| (v << 6).to_le_bytes())
+                .collect(),
+        }
+    }
+}
+
+/// Converts a 4:2:0 frame in the `video` color space to interleaved 8-bit RGBA
+/// in the `target` color space. Chroma is assumed to be left sited, the default for
+/// H.264 and HEVC, and is upsampled bilinearly. Alpha is always opaque.
+///
+/// `format`'s bit depth must match the layout.
+pub fn yuv_to_rgba8(
+    bytes: &[u8],
+    width: usize,
+    height: usize,
+    layout: YuvLayout,
+    format: &YCbCrFormat,
+    video: &ColorSpace,
+    target: &ColorSpace,
+) -> Vec<u8> {
+    assert!(format.bit_depth == layout.bit_depth());
+    let planes = YCbCrPlanes::from_yuv_bytes(bytes, width, height, layout, ChromaSiting::Left);
+    let to_byte = |v: f64| float::round(v.clamp(0.0, 1.0) * 255.0) as u8;
+    planes
+        .to_rgb(format, ChromaFilter::Bilinear, video, target)
+        .iter()
+        .flat_map(|p| [to_byte(p[0]), to_byte(p[1]), to_byte(p[2]), 255])
+        .collect()
+}
+
+/// Converts interleaved 8-bit RGBA in the `source` color space to a left sited 4:2:0 frame
+/// in the `video` color space. Alpha is ignored.
+///
+/// `format`'s bit depth must match the layout.
+pub fn rgba8_to_yuv(
+    rgba: &[u8],
+    width: usize,
+    source: &ColorSpace,
+    video: &ColorSpace,
+    format: &YCbCrFormat,
+    layout: YuvLayout,
+) -> Vec<u8> {
+    assert!(format.bit_depth == layout.bit_depth());
+    let pixels: Vec<[f64; 3]> = rgba
+        .chunks_exact(4)
+        .map(|p| {
+            [
+                p[0] as f64 / 255.0,
+                p[1] as f64 / 255.0,
+                p[2] as f64 / 255.0,
+            ]
+        })
+        .collect();
+    YCbCrPlanes::from_rgb(
+        &pixels,
+        width,
+        source,
+        video,
+        format,
+        ChromaSubsampling::Yuv420,
+        ChromaSiting::Left,
+    )
+    .to_yuv_bytes(layout)
+}
@@ -0,0 +1,209 @@
+//! The CIE standard observer color matching functions, which describe how strongly an average
+//! observer's eyes respond to each wavelength of light.
+//!
+//! Both are the tabulated CIE data in 5 nanometer steps, linearly interpolated between.
+use crate::XYZ;
+
+/// The response of the CIE 1931 2 degree standard observer to light of `wavelength` nanometers.
+/// The peak of the Y response is 1.0 at 555 nanometers.
+/// Wavelengths outside of 380 to 780 nanometers have no response.
+pub fn cie_1931(wavelength: f64) -> XYZ {
+    interpolate(&CIE_1931, wavelength)
+}
+
+/// The response of the CIE 1964 10 degree standard observer to light of `wavelength` nanometers.
+/// The peak of the Y response is close to 1.0 at 555 to 560 nanometers.
+/// Wavelengths outside of 380 to 780 nanometers have no response.
+pub fn cie_1964(wavelength: f64) -> XYZ {
+    interpolate(&CIE_1964, wavelength)
+}
+
+/// Linearly interpolates between the values of a table starting at 380 nanometers in 5 nanometer steps.
+fn interpolate(table: &[[f64; 3]; 81], wavelength: f64) -> XYZ {
+    const START: f64 = 380.0;
+    const STEP: f64 = 5.0;
+    let position = (wavelength - START) / STEP;
+    if !(0.0..=(table.len() - 1) as f64).contains(&position) {
+        return XYZ::new(0.0, 0.0, 0.0);
+    }
+
+    let index = position as usize;
+    let next = (index + 1).min(table.len() - 1);
+    let t = position - index as f64;
+    let lerp = |channel: usize| table[index][channel] * (1.0 - t) + table[next][channel] * t;
+    XYZ::new(lerp(0), lerp(1), lerp(2))
+}
+
+/// The CIE 1931 2 degree color matching functions from 380 to 780 nanometers in 5 nanometer steps.
+// The standard observer data published by the CIE.
+#[rustfmt::skip]
+const CIE_1931: [[f64; 3]; 81] = [
+    [0.001368, 0.000039, 0.006450],
+    [0.002236, 0.000064, 0.010550],
+    [0.004243, 0.000120, 0.020050],
+    [0.007650, 0.000217, 0.036210],
+    [0.014310, 0.000396, 0.067850],
+    [0.023190, 0.000640, 0.110200],
+    [0.043510, 0.001210, 0.207400],
+    [0.077630, 0.002180, 0.371300],
+    [0.134380, 0.004000, 0.645600],
+    [0.214770, 0.007300, 1.039050],
+    [0.283900, 0.011600, 1.385600],
+    [0.328500, 0.016840, 1.622960],
+    [0.348280, 0.023000, 1.747060],
+    [0.348060, 0.029800, 1.782600],
+    [0.336200, 0.038000, 1.772110],
+    [0.318700, 0.048000, 1.744100],
+    [0.290800, 0.060000, 1.669200],
+    [0.251100, 0.073900, 1.528100],
+    [0.195360, 0.090980, 1.287640],
+    [0.142100, 0.112600, 1.041900],
+    [0.095640, 0.139020, 0.812950],
+    [0.057950, 0.169300, 0.616200],
+    [0.032010, 0.208020, 0.465180],
+    [0.014700, 0.258600, 0.353300],
+    [0.004900, 0.323000, 0.272000],
+    [0.002400, 0.407300, 0.212300],
+    [0.009300, 0.503000, 0.158200],
+    [0.029100, 0.608200, 0.111700],
+    [0.063270, 0.710000, 0.078250],
+    [0.109600, 0.793200, 0.057250],
+    [0.165500, 0.862000, 0.042160],
+    [0.225750, 0.914850, 0.029840],
+    [0.290400, 0.954000, 0.020300],
+    [0.359700, 0.980300, 0.013400],
+    [0.433450, 0.994950, 0.008750],
+    [0.512050, 1.000000, 0.005750],
+    [0.594500, 0.995000, 0.003900],
+    [0.678400, 0.978600, 0.002750],
+    [0.762100, 0.952000, 0.002100],
+    [0.842500, 0.915400, 0.001800],
+    [0.916300, 0.870000, 0.001650],
+    [0.978600, 0.816300, 0.001400],
+    [1.026300, 0.757000, 0.001100],
+    [1.056700, 0.694900, 0.001000],
+    [1.062200, 0.631000, 0.000800],
+    [1.045600, 0.566800, 0.000600],
+    [1.002600, 0.503000, 0.000340],
+    [0.938400, 0.441200, 0.000240],
+    [0.854450, 0.381000, 0.000190],
+    [0.751400, 0.321000, 0.000100],
+    [0.642400, 0.265000, 0.000050],
+    [0.541900, 0.217000, 0.000030],
+    [0.447900, 0.175000, 0.000020],
+    [0.360800, 0.138200, 0.000010],
+    [0.283500, 0.107000, 0.000000],
+    [0.218700, 0.081600, 0.000000],
+    [0.164900, 0.061000, 0.000000],
+    [0.121200, 0.044580, 0.000000],
+    [0.087400, 0.032000, 0.000000],
+    [0.063600, 0.023200, 0.000000],
+    [0.046770, 0.017000, 0.000000],
+    [0.032900, 0.011920, 0.000000],
+    [0.022700, 0.008210, 0.000000],
+    [0.015840, 0.005723, 0.000000],
+    [0.011359, 0.004102, 0.000000],
+    [0.008111, 0.002929, 0.000000],
+    [0.005790, 0.002091, 0.000000],
+    [0.004109, 0.001484, 0.000000],
+    [0.002899, 0.001047, 0.000000],
+    [0.002049, 0.000740, 0.000000],
+    [0.001440, 0.000520, 0.000000],
+    [0.001000, 0.000361, 0.000000],
+    [0.000690, 0.000249, 0.000000],
+    [0.000476, 0.000172, 0.000000],
+    [0.000332, 0.000120, 0.000000],
+    [0.000235, 0.000085, 0.000000],
+    [0.000166, 0.000060, 0.000000],
+    [0.000117, 0.000042, 0.000000],
+    [0.000083, 0.000030, 0.000000],
+    [0.000059, 0.000021, 0.000000],
+    [0.000042, 0.000015, 0.000000],
+];
+
+/// The CIE 1964 10 degree color matching functions from 380 to 780 nanometers in 5 nanometer steps.
+// The standard observer data published by the CIE.
+#[rustfmt::skip]
+const CIE_1964: [[f64; 3]; 81] = [
+    [0.000159952, 0.000017364, 0.000704776],
+    [0.00066244, 0.00007156, 0.0029278],
+    [0.0023616, 0.0002534, 0.0104822],
+    [0.0072423, 0.0007685, 0.032344],
+    [0.0191097, 0.0020044, 0.0860109],
+    [0.0434, 0.004509, 0.19712],
+    [0.084736, 0.008756, 0.389366],
+    [0.140638, 0.014456, 0.65676],
+    [0.204492, 0.021391, 0.972542],
+    [0.264737, 0.029497, 1.2825],
+    [0.314679, 0.038676, 1.55348],
+    [0.357719, 0.049602, 1.7985],
+    [0.383734, 0.062077, 1.96728],
+    [0.386726, 0.074704, 2.0273],
+    [0.370702, 0.089456, 1.9948],
+    [0.342957, 0.106256, 1.9007],
+    [0.302273, 0.128201, 1.74537],
+    [0.254085, 0.152761, 1.5549],
+    [0.195618, 0.18519, 1.31756],
+    [0.132349, 0.21994, 1.0302],
+    [0.080507, 0.253589, 0.772125],
+    [0.041072, 0.297665, 0.57006],
+    [0.016172, 0.339133, 0.415254],
+    [0.005132, 0.395379, 0.302356],
+    [0.003816, 0.460777, 0.218502],
+    [0.015444, 0.53136, 0.159249],
+    [0.037465, 0.606741, 0.112044],
+    [0.071358, 0.68566, 0.082248],
+    [0.117749, 0.761757, 0.060709],
+    [0.172953, 0.82333, 0.04305],
+    [0.236491, 0.875211, 0.030451],
+    [0.304213, 0.92381, 0.020584],
+    [0.376772, 0.961988, 0.013676],
+    [0.451584, 0.9822, 0.007918],
+    [0.529826, 0.991761, 0.003988],
+    [0.616053, 0.99911, 0.001091],
+    [0.705224, 0.99734, 0.0],
+    [0.793832, 0.98238, 0.0],
+    [0.878655, 0.955552, 0.0],
+    [0.951162, 0.915175, 0.0],
+    [1.01416, 0.868934, 0.0],
+    [1.0743, 0.825623, 0.0],
+    [1.11852, 0.777405, 0.0],
+    [1.1343, 0.720353, 0.0],
+    [1.12399, 0.658341, 0.0],
+    [1.0891, 0.593878, 0.0],
+    [1.03048, 0.527963, 0.0],
+    [0.95074, 0.461834, 0.0],
+    [0.856297, 0.398057, 0.0],
+    [0.75493, 0.339554, 0.0],
+    [0.647467, 0.283493, 0.0],
+    [0.53511, 0.228254, 0.0],
+    [0.431567, 0.179828, 0.0],
+    [0.34369, 0.140211, 0.0],
+    [0.268329, 0.107633, 0.0],
+    [0.2043, 0.081187, 0.0],
+    [0.152568, 0.060281, 0.0],
+    [0.11221, 0.044096, 0.0],
+    [0.0812606, 0.0318004, 0.0],
+    [0.05793, 0.0226017, 0.0],
+    [0.0408508, 0.0159051, 0.0],
+    [0.028623, 0.0111303, 0.0],
+    [0.0199413, 0.0077488, 0.0],
+    [0.013842, 0.0053751, 0.0],
+    [0.00957688, 0.00371774, 0.0],
+    [0.0066052, 0.00256456, 0.0],
+    [0.00455263, 0.00176847, 0.0],
+    [0.0031447, 0.00122239, 0.0],
+    [0.00217496, 0.00084619, 0.0],
+    [0.0015057, 0.00058644, 0.0],
+    [0.00104476, 0.00040741, 0.0],
+    [0.00072745, 0.000284041, 0.0],
+    [0.000508258, 0.00019873, 0.0],
+    [0.00035638, 0.00013955, 0.0],
+    [0.000250969, 0.000098428, 0.0],
+    [0.00017773, 0.000069819, 0.0],
+    [0.00012639, 0.000049737, 0.0],
+    [0.000090151, 0.000035541, 0.0],
+    [0.000064526, 0.000025486, 0.0],
+    [0.000046339, 0.000018338, 0.0],
+    [0.000033412, 0.000013249, 0.0],
+];
@@ -0,0 +1,36 @@
+// An approximation of `cbrt` used by the `fast-math` feature.
+//
+// It trades a tiny amount of precision for avoiding the libm call, which
+// matters when converting large buffers of colors to Lab in realtime.
+//
+// Measured maximum relative error (see the `fast_math` test in kcolor):
+// * `cbrt` for all positive finite inputs: 1e-14
+//
+// `powf` isn't approximated: no approximation accurate enough for 16 bit color was faster
+// than the platform's `powf` in the `conversions` benchmark.
+
+use crate::float;
+
+/// An approximation of `x.cbrt()`.
+/// Negative values return the negative cube root of their absolute value.
+#[inline]
+pub fn cbrt(x: f64) -> f64 {
+    if x == 0.0 || !x.is_finite() || x.abs() < f64::MIN_POSITIVE {
+        return float::cbrt(x);
+    }
+    let sign = x.signum();
+    let x = x.abs();
+
+    // Divide the exponent by 3 by dividing the upper bits of the float.
+    // This gives an initial guess within a few percent.
+    // The constant is from fdlibm's cbrt.
+    let high = (x.to_bits() >> 32) as u32;
+    let mut y = f64::from_bits(((high / 3 + 715_094_163) as u64) << 32);
+
+    // Halley's method roughly cubes the error each iteration.
+    for _ in 0..2 {
+        let y3 = y * y * y;
+        y *= (y3 + 2.0 * x) / (2.0 * y3 + x);
+    }
+    y * sign
+}
@@ -0,0 +1,138 @@
+//! Floating point functions that aren't available in `core`.
+//! These use `std` when the `std` feature is enabled and `libm` otherwise.
+//!
+//! Prefer the crate level `powf` and `cbrt`. `cbrt` honors the `fast-math` feature.
+
+#[cfg(not(any(feature = "std", feature = "libm")))]
+compile_error!("Either the `std` or `libm` feature must be enabled.");
+
+#[inline]
+pub fn powf(x: f64, y: f64) -> f64 {
+    #[cfg(feature = "std")]
+    {
+        x.powf(y)
+    }
+    #[cfg(not(feature = "std"))]
+    {
+        libm::pow(x, y)
+    }
+}
+
+#[inline]
+pub fn cbrt(x: f64) -> f64 {
+    #[cfg(feature = "std")]
+    {
+        x.cbrt()
+    }
+    #[cfg(not(feature = "std"))]
+    {
+        libm::cbrt(x)
+    }
+}
+
+#[inline]
+pub fn round(x: f64) -> f64 {
+    #[cfg(feature = "std")]
+    {
+        x.round()
+    }
+    #[cfg(not(feature = "std"))]
+    {
+        libm::round(x)
+    }
+}
+
+#[inline]
+pub fn exp(x: f64) -> f64 {
+    #[cfg(feature = "std")]
+    {
+        x.exp()
+    }
+    #[cfg(not(feature = "std"))]
+    {
+        libm::exp(x)
+    }
+}
+
+#[inline]
+pub fn sqrt(x: f64) -> f64 {
+    #[cfg(feature = "std")]
+    {
+        x.sqrt()
+    }
+    #[cfg(not(feature = "std"))]
+    {
+        libm::sqrt(x)
+    }
+}
+
+#[inline]
+pub fn sin(x: f64) -> f64 {
+    #[cfg(feature = "std")]
+    {
+        x.sin()
+    }
+    #[cfg(not(feature = "std"))]
+    {
+        libm::sin(x)
+    }
+}
+
+#[inline]
+pub fn cos(x: f64) -> f64 {
+    #[cfg(feature = "std")]
+    {
+        x.cos()
+    }
+    #[cfg(not(feature = "std"))]
+    {
+        libm::cos(x)
+    }
+}
+
+#[inline]
+pub fn ln(x: f64) -> f64 {
+    #[cfg(feature = "std")]
+    {
+        x.ln()
+    }
+    #[cfg(not(feature = "std"))]
+    {
+        libm::log(x)
+    }
+}
+
+#[inline]
+pub fn atan2(y: f64, x: f64) -> f64 {
+    #[cfg(feature = "std")]
+    {
+        y.atan2(x)
+    }
+    #[cfg(not(feature = "std"))]
+    {
+        libm::atan2(y, x)
+    }
+}
+
+#[inline]
+pub fn floor(x: f64) -> f64 {
+    #[cfg(feature = "std")]
+    {
+        x.floor()
+    }
+    #[cfg(not(feature = "std"))]
+    {
+        libm::floor(x)
+    }
+}
+
+/// The least non-negative remainder of `x / y`, like `f64::rem_euclid`.
+#[inline]
+pub fn rem_euclid(x: f64, y: f64) -> f64 {
+    let r = x % y;
+    if r < 0.0 {
+        r + y.abs()
+    } else {
+        r
+    }
+}
@@ -1,14 +1,23 @@
 //! An internal library used to define common data structures between `icc_parser` and `kcolor`
+#![cfg_attr(not(feature = "std"), no_std)]
+
+extern crate alloc;
+use alloc::vec::Vec;
 
 mod math;
 pub use math::*;
 
+pub mod color_matching;
+pub mod fast_math;
+pub mod float;
+
 /// A coordinate in the 1931 CIE XYZ color space.
 /// Y corresponds to luminance, X and Y are hue.
 // Snake case name is allowed because upper and lowercase are used to mean different things
 // in different color spaces.
 #[allow(non_snake_case)]
 #[derive(Debug, Copy, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct XYZ {
     pub X: f64,
     pub Y: f64,
@@ -21,7 +30,8 @@ impl XYZ {
         Self { X, Y, Z }
     }
 
-    // Does this function work as expected?
+    /// The chromaticity (x, y) of the XYZ value, discarding its luminance.
+    /// Black has no chromaticity and returns NaN values.
     pub fn to_chromaticity(&self) -> Chromaticity {
         Chromaticity {
             x: self.X / (self.X + self.Y + self.Z),
@@ -29,6 +39,42 @@ impl XYZ {
         }
     }
 
+    /// The white point of a light source with the given correlated color temperature in Kelvin.
+    /// Temperatures of 4000K and above follow the CIE daylight locus (the D-series illuminants)
+    /// and lower temperatures follow the Planckian (black body) locus.
+    /// The temperature is clamped to the range 1667K to 25000K.
+    /// The returned value has a luminance (Y) of 1.0.
+    ///
+    /// The daylight locus formula is from here:
+    /// https://en.wikipedia.org/wiki/Standard_illuminant#Illuminant_series_D
+    /// The Planckian locus approximation is from Kim et al. and is described here:
+    /// https://en.wikipedia.org/wiki/Planckian_locus#Approximation
+    pub fn from_cct(kelvin: f64) -> Self {
+        let t = kelvin.clamp(1667.0, 25000.0);
+        let t2 = t * t;
+        let t3 = t2 * t;
+
+        let (x, y) = if t >= 4000.0 {
+            let x = if t <= 7000.0 {
+                -4.6070e9 / t3 + 2.9678e6 / t2 + 0.09911e3 / t + 0.244063
+            } else {
+                -2.0064e9 / t3 + 1.9018e6 / t2 + 0.24748e3 / t + 0.237040
+            };
+            (x, -3.000 * x * x + 2.870 * x - 0.275)
+        } else {
+            let x = -0.2661239e9 / t3 - 0.2343589e6 / t2 + 0.8776956e3 / t + 0.179910;
+            let x2 = x * x;
+            let x3 = x2 * x;
+            let y = if t <= 2222.0 {
+                -1.1063814 * x3 - 1.34811020 * x2 + 2.18555832 * x - 0.20219683
+            } else {
+                -0.9549476 * x3 - 1.37418593 * x2 + 2.09137015 * x - 0.16748867
+            };
+            (x, y)
+        };
+        Chromaticity::new(x, y).to_XYZ()
+    }
+
     pub fn to_vector3(&self) -> Vector3 {
         Vector3 {
             x: self.X,
@@ -36,9 +82,61 @@ impl XYZ {
             z: self.Z,
         }
     }
+
+    /// The XYZ value with the same chromaticity and a luminance (Y) of 1.0,
+    /// as white points are usually specified.
+    /// A value with a luminance of 0.0 has no chromaticity and returns NaN values.
+    pub fn normalize(&self) -> XYZ {
+        *self / self.Y
+    }
+
+    /// The XYZ value with the same chromaticity and the given luminance (Y).
+    #[allow(non_snake_case)]
+    pub fn with_luminance(&self, Y: f64) -> XYZ {
+        self.normalize() * Y
+    }
+}
+
+impl core::ops::Add for XYZ {
+    type Output = XYZ;
+
+    #[inline]
+    fn add(self, other: XYZ) -> XYZ {
+        XYZ {
+            X: self.X + other.X,
+            Y: self.Y + other.Y,
+            Z: self.Z + other.Z,
+        }
+    }
+}
+
+impl core::ops::Sub for XYZ {
+    type Output = XYZ;
+
+    #[inline]
+    fn sub(self, other: XYZ) -> XYZ {
+        XYZ {
+            X: self.X - other.X,
+            Y: self.Y - other.Y,
+            Z: self.Z - other.Z,
+        }
+    }
+}
+
+impl core::ops::Div<f64> for XYZ {
+    type Output = XYZ;
+
+    #[inline]
+    fn div(self, other: f64) -> XYZ {
+        XYZ {
+            X: self.X / other,
+            Y: self.Y / other,
+            Z: self.Z / other,
+        }
+    }
 }
 
-impl std::ops::Mul<f64> for XYZ {
+impl core::ops::Mul<f64> for XYZ {
     type Output = XYZ;
 
     #[inline]
@@ -53,6 +151,7 @@ impl std::ops::Mul<f64> for XYZ {
 
 /// Chromaticity values represent the hue of a color, irrespective of brightness
 #[derive(Debug, Copy, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Chromaticity {
     pub x: f64,
     pub y: f64,
@@ -63,16 +162,52 @@ impl Chromaticity {
         Chromaticity { x, y }
     }
 
+    /// The chromaticity of pure light of `wavelength` nanometers for the CIE 1931 2 degree observer,
+    /// which is on the edge (the spectral locus) of the chromaticity diagram.
+    /// Lasers and some LEDs are close to pure wavelengths.
+    ///
+    /// Wavelengths are clamped to 380 to 780 nanometers, the ends of the tabulated data,
+    /// because the eye barely responds outside of that range.
+    pub fn from_wavelength(wavelength: f64) -> Self {
+        color_matching::cie_1931(wavelength.clamp(380.0, 780.0)).to_chromaticity()
+    }
+
+    /// The XYZ value with this chromaticity and a luminance (Y) of 1.0.
     #[allow(non_snake_case)]
     pub fn to_XYZ(&self) -> XYZ {
         XYZ::new(self.x / self.y, 1.0, (1.0 - self.x - self.y) / self.y)
     }
+
+    /// The XYZ value with this chromaticity and the given luminance (Y).
+    /// This is the conversion from CIE xyY to XYZ.
+    #[allow(non_snake_case)]
+    pub fn to_XYZ_with_luminance(&self, Y: f64) -> XYZ {
+        self.to_XYZ() * Y
+    }
 }
 
 /// A transfer function describes how to convert to and from linear color space.
 #[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum TransferFunction {
     ParametricCurve(ParametricCurve),
+    /// The SMPTE ST 2084 perceptual quantizer (PQ) used for HDR video (HDR10, Dolby Vision),
+    /// which encodes absolute luminance from 0 to 10000 cd/m².
+    ///
+    /// `reference_white` is the luminance in cd/m² of a linear value of 1.0.
+    /// Use 203.0 to match the diffuse white of ITU-R BT.2408 so SDR content keeps its brightness,
+    /// or 1.0 to make linear values absolute luminance in cd/m².
+    Pq {
+        reference_white: f64,
+    },
+    /// The hybrid log-gamma (HLG) curve from ITU-R BT.2100, used for HDR broadcast video.
+    ///
+    /// Linear values are scene light from 0.0 to 1.0, not display light.
+    /// Use `Color::hlg_ootf` to convert scene light to display light.
+    Hlg,
+    /// A transfer function that is only known from samples, like a measured display response.
+    /// Create one with `TransferFunctionLut::from_decode`.
+    Sampled(TransferFunctionLut),
     None,
 }
 
@@ -84,6 +219,7 @@ pub enum TransferFunction {
 /// The equations below are used to describe the transfer function to linear space
 /// from nonlinear space.
 #[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum ParametricCurve {
     /// X is the input value and Y is the returned value:
     ///
@@ -152,13 +288,31 @@ impl TransferFunction {
     // http://www.color.org/specification/ICC1-2010_Cumulative_Errata_List_2019-05-29.pdf
     pub fn to_linear(&self, x: f64) -> f64 {
         match self {
+            // Negative values are mirrored, like `Function3`.
+            TransferFunction::ParametricCurve(ParametricCurve::Function0 { gamma }) => {
+                x.signum() * powf(x.abs(), *gamma)
+            }
+            TransferFunction::ParametricCurve(ParametricCurve::Function1 { gamma, a, b }) => {
+                if x >= -b / a {
+                    powf(a * x + b, *gamma)
+                } else {
+                    0.0
+                }
+            }
+            TransferFunction::ParametricCurve(ParametricCurve::Function2 { gamma, a, b, c }) => {
+                if x >= -b / a {
+                    powf(a * x + b, *gamma) + c
+                } else {
+                    *c
+                }
+            }
             TransferFunction::ParametricCurve(ParametricCurve::Function3 { gamma, a, b, c, d }) => {
                 // Calculate with the absolute value of x if x is negative.
                 // It's technically not correct, but some extended color spaces like extended sRGB expect it.
                 let sign = x.signum();
                 let x = x.abs();
                 let x = if x >= *d {
-                    f64::powf(a * x + b, *gamma)
+                    powf(a * x + b, *gamma)
                 } else {
                     x * c
                 };
@@ -175,25 +329,59 @@ impl TransferFunction {
                 f,
             }) => {
                 if x >= *d {
-                    f64::powf(a * x + b, *gamma) + e
+                    powf(a * x + b, *gamma) + e
                 } else {
                     c * x + f
                 }
             }
+            TransferFunction::Pq { reference_white } => {
+                let sign = x.signum();
+                let e = powf(x.abs(), 1.0 / PQ_M2);
+                let y = powf((e - PQ_C1).max(0.0) / (PQ_C2 - PQ_C3 * e), 1.0 / PQ_M1);
+                sign * y * 10000.0 / reference_white
+            }
+            TransferFunction::Hlg => {
+                let sign = x.signum();
+                let x = x.abs();
+                let y = if x <= 0.5 {
+                    x * x / 3.0
+                } else {
+                    (float::exp((x - HLG_C) / HLG_A) + HLG_B) / 12.0
+                };
+                sign * y
+            }
+            TransferFunction::Sampled(lut) => lut.to_linear(x),
             TransferFunction::None => x,
-            _ => unimplemented!(),
         }
     }
 
     pub fn from_linear(&self, x: f64) -> f64 {
         match self {
+            TransferFunction::ParametricCurve(ParametricCurve::Function0 { gamma }) => {
+                x.signum() * powf(x.abs(), 1.0 / *gamma)
+            }
+            // Values below the curve's minimum return the start of the curve.
+            TransferFunction::ParametricCurve(ParametricCurve::Function1 { gamma, a, b }) => {
+                if x > 0.0 {
+                    (powf(x, 1.0 / *gamma) - b) / a
+                } else {
+                    -b / a
+                }
+            }
+            TransferFunction::ParametricCurve(ParametricCurve::Function2 { gamma, a, b, c }) => {
+                if x > *c {
+                    (powf(x - c, 1.0 / *gamma) - b) / a
+                } else {
+                    -b / a
+                }
+            }
             TransferFunction::ParametricCurve(ParametricCurve::Function3 { gamma, a, b, c, d }) => {
                 // Calculate with the absolute value of x if x is negative.
                 // It's technically not correct, but some extended color spaces like extended sRGB expect it.
                 let sign = x.signum();
                 let x = x.abs();
                 let x = if x >= *d * c {
-                    (f64::powf(x, 1.0 / *gamma) - b) / a
+                    (powf(x, 1.0 / *gamma) - b) / a
                 } else {
                     x / c
                 };
@@ -210,13 +398,162 @@ impl TransferFunction {
                 f,
             }) => {
                 if x >= *d * c + f {
-                    (f64::powf(x - e, 1.0 / *gamma) - b) / a
+                    (powf(x - e, 1.0 / *gamma) - b) / a
                 } else {
                     (x - f) / c
                 }
             }
+            TransferFunction::Pq { reference_white } => {
+                let sign = x.signum();
+                let y = powf(x.abs() * reference_white / 10000.0, PQ_M1);
+                sign * powf((PQ_C1 + PQ_C2 * y) / (1.0 + PQ_C3 * y), PQ_M2)
+            }
+            TransferFunction::Hlg => {
+                let sign = x.signum();
+                let x = x.abs();
+                let y = if x <= 1.0 / 12.0 {
+                    float::sqrt(3.0 * x)
+                } else {
+                    HLG_A * float::ln(12.0 * x - HLG_B) + HLG_C
+                };
+                sign * y
+            }
+            TransferFunction::Sampled(lut) => lut.from_linear(x),
             TransferFunction::None => x,
-            _ => unimplemented!(),
         }
     }
 }
+
+impl TransferFunction {
+    /// Samples the transfer function at `size` evenly spaced values from 0.0 to 1.0,
+    /// for GPU pipelines that can't afford to evaluate `powf` for every pixel.
+    pub fn to_lut(&self, size: usize) -> TransferFunctionLut {
+        let size = size.max(2);
+        let step = 1.0 / (size - 1) as f64;
+        TransferFunctionLut {
+            decode: (0..size).map(|i| self.to_linear(i as f64 * step)).collect(),
+            encode: (0..size)
+                .map(|i| self.from_linear(i as f64 * step))
+                .collect(),
+        }
+    }
+}
+
+/// Tables sampled from a `TransferFunction` with `TransferFunction::to_lut`.
+/// Each table has at least 2 entries.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(try_from = "TransferFunctionLutTables"))]
+pub struct TransferFunctionLut {
+    decode: Vec<f64>,
+    encode: Vec<f64>,
+}
+
+/// The unvalidated form of `TransferFunctionLut` that's deserialized.
+#[cfg(feature = "serde")]
+#[derive(serde::Deserialize)]
+struct TransferFunctionLutTables {
+    decode: Vec<f64>,
+    encode: Vec<f64>,
+}
+
+#[cfg(feature = "serde")]
+impl core::convert::TryFrom<TransferFunctionLutTables> for TransferFunctionLut {
+    type Error = LutTooShortError;
+    fn try_from(tables: TransferFunctionLutTables) -> Result<Self, Self::Error> {
+        Self::new(tables.decode, tables.encode)
+    }
+}
+
+/// Returned when a table has fewer than the 2 entries needed to interpolate.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct LutTooShortError;
+
+impl core::fmt::Display for LutTooShortError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.write_str("a lookup table needs at least 2 entries")
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for LutTooShortError {}
+
+impl TransferFunctionLut {
+    /// Creates tables from samples of `to_linear` (`decode`) and `from_linear` (`encode`)
+    /// for evenly spaced values from 0.0 to 1.0.
+    pub fn new(decode: Vec<f64>, encode: Vec<f64>) -> Result<Self, LutTooShortError> {
+        if decode.len() < 2 || encode.len() < 2 {
+            return Err(LutTooShortError);
+        }
+        Ok(Self { decode, encode })
+    }
+
+    /// Creates tables from evenly spaced samples of `to_linear` for encoded values from 0.0 to 1.0,
+    /// which must be increasing. The `from_linear` table is found by inverting them,
+    /// with `encode_size` entries. Linear values above the largest sample encode to 1.0.
+    pub fn from_decode(decode: Vec<f64>, encode_size: usize) -> Result<Self, LutTooShortError> {
+        if decode.len() < 2 {
+            return Err(LutTooShortError);
+        }
+        let encode_size = encode_size.max(2);
+        let max = (decode.len() - 1) as f64;
+        let encode = (0..encode_size)
+            .map(|i| {
+                let y = i as f64 / (encode_size - 1) as f64;
+                // The first sample at or above `y`, then interpolate within the segment before it.
+                match decode.iter().position(|v| *v >= y) {
+                    Some(0) => 0.0,
+                    Some(index) => {
+                        let (y0, y1) = (decode[index - 1], decode[index]);
+                        let fraction = if y1 > y0 { (y - y0) / (y1 - y0) } else { 0.0 };
+                        (index as f64 - 1.0 + fraction) / max
+                    }
+                    None => 1.0,
+                }
+            })
+            .collect();
+        Ok(Self { decode, encode })
+    }
+
+    /// Samples of `to_linear` for evenly spaced encoded values from 0.0 to 1.0.
+    pub fn decode(&self) -> &[f64] {
+        &self.decode
+    }
+
+    /// Samples of `from_linear` for evenly spaced linear values from 0.0 to 1.0.
+    pub fn encode(&self) -> &[f64] {
+        &self.encode
+    }
+
+    /// Approximates `TransferFunction::to_linear` by interpolating the table.
+    /// Inputs outside of 0.0 to 1.0 are clamped.
+    pub fn to_linear(&self, x: f64) -> f64 {
+        interpolate_table(&self.decode, x)
+    }
+
+    /// Approximates `TransferFunction::from_linear` by interpolating the table.
+    /// Inputs outside of 0.0 to 1.0 are clamped.
+    pub fn from_linear(&self, x: f64) -> f64 {
+        interpolate_table(&self.encode, x)
+    }
+}
+
+fn interpolate_table(table: &[f64], x: f64) -> f64 {
+    let max = (table.len() - 1) as f64;
+    let position = x.clamp(0.0, 1.0) * max;
+    let index = (float::floor(position) as usize).min(table.len() - 2);
+    let fraction = position - index as f64;
+    table[index] * (1.0 - fraction) + table[index + 1] * fraction
+}
+
+// The constants of the perceptual quantizer from SMPTE ST 2084.
+const PQ_M1: f64 = 2610.0 / 16384.0;
+const PQ_M2: f64 = 2523.0 / 4096.0 * 128.0;
+const PQ_C1: f64 = 3424.0 / 4096.0;
+const PQ_C2: f64 = 2413.0 / 4096.0 * 32.0;
+const PQ_C3: f64 = 2392.0 / 4096.0 * 32.0;
+
+// The constants of the hybrid log-gamma curve from ITU-R BT.2100.
+const HLG_A: f64 = 0.17883277;
+const HLG_B: f64 = 0.28466892;
+const HLG_C: f64 = 0.55991073;
@@ -1,7 +1,29 @@
 // Select snippets from kettlemath extracted for use in this library and made to use f64.
 
-use std::ops::{Mul, Sub};
+use core::ops::{Add, Mul, Sub};
+
+/// `x` raised to the power of `y`.
+#[inline]
+pub fn powf(x: f64, y: f64) -> f64 {
+    crate::float::powf(x, y)
+}
+
+/// The cube root of `x`.
+/// With the `fast-math` feature enabled this uses an approximation instead.
+#[inline]
+pub fn cbrt(x: f64) -> f64 {
+    #[cfg(feature = "fast-math")]
+    {
+        crate::fast_math::cbrt(x)
+    }
+    #[cfg(not(feature = "fast-math"))]
+    {
+        crate::float::cbrt(x)
+    }
+}
+
 #[derive(Debug, Copy, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Vector3 {
     pub x: f64,
     pub y: f64,
@@ -46,6 +68,19 @@ impl Mul<Vector3> for Vector3 {
     }
 }
 
+impl Add for Vector3 {
+    type Output = Vector3;
+
+    #[inline]
+    fn add(self, other: Vector3) -> Vector3 {
+        Vector3 {
+            x: self.x + other.x,
+            y: self.y + other.y,
+            z: self.z + other.z,
+        }
+    }
+}
+
 impl Sub for Vector3 {
     type Output = Vector3;
 
@@ -72,6 +107,7 @@ impl Mul<f64> for Vector3 {
     }
 }
 #[derive(Debug, Copy, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Matrix3x3 {
     pub c0: Vector3,
     pub c1: Vector3,
@@ -91,6 +127,24 @@ impl Matrix3x3 {
         }
     }
 
+    /// The matrix as an array of rows, the layout most commonly used in documentation.
+    pub fn to_rows_array(&self) -> [[f64; 3]; 3] {
+        [
+            [self.c0.x, self.c1.x, self.c2.x],
+            [self.c0.y, self.c1.y, self.c2.y],
+            [self.c0.z, self.c1.z, self.c2.z],
+        ]
+    }
+
+    /// The matrix as an array of columns, the layout used by most graphics APIs.
+    pub fn to_columns_array(&self) -> [[f64; 3]; 3] {
+        [
+            [self.c0.x, self.c0.y, self.c0.z],
+            [self.c1.x, self.c1.y, self.c1.z],
+            [self.c2.x, self.c2.y, self.c2.z],
+        ]
+    }
+
     pub fn row0(&self) -> Vector3 {
         Vector3::new(self.c0.x, self.c1.x, self.c2.x)
     }
@@ -107,6 +161,16 @@ impl Matrix3x3 {
         Vector3::dot(self.c2, Vector3::cross(self.c0, self.c1))
     }
 
+    /// The inverse of the matrix, or `None` if the matrix is singular (its determinant is 0.0)
+    /// and `inverse` would return a matrix of infinities and NaNs.
+    pub fn try_inverse(&self) -> Option<Matrix3x3> {
+        let determinant = self.determinant();
+        if determinant == 0.0 || !determinant.is_finite() {
+            return None;
+        }
+        Some(self.inverse())
+    }
+
     // Unverified, code hasn't event been run.
     pub fn inverse(&self) -> Matrix3x3 {
         let inverse_determinant = 1.0 / self.determinant();
@@ -192,3 +256,52 @@ impl Mul<XYZ> for Matrix3x3 {
         }
     }
 }
+
+#[cfg(feature = "mint")]
+mod mint_conversions {
+    use super::*;
+
+    impl From<mint::Vector3<f64>> for Vector3 {
+        fn from(v: mint::Vector3<f64>) -> Self {
+            Vector3::new(v.x, v.y, v.z)
+        }
+    }
+
+    impl From<Vector3> for mint::Vector3<f64> {
+        fn from(v: Vector3) -> Self {
+            mint::Vector3 {
+                x: v.x,
+                y: v.y,
+                z: v.z,
+            }
+        }
+    }
+
+    impl From<mint::ColumnMatrix3<f64>> for Matrix3x3 {
+        fn from(m: mint::ColumnMatrix3<f64>) -> Self {
+            Matrix3x3::from_columns(m.x.into(), m.y.into(), m.z.into())
+        }
+    }
+
+    impl From<Matrix3x3> for mint::ColumnMatrix3<f64> {
+        fn from(m: Matrix3x3) -> Self {
+            mint::ColumnMatrix3 {
+                x: m.c0.into(),
+                y: m.c1.into(),
+                z: m.c2.into(),
+            }
+        }
+    }
+
+    impl From<mint::RowMatrix3<f64>> for Matrix3x3 {
+        fn from(m: mint::RowMatrix3<f64>) -> Self {
+            mint::ColumnMatrix3::from(m).into()
+        }
+    }
+
+    impl From<Matrix3x3> for mint::RowMatrix3<f64> {
+        fn from(m: Matrix3x3) -> Self {
+            mint::ColumnMatrix3::from(m).into()
+        }
+    }
+}